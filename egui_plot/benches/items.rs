@@ -0,0 +1,83 @@
+//! Benchmarks of the shapes of the items and of finding the closest point for tooltips.
+//!
+//! Run with `cargo bench -p egui_plot`, optionally followed by `-- <filter>` to only run the
+//! benchmarks whose name matches `<filter>`, e.g. `cargo bench -p egui_plot -- 100k`.
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use egui::{Context, Pos2, RawInput, Rect, Shape, Ui};
+use egui_plot::{Band, ColumnarSeries, Line, PlotBounds, PlotItem as _, PlotTransform, Scatter};
+
+const SIZES: [(&str, usize); 3] = [("1k", 1_000), ("100k", 100_000), ("10M", 10_000_000)];
+
+fn items(c: &mut Criterion) {
+    let ctx = Context::default();
+    let _output: egui::FullOutput = ctx.run(RawInput::default(), |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            for (label, n) in SIZES {
+                bench_size(c, ui, label, n);
+            }
+        });
+    });
+}
+
+fn bench_size(c: &mut Criterion, ui: &Ui, label: &str, n: usize) {
+    let xs: Vec<f64> = (0..n).map(|i| i as f64 / n as f64).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| (x * 100.0).sin()).collect();
+    let y_min: Vec<f64> = ys.iter().map(|y| y - 0.2).collect();
+    let y_max: Vec<f64> = ys.iter().map(|y| y + 0.2).collect();
+
+    let frame = Rect::from_min_size(Pos2::ZERO, egui::vec2(800.0, 400.0));
+    let bounds = PlotBounds::from_min_max([0.0, -1.2], [1.0, 1.2]);
+    let transform = PlotTransform::new(frame, bounds, false);
+    let pointer = frame.center();
+
+    let line = Line::new_xy("line", &xs, &ys);
+    let scatter = Scatter::from_series("scatter", ColumnarSeries::new(&xs, &ys));
+    let band = Band::from_columns(&xs, &y_min, &y_max);
+
+    let mut shapes: Vec<Shape> = Vec::new();
+    let mut group = c.benchmark_group("shapes");
+    group.sample_size(sample_size(n));
+    group.bench_function(BenchmarkId::new("line", label), |b| {
+        b.iter(|| {
+            shapes.clear();
+            line.shapes(ui, &transform, &mut shapes);
+            black_box(&shapes);
+        });
+    });
+    group.bench_function(BenchmarkId::new("scatter", label), |b| {
+        b.iter(|| {
+            shapes.clear();
+            scatter.shapes(ui, &transform, &mut shapes);
+            black_box(&shapes);
+        });
+    });
+    group.bench_function(BenchmarkId::new("band", label), |b| {
+        b.iter(|| {
+            shapes.clear();
+            band.shapes(ui, &transform, &mut shapes);
+            black_box(&shapes);
+        });
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("find_closest");
+    group.sample_size(sample_size(n));
+    group.bench_function(BenchmarkId::new("line", label), |b| {
+        b.iter(|| black_box(line.find_closest(pointer, &transform)));
+    });
+    group.bench_function(BenchmarkId::new("scatter", label), |b| {
+        b.iter(|| black_box(scatter.find_closest(pointer, &transform)));
+    });
+    group.finish();
+}
+
+/// Fewer samples of the largest sizes, which take long to run.
+fn sample_size(n: usize) -> usize {
+    if n >= 1_000_000 { 10 } else { 100 }
+}
+
+criterion_group!(benches, items);
+criterion_main!(benches);
@@ -1,13 +1,14 @@
-use std::{collections::VecDeque, ops::RangeInclusive};
+use std::{collections::VecDeque, ops::RangeInclusive, time::Duration};
 
-use egui::{Id, Key, Modifiers, PointerButton, Pos2, Shape, Vec2, Vec2b};
+use egui::{Color32, Id, Key, Modifiers, PointerButton, Pos2, Shape, Vec2, Vec2b};
 
-use crate::{PlotPoint, transform::PlotBounds};
+use crate::{Interval, PlotPoint, SeriesStyle, transform::PlotBounds};
 
 /// Describes what caused the plot’s bounds or transform to change during this frame.
 ///
 /// This single enum is used for all change types (like zooming or panning).
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum BoundsChangeCause {
     /// Code requested a change via input actions (`SetBounds`*/Translate/Zoom).
     Programmatic,
@@ -27,10 +28,17 @@ pub enum BoundsChangeCause {
     AutoFit,
     /// This plot synced from a linked group.
     LinkSync,
+    /// A step of an animation started with
+    /// [`PlotUi::animate_bounds_to`](crate::PlotUi::animate_bounds_to).
+    Animation,
+    /// Stepped back or forward through the views with
+    /// [`PlotUi::undo_view`](crate::PlotUi::undo_view) or [`PlotUi::redo_view`](crate::PlotUi::redo_view).
+    History,
 }
 
 /// Optional input telemetry attached to events.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct InputInfo {
     /// Latest screen pointer position when the event was generated.
     pub pointer: Option<Pos2>,
@@ -45,6 +53,7 @@ pub type PlotItemId = Id;
 
 /// Lightweight snapshot for a "pin".
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PinSnapshot {
     pub plot_x: f64,
     pub rows: Vec<PinRow>,
@@ -52,6 +61,7 @@ pub struct PinSnapshot {
 
 /// One row of a pin snapshot (series/value/color).
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PinRow {
     pub series_name: String,
     pub x: f64,
@@ -73,6 +83,12 @@ pub trait BoundsLike: Clone {
     ///
     /// Interpretation: visible extent is divided by `factor` (factor>1.0 zooms in).
     fn zoom(&mut self, factor: Vec2, center: PlotPoint);
+
+    /// The bounds as [`PlotBounds`], to report them in
+    /// [`PlotEvent::BoundsActionApplied`]. Without it, no such events are emitted.
+    fn plot_bounds(&self) -> Option<PlotBounds> {
+        None
+    }
 }
 
 /// Output events produced by the widget during the render/interaction phase.
@@ -94,6 +110,7 @@ pub trait BoundsLike: Clone {
 /// }
 /// ```
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum PlotEvent {
     /// keyboard
     KeyPressed {
@@ -122,13 +139,38 @@ pub enum PlotEvent {
         item: Option<PlotItemId>,
     },
 
+    /// The plot was captured with "Copy as image" from the context menu, see
+    /// [`crate::Plot::show_context_menu`]. The image is already on the clipboard.
+    ImageCaptured {
+        #[cfg_attr(feature = "serde", serde(skip))]
+        image: std::sync::Arc<egui::ColorImage>,
+    },
+
     // frame summaries
+    /// The bounds at the end of the frame differ from the ones at its start. Emitted once per
+    /// frame, after all the changes that led to it.
     BoundsChanged {
         old: PlotBounds,
         new: PlotBounds,
         cause: BoundsChangeCause,
     },
 
+    /// A single programmatic action changed the bounds, like
+    /// [`PlotUi::set_plot_bounds_x`](crate::PlotUi::set_plot_bounds_x). For tracing; the frame
+    /// still ends with one `BoundsChanged` covering all of them.
+    BoundsActionApplied {
+        old: PlotBounds,
+        new: PlotBounds,
+    },
+
+    /// A single programmatic action changed which axes are auto-bounded, like
+    /// [`PlotUi::set_auto_bounds`](crate::PlotUi::set_auto_bounds), or a change of the bounds
+    /// that turned them off.
+    AutoBoundsActionApplied {
+        old: Vec2b,
+        new: Vec2b,
+    },
+
     /// Transform was updated explicitly
     TransformChanged {
         old: PlotBounds,
@@ -146,6 +188,14 @@ pub enum PlotEvent {
         input: InputInfo,
     },
 
+    /// The plot was double-clicked at `pos`, over `item` if any, see
+    /// [`PlotInputConfig::double_click`](crate::PlotInputConfig::double_click).
+    DoubleClicked {
+        pos: PlotPoint,
+        item: Option<PlotItemId>,
+        input: InputInfo,
+    },
+
     //  deltas
     PanStarted {
         input: InputInfo,
@@ -208,18 +258,71 @@ pub enum PlotEvent {
         pos: PlotPoint,
     },
 
+    /// An item was clicked, at its element `index` (point, bar, …).
     ItemClicked {
         item: PlotItemId,
         pos: PlotPoint,
         button: PointerButton,
         input: InputInfo,
+        index: usize,
+
+        /// The payload of the element, see [`crate::Scatter::with_metadata`].
+        #[cfg_attr(feature = "serde", serde(skip))]
+        metadata: Option<crate::PointMetadata>,
+    },
+
+    /// A point of an editable item was dragged to `new` (see [`crate::PointEdit`]).
+    ///
+    /// Emitted every frame while dragging; apply it to your data to move the point.
+    PointEdited {
+        item: PlotItemId,
+        index: usize,
+        new: PlotPoint,
+    },
+
+    /// A [`draggable`](crate::HSpan::draggable) line or span was dragged to cover `new_interval`
+    /// on its axis. For lines, both ends of the interval are the new position.
+    ///
+    /// Emitted every frame while dragging; apply it to your data to move the item.
+    ItemDragged {
+        id: PlotItemId,
+        new_interval: Interval,
+    },
+
+    /// A flag of an [`EventFlags`](crate::EventFlags) item was clicked.
+    FlagClicked {
+        item: PlotItemId,
+        flag: Id,
+    },
+
+    /// The t₀ marker of a plot with a [`time origin`](crate::Plot::time_origin) was dragged, or
+    /// moved with [`PlotUi::set_time_origin`](crate::PlotUi::set_time_origin).
+    TimeOriginMoved {
+        t0: f64,
+    },
+
+    /// The selected items changed, see [`crate::Plot::select_items`]. `selected` is the new
+    /// selection, in the order the items were selected in.
+    SelectionChanged {
+        selected: Vec<PlotItemId>,
     },
 
+    /// An item was shown or hidden, either by clicking it in the legend or programmatically
+    /// with [`PlotUi::set_item_visible`](crate::PlotUi::set_item_visible).
     LegendItemToggled {
         item: PlotItemId,
         now_visible: bool,
     },
 
+    /// The style of an item was edited from the legend, see
+    /// [`Legend::style_editing`](crate::Legend::style_editing).
+    ///
+    /// The plot keeps applying the style while its memory lives; store it to restore it later.
+    SeriesStyleChanged {
+        item: PlotItemId,
+        style: SeriesStyle,
+    },
+
     // Pins
     PinAdded {
         snapshot: PinSnapshot,
@@ -230,6 +333,251 @@ pub enum PlotEvent {
     PinsCleared,
 }
 
+impl PlotEvent {
+    /// The kind of this event, as filtered by [`crate::Plot::emit_events`].
+    pub fn mask(&self) -> EventMask {
+        match self {
+            Self::KeyPressed { .. } | Self::KeyReleased { .. } => EventMask::KEYS,
+            Self::Hover { .. } | Self::CursorMoved { .. } | Self::ItemHovered { .. } => {
+                EventMask::HOVER
+            }
+            Self::Activate { .. }
+            | Self::ItemClicked { .. }
+            | Self::FlagClicked { .. }
+            | Self::DoubleClicked { .. }
+            | Self::SelectionChanged { .. } => EventMask::CLICKS,
+            Self::ContextMenuRequested { .. } | Self::ImageCaptured { .. } => EventMask::MENU,
+            Self::BoundsChanged { .. }
+            | Self::BoundsActionApplied { .. }
+            | Self::AutoBoundsActionApplied { .. }
+            | Self::TransformChanged { .. }
+            | Self::AutoFitApplied { .. }
+            | Self::ResetApplied { .. } => EventMask::BOUNDS,
+            Self::PanStarted { .. }
+            | Self::PanDelta { .. }
+            | Self::PanFinished { .. }
+            | Self::ZoomStarted { .. }
+            | Self::ZoomDelta { .. }
+            | Self::ZoomFinished { .. }
+            | Self::AxisZoomDragStarted { .. }
+            | Self::AxisZoomDragDelta { .. }
+            | Self::AxisZoomDragFinished { .. }
+            | Self::BoxZoomStarted { .. }
+            | Self::BoxZoomFinished { .. } => EventMask::NAVIGATION,
+            Self::PointEdited { .. } | Self::ItemDragged { .. } | Self::TimeOriginMoved { .. } => {
+                EventMask::EDITS
+            }
+            Self::LegendItemToggled { .. } | Self::SeriesStyleChanged { .. } => EventMask::LEGEND,
+            Self::PinAdded { .. } | Self::PinRemoved { .. } | Self::PinsCleared => EventMask::PINS,
+        }
+    }
+}
+
+/// Kinds of [`PlotEvent`]s, combined with `|`, see [`crate::Plot::emit_events`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventMask(u16);
+
+impl EventMask {
+    pub const NONE: Self = Self(0);
+
+    /// [`PlotEvent::KeyPressed`] and [`PlotEvent::KeyReleased`].
+    pub const KEYS: Self = Self(1 << 0);
+
+    /// [`PlotEvent::Hover`], [`PlotEvent::CursorMoved`] and [`PlotEvent::ItemHovered`], emitted
+    /// every frame the pointer is over the plot.
+    pub const HOVER: Self = Self(1 << 1);
+
+    /// [`PlotEvent::Activate`], [`PlotEvent::ItemClicked`], [`PlotEvent::FlagClicked`],
+    /// [`PlotEvent::DoubleClicked`] and [`PlotEvent::SelectionChanged`].
+    pub const CLICKS: Self = Self(1 << 2);
+
+    /// [`PlotEvent::ContextMenuRequested`] and [`PlotEvent::ImageCaptured`].
+    pub const MENU: Self = Self(1 << 3);
+
+    /// [`PlotEvent::BoundsChanged`] and the other changes of the bounds as a whole, like
+    /// [`PlotEvent::AutoFitApplied`].
+    pub const BOUNDS: Self = Self(1 << 4);
+
+    /// The steps of panning and zooming, like [`PlotEvent::PanDelta`] and
+    /// [`PlotEvent::ZoomDelta`].
+    pub const NAVIGATION: Self = Self(1 << 5);
+
+    /// [`PlotEvent::PointEdited`], [`PlotEvent::ItemDragged`] and
+    /// [`PlotEvent::TimeOriginMoved`].
+    pub const EDITS: Self = Self(1 << 6);
+
+    /// [`PlotEvent::LegendItemToggled`] and [`PlotEvent::SeriesStyleChanged`].
+    pub const LEGEND: Self = Self(1 << 7);
+
+    /// [`PlotEvent::PinAdded`], [`PlotEvent::PinRemoved`] and [`PlotEvent::PinsCleared`].
+    pub const PINS: Self = Self(1 << 8);
+
+    pub const ALL: Self = Self((1 << 9) - 1);
+
+    /// Whether all the kinds of `other` are in `self`.
+    #[inline]
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+}
+
+impl Default for EventMask {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl std::ops::BitOr for EventMask {
+    type Output = Self;
+
+    #[inline]
+    fn bitor(self, rhs: Self) -> Self {
+        Self(self.0 | rhs.0)
+    }
+}
+
+impl std::ops::BitOrAssign for EventMask {
+    #[inline]
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// Merge the [`PlotEvent::PanDelta`]s of a frame into the first of them, and likewise for
+/// [`PlotEvent::ZoomDelta`] and [`PlotEvent::AxisZoomDragDelta`], see
+/// [`crate::Plot::coalesce_events`].
+///
+/// The merged event has the total change and the input of the last one.
+pub(crate) fn coalesce_deltas(events: &mut Vec<PlotEvent>) {
+    let [mut pan, mut zoom, mut axis_zoom] = [None::<usize>; 3];
+    let mut merged: Vec<PlotEvent> = Vec::with_capacity(events.len());
+    for event in events.drain(..) {
+        match (event, pan, zoom, axis_zoom) {
+            (
+                PlotEvent::PanDelta {
+                    delta_plot_x,
+                    delta_plot_y,
+                    input,
+                },
+                Some(i),
+                _,
+                _,
+            ) => {
+                if let PlotEvent::PanDelta {
+                    delta_plot_x: x,
+                    delta_plot_y: y,
+                    input: last_input,
+                } = &mut merged[i]
+                {
+                    *x += delta_plot_x;
+                    *y += delta_plot_y;
+                    *last_input = input;
+                }
+            }
+            (
+                PlotEvent::ZoomDelta {
+                    factor_x,
+                    factor_y,
+                    center_plot_x,
+                    center_plot_y,
+                    input,
+                },
+                _,
+                Some(i),
+                _,
+            ) => {
+                if let PlotEvent::ZoomDelta {
+                    factor_x: x,
+                    factor_y: y,
+                    center_plot_x: center_x,
+                    center_plot_y: center_y,
+                    input: last_input,
+                } = &mut merged[i]
+                {
+                    *x *= factor_x;
+                    *y *= factor_y;
+                    (*center_x, *center_y) = (center_plot_x, center_plot_y);
+                    *last_input = input;
+                }
+            }
+            (
+                PlotEvent::AxisZoomDragDelta {
+                    factor_x,
+                    factor_y,
+                    input,
+                },
+                _,
+                _,
+                Some(i),
+            ) => {
+                if let PlotEvent::AxisZoomDragDelta {
+                    factor_x: x,
+                    factor_y: y,
+                    input: last_input,
+                } = &mut merged[i]
+                {
+                    *x *= factor_x;
+                    *y *= factor_y;
+                    *last_input = input;
+                }
+            }
+            (event, ..) => {
+                let index = Some(merged.len());
+                match event {
+                    PlotEvent::PanDelta { .. } => pan = index,
+                    PlotEvent::ZoomDelta { .. } => zoom = index,
+                    PlotEvent::AxisZoomDragDelta { .. } => axis_zoom = index,
+                    _ => {}
+                }
+                merged.push(event);
+            }
+        }
+    }
+    *events = merged;
+}
+
+#[test]
+fn test_coalesce_deltas() {
+    let pan = |dx| PlotEvent::PanDelta {
+        delta_plot_x: dx,
+        delta_plot_y: 1.0,
+        input: InputInfo::default(),
+    };
+    let zoom = |factor| PlotEvent::ZoomDelta {
+        factor_x: factor,
+        factor_y: 1.0,
+        center_plot_x: factor as f64,
+        center_plot_y: 0.0,
+        input: InputInfo::default(),
+    };
+    let mut events = vec![
+        pan(1.0),
+        zoom(2.0),
+        PlotEvent::PinsCleared,
+        pan(2.0),
+        zoom(3.0),
+    ];
+    coalesce_deltas(&mut events);
+    assert_eq!(events.len(), 3);
+    assert!(matches!(
+        events[0],
+        PlotEvent::PanDelta {
+            delta_plot_x: 3.0,
+            delta_plot_y: 2.0,
+            ..
+        }
+    ));
+    assert!(matches!(
+        events[1],
+        PlotEvent::ZoomDelta {
+            factor_x: 6.0,
+            center_plot_x: 3.0,
+            ..
+        }
+    ));
+    assert!(matches!(events[2], PlotEvent::PinsCleared));
+}
+
 /// Input actions recorded during the build phase (`PlotUi`).
 ///
 /// `I` is your item type (e.g., `Box<dyn PlotItem>`).
@@ -253,6 +601,41 @@ pub enum PlotAction<I> {
     /// Zoom by a per-axis factor around a plot-space `center`. Disables auto-bounds.
     Zoom(Vec2, PlotPoint),
 
+    /// Move smoothly to the given bounds over the given duration. Disables auto-bounds.
+    AnimateBounds(PlotBounds, Duration),
+
+    // ------------------------ Visibility --------------------------
+    /// Show (`true`) or hide (`false`) the item with the given id, as if toggled in the legend.
+    SetItemVisible(Id, bool),
+
+    // ------------------------ Selection --------------------------
+    /// Select exactly these items, see [`crate::Plot::select_items`].
+    SetSelection(Vec<Id>),
+
+    /// Draw the item above the others of its [`crate::PaintStage`].
+    BringToFront(Id),
+
+    /// Change the color of the item, as if edited in the legend.
+    SetItemColor(Id, Color32),
+
+    /// Move the t₀ marker of the relative time axis to the given x value.
+    SetTimeOrigin(f64),
+
+    /// Keep the items of this frame as the reference (`true`), or clear it (`false`), see
+    /// [`PlotUi::set_reference_from_current`](crate::PlotUi::set_reference_from_current).
+    SetReference(bool),
+
+    /// Go back to the view before the last change, see
+    /// [`PlotUi::undo_view`](crate::PlotUi::undo_view).
+    UndoView,
+
+    /// Go forward to the view undone last, see [`PlotUi::redo_view`](crate::PlotUi::redo_view).
+    RedoView,
+
+    /// Fit the bounds to the items with these ids, see
+    /// [`PlotUi::fit_to_items`](crate::PlotUi::fit_to_items).
+    FitToItems(Vec<Id>),
+
     // ------------------------ Decorations / overlays --------------------------
     /// Add an overlay `Shape` to be painted after items.
     AddOverlayShape(Shape),
@@ -367,6 +750,56 @@ impl<I> ActionQueue<I> {
     pub fn zoom(&mut self, zoom_factor: egui::Vec2, center: PlotPoint) {
         self.push(PlotAction::Zoom(zoom_factor, center));
     }
+
+    #[inline]
+    pub fn set_selection(&mut self, ids: Vec<Id>) {
+        self.push(PlotAction::SetSelection(ids));
+    }
+
+    #[inline]
+    pub fn bring_to_front(&mut self, id: Id) {
+        self.push(PlotAction::BringToFront(id));
+    }
+
+    #[inline]
+    pub fn set_item_color(&mut self, id: Id, color: Color32) {
+        self.push(PlotAction::SetItemColor(id, color));
+    }
+
+    #[inline]
+    pub fn set_item_visible(&mut self, id: Id, visible: bool) {
+        self.push(PlotAction::SetItemVisible(id, visible));
+    }
+
+    #[inline]
+    pub fn set_time_origin(&mut self, t0: f64) {
+        self.push(PlotAction::SetTimeOrigin(t0));
+    }
+
+    #[inline]
+    pub fn set_reference(&mut self, keep: bool) {
+        self.push(PlotAction::SetReference(keep));
+    }
+
+    #[inline]
+    pub fn undo_view(&mut self) {
+        self.push(PlotAction::UndoView);
+    }
+
+    #[inline]
+    pub fn redo_view(&mut self) {
+        self.push(PlotAction::RedoView);
+    }
+
+    #[inline]
+    pub fn fit_to_items(&mut self, ids: Vec<Id>) {
+        self.push(PlotAction::FitToItems(ids));
+    }
+
+    #[inline]
+    pub fn animate_bounds(&mut self, bounds: PlotBounds, duration: Duration) {
+        self.push(PlotAction::AnimateBounds(bounds, duration));
+    }
 }
 
 /// Result of applying a queue of actions in a given state.
@@ -375,6 +808,11 @@ impl<I> ActionQueue<I> {
 /// - `auto_bounds`: final auto-bounds flags
 /// - `bounds`: final mutated bounds
 /// - `overlays`: overlay shapes to paint last
+/// - `item_visibility`: requested visibility changes, in order
+/// - `time_origin`: the last requested t₀, if any
+/// - `bounds_animation`: the last requested animation of the bounds, if any
+/// - `view_steps`: how many views to go back (or forward, if negative) in the history
+/// - `fit_items`: the items to fit the bounds to last, if any
 /// - `events`: empty Vec; fill during interaction rendering
 #[derive(Debug)]
 pub struct AppliedActions<I, B> {
@@ -382,6 +820,15 @@ pub struct AppliedActions<I, B> {
     pub auto_bounds: Vec2b,
     pub bounds: B,
     pub overlays: Vec<Shape>,
+    pub item_visibility: Vec<(Id, bool)>,
+    pub time_origin: Option<f64>,
+    pub bounds_animation: Option<(PlotBounds, Duration)>,
+    pub reference: Option<bool>,
+    pub view_steps: isize,
+    pub fit_items: Option<Vec<Id>>,
+    pub selection: Option<Vec<Id>>,
+    pub raised: Vec<Id>,
+    pub item_colors: Vec<(Id, Color32)>,
     pub events: Vec<PlotEvent>,
 }
 
@@ -400,7 +847,9 @@ impl<I, B> AppliedActions<I, B> {
 ///
 /// Order inside a single frame:
 /// 1) Bounds-affecting actions: `SetBounds*`, `Translate`, `SetAutoBounds`, `Zoom`
-/// 2) Data actions: `AddItem`
+/// 2) Data actions: `AddItem`, `SetItemVisible`, `SetTimeOrigin`, `SetReference`,
+///    `AnimateBounds`, `UndoView`, `RedoView`, `FitToItems` (applied later by the plot, from
+///    its current bounds)
 /// 3) Decorations: `AddOverlayShape`
 ///
 /// Auto-fitting to content is **not** performed here.
@@ -428,4 +877,9 @@ impl BoundsLike for PlotBounds {
     fn zoom(&mut self, factor: Vec2, center: PlotPoint) {
         Self::zoom(self, factor, center);
     }
+
+    #[inline]
+    fn plot_bounds(&self) -> Option<PlotBounds> {
+        Some(*self)
+    }
 }
@@ -6,7 +6,7 @@ use egui::{
     epaint::TextShape,
 };
 
-use super::{GridMark, transform::PlotTransform};
+use super::{GridMark, TimeHeader, transform::PlotTransform};
 
 // Gap between tick labels and axis label in units of the axis label height
 const AXIS_LABEL_GAP: f32 = 0.25;
@@ -47,6 +47,40 @@ pub enum HPlacement {
     Right,
 }
 
+/// Position of a plot in a vertical stack of plots sharing their x-axis, see
+/// [`crate::Plot::stack_position`].
+///
+/// Only the outer edges of the stack get the labels of the main x-axis, so the plots can sit
+/// right on top of each other.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StackPosition {
+    /// The main x-axis is mirrored to the top edge.
+    Top,
+
+    /// The main x-axis is hidden.
+    Middle,
+
+    /// The main x-axis is at the bottom edge.
+    Bottom,
+}
+
+impl StackPosition {
+    /// Position of plot number `index` in a stack of `count` plots, counted from the top.
+    ///
+    /// `None` for a single plot, which keeps its axes as configured.
+    pub fn of(index: usize, count: usize) -> Option<Self> {
+        if count <= 1 {
+            None
+        } else if index == 0 {
+            Some(Self::Top)
+        } else if index + 1 >= count {
+            Some(Self::Bottom)
+        } else {
+            Some(Self::Middle)
+        }
+    }
+}
+
 /// Placement of an axis.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Placement {
@@ -97,6 +131,25 @@ impl From<Placement> for VPlacement {
     }
 }
 
+/// What to do with the tick labels of an X axis that would overlap, see
+/// [`AxisHints::tick_label_overlap`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TickLabelOverlap {
+    /// Leave out all labels of a step size that are wider than the space between their ticks.
+    #[default]
+    Hide,
+
+    /// Leave out just enough labels so the others don't overlap, keeping those of the coarser
+    /// steps.
+    Thin,
+
+    /// Rotate the labels by 45° when they would overlap, and thin out any that still do.
+    Rotate45,
+
+    /// Rotate the labels by 90° when they would overlap, and thin out any that still do.
+    Rotate90,
+}
+
 /// Axis configuration.
 ///
 /// Used to configure axis label and ticks.
@@ -107,6 +160,10 @@ pub struct AxisHints<'a> {
     pub(super) min_thickness: f32,
     pub(super) placement: Placement,
     pub(super) label_spacing: Rangef,
+    pub(super) label_text_style: TextStyle,
+    pub(super) vertical_label: bool,
+    pub(super) time_header: Option<TimeHeader>,
+    pub(super) tick_label_overlap: TickLabelOverlap,
 }
 
 impl<'a> AxisHints<'a> {
@@ -134,6 +191,10 @@ impl<'a> AxisHints<'a> {
                 Axis::X => Rangef::new(60.0, 80.0), // labels can get pretty wide
                 Axis::Y => Rangef::new(20.0, 30.0), // text isn't very high
             },
+            label_text_style: TextStyle::Body,
+            vertical_label: true,
+            time_header: None,
+            tick_label_overlap: TickLabelOverlap::Hide,
         }
     }
 
@@ -165,6 +226,44 @@ impl<'a> AxisHints<'a> {
         self
     }
 
+    /// Text style used for the axis label, unless the label is a [`egui::RichText`]
+    /// with its own font. Default: `TextStyle::Body`.
+    #[inline]
+    pub fn label_text_style(mut self, style: TextStyle) -> Self {
+        self.label_text_style = style;
+        self
+    }
+
+    /// Whether the label of a Y-axis is rotated to run along the axis. Default: `true`.
+    ///
+    /// If `false`, the label is drawn horizontally, which makes the axis wider.
+    /// Has no effect on X-axes.
+    #[inline]
+    pub fn vertical_label(mut self, vertical: bool) -> Self {
+        self.vertical_label = vertical;
+        self
+    }
+
+    /// Replace the tick labels by a two-row calendar header, e.g. days above hours.
+    ///
+    /// The axis values are interpreted as seconds since the Unix epoch.
+    /// Has no effect on Y-axes.
+    #[inline]
+    pub fn time_header(mut self, header: TimeHeader) -> Self {
+        self.time_header = Some(header);
+        self
+    }
+
+    /// What to do with tick labels that would overlap, e.g. long timestamps when zoomed out.
+    ///
+    /// The axis gets thicker to fit rotated labels. Has no effect on Y-axes.
+    /// Default: [`TickLabelOverlap::Hide`].
+    #[inline]
+    pub fn tick_label_overlap(mut self, overlap: TickLabelOverlap) -> Self {
+        self.tick_label_overlap = overlap;
+        self
+    }
+
     /// Specify minimum thickness of the axis
     #[inline]
     pub fn min_thickness(mut self, min_thickness: f32) -> Self {
@@ -225,6 +324,34 @@ impl<'a> AxisWidget<'a> {
         }
     }
 
+    /// The part of [`Self::rect`] where dragging zooms the axis: the strip of the given
+    /// `thickness` next to the plot frame, or the whole rect.
+    pub fn zoom_zone(&self, axis: Axis, thickness: Option<f32>) -> Rect {
+        let Some(thickness) = thickness else {
+            return self.rect;
+        };
+        let rect = self.rect;
+        match (axis, self.hints.placement) {
+            // Bottom axis, the frame is above
+            (Axis::X, Placement::LeftBottom) => {
+                Rect::from_min_max(rect.min, Pos2::new(rect.max.x, rect.min.y + thickness))
+            }
+            // Top axis, the frame is below
+            (Axis::X, Placement::RightTop) => {
+                Rect::from_min_max(Pos2::new(rect.min.x, rect.max.y - thickness), rect.max)
+            }
+            // Left axis, the frame is to the right
+            (Axis::Y, Placement::LeftBottom) => {
+                Rect::from_min_max(Pos2::new(rect.max.x - thickness, rect.min.y), rect.max)
+            }
+            // Right axis, the frame is to the left
+            (Axis::Y, Placement::RightTop) => {
+                Rect::from_min_max(rect.min, Pos2::new(rect.min.x + thickness, rect.max.y))
+            }
+        }
+        .intersect(rect)
+    }
+
     /// Returns the actual thickness of the axis.
     pub fn ui(self, ui: &mut Ui, axis: Axis) -> (Response, f32) {
         let response = ui.allocate_rect(self.rect, Sense::hover());
@@ -236,7 +363,15 @@ impl<'a> AxisWidget<'a> {
         let Some(transform) = self.transform else {
             return (response, 0.0);
         };
-        let tick_labels_thickness = self.add_tick_labels(ui, transform, axis);
+        let tick_labels_thickness = match (&self.hints.time_header, axis) {
+            (Some(header), Axis::X) => header.ui(
+                ui,
+                self.rect,
+                &transform,
+                VPlacement::from(self.hints.placement),
+            ),
+            _ => self.add_tick_labels(ui, transform, axis),
+        };
 
         if self.hints.label.is_empty() {
             return (response, tick_labels_thickness);
@@ -246,9 +381,26 @@ impl<'a> AxisWidget<'a> {
             ui,
             Some(TextWrapMode::Extend),
             f32::INFINITY,
-            TextStyle::Body,
+            self.hints.label_text_style.clone(),
         );
 
+        if axis == Axis::Y && !self.hints.vertical_label {
+            let pos = match HPlacement::from(self.hints.placement) {
+                HPlacement::Left => Pos2::new(
+                    response.rect.left(),
+                    response.rect.center().y - galley.size().y * 0.5,
+                ),
+                HPlacement::Right => Pos2::new(
+                    response.rect.right() - galley.size().x,
+                    response.rect.center().y - galley.size().y * 0.5,
+                ),
+            };
+            let axis_label_thickness = galley.size().x + galley.size().y * AXIS_LABEL_GAP;
+            ui.painter()
+                .add(TextShape::new(pos, galley, ui.visuals().text_color()));
+            return (response, tick_labels_thickness + axis_label_thickness);
+        }
+
         let text_pos = match self.hints.placement {
             Placement::LeftBottom => match axis {
                 Axis::X => {
@@ -297,6 +449,9 @@ impl<'a> AxisWidget<'a> {
 
     /// Add tick labels to the axis. Returns the thickness of the axis.
     fn add_tick_labels(&self, ui: &Ui, transform: PlotTransform, axis: Axis) -> f32 {
+        if axis == Axis::X && self.hints.tick_label_overlap != TickLabelOverlap::Hide {
+            return self.add_fitted_x_tick_labels(ui, transform);
+        }
         let font_id = TextStyle::Body.resolve(ui.style());
         let label_spacing = self.hints.label_spacing;
         let mut thickness: f32 = 0.0;
@@ -306,7 +461,8 @@ impl<'a> AxisWidget<'a> {
 
         // Add tick labels:
         for step in self.steps.iter() {
-            let text = (self.hints.formatter)(*step, &self.range);
+            let mark = transform.mark_value(usize::from(axis), *step);
+            let text = (self.hints.formatter)(mark, &self.range);
             if !text.is_empty() {
                 let spacing_in_points =
                     (transform.dpos_dvalue()[usize::from(axis)] * step.step_size).abs() as f32;
@@ -334,8 +490,7 @@ impl<'a> AxisWidget<'a> {
                     Axis::X => {
                         thickness = thickness.max(galley_size.y);
 
-                        let projected_point = super::PlotPoint::new(step.value, 0.0);
-                        let center_x = transform.position_from_point(&projected_point).x;
+                        let center_x = transform.position_from_display(0, step.value);
                         let y = match VPlacement::from(self.hints.placement) {
                             VPlacement::Bottom => self.rect.min.y,
                             VPlacement::Top => self.rect.max.y - galley_size.y,
@@ -346,8 +501,7 @@ impl<'a> AxisWidget<'a> {
                     Axis::Y => {
                         thickness = thickness.max(galley_size.x);
 
-                        let projected_point = super::PlotPoint::new(0.0, step.value);
-                        let center_y = transform.position_from_point(&projected_point).y;
+                        let center_y = transform.position_from_display(1, step.value);
 
                         match HPlacement::from(self.hints.placement) {
                             HPlacement::Left => {
@@ -381,4 +535,194 @@ impl<'a> AxisWidget<'a> {
         }
         thickness
     }
+
+    /// Add tick labels to the X axis, thinned out or rotated so they don't overlap, see
+    /// [`TickLabelOverlap`]. Returns the thickness of the axis.
+    fn add_fitted_x_tick_labels(&self, ui: &Ui, transform: PlotTransform) -> f32 {
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let label_spacing = self.hints.label_spacing;
+        let painter = ui.painter();
+
+        let mut slots = Vec::new();
+        let mut galleys = Vec::new();
+        for step in self.steps.iter() {
+            let text = (self.hints.formatter)(transform.mark_value(0, *step), &self.range);
+            let spacing_in_points = (transform.dpos_dvalue()[0] * step.step_size).abs() as f32;
+            if text.is_empty() || spacing_in_points <= label_spacing.min {
+                continue;
+            }
+            let strength = remap_clamp(spacing_in_points, label_spacing, 0.0..=1.0);
+            let text_color = super::color_from_strength(ui, strength);
+            let galley = painter.layout_no_wrap(text, font_id.clone(), text_color);
+            slots.push(TickLabelSlot {
+                step_size: step.step_size,
+                spacing: spacing_in_points,
+                center_x: transform.position_from_display(0, step.value),
+                size: galley.size(),
+            });
+            galleys.push((galley, text_color));
+        }
+
+        let fit = fit_x_tick_labels(self.hints.tick_label_overlap, &slots);
+        let angle = fit.angle;
+        let cos = angle.cos().abs();
+        for i in fit.shown {
+            let TickLabelSlot { center_x, size, .. } = slots[i];
+            let (galley, text_color) = galleys[i].clone();
+            let pos = if angle == 0.0 {
+                let y = match VPlacement::from(self.hints.placement) {
+                    VPlacement::Bottom => self.rect.min.y,
+                    VPlacement::Top => self.rect.max.y - size.y,
+                };
+                Pos2::new(center_x - size.x / 2.0, y)
+            } else {
+                let rot = Rot2::from_angle(angle);
+                let lift = size.y / 2.0 * cos;
+                match VPlacement::from(self.hints.placement) {
+                    // The label ends at its tick, reading up towards it:
+                    VPlacement::Bottom => {
+                        Pos2::new(center_x, self.rect.min.y + lift)
+                            - rot * Vec2::new(size.x, size.y / 2.0)
+                    }
+                    // The label starts at its tick, reading up from it:
+                    VPlacement::Top => {
+                        Pos2::new(center_x, self.rect.max.y - lift)
+                            - rot * Vec2::new(0.0, size.y / 2.0)
+                    }
+                }
+            };
+            painter.add(TextShape::new(pos, galley, text_color).with_angle(angle));
+        }
+        fit.thickness
+    }
+}
+
+/// A tick label on the X axis, as seen by [`fit_x_tick_labels`].
+#[derive(Clone, Copy, Debug)]
+struct TickLabelSlot {
+    /// The step size of the tick, coarser ticks win over finer ones.
+    step_size: f64,
+
+    /// Screen distance to the neighbouring ticks of the same step size.
+    spacing: f32,
+
+    /// Screen position of the tick.
+    center_x: f32,
+
+    /// Size of the unrotated label.
+    size: Vec2,
+}
+
+/// Which X axis tick labels to show, and how, see [`fit_x_tick_labels`].
+#[derive(Clone, Debug, PartialEq)]
+struct FittedTickLabels {
+    /// Angle of all labels, 0 for horizontal.
+    angle: f32,
+
+    /// Indices of the labels to show, coarser steps first.
+    shown: Vec<usize>,
+
+    /// How far the labels reach into the axis, perpendicular to it.
+    thickness: f32,
+}
+
+/// Rotate the labels if any of them doesn't fit between its neighbours and `overlap` asks for it,
+/// then leave out those still overlapping a label of a coarser step.
+fn fit_x_tick_labels(overlap: TickLabelOverlap, labels: &[TickLabelSlot]) -> FittedTickLabels {
+    const LABEL_GAP: f32 = 4.0; // Minimum space between two labels.
+
+    let crowded = labels
+        .iter()
+        .any(|label| label.size.x + LABEL_GAP > label.spacing);
+    let angle = match overlap {
+        TickLabelOverlap::Rotate45 if crowded => -std::f32::consts::FRAC_PI_4,
+        TickLabelOverlap::Rotate90 if crowded => -std::f32::consts::FRAC_PI_2,
+        _ => 0.0,
+    };
+    let (sin, cos) = (angle.sin().abs(), angle.cos().abs());
+
+    // Place the labels of the coarser steps first, and leave out those overlapping them:
+    let mut order: Vec<usize> = (0..labels.len()).collect();
+    order.sort_by(|&a, &b| labels[b].step_size.total_cmp(&labels[a].step_size));
+    let mut placed: Vec<Rangef> = Vec::new();
+    let mut shown = Vec::new();
+    let mut thickness: f32 = 0.0;
+    for i in order {
+        let TickLabelSlot { center_x, size, .. } = labels[i];
+        // How much of the axis the label takes up; rotated labels are parallel lines:
+        let width = if angle == 0.0 { size.x } else { size.y / sin };
+        let span = Rangef::new(center_x - width / 2.0, center_x + width / 2.0 + LABEL_GAP);
+        if placed.iter().any(|other| other.intersects(span)) {
+            continue;
+        }
+        placed.push(span);
+        shown.push(i);
+        thickness = thickness.max(size.x * sin + size.y * cos);
+    }
+    FittedTickLabels {
+        angle,
+        shown,
+        thickness,
+    }
+}
+
+#[test]
+fn test_stack_position_of() {
+    assert_eq!(StackPosition::of(0, 0), None);
+    assert_eq!(StackPosition::of(0, 1), None);
+    assert_eq!(StackPosition::of(0, 2), Some(StackPosition::Top));
+    assert_eq!(StackPosition::of(1, 2), Some(StackPosition::Bottom));
+    assert_eq!(StackPosition::of(0, 4), Some(StackPosition::Top));
+    assert_eq!(StackPosition::of(1, 4), Some(StackPosition::Middle));
+    assert_eq!(StackPosition::of(2, 4), Some(StackPosition::Middle));
+    assert_eq!(StackPosition::of(3, 4), Some(StackPosition::Bottom));
+}
+
+#[cfg(test)]
+fn tick_label_slots() -> Vec<TickLabelSlot> {
+    // Alternating coarse and fine ticks, each label too wide for the fine spacing:
+    [0.0, 40.0, 80.0, 120.0, 160.0]
+        .into_iter()
+        .enumerate()
+        .map(|(i, center_x)| {
+            let (step_size, spacing) = if i % 2 == 0 { (2.0, 80.0) } else { (1.0, 40.0) };
+            TickLabelSlot {
+                step_size,
+                spacing,
+                center_x,
+                size: Vec2::new(50.0, 14.0),
+            }
+        })
+        .collect()
+}
+
+#[test]
+fn test_fit_x_tick_labels_thin() {
+    let fit = fit_x_tick_labels(TickLabelOverlap::Thin, &tick_label_slots());
+    assert_eq!(fit.angle, 0.0);
+    assert_eq!(fit.shown, vec![0, 2, 4]);
+    assert_eq!(fit.thickness, 14.0);
+}
+
+#[test]
+fn test_fit_x_tick_labels_rotate() {
+    let slots = tick_label_slots();
+
+    // Rotated by 45°, each label takes up 14 / sin(45°) ≈ 19.8 points of the axis:
+    let fit = fit_x_tick_labels(TickLabelOverlap::Rotate45, &slots);
+    assert_eq!(fit.angle, -std::f32::consts::FRAC_PI_4);
+    assert_eq!(fit.shown, vec![0, 2, 4, 1, 3]);
+    assert!((fit.thickness - 64.0 * std::f32::consts::FRAC_1_SQRT_2).abs() < 1e-3);
+
+    // Upright labels take up their height, and reach as far as they are wide:
+    let fit = fit_x_tick_labels(TickLabelOverlap::Rotate90, &slots);
+    assert_eq!(fit.angle, -std::f32::consts::FRAC_PI_2);
+    assert_eq!(fit.shown, vec![0, 2, 4, 1, 3]);
+    assert!((fit.thickness - 50.0).abs() < 1e-3);
+
+    // Labels that fit stay horizontal:
+    let coarse: Vec<_> = slots.into_iter().step_by(2).collect();
+    let fit = fit_x_tick_labels(TickLabelOverlap::Rotate45, &coarse);
+    assert_eq!(fit.angle, 0.0);
+    assert_eq!(fit.shown, vec![0, 1, 2]);
 }
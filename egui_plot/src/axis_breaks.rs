@@ -0,0 +1,206 @@
+//! Gaps cut out of an axis, to show data clustered at very different values side by side.
+
+use egui::{Rect, Shape, Stroke, Ui, pos2, vec2};
+
+use crate::{Interval, IntervalSet, PlotTransform};
+
+/// Half the length of the slanted break marks, in points.
+const MARK_SIZE: f32 = 4.0;
+
+/// Space between the two slanted lines of a break mark, in points.
+const MARK_GAP: f32 = 3.0;
+
+/// The most gaps an axis can have, so that [`AxisBreaks`] stays `Copy` like [`PlotTransform`].
+const MAX_GAPS: usize = 4;
+
+/// Gaps removed from an axis, e.g. to skip `x ∈ [100, 10 000]` between two clusters of data.
+///
+/// Set them with [`crate::Plot::x_axis_breaks`] and plot the values as they are: the plot
+/// transform collapses each gap, so that the segments around it touch on the screen, and marks
+/// the breaks on the plot frame. [`Self::to_display`] and [`Self::to_value`] are the mapping
+/// it uses, between values and the *display* coordinates the plot is laid out in.
+///
+/// ```
+/// # use egui_plot::AxisBreaks;
+/// let breaks = AxisBreaks::new().gap(100.0, 10_000.0);
+/// assert_eq!(breaks.to_display(50.0), 50.0);
+/// assert_eq!(breaks.to_display(10_050.0), 150.0);
+/// assert_eq!(breaks.to_value(150.0), 10_050.0);
+/// ```
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{AxisBreaks, Line, Plot};
+///
+/// let points = vec![[10.0, 1.0], [90.0, 2.0], [10_010.0, 3.0]];
+/// Plot::new("axis_breaks")
+///     .x_axis_breaks(AxisBreaks::new().gap(100.0, 10_000.0))
+///     .show(ui, |plot_ui| plot_ui.line(Line::new("data", points)));
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct AxisBreaks {
+    /// Sorted and disjoint, of which the first `len` are used.
+    gaps: [Interval; MAX_GAPS],
+    len: usize,
+}
+
+impl Default for AxisBreaks {
+    fn default() -> Self {
+        Self {
+            gaps: [Interval::new(0.0, 0.0); MAX_GAPS],
+            len: 0,
+        }
+    }
+}
+
+impl AxisBreaks {
+    /// An axis without gaps.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Cut the values from `start` to `end` out of the axis. Overlapping gaps are merged.
+    ///
+    /// An axis has at most four gaps: a gap that would make it more is ignored.
+    #[inline]
+    pub fn gap(mut self, start: f64, end: f64) -> Self {
+        if start.is_finite() && end.is_finite() {
+            let gaps = self.gaps().iter().copied();
+            let merged = IntervalSet::from_intervals(gaps.chain([Interval::new(start, end)]));
+            let merged = merged.intervals();
+            if merged.len() <= MAX_GAPS {
+                self.gaps[..merged.len()].copy_from_slice(merged);
+                self.len = merged.len();
+            }
+        }
+        self
+    }
+
+    /// The gaps, sorted and disjoint.
+    #[inline]
+    pub fn gaps(&self) -> &[Interval] {
+        &self.gaps[..self.len]
+    }
+
+    /// Whether there are no gaps, so values and display coordinates are the same.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Display position of the value `v`.
+    ///
+    /// Values inside of a gap map to the break.
+    pub fn to_display(self, v: f64) -> f64 {
+        let mut removed = 0.0;
+        for &Interval { start, end } in self.gaps() {
+            if v >= end {
+                removed += end - start;
+            } else if v > start {
+                return start - removed;
+            } else {
+                break;
+            }
+        }
+        v - removed
+    }
+
+    /// The value at the display position `x`.
+    ///
+    /// A break maps to the end of its gap.
+    pub fn to_value(self, x: f64) -> f64 {
+        let mut added = 0.0;
+        for &Interval { start, end } in self.gaps() {
+            if x >= start - added {
+                added += end - start;
+            } else {
+                break;
+            }
+        }
+        x + added
+    }
+}
+
+/// Paint the breaks of the x axis (`axis == 0`) or y axis on the plot `frame`, where `transform`
+/// collapses the gaps: a faint line through the plot, and a pair of slanted marks across both
+/// edges of the frame.
+pub(crate) fn paint_marks(
+    ui: &Ui,
+    frame: Rect,
+    transform: &PlotTransform,
+    axis: usize,
+    breaks: &AxisBreaks,
+) {
+    let visuals = ui.visuals();
+    let painter = ui.painter();
+    let line = Stroke::new(1.0, visuals.widgets.noninteractive.bg_stroke.color);
+    let mark = Stroke::new(1.0, visuals.text_color());
+    let background = visuals.extreme_bg_color;
+    for &Interval { start: at, .. } in breaks.gaps() {
+        let centers = if axis == 0 {
+            let x = transform.position_from_point_x(at);
+            if !frame.x_range().contains(x) {
+                continue;
+            }
+            painter
+                .with_clip_rect(frame)
+                .add(Shape::vline(x, frame.y_range(), line));
+            [pos2(x, frame.top()), pos2(x, frame.bottom())]
+        } else {
+            let y = transform.position_from_point_y(at);
+            if !frame.y_range().contains(y) {
+                continue;
+            }
+            painter
+                .with_clip_rect(frame)
+                .add(Shape::hline(frame.x_range(), y, line));
+            [pos2(frame.left(), y), pos2(frame.right(), y)]
+        };
+        // Two slanted lines across the edge, with the frame cut out between them:
+        let (across, slant) = if axis == 0 {
+            (vec2(MARK_GAP / 2.0, 0.0), vec2(MARK_SIZE / 2.0, -MARK_SIZE))
+        } else {
+            (vec2(0.0, MARK_GAP / 2.0), vec2(MARK_SIZE, -MARK_SIZE / 2.0))
+        };
+        for center in centers {
+            painter.rect_filled(
+                Rect::from_center_size(center, across * 2.0 + vec2(1.0, 1.0)),
+                0,
+                background,
+            );
+            for side in [-1.0, 1.0] {
+                let c = center + side * across;
+                painter.line_segment([c - slant, c + slant], mark);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_axis_breaks() {
+    let breaks = AxisBreaks::new()
+        .gap(10.0, 20.0)
+        .gap(40.0, 30.0)
+        .gap(35.0, 50.0)
+        .gap(f64::NAN, 1.0);
+    assert_eq!(
+        breaks.gaps(),
+        [Interval::new(10.0, 20.0), Interval::new(30.0, 50.0)]
+    );
+
+    assert_eq!(breaks.to_display(5.0), 5.0);
+    assert_eq!(breaks.to_display(15.0), 10.0);
+    assert_eq!(breaks.to_display(25.0), 15.0);
+    assert_eq!(breaks.to_display(60.0), 30.0);
+    for v in [5.0, 20.0, 25.0, 50.0, 60.0] {
+        assert_eq!(breaks.to_value(breaks.to_display(v)), v);
+    }
+
+    // A fifth gap doesn't fit:
+    let full = (0..4).fold(AxisBreaks::new(), |breaks, i| {
+        breaks.gap(f64::from(i) * 10.0, f64::from(i) * 10.0 + 5.0)
+    });
+    assert_eq!(full.gap(100.0, 200.0), full);
+    assert_eq!(full.gap(2.0, 7.0).gaps().len(), 4);
+}
@@ -0,0 +1,128 @@
+//! Non-linear axes: logarithmic, symmetric logarithmic and custom monotonic scales.
+
+use std::fmt;
+
+/// A monotonic function of the values of an axis, see [`AxisScale::custom`].
+pub type AxisScaleFn = fn(f64) -> f64;
+
+/// How the values of an axis are spread over it, see [`crate::Plot::x_axis_scale`].
+///
+/// The plot transform spreads the *display* coordinates of the values linearly over the screen,
+/// converting them with [`Self::to_display`] and back with [`Self::to_value`]. Items, tick
+/// labels and formatters keep working with the values as they are.
+///
+/// ```
+/// # use egui_plot::AxisScale;
+/// let log = AxisScale::Log;
+/// assert_eq!(log.to_display(1000.0), 3.0);
+/// assert_eq!(log.to_value(2.0), 100.0);
+///
+/// // The probability of a normal plot, as log-odds:
+/// let logit = AxisScale::logit();
+/// assert_eq!(logit.to_display(0.5), 0.0);
+/// ```
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{AxisScale, Line, Plot};
+///
+/// let points: Vec<[f64; 2]> = (0..=30)
+///     .map(|i| {
+///         let x = f64::from(i) / 10.0;
+///         [x, 10_f64.powf(x)]
+///     })
+///     .collect();
+/// Plot::new("log_axis")
+///     .y_axis_scale(AxisScale::Log)
+///     .show(ui, |plot_ui| plot_ui.line(Line::new("10^x", points)));
+/// # });
+/// ```
+#[derive(Clone, Copy, Default)]
+pub enum AxisScale {
+    #[default]
+    Linear,
+
+    /// Equal ratios get equal steps. Values that aren't positive can't be shown.
+    Log,
+
+    /// Logarithmic for both positive and negative values, linear within `linthresh` of zero.
+    SymLog { linthresh: f64 },
+
+    /// A monotonic function of the values, and its inverse.
+    Custom {
+        forward: AxisScaleFn,
+        inverse: AxisScaleFn,
+    },
+}
+
+impl fmt::Debug for AxisScale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linear => write!(f, "Linear"),
+            Self::Log => write!(f, "Log"),
+            Self::SymLog { linthresh } => f
+                .debug_struct("SymLog")
+                .field("linthresh", linthresh)
+                .finish(),
+            Self::Custom { .. } => write!(f, "Custom"),
+        }
+    }
+}
+
+impl AxisScale {
+    /// A scale with the monotonic function `forward` from values to display coordinates, and
+    /// its `inverse`.
+    ///
+    /// These are plain functions, or closures that don't capture anything, so that the scale
+    /// stays `Copy` like the [`crate::PlotTransform`] it is part of.
+    pub fn custom(forward: AxisScaleFn, inverse: AxisScaleFn) -> Self {
+        Self::Custom { forward, inverse }
+    }
+
+    /// Square root scale, e.g. for counts. Negative values are mirrored.
+    pub fn sqrt() -> Self {
+        Self::custom(|v| v.signum() * v.abs().sqrt(), |x| x.signum() * x * x)
+    }
+
+    /// Log-odds of probabilities in `(0, 1)`, spreading out the values near `0` and `1`.
+    pub fn logit() -> Self {
+        Self::custom(|p| (p / (1.0 - p)).ln(), |x| 1.0 / (1.0 + (-x).exp()))
+    }
+
+    /// Display position of the value `v`.
+    pub fn to_display(self, v: f64) -> f64 {
+        match self {
+            Self::Linear => v,
+            Self::Log => v.log10(),
+            Self::SymLog { linthresh } => {
+                v.signum() * (v.abs() / linthresh.abs()).ln_1p() / std::f64::consts::LN_10
+            }
+            Self::Custom { forward, .. } => forward(v),
+        }
+    }
+
+    /// The value at the display position `x`.
+    pub fn to_value(self, x: f64) -> f64 {
+        match self {
+            Self::Linear => x,
+            Self::Log => 10_f64.powf(x),
+            Self::SymLog { linthresh } => {
+                x.signum() * linthresh.abs() * (10_f64.powf(x.abs()) - 1.0)
+            }
+            Self::Custom { inverse, .. } => inverse(x),
+        }
+    }
+}
+
+#[test]
+fn test_axis_scale() {
+    let symlog = AxisScale::SymLog { linthresh: 1.0 };
+    for scale in [AxisScale::Linear, AxisScale::Log, symlog, AxisScale::sqrt()] {
+        for v in [0.5, 3.0, 250.0] {
+            assert!((scale.to_value(scale.to_display(v)) - v).abs() < 1e-9 * v);
+        }
+    }
+    assert_eq!(symlog.to_display(0.0), 0.0);
+    assert_eq!(symlog.to_display(-9.0), -1.0);
+    assert!(AxisScale::Log.to_display(-1.0).is_nan());
+}
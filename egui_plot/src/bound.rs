@@ -2,6 +2,7 @@
 
 /// A numeric interval on `R` with optional ±∞ on either side.
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct Interval {
     /// Lower bound in data units. Can be -∞.
     pub start: f64,
@@ -55,3 +56,184 @@ impl Interval {
         x >= self.start && x <= self.end
     }
 }
+
+/// A union of disjoint intervals, e.g. all the periods in which an alarm was active.
+///
+/// The intervals are kept sorted, without overlaps and without empty intervals.
+///
+/// ```
+/// # use egui_plot::{Interval, IntervalSet};
+/// let xs = [0.0, 1.0, 2.0, 3.0, 4.0, 5.0];
+/// let alarm = IntervalSet::from_mask(&xs, &[false, true, true, false, true, true]);
+/// assert_eq!(alarm.intervals(), [Interval::new(1.0, 3.0), Interval::new(4.0, 5.0)]);
+///
+/// let office_hours = IntervalSet::from(Interval::new(2.5, 4.5));
+/// assert_eq!(
+///     alarm.intersection(&office_hours).intervals(),
+///     [Interval::new(2.5, 3.0), Interval::new(4.0, 4.5)]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    /// The empty set.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The union of `intervals`, which may overlap and come in any order.
+    pub fn from_intervals(intervals: impl IntoIterator<Item = Interval>) -> Self {
+        let mut intervals: Vec<Interval> = intervals
+            .into_iter()
+            .filter(|interval| !interval.is_empty() && !interval.start.is_nan())
+            .collect();
+        intervals.sort_by(|a, b| a.start.total_cmp(&b.start));
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last_mut() {
+                Some(last) if interval.start <= last.end => last.end = last.end.max(interval.end),
+                _ => merged.push(interval),
+            }
+        }
+        Self { intervals: merged }
+    }
+
+    /// The regions where `mask` is `true`, for samples at the sorted positions `xs`.
+    ///
+    /// A run of `true` samples lasts until the next `false` sample, like a value that is held
+    /// until the next sample. A run at the end lasts until the last sample.
+    pub fn from_mask(xs: &[f64], mask: &[bool]) -> Self {
+        assert_eq!(
+            xs.len(),
+            mask.len(),
+            "IntervalSet: xs and mask must have the same length"
+        );
+        let mut intervals = Vec::new();
+        let mut run_start = None;
+        for (&x, &set) in xs.iter().zip(mask) {
+            match (run_start, set) {
+                (None, true) => run_start = Some(x),
+                (Some(start), false) => {
+                    intervals.push(Interval::new(start, x));
+                    run_start = None;
+                }
+                _ => {}
+            }
+        }
+        if let (Some(start), Some(&last)) = (run_start, xs.last()) {
+            intervals.push(Interval::new(start, last));
+        }
+        Self::from_intervals(intervals)
+    }
+
+    /// The sorted, disjoint intervals of the set.
+    #[inline]
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns `true` if `x` lies in one of the intervals.
+    pub fn contains(&self, x: f64) -> bool {
+        self.intervals.iter().any(|interval| interval.contains(x))
+    }
+
+    /// Everything in `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        Self::from_intervals(self.intervals.iter().chain(&other.intervals).copied())
+    }
+
+    /// Everything in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intervals = Vec::new();
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                intervals.push(Interval { start, end });
+            }
+            // Continue with the next interval of the one that ends first:
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+        Self { intervals }
+    }
+
+    /// Everything not in `self`.
+    pub fn complement(&self) -> Self {
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        let mut start = f64::NEG_INFINITY;
+        for interval in &self.intervals {
+            if start < interval.start {
+                intervals.push(Interval {
+                    start,
+                    end: interval.start,
+                });
+            }
+            start = interval.end;
+        }
+        if start < f64::INFINITY {
+            intervals.push(Interval {
+                start,
+                end: f64::INFINITY,
+            });
+        }
+        Self { intervals }
+    }
+
+    /// Everything in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        self.intersection(&other.complement())
+    }
+}
+
+impl From<Interval> for IntervalSet {
+    fn from(interval: Interval) -> Self {
+        Self::from_intervals([interval])
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(iter: I) -> Self {
+        Self::from_intervals(iter)
+    }
+}
+
+#[test]
+fn test_interval_set_algebra() {
+    let a = IntervalSet::from_intervals([Interval::new(3.0, 5.0), Interval::new(0.0, 2.0)]);
+    let b = IntervalSet::from_intervals([Interval::new(1.0, 4.0), Interval::new(4.5, 4.5)]);
+
+    assert_eq!(
+        a.intervals(),
+        [Interval::new(0.0, 2.0), Interval::new(3.0, 5.0)]
+    );
+    assert_eq!(a.union(&b).intervals(), [Interval::new(0.0, 5.0)]);
+    assert_eq!(
+        a.difference(&b).intervals(),
+        [Interval::new(0.0, 1.0), Interval::new(4.0, 5.0)]
+    );
+    assert_eq!(
+        a.complement().intervals(),
+        [
+            Interval::below(0.0),
+            Interval::new(2.0, 3.0),
+            Interval::above(5.0)
+        ]
+    );
+    assert!(IntervalSet::from(Interval::all()).complement().is_empty());
+    assert!(IntervalSet::from_mask(&[0.0, 1.0], &[false, false]).is_empty());
+}
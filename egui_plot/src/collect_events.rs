@@ -1,8 +1,8 @@
 use egui::{Response, Shape, Vec2b};
 
 use crate::{
-    ActionExecutor, ActionQueue, PlotBounds, PlotEvent,
-    action::{AppliedActions, BoundsChangeCause, BoundsLike, PlotAction},
+    ActionExecutor, ActionQueue, PlotEvent,
+    action::{AppliedActions, BoundsLike, PlotAction},
 };
 
 impl ActionExecutor {
@@ -18,12 +18,27 @@ impl ActionExecutor {
     {
         let mut items: Vec<I> = Vec::new();
         let mut overlays: Vec<Shape> = Vec::new();
+        let mut item_visibility = Vec::new();
+        let mut time_origin = None;
+        let mut bounds_animation = None;
+        let mut reference = None;
+        let mut view_steps = 0;
+        let mut fit_items = None;
+        let mut selection = None;
+        let mut raised = Vec::new();
+        let mut item_colors = Vec::new();
         let mut events: Vec<PlotEvent> = Vec::new();
 
         for action in queue.drain() {
             if let Some(ev) = action.as_event() {
                 events.push(ev);
             }
+            let old = if action.changes_bounds() {
+                bounds.plot_bounds()
+            } else {
+                None
+            };
+            let old_auto_bounds = auto_bounds;
 
             match action {
                 PlotAction::AddItem(item) => items.push(item),
@@ -47,8 +62,33 @@ impl ActionExecutor {
                     bounds.zoom(factor, center);
                     auto_bounds = Vec2b::from([false, false]);
                 }
+                PlotAction::SetItemVisible(id, visible) => item_visibility.push((id, visible)),
+                PlotAction::SetSelection(ids) => selection = Some(ids),
+                PlotAction::BringToFront(id) => raised.push(id),
+                PlotAction::SetItemColor(id, color) => item_colors.push((id, color)),
+                PlotAction::SetTimeOrigin(t0) => time_origin = Some(t0),
+                PlotAction::SetReference(keep) => reference = Some(keep),
+                PlotAction::UndoView => view_steps += 1,
+                PlotAction::RedoView => view_steps -= 1,
+                PlotAction::FitToItems(ids) => {
+                    fit_items = Some(ids);
+                    auto_bounds = Vec2b::FALSE;
+                }
+                PlotAction::AnimateBounds(target, duration) => {
+                    bounds_animation = Some((target, duration));
+                    auto_bounds = Vec2b::from([false, false]);
+                }
                 PlotAction::AddOverlayShape(shape) => overlays.push(shape),
             }
+            if let (Some(old), Some(new)) = (old, bounds.plot_bounds()) {
+                events.push(PlotEvent::BoundsActionApplied { old, new });
+            }
+            if auto_bounds != old_auto_bounds {
+                events.push(PlotEvent::AutoBoundsActionApplied {
+                    old: old_auto_bounds,
+                    new: auto_bounds,
+                });
+            }
         }
 
         AppliedActions {
@@ -56,38 +96,132 @@ impl ActionExecutor {
             auto_bounds,
             bounds,
             overlays,
+            item_visibility,
+            time_origin,
+            bounds_animation,
+            reference,
+            view_steps,
+            fit_items,
+            selection,
+            raised,
+            item_colors,
             events,
         }
     }
 }
 
 impl<I> PlotAction<I> {
+    /// Whether applying this action changes the bounds directly.
+    fn changes_bounds(&self) -> bool {
+        matches!(
+            self,
+            Self::SetBoundsX(_) | Self::SetBoundsY(_) | Self::Translate(_) | Self::Zoom(_, _)
+        )
+    }
+
     /// Turn action to events.
+    ///
+    /// Changes of the bounds need the bounds before the action, so they are reported by
+    /// [`ActionExecutor::apply`] as [`PlotEvent::BoundsActionApplied`] and
+    /// [`PlotEvent::AutoBoundsActionApplied`] instead.
     pub fn as_event(&self) -> Option<PlotEvent> {
         match self {
-            Self::SetBoundsX(range) => Some(PlotEvent::BoundsChanged {
-                old: PlotBounds::NOTHING,
-                new: PlotBounds {
-                    min: [*range.start(), f64::NEG_INFINITY],
-                    max: [*range.end(), f64::INFINITY],
-                },
-                cause: BoundsChangeCause::Programmatic,
-            }),
-
-            Self::SetBoundsY(range) => Some(PlotEvent::BoundsChanged {
-                old: PlotBounds::NOTHING,
-                new: PlotBounds {
-                    min: [f64::NEG_INFINITY, *range.start()],
-                    max: [f64::INFINITY, *range.end()],
-                },
-                cause: BoundsChangeCause::Programmatic,
-            }),
-
-            Self::Translate(_)
+            Self::SetBoundsX(_)
+            | Self::SetBoundsY(_)
+            | Self::Translate(_)
             | Self::Zoom(_, _)
             | Self::SetAutoBounds(_)
             | Self::AddOverlayShape(_)
-            | Self::AddItem(_) => None,
+            | Self::AddItem(_)
+            // Reported as `LegendItemToggled` once the change is applied:
+            | Self::SetItemVisible(_, _)
+            // Reported as `SelectionChanged` once the change is applied:
+            | Self::SetSelection(_)
+            | Self::BringToFront(_)
+            // Reported as `SeriesStyleChanged` once the change is applied:
+            | Self::SetItemColor(_, _)
+            // Reported as `TimeOriginMoved` once the change is applied:
+            | Self::SetTimeOrigin(_)
+            | Self::SetReference(_)
+            // Reported as `BoundsChanged` with the `History` cause once applied:
+            | Self::UndoView
+            | Self::RedoView
+            // Reported as `BoundsChanged` with the `Programmatic` cause once applied:
+            | Self::FitToItems(_)
+            // Reported as `BoundsChanged` with the `Animation` cause every frame:
+            | Self::AnimateBounds(_, _) => None,
+        }
+    }
+}
+
+#[test]
+fn test_bounds_action_events() {
+    use crate::PlotBounds;
+
+    let mut queue = ActionQueue::<()>::new();
+    queue.set_bounds_x(0.0..=10.0);
+    queue.translate(egui::vec2(1.0, 2.0));
+    let start = PlotBounds::from_min_max([-1.0, -1.0], [1.0, 1.0]);
+    let applied = ActionExecutor::apply(queue, start, Vec2b::TRUE, None, None);
+
+    let steps: Vec<_> = applied
+        .events
+        .iter()
+        .filter_map(|event| match event {
+            PlotEvent::BoundsActionApplied { old, new } => Some((*old, *new)),
+            PlotEvent::AutoBoundsActionApplied { .. } => None,
+            _ => panic!("unexpected event {event:?}"),
+        })
+        .collect();
+    let set_x = PlotBounds::from_min_max([0.0, -1.0], [10.0, 1.0]);
+    let translated = PlotBounds::from_min_max([1.0, 1.0], [11.0, 3.0]);
+    assert_eq!(steps, [(start, set_x), (set_x, translated)]);
+    assert_eq!(applied.bounds, translated);
+}
+
+#[test]
+fn test_replay_bounds_action_events() {
+    use crate::{PlotBounds, PlotPoint};
+
+    let mut queue = ActionQueue::<()>::new();
+    queue.set_bounds_y(-5.0..=5.0);
+    queue.zoom(egui::vec2(2.0, 0.5), PlotPoint::new(0.0, 1.0));
+    queue.set_auto_bounds(Vec2b::new(true, false));
+    queue.translate(egui::vec2(-3.0, 0.5));
+    queue.set_auto_bounds(Vec2b::new(false, true));
+    let start = PlotBounds::from_min_max([-1.0, -1.0], [1.0, 1.0]);
+    let applied = ActionExecutor::apply(queue, start, Vec2b::TRUE, None, None);
+
+    // Each event starts where the previous one ended, and replaying them gets to the end:
+    let (mut bounds, mut auto_bounds) = (start, Vec2b::TRUE);
+    for event in &applied.events {
+        match event {
+            PlotEvent::BoundsActionApplied { old, new } => {
+                assert_eq!(*old, bounds);
+                bounds = *new;
+            }
+            PlotEvent::AutoBoundsActionApplied { old, new } => {
+                assert_eq!(*old, auto_bounds);
+                auto_bounds = *new;
+            }
+            _ => panic!("unexpected event {event:?}"),
         }
     }
+    assert_eq!(bounds, applied.bounds);
+    assert_eq!(auto_bounds, applied.auto_bounds);
+    assert_eq!(auto_bounds, Vec2b::new(false, true));
+}
+
+#[test]
+fn test_fit_to_items_action() {
+    use crate::PlotBounds;
+
+    let item = egui::Id::new("a");
+    let mut queue = ActionQueue::<()>::new();
+    queue.fit_to_items(vec![item]);
+    let start = PlotBounds::from_min_max([-1.0, -1.0], [1.0, 1.0]);
+    let applied = ActionExecutor::apply(queue, start, Vec2b::TRUE, None, None);
+    assert_eq!(applied.fit_items, Some(vec![item]));
+    assert_eq!(applied.auto_bounds, Vec2b::FALSE);
+    assert_eq!(applied.bounds, start);
 }
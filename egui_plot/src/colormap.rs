@@ -0,0 +1,458 @@
+//! Mapping values to colors, for heatmaps, gradient lines and scatter colors, and the
+//! [`Colorbar`] showing the scale.
+
+use std::{ops::RangeInclusive, sync::Arc};
+
+use egui::{
+    Align2, Color32, Mesh, Pos2, Rect, Response, Sense, Shape, Stroke, StrokeKind, TextStyle, Ui,
+    Vec2, Widget, pos2, vec2,
+};
+
+use crate::{
+    Corner, PaintStage, PlotBounds, PlotGeometry, PlotItem, PlotItemBase, PlotPoint, PlotTransform,
+    format_number,
+};
+
+/// Width of the bar of a [`Colorbar`].
+const BAR_WIDTH: f32 = 12.0;
+
+/// Gap between the bar, its ticks and their labels, and the frame of the plot.
+const GAP: f32 = 4.0;
+
+/// How many ticks a [`Colorbar`] has, including both ends.
+const TICKS: usize = 5;
+
+const VIRIDIS: [Color32; 10] = [
+    Color32::from_rgb(0x44, 0x01, 0x54),
+    Color32::from_rgb(0x48, 0x28, 0x78),
+    Color32::from_rgb(0x3E, 0x4A, 0x89),
+    Color32::from_rgb(0x31, 0x68, 0x8E),
+    Color32::from_rgb(0x26, 0x82, 0x8E),
+    Color32::from_rgb(0x1F, 0x9E, 0x89),
+    Color32::from_rgb(0x35, 0xB7, 0x79),
+    Color32::from_rgb(0x6D, 0xCD, 0x59),
+    Color32::from_rgb(0xB4, 0xDE, 0x2C),
+    Color32::from_rgb(0xFD, 0xE7, 0x25),
+];
+
+const MAGMA: [Color32; 11] = [
+    Color32::from_rgb(0x00, 0x00, 0x04),
+    Color32::from_rgb(0x14, 0x0E, 0x36),
+    Color32::from_rgb(0x3B, 0x0F, 0x70),
+    Color32::from_rgb(0x64, 0x1A, 0x80),
+    Color32::from_rgb(0x8C, 0x29, 0x81),
+    Color32::from_rgb(0xB7, 0x37, 0x79),
+    Color32::from_rgb(0xDE, 0x49, 0x68),
+    Color32::from_rgb(0xF7, 0x70, 0x5C),
+    Color32::from_rgb(0xFE, 0x9F, 0x6D),
+    Color32::from_rgb(0xFE, 0xCF, 0x92),
+    Color32::from_rgb(0xFC, 0xFD, 0xBF),
+];
+
+const COOLWARM: [Color32; 8] = [
+    Color32::from_rgb(0x3B, 0x4C, 0xC0),
+    Color32::from_rgb(0x67, 0x88, 0xEE),
+    Color32::from_rgb(0x9A, 0xBB, 0xFF),
+    Color32::from_rgb(0xC9, 0xD7, 0xF0),
+    Color32::from_rgb(0xED, 0xD1, 0xC2),
+    Color32::from_rgb(0xF7, 0xA8, 0x89),
+    Color32::from_rgb(0xE2, 0x69, 0x52),
+    Color32::from_rgb(0xB4, 0x04, 0x26),
+];
+
+/// Colors for the numbers from `0.0` to `1.0`, see [`ColorScale`].
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Colormap {
+    /// Dark blue to yellow, evenly bright steps that stay readable in grayscale and with color
+    /// blindness.
+    #[default]
+    Viridis,
+
+    /// Black to light yellow through purple and orange.
+    Magma,
+
+    /// Blue to red through light gray, for values diverging from a middle one.
+    Coolwarm,
+
+    /// Colors at positions from `0.0` to `1.0`, in increasing order, interpolated in between.
+    Custom(Vec<(f32, Color32)>),
+}
+
+impl Colormap {
+    /// The color at `t`, clamped to `0.0..=1.0`. `NaN` is the color at `0.0`.
+    pub fn color(&self, t: f32) -> Color32 {
+        let t = if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) };
+        let evenly = |colors: &[Color32]| {
+            let scaled = t * (colors.len() - 1) as f32;
+            let i = (scaled.floor() as usize).min(colors.len() - 2);
+            colors[i].lerp_to_gamma(colors[i + 1], scaled - i as f32)
+        };
+        match self {
+            Self::Viridis => evenly(&VIRIDIS),
+            Self::Magma => evenly(&MAGMA),
+            Self::Coolwarm => evenly(&COOLWARM),
+            Self::Custom(stops) => {
+                let Some(&(first_t, first)) = stops.first() else {
+                    return Color32::TRANSPARENT;
+                };
+                if t <= first_t {
+                    return first;
+                }
+                for pair in stops.windows(2) {
+                    let [(t0, c0), (t1, c1)] = [pair[0], pair[1]];
+                    if t <= t1 {
+                        let f = if t1 > t0 { (t - t0) / (t1 - t0) } else { 1.0 };
+                        return c0.lerp_to_gamma(c1, f);
+                    }
+                }
+                stops.last().map_or(first, |&(_, last)| last)
+            }
+        }
+    }
+}
+
+/// How the values of a [`ColorScale`] are spread over its [`Colormap`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Normalization {
+    #[default]
+    Linear,
+
+    /// Equal ratios get equal steps of color. Values that aren't positive get the color of the
+    /// minimum.
+    Log,
+
+    /// Logarithmic for both positive and negative values, linear within `linthresh` of zero.
+    SymLog { linthresh: f64 },
+}
+
+impl Normalization {
+    /// `value` in the space the colors are spread evenly in.
+    fn forward(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log => value.log10(),
+            Self::SymLog { linthresh } => {
+                value.signum() * (value.abs() / linthresh.abs()).ln_1p() / std::f64::consts::LN_10
+            }
+        }
+    }
+
+    fn inverse(self, value: f64) -> f64 {
+        match self {
+            Self::Linear => value,
+            Self::Log => 10_f64.powf(value),
+            Self::SymLog { linthresh } => {
+                value.signum() * linthresh.abs() * (10_f64.powf(value.abs()) - 1.0)
+            }
+        }
+    }
+}
+
+/// Maps values in a range to the colors of a [`Colormap`].
+///
+/// ```
+/// # use egui_plot::{ColorScale, Colormap, Normalization};
+/// let scale = ColorScale::new(Colormap::Magma, 1.0..=1000.0).normalization(Normalization::Log);
+/// let colors = scale.colors(&[1.0, 10.0, 100.0, 1000.0]);
+/// assert_eq!(colors[0], Colormap::Magma.color(0.0));
+/// ```
+///
+/// Use [`Self::colors`] for the per-point colors of a [`crate::Scatter`] (see
+/// [`crate::ScatterEncodings`]), [`Self::gradient`] for a [`crate::Line::gradient_color`], and
+/// show the scale with a [`Colorbar`].
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct ColorScale {
+    colormap: Colormap,
+    normalization: Normalization,
+    min: f64,
+    max: f64,
+}
+
+impl ColorScale {
+    /// The values in `range` spread linearly over `colormap`. The values out of the range get
+    /// the color of its closest end.
+    pub fn new(colormap: Colormap, range: RangeInclusive<f64>) -> Self {
+        Self {
+            colormap,
+            normalization: Normalization::Linear,
+            min: *range.start(),
+            max: *range.end(),
+        }
+    }
+
+    /// How the values are spread over the colors. Default: [`Normalization::Linear`].
+    #[inline]
+    pub fn normalization(mut self, normalization: Normalization) -> Self {
+        self.normalization = normalization;
+        self
+    }
+
+    pub fn colormap(&self) -> &Colormap {
+        &self.colormap
+    }
+
+    pub fn range(&self) -> RangeInclusive<f64> {
+        self.min..=self.max
+    }
+
+    /// Where `value` is in the range, from `0.0` at the minimum to `1.0` at the maximum.
+    pub fn normalize(&self, value: f64) -> f32 {
+        let [min, value, max] = [self.min, value, self.max].map(|v| self.normalization.forward(v));
+        let t = (value - min) / (max - min);
+        if t.is_finite() {
+            t.clamp(0.0, 1.0) as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// The value at `t` of the range, the inverse of [`Self::normalize`].
+    pub fn value_at(&self, t: f32) -> f64 {
+        let [min, max] = [self.min, self.max].map(|v| self.normalization.forward(v));
+        self.normalization.inverse(min + (max - min) * t as f64)
+    }
+
+    pub fn color(&self, value: f64) -> Color32 {
+        self.colormap.color(self.normalize(value))
+    }
+
+    /// The colors of `values`, e.g. for [`crate::ScatterEncodings::per_point_colors`].
+    pub fn colors(&self, values: &[f64]) -> Vec<Color32> {
+        values.iter().map(|value| self.color(*value)).collect()
+    }
+
+    /// Colors by the y value, for [`crate::Line::gradient_color`].
+    pub fn gradient(&self) -> Arc<dyn Fn(PlotPoint) -> Color32 + Send + Sync> {
+        let scale = self.clone();
+        Arc::new(move |point| scale.color(point.y))
+    }
+}
+
+/// Shows a [`ColorScale`] as a bar of its colors with ticks, in a corner of the plot or next to
+/// it.
+///
+/// As a plot item, add it with [`crate::PlotUi::add`]:
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui_plot::{ColorScale, Colorbar, Colormap, Plot};
+/// let scale = ColorScale::new(Colormap::Viridis, 0.0..=100.0);
+/// Plot::new("heatmap").show(ui, |plot_ui| {
+///     plot_ui.add(Colorbar::new(scale.clone()).label("°C"));
+/// });
+/// // Or next to the plot:
+/// ui.add(&Colorbar::new(scale));
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Colorbar {
+    base: PlotItemBase,
+    scale: ColorScale,
+    label: Option<String>,
+    corner: Corner,
+    length: Option<f32>,
+}
+
+impl Colorbar {
+    pub fn new(scale: ColorScale) -> Self {
+        Self {
+            base: PlotItemBase::new(String::new()).paint_stage(PaintStage::Overlay),
+            scale,
+            label: None,
+            corner: Corner::RightBottom,
+            length: None,
+        }
+    }
+
+    /// A title above the bar, like the unit of the values.
+    #[inline]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The corner of the plot the bar is in. Default: [`Corner::RightBottom`], out of the way of
+    /// the legend.
+    #[inline]
+    pub fn corner(mut self, corner: Corner) -> Self {
+        self.corner = corner;
+        self
+    }
+
+    /// Length of the bar in points. Default: half the height of the plot, or 200 next to it.
+    #[inline]
+    pub fn length(mut self, length: f32) -> Self {
+        self.length = Some(length);
+        self
+    }
+
+    /// The tick labels, from the minimum up, and the size they need.
+    fn tick_labels(&self, ui: &Ui) -> (Vec<String>, Vec2) {
+        let step = (self.scale.max - self.scale.min).abs() / (TICKS - 1) as f64;
+        let decimals = if step > 0.0 {
+            (1.0 - step.log10().floor()).clamp(0.0, 6.0) as usize
+        } else {
+            1
+        };
+        let labels: Vec<String> = (0..TICKS)
+            .map(|i| {
+                let value = self.scale.value_at(i as f32 / (TICKS - 1) as f32);
+                format_number(value, decimals)
+            })
+            .collect();
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let size = labels.iter().fold(Vec2::ZERO, |size, label| {
+            let galley = ui
+                .fonts(|f| f.layout_no_wrap(label.clone(), font_id.clone(), Color32::PLACEHOLDER));
+            size.max(galley.size())
+        });
+        (labels, size)
+    }
+
+    /// The bar in `bar`, with its tick labels on the side `labels_left`.
+    fn paint(&self, ui: &Ui, bar: Rect, labels_left: bool, shapes: &mut Vec<Shape>) {
+        let text_color = ui.visuals().text_color();
+        let mut mesh = Mesh::default();
+        let steps = 64;
+        for i in 0..=steps {
+            let t = i as f32 / steps as f32;
+            let y = bar.bottom() - t * bar.height();
+            let color = self.scale.colormap.color(t);
+            mesh.colored_vertex(pos2(bar.left(), y), color);
+            mesh.colored_vertex(pos2(bar.right(), y), color);
+            if i > 0 {
+                let v = 2 * i as u32;
+                mesh.add_triangle(v - 2, v - 1, v);
+                mesh.add_triangle(v - 1, v, v + 1);
+            }
+        }
+        shapes.push(Shape::mesh(mesh));
+        let stroke = Stroke::new(1.0, text_color);
+        shapes.push(Shape::rect_stroke(bar, 0.0, stroke, StrokeKind::Outside));
+
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let (labels, _) = self.tick_labels(ui);
+        for (i, label) in labels.into_iter().enumerate() {
+            let y = bar.bottom() - i as f32 / (TICKS - 1) as f32 * bar.height();
+            let (tick, anchor, pos) = if labels_left {
+                let x = bar.left() - GAP;
+                (
+                    [pos2(x, y), pos2(bar.left(), y)],
+                    Align2::RIGHT_CENTER,
+                    pos2(x - 2.0, y),
+                )
+            } else {
+                let x = bar.right() + GAP;
+                (
+                    [pos2(bar.right(), y), pos2(x, y)],
+                    Align2::LEFT_CENTER,
+                    pos2(x + 2.0, y),
+                )
+            };
+            shapes.push(Shape::line_segment(tick, stroke));
+            let galley = ui.fonts(|f| f.layout_no_wrap(label, font_id.clone(), text_color));
+            let rect = anchor.anchor_size(pos, galley.size());
+            shapes.push(Shape::galley(rect.min, galley, text_color));
+        }
+        if let Some(label) = &self.label {
+            let galley = ui.fonts(|f| f.layout_no_wrap(label.clone(), font_id, text_color));
+            let rect =
+                Align2::CENTER_BOTTOM.anchor_size(bar.center_top() - vec2(0.0, GAP), galley.size());
+            shapes.push(Shape::galley(rect.min, galley, text_color));
+        }
+    }
+
+    /// The space taken by the labels around the bar: on its side, and above it.
+    fn margins(&self, ui: &Ui) -> Vec2 {
+        let (_, label_size) = self.tick_labels(ui);
+        let title_height = if self.label.is_some() {
+            TextStyle::Small.resolve(ui.style()).size + GAP
+        } else {
+            0.0
+        };
+        vec2(label_size.x + GAP + 2.0, label_size.y / 2.0 + title_height)
+    }
+}
+
+impl PlotItem for Colorbar {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let frame = transform.frame().shrink(2.0 * GAP);
+        let margins = self.margins(ui);
+        let length = self
+            .length
+            .unwrap_or(frame.height() / 2.0)
+            .min(frame.height() - margins.y);
+        let size = vec2(BAR_WIDTH, length);
+        let labels_left = matches!(self.corner, Corner::RightTop | Corner::RightBottom);
+        let bar = match self.corner {
+            Corner::LeftTop => Rect::from_min_size(frame.left_top() + vec2(0.0, margins.y), size),
+            Corner::RightTop => {
+                Align2::RIGHT_TOP.anchor_size(frame.right_top() + vec2(0.0, margins.y), size)
+            }
+            Corner::LeftBottom => Align2::LEFT_BOTTOM.anchor_size(frame.left_bottom(), size),
+            Corner::RightBottom => Align2::RIGHT_BOTTOM.anchor_size(frame.right_bottom(), size),
+        };
+        self.paint(ui, bar, labels_left, shapes);
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.scale.colormap.color(0.5)
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        PlotBounds::NOTHING
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+impl Widget for &Colorbar {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let margins = self.margins(ui);
+        let length = self.length.unwrap_or(200.0);
+        let size = vec2(BAR_WIDTH + margins.x, length + margins.y);
+        let (rect, response) = ui.allocate_exact_size(size, Sense::hover());
+        if ui.is_rect_visible(rect) {
+            let bar = Rect::from_min_size(
+                Pos2::new(rect.left(), rect.top() + margins.y),
+                vec2(BAR_WIDTH, length),
+            );
+            let mut shapes = Vec::new();
+            self.paint(ui, bar, false, &mut shapes);
+            ui.painter().extend(shapes);
+        }
+        response
+    }
+}
+
+#[test]
+fn test_color_scale() {
+    let stops = Colormap::Custom(vec![(0.0, Color32::BLACK), (1.0, Color32::WHITE)]);
+    assert_eq!(stops.color(-1.0), Color32::BLACK);
+    assert_eq!(stops.color(0.5), Color32::from_gray(128));
+    assert_eq!(Colormap::Viridis.color(1.0), VIRIDIS[9]);
+
+    let log = ColorScale::new(Colormap::Viridis, 1.0..=100.0).normalization(Normalization::Log);
+    assert_eq!(log.normalize(10.0), 0.5);
+    assert_eq!(log.normalize(-1.0), 0.0);
+    assert!((log.value_at(0.5) - 10.0).abs() < 1e-9);
+
+    let symlog = ColorScale::new(Colormap::Coolwarm, -100.0..=100.0)
+        .normalization(Normalization::SymLog { linthresh: 1.0 });
+    assert_eq!(symlog.normalize(0.0), 0.5);
+    assert!((symlog.value_at(symlog.normalize(-9.0)) + 9.0).abs() < 1e-4);
+}
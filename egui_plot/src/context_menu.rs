@@ -0,0 +1,213 @@
+//! The menu shown when right-clicking the plot, see [`crate::Plot::context_menu`].
+
+use egui::{Id, Response, Ui};
+
+use crate::{HitPoint, PlotBounds, PlotPoint, data_table::Row};
+
+/// Adds entries to the context menu, see [`crate::Plot::context_menu`].
+pub(crate) type ContextMenuFn<'a> = Box<dyn FnMut(&mut Ui, &PlotMenuContext) + 'a>;
+
+/// Where the context menu of a plot was opened.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PlotMenuContext {
+    /// The plot position that was right-clicked.
+    pub pos: PlotPoint,
+
+    /// The item under the pointer when the menu was opened, if any.
+    pub item: Option<Id>,
+
+    /// The plot bounds when the menu was opened.
+    pub bounds: PlotBounds,
+}
+
+/// How to format the values copied at the cursor, see [`crate::Plot::copy_format`].
+///
+/// The values are the x of the cursor and the y of each series there, as found for
+/// [`crate::PlotUi::show_tooltip_with_options`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CopyFormat {
+    /// A header row with `x` and the series names, then a row of values, separated by tabs.
+    /// Pastes into spreadsheets as two rows.
+    #[default]
+    Tsv,
+
+    /// Like [`Self::Tsv`], separated by commas.
+    Csv,
+
+    /// An object like `{"x": 1.5, "series": {"a": 2.0, "b": -1.0}}`.
+    Json,
+}
+
+impl CopyFormat {
+    /// The x of the cursor and the values of the `hits` there, in this format.
+    pub(crate) fn format(self, x: f64, hits: &[HitPoint]) -> String {
+        let names = hits.iter().map(|hit| hit.series_name.as_str());
+        let values = hits.iter().map(|hit| hit.value.y.to_string());
+        match self {
+            Self::Tsv => {
+                let header: Vec<&str> = std::iter::once("x").chain(names).collect();
+                let row: Vec<String> = std::iter::once(x.to_string()).chain(values).collect();
+                format!("{}\n{}\n", header.join("\t"), row.join("\t"))
+            }
+            Self::Csv => {
+                let header: Vec<String> =
+                    std::iter::once("x").chain(names).map(csv_field).collect();
+                let row: Vec<String> = std::iter::once(x.to_string()).chain(values).collect();
+                format!("{}\n{}\n", header.join(","), row.join(","))
+            }
+            Self::Json => {
+                let series: Vec<String> = names
+                    .zip(hits)
+                    .map(|(name, hit)| {
+                        format!("{}: {}", json_string(name), json_number(hit.value.y))
+                    })
+                    .collect();
+                format!(
+                    "{{\"x\": {}, \"series\": {{{}}}}}",
+                    json_number(x),
+                    series.join(", ")
+                )
+            }
+        }
+    }
+}
+
+/// `field` quoted if needed to be a single CSV field.
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_owned()
+    }
+}
+
+fn json_string(text: &str) -> String {
+    let mut json = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            c if c.is_control() => json.push_str(&format!("\\u{:04x}", c as u32)),
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+/// JSON has no infinities or NaN.
+fn json_number(value: f64) -> String {
+    if value.is_finite() {
+        value.to_string()
+    } else {
+        "null".to_owned()
+    }
+}
+
+/// A built-in entry that needs the plot to act on it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum MenuCommand {
+    ResetView,
+    FitToData,
+    ToggleLegend,
+    CopyImage,
+}
+
+/// Show the context menu of the plot `response`, with the built-in entries followed by the ones
+/// of `add_entries`.
+///
+/// `legend_shown` is `None` for plots without a legend. `values` and `rows` are only needed
+/// once the menu is open, to copy the values at the cursor and all the data.
+pub(crate) fn menu_ui(
+    response: &Response,
+    context: &PlotMenuContext,
+    legend_shown: Option<bool>,
+    values: Option<String>,
+    rows: Option<&[Row]>,
+    add_entries: Option<&mut ContextMenuFn<'_>>,
+) -> Option<MenuCommand> {
+    let mut command = None;
+    response.context_menu(|ui| {
+        if ui.button("Reset view").clicked() {
+            command = Some(MenuCommand::ResetView);
+        }
+        if ui.button("Fit to data").clicked() {
+            command = Some(MenuCommand::FitToData);
+        }
+        if let Some(shown) = legend_shown {
+            let label = if shown { "Hide legend" } else { "Show legend" };
+            if ui.button(label).clicked() {
+                command = Some(MenuCommand::ToggleLegend);
+            }
+        }
+        ui.separator();
+        if ui.button("Copy values at cursor").clicked() {
+            if let Some(values) = values {
+                ui.ctx().copy_text(values);
+            }
+        }
+        if ui.button("Copy data as CSV").clicked() {
+            if let Some(rows) = rows {
+                ui.ctx().copy_text(csv(rows));
+            }
+        }
+        if ui.button("Copy as image").clicked() {
+            command = Some(MenuCommand::CopyImage);
+        }
+        if let Some(add_entries) = add_entries {
+            ui.separator();
+            add_entries(ui, context);
+        }
+    });
+    command
+}
+
+/// The rows as comma-separated values, with a header.
+pub(crate) fn csv(rows: &[Row]) -> String {
+    let mut csv = String::from("series,index,x,y\n");
+    for row in rows {
+        let series = csv_field(&row.series);
+        csv += &format!("{series},{},{},{}\n", row.index, row.x, row.y);
+    }
+    csv
+}
+
+#[test]
+fn test_csv() {
+    let row = |series: &str, index, x, y| Row {
+        series: series.to_owned(),
+        index,
+        x,
+        y,
+    };
+    let rows = [row("a", 0, 1.0, 2.5), row("say \"hi\", b", 1, -1.0, 0.0)];
+    assert_eq!(
+        csv(&rows),
+        "series,index,x,y\na,0,1,2.5\n\"say \"\"hi\"\", b\",1,-1,0\n"
+    );
+}
+
+#[test]
+fn test_copy_format() {
+    let hit = |name: &str, y| HitPoint {
+        series_name: name.to_owned(),
+        color: egui::Color32::RED,
+        value: PlotPoint::new(1.0, y),
+        screen_pos: egui::Pos2::ZERO,
+        screen_dx: 0.0,
+    };
+    let hits = [hit("a", 2.0), hit("b, \"c\"", f64::NAN)];
+    assert_eq!(
+        CopyFormat::Tsv.format(1.5, &hits),
+        "x\ta\tb, \"c\"\n1.5\t2\tNaN\n"
+    );
+    assert_eq!(
+        CopyFormat::Csv.format(1.5, &hits),
+        "x,a,\"b, \"\"c\"\"\"\n1.5,2,NaN\n"
+    );
+    assert_eq!(
+        CopyFormat::Json.format(1.5, &hits),
+        r#"{"x": 1.5, "series": {"a": 2, "b, \"c\"": null}}"#
+    );
+}
@@ -0,0 +1,197 @@
+//! A table of the plotted samples, as an accessible alternative to the plot.
+
+use egui::{Align, Button, Label, Layout, Rect, ScrollArea, TextStyle, Ui, UiBuilder};
+
+use crate::{PlotGeometry, PlotItem};
+
+/// Space between the plot and a table below it.
+pub(crate) const TABLE_GAP: f32 = 4.0;
+
+/// Where to show a table of the samples of the visible series, see [`crate::Plot::data_table`].
+///
+/// The table is made of regular egui widgets, so it can be read by screen readers, and can be
+/// sorted by clicking the column headers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DataTableMode {
+    /// Only show the plot.
+    #[default]
+    Off,
+
+    /// Show the table instead of the plot.
+    Replace,
+
+    /// Show the table below the plot, in the bottom third of the widget.
+    Below,
+}
+
+/// A column of the data table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Column {
+    Series,
+    Index,
+    X,
+    Y,
+}
+
+impl Column {
+    const ALL: [Self; 4] = [Self::Series, Self::Index, Self::X, Self::Y];
+
+    fn title(self) -> &'static str {
+        match self {
+            Self::Series => "Series",
+            Self::Index => "#",
+            Self::X => "x",
+            Self::Y => "y",
+        }
+    }
+}
+
+/// One sample of a series.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct Row {
+    pub(crate) series: String,
+    pub(crate) index: usize,
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+}
+
+impl Row {
+    fn cell(&self, column: Column) -> String {
+        match column {
+            Column::Series => self.series.clone(),
+            Column::Index => self.index.to_string(),
+            Column::X => self.x.to_string(),
+            Column::Y => self.y.to_string(),
+        }
+    }
+}
+
+/// The samples of all items with point geometry, in plot order.
+pub(crate) fn rows(items: &[Box<dyn PlotItem + '_>]) -> Vec<Row> {
+    let mut rows = Vec::new();
+    for item in items {
+        let points: Vec<(f64, f64)> = match item.geometry() {
+            PlotGeometry::None | PlotGeometry::Rects => continue,
+            PlotGeometry::Points(points) => points.iter().map(|p| (p.x, p.y)).collect(),
+            PlotGeometry::PointsXY { xs, ys } => {
+                xs.iter().copied().zip(ys.iter().copied()).collect()
+            }
+            PlotGeometry::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => xs_blocks
+                .iter()
+                .zip(&ys_blocks)
+                .flat_map(|(xs, ys)| xs.iter().copied().zip(ys.iter().copied()))
+                .collect(),
+        };
+        rows.extend(points.into_iter().enumerate().map(|(index, (x, y))| Row {
+            series: item.name().to_owned(),
+            index,
+            x,
+            y,
+        }));
+    }
+    rows
+}
+
+/// Sort by `column`, keeping the plot order of equal rows.
+fn sort_rows(rows: &mut [Row], (column, ascending): (Column, bool)) {
+    rows.sort_by(|a, b| {
+        let ordering = match column {
+            Column::Series => a.series.cmp(&b.series),
+            Column::Index => a.index.cmp(&b.index),
+            Column::X => a.x.total_cmp(&b.x),
+            Column::Y => a.y.total_cmp(&b.y),
+        };
+        if ascending {
+            ordering
+        } else {
+            ordering.reverse()
+        }
+    });
+}
+
+/// Show `rows` in `rect`, with clickable headers changing `sort`.
+pub(crate) fn table_ui(
+    ui: &mut Ui,
+    rect: Rect,
+    mut rows: Vec<Row>,
+    sort: &mut Option<(Column, bool)>,
+) {
+    if let Some(sort) = *sort {
+        sort_rows(&mut rows, sort);
+    }
+
+    let mut ui = ui.new_child(
+        UiBuilder::new()
+            .max_rect(rect)
+            .layout(Layout::top_down(Align::Min)),
+    );
+    let column_width = (ui.available_width() / Column::ALL.len() as f32).floor();
+    let row_height = ui.text_style_height(&TextStyle::Body) + ui.spacing().item_spacing.y;
+    ui.spacing_mut().item_spacing.x = 0.0;
+
+    ui.horizontal(|ui| {
+        for column in Column::ALL {
+            let ascending = match *sort {
+                Some((sorted, ascending)) if sorted == column => Some(ascending),
+                _ => None,
+            };
+            let title = match ascending {
+                Some(true) => format!("{} ⏶", column.title()),
+                Some(false) => format!("{} ⏷", column.title()),
+                None => column.title().to_owned(),
+            };
+            let header = ui
+                .add_sized(
+                    [column_width, row_height],
+                    Button::selectable(ascending.is_some(), title),
+                )
+                .on_hover_text(format!("Sort by {}", column.title()));
+            if header.clicked() {
+                // Ascending first, then descending:
+                *sort = Some((column, ascending != Some(true)));
+            }
+        }
+    });
+    ui.separator();
+
+    ScrollArea::vertical().auto_shrink(false).show_rows(
+        &mut ui,
+        row_height,
+        rows.len(),
+        |ui, range| {
+            for row in &rows[range] {
+                ui.horizontal(|ui| {
+                    for column in Column::ALL {
+                        ui.add_sized([column_width, row_height], Label::new(row.cell(column)));
+                    }
+                });
+            }
+        },
+    );
+}
+
+#[test]
+fn test_sort_rows() {
+    let row = |series: &str, index, y| Row {
+        series: series.to_owned(),
+        index,
+        x: index as f64,
+        y,
+    };
+    let mut rows = vec![row("b", 0, 2.0), row("a", 0, 3.0), row("b", 1, 1.0)];
+
+    sort_rows(&mut rows, (Column::Y, true));
+    let ys: Vec<f64> = rows.iter().map(|row| row.y).collect();
+    assert_eq!(ys, [1.0, 2.0, 3.0]);
+
+    // Equal series keep their order:
+    sort_rows(&mut rows, (Column::Series, false));
+    let cells: Vec<String> = rows
+        .iter()
+        .map(|row| format!("{}{}", row.series, row.index))
+        .collect();
+    assert_eq!(cells, ["b1", "b0", "a0"]);
+}
@@ -0,0 +1,268 @@
+//! Interactive editing of plot item data by dragging points, lines and spans.
+
+use egui::{CursorIcon, Id, Pos2};
+
+use crate::{
+    Axis, Interval, PlotGeometry, PlotItem, PlotPoint, PlotTransform, items::ItemInteraction,
+};
+
+/// How close (in points) the pointer must be to a point to grab it.
+pub(crate) const GRAB_RADIUS: f32 = 8.0;
+
+/// Configures how the points of an editable [`Line`](crate::Line) or
+/// [`Scatter`](crate::Scatter) can be dragged.
+///
+/// The plot never mutates your data. Instead it emits
+/// [`PlotEvent::PointEdited`](crate::PlotEvent::PointEdited) while a point is dragged, which you
+/// apply to your own data before the next frame.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, Plot, PlotEvent, PointEdit};
+///
+/// let mut keyframes = vec![[0.0, 0.0], [1.0, 0.5], [2.0, 1.0]];
+/// let line = Line::new("keyframes", keyframes.clone()).editable(PointEdit::new());
+/// let response = Plot::new("keyframe_editor").show(ui, |plot_ui| plot_ui.line(line));
+/// for event in &response.events {
+///     if let PlotEvent::PointEdited { index, new, .. } = event {
+///         keyframes[*index] = [new.x, new.y];
+///     }
+/// }
+/// # });
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct PointEdit {
+    y_only: bool,
+    snap: Option<[f64; 2]>,
+}
+
+impl PointEdit {
+    /// Points can be dragged freely in both directions.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only allow changing the y-value of a point. Default: `false`.
+    #[inline]
+    pub fn y_only(mut self, y_only: bool) -> Self {
+        self.y_only = y_only;
+        self
+    }
+
+    /// Round the edited coordinates to multiples of the given x and y step.
+    /// A step of `0.0` disables snapping for that axis.
+    #[inline]
+    pub fn snap_to_grid(mut self, step: [f64; 2]) -> Self {
+        self.snap = Some(step);
+        self
+    }
+
+    /// Where a point at `original` ends up when dragged to `pointer`.
+    pub(crate) fn apply(&self, original: PlotPoint, pointer: PlotPoint) -> PlotPoint {
+        let mut new = if self.y_only {
+            PlotPoint::new(original.x, pointer.y)
+        } else {
+            pointer
+        };
+        if let Some([step_x, step_y]) = self.snap {
+            if step_x > 0.0 && !self.y_only {
+                new.x = (new.x / step_x).round() * step_x;
+            }
+            if step_y > 0.0 {
+                new.y = (new.y / step_y).round() * step_y;
+            }
+        }
+        new
+    }
+}
+
+/// The value at `index` in the (flattened) geometry of an item.
+fn point_at(geometry: PlotGeometry<'_>, index: usize) -> Option<PlotPoint> {
+    match geometry {
+        PlotGeometry::Points(points) => points.get(index).copied(),
+        PlotGeometry::PointsXY { xs, ys } => Some(PlotPoint::new(*xs.get(index)?, *ys.get(index)?)),
+        PlotGeometry::BlocksXY {
+            xs_blocks,
+            ys_blocks,
+        } => {
+            let mut index = index;
+            for (xs, ys) in xs_blocks.iter().zip(&ys_blocks) {
+                let n = xs.len().min(ys.len());
+                if index < n {
+                    return Some(PlotPoint::new(xs[index], ys[index]));
+                }
+                index -= n;
+            }
+            None
+        }
+        PlotGeometry::None | PlotGeometry::Rects => None,
+    }
+}
+
+/// Finds the editable point under `pointer`, topmost item first.
+pub(crate) fn find_editable_point(
+    items: &[Box<dyn PlotItem + '_>],
+    pointer: Pos2,
+    transform: &PlotTransform,
+) -> Option<(Id, usize)> {
+    items.iter().rev().find_map(|item| {
+        point_edit(&**item)?;
+        let elem = item.find_closest(pointer, transform)?;
+        (elem.dist_sq <= GRAB_RADIUS * GRAB_RADIUS).then_some((item.id(), elem.index))
+    })
+}
+
+/// How the points of `item` are edited, if they can be dragged.
+fn point_edit(item: &dyn PlotItem) -> Option<PointEdit> {
+    match item.interaction() {
+        ItemInteraction::EditPoints(edit) => Some(edit),
+        _ => None,
+    }
+}
+
+/// The new value of a dragged point, or `None` if the item or point is gone.
+pub(crate) fn dragged_value(
+    items: &[Box<dyn PlotItem + '_>],
+    (item_id, index): (Id, usize),
+    pointer: Pos2,
+    transform: &PlotTransform,
+) -> Option<PlotPoint> {
+    let item = items.iter().find(|item| item.id() == item_id)?;
+    let edit = point_edit(&**item)?;
+    let original = point_at(item.geometry(), index)?;
+    Some(edit.apply(original, transform.value_from_position(pointer)))
+}
+
+/// Which part of a draggable line or span is grabbed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum DragHandle {
+    Start,
+    End,
+    Whole,
+}
+
+/// A line or span being dragged, see [`PlotItem::drag_interval`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct ItemDrag {
+    pub(crate) item: Id,
+    axis: Axis,
+    handle: DragHandle,
+
+    /// The interval of the item when it was grabbed.
+    original: Interval,
+
+    /// The value on `axis` where the item was grabbed.
+    grab: f64,
+}
+
+impl ItemDrag {
+    /// The interval of the item when the pointer is at `pointer`.
+    pub(crate) fn interval(&self, pointer: PlotPoint) -> Interval {
+        let value = match self.axis {
+            Axis::X => pointer.x,
+            Axis::Y => pointer.y,
+        };
+        let delta = value - self.grab;
+        let Interval { start, end } = self.original;
+        match self.handle {
+            DragHandle::Start => Interval::new(start + delta, end),
+            DragHandle::End => Interval::new(start, end + delta),
+            DragHandle::Whole => Interval::new(start + delta, end + delta),
+        }
+    }
+
+    pub(crate) fn cursor(&self) -> CursorIcon {
+        match (self.handle, self.axis) {
+            (DragHandle::Whole, _) if !self.original.is_empty() => CursorIcon::Move,
+            (_, Axis::X) => CursorIcon::ResizeHorizontal,
+            (_, Axis::Y) => CursorIcon::ResizeVertical,
+        }
+    }
+}
+
+/// Finds the draggable line or span under `pointer`, topmost item first.
+///
+/// Edges are grabbed within [`GRAB_RADIUS`], the inside of a span moves all of it.
+pub(crate) fn find_draggable_item(
+    items: &[Box<dyn PlotItem + '_>],
+    pointer: Pos2,
+    transform: &PlotTransform,
+) -> Option<ItemDrag> {
+    let value = transform.value_from_position(pointer);
+    items.iter().rev().find_map(|item| {
+        let ItemInteraction::Drag { axis, interval } = item.interaction() else {
+            return None;
+        };
+        let (pointer, grab) = match axis {
+            Axis::X => (pointer.x, value.x),
+            Axis::Y => (pointer.y, value.y),
+        };
+        let screen = |value: f64| match axis {
+            Axis::X => transform.position_from_point_x(value),
+            Axis::Y => transform.position_from_point_y(value),
+        };
+        let near = |value: f64| value.is_finite() && (screen(value) - pointer).abs() <= GRAB_RADIUS;
+        let handle = if interval.is_empty() {
+            near(interval.start).then_some(DragHandle::Whole)?
+        } else if near(interval.start) {
+            DragHandle::Start
+        } else if near(interval.end) {
+            DragHandle::End
+        } else if interval.contains(grab) {
+            DragHandle::Whole
+        } else {
+            return None;
+        };
+        Some(ItemDrag {
+            item: item.id(),
+            axis,
+            handle,
+            original: interval,
+            grab,
+        })
+    })
+}
+
+#[test]
+fn test_point_edit_apply() {
+    let original = PlotPoint::new(1.0, 2.0);
+    let pointer = PlotPoint::new(1.26, 2.74);
+
+    let free = PointEdit::new().apply(original, pointer);
+    assert_eq!((free.x, free.y), (1.26, 2.74));
+
+    let snapped = PointEdit::new()
+        .y_only(true)
+        .snap_to_grid([0.5, 0.5])
+        .apply(original, pointer);
+    assert_eq!((snapped.x, snapped.y), (1.0, 2.5));
+}
+
+#[test]
+fn test_item_drag_interval() {
+    let drag = |handle| ItemDrag {
+        item: Id::NULL,
+        axis: Axis::Y,
+        handle,
+        original: Interval::new(1.0, 3.0),
+        grab: 2.0,
+    };
+    let pointer = PlotPoint::new(10.0, 2.5);
+    assert_eq!(
+        drag(DragHandle::Start).interval(pointer),
+        Interval::new(1.5, 3.0)
+    );
+    assert_eq!(
+        drag(DragHandle::End).interval(pointer),
+        Interval::new(1.0, 3.5)
+    );
+    assert_eq!(
+        drag(DragHandle::Whole).interval(pointer),
+        Interval::new(1.5, 3.5)
+    );
+    // Dragging the start past the end swaps them:
+    assert_eq!(
+        drag(DragHandle::Start).interval(PlotPoint::new(0.0, 5.0)),
+        Interval::new(3.0, 4.0)
+    );
+}
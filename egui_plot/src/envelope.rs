@@ -0,0 +1,221 @@
+//! Envelope editor preset: a piecewise-linear or step-and-hold curve whose vertices can be
+//! dragged, added with a double click and removed with a right click.
+
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Shape, Stroke, Ui};
+
+use crate::{
+    PlotBounds, PlotEvent, PlotGeometry, PlotItem, PlotItemBase, PlotPoint, PlotResponse,
+    PlotTransform, PlotUi, PointEdit, edit::GRAB_RADIUS, items::ItemInteraction,
+};
+
+/// An editable envelope: a piecewise-linear or step-and-hold curve whose vertices can be dragged,
+/// added with a double click and removed with a right click.
+///
+/// The plot does not own your data, so the editor is rebuilt from your points every frame and
+/// the user's edits are applied afterwards with [`EnvelopeEditor::apply`].
+///
+/// The plot reacts to the same clicks itself, so turn off its double-click reset and context
+/// menu with [`crate::Plot::allow_double_click_reset`] and [`crate::Plot::show_context_menu`],
+/// as below. Otherwise adding a vertex also resets the view, and removing one opens the menu.
+///
+/// # Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{EnvelopeEditor, Plot, PlotPoint};
+///
+/// let mut points = vec![PlotPoint::new(0.0, 0.0), PlotPoint::new(0.1, 1.0), PlotPoint::new(1.0, 0.0)];
+///
+/// let editor = EnvelopeEditor::new("attack", &points).snap_to_grid([0.01, 0.05]);
+/// let response = Plot::new("envelope")
+///     .allow_drag(false)
+///     .allow_double_click_reset(false)
+///     .show_context_menu(false)
+///     .show(ui, |plot_ui| plot_ui.envelope(editor.clone()));
+/// editor.apply(&mut points, &response);
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct EnvelopeEditor {
+    base: PlotItemBase,
+
+    /// Vertices, sorted by x.
+    points: Vec<PlotPoint>,
+
+    /// Hold each value until the next vertex instead of interpolating linearly.
+    step: bool,
+
+    edit: PointEdit,
+    stroke: Stroke,
+}
+
+impl EnvelopeEditor {
+    /// An editor showing a copy of `points`, which should be sorted by x.
+    pub fn new(name: impl Into<String>, points: &[PlotPoint]) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            points: points.to_vec(),
+            step: false,
+            edit: PointEdit::new(),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+        }
+    }
+
+    /// Draw a step-and-hold envelope instead of a piecewise-linear one. Default: `false`.
+    #[inline]
+    pub fn step(mut self, step: bool) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Round dragged and added vertices to multiples of the given x and y step.
+    #[inline]
+    pub fn snap_to_grid(mut self, step: [f64; 2]) -> Self {
+        self.edit = self.edit.snap_to_grid(step);
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Stroke width. Default: `1.5`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Apply this frame's edits of the plot shown in `response` to `points`.
+    ///
+    /// * Dragged vertices move, but never past their neighbors.
+    /// * A double click adds a vertex (disable [`crate::Plot::allow_double_click_reset`]).
+    /// * A right click on a vertex removes it (disable [`crate::Plot::show_context_menu`]); the
+    ///   last vertex is never removed.
+    ///
+    /// Returns `true` if `points` changed.
+    pub fn apply<R>(&self, points: &mut Vec<PlotPoint>, response: &PlotResponse<R>) -> bool {
+        let mut changed = false;
+
+        for event in &response.events {
+            if let PlotEvent::PointEdited { item, index, new } = event {
+                if *item != self.id() || *index >= points.len() {
+                    continue;
+                }
+                let min_x = index
+                    .checked_sub(1)
+                    .map_or(f64::NEG_INFINITY, |i| points[i].x);
+                let max_x = points.get(index + 1).map_or(f64::INFINITY, |p| p.x);
+                points[*index] = PlotPoint::new(new.x.clamp(min_x, max_x), new.y);
+                changed = true;
+            }
+        }
+
+        let transform = &response.transform;
+        let pointer = response.response.interact_pointer_pos();
+        if let Some(pointer) = pointer {
+            if response.response.double_clicked() {
+                let value = transform.value_from_position(pointer);
+                let new = self.edit.apply(value, value);
+                let index = points.partition_point(|p| p.x < new.x);
+                points.insert(index, new);
+                changed = true;
+            } else if response.response.secondary_clicked() && points.len() > 1 {
+                let closest = points
+                    .iter()
+                    .map(|p| transform.position_from_point(p).distance_sq(pointer))
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.total_cmp(b));
+                if let Some((index, dist_sq)) = closest {
+                    if dist_sq <= GRAB_RADIUS * GRAB_RADIUS {
+                        points.remove(index);
+                        changed = true;
+                    }
+                }
+            }
+        }
+
+        changed
+    }
+
+    /// The drawn path through the vertices.
+    fn path(&self) -> Vec<PlotPoint> {
+        if !self.step {
+            return self.points.clone();
+        }
+        let mut path = Vec::with_capacity(2 * self.points.len());
+        for (i, point) in self.points.iter().enumerate() {
+            if i > 0 {
+                path.push(PlotPoint::new(point.x, self.points[i - 1].y));
+            }
+            path.push(*point);
+        }
+        path
+    }
+}
+
+impl PlotItem for EnvelopeEditor {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.highlighted() {
+            stroke.width *= 2.0;
+        }
+        let path: Vec<_> = self
+            .path()
+            .iter()
+            .map(|p| transform.position_from_point(p))
+            .collect();
+        shapes.push(Shape::line(path, stroke));
+
+        for point in &self.points {
+            let pos = transform.position_from_point(point);
+            shapes.push(Shape::circle_filled(pos, 4.0, self.stroke.color));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.points)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in &self.points {
+            bounds.extend_with(point);
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+
+    fn interaction(&self) -> ItemInteraction<'_> {
+        ItemInteraction::EditPoints(self.edit)
+    }
+}
+
+impl PlotUi<'_> {
+    /// Add an [`EnvelopeEditor`] to the plot.
+    ///
+    /// If no color is set, one will be chosen automatically.
+    pub fn envelope(&mut self, mut envelope: EnvelopeEditor) {
+        if envelope.stroke.color == Color32::TRANSPARENT {
+            envelope.stroke.color = self.auto_color();
+        }
+        self.add(envelope);
+    }
+}
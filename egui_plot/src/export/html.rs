@@ -0,0 +1,289 @@
+//! Export of a plot as a standalone HTML page with a small interactive canvas chart.
+//!
+//! The data is embedded as JSON and drawn by `plot.js`, so the page works offline.
+
+use egui::{Color32, Stroke};
+
+use super::ExportShape;
+use crate::{Axis, LineStyle, PlotItem, PlotTransform};
+
+/// The renderer embedded in every page.
+const SCRIPT: &str = include_str!("plot.js");
+
+/// A standalone HTML page showing `items`, see [`crate::Plot::export_html`].
+pub(crate) fn html_page(
+    items: &[Box<dyn PlotItem + '_>],
+    transform: &PlotTransform,
+    title: &str,
+    [x_label, y_label]: [&str; 2],
+    decimated: bool,
+) -> String {
+    let bounds = transform.bounds();
+    let inverted = transform.inverted();
+    let series: Vec<String> = items
+        .iter()
+        .filter_map(|item| {
+            let shapes: Vec<String> = super::item_shapes(&**item, transform, decimated)?
+                .iter()
+                .map(shape_json)
+                .collect();
+            Some(format!(
+                "{{\"name\":{},\"shapes\":[{}]}}",
+                json_str(item.name()),
+                shapes.join(",")
+            ))
+        })
+        .collect();
+    let data = format!(
+        "{{\"title\":{},\"xLabel\":{},\"yLabel\":{},\"bounds\":{},\"inverted\":[{},{}],\"series\":[{}]}}",
+        json_str(title),
+        json_str(x_label),
+        json_str(y_label),
+        json_array(&[
+            bounds.min()[0],
+            bounds.max()[0],
+            bounds.min()[1],
+            bounds.max()[1]
+        ]),
+        inverted.x,
+        inverted.y,
+        series.join(",\n"),
+    );
+    let page_title = if title.is_empty() { "Plot" } else { title };
+    format!(
+        "<!DOCTYPE html>
+<html>
+<head>
+<meta charset=\"utf-8\">
+<title>{}</title>
+<style>
+html, body {{ margin: 0; height: 100%; font-family: sans-serif; }}
+canvas {{ display: block; width: 100%; height: 100%; }}
+</style>
+</head>
+<body>
+<canvas id=\"plot\"></canvas>
+<script>
+const PLOT = {data};
+{SCRIPT}</script>
+</body>
+</html>
+",
+        html_escape(page_title)
+    )
+}
+
+/// A JSON object describing `shape` for `plot.js`.
+fn shape_json(shape: &ExportShape) -> String {
+    match shape {
+        ExportShape::Line {
+            xs,
+            ys,
+            stroke,
+            style,
+            marker,
+        } => format!(
+            "{{\"kind\":\"line\",\"xs\":{},\"ys\":{},{},\"dash\":{},\"markers\":{}}}",
+            json_array(xs),
+            json_array(ys),
+            stroke_json(*stroke),
+            dash_json(*style),
+            marker.is_some(),
+        ),
+        ExportShape::Markers {
+            xs,
+            ys,
+            color,
+            radius,
+            filled,
+            ..
+        } => format!(
+            "{{\"kind\":\"markers\",\"xs\":{},\"ys\":{},\"color\":{},\"radius\":{},\"filled\":{filled}}}",
+            json_array(xs),
+            json_array(ys),
+            json_color(*color),
+            json_float(f64::from(*radius)),
+        ),
+        ExportShape::FillBetween {
+            xs,
+            lower,
+            upper,
+            color,
+        } => format!(
+            "{{\"kind\":\"fill\",\"xs\":{},\"lower\":{},\"upper\":{},\"color\":{}}}",
+            json_array(xs),
+            json_array(lower),
+            json_array(upper),
+            json_color(*color),
+        ),
+        ExportShape::Polygon { xs, ys, color } => format!(
+            "{{\"kind\":\"polygon\",\"xs\":{},\"ys\":{},\"color\":{}}}",
+            json_array(xs),
+            json_array(ys),
+            json_color(*color),
+        ),
+        ExportShape::Rule {
+            axis,
+            value,
+            stroke,
+            style,
+        } => format!(
+            "{{\"kind\":\"rule\",\"axis\":{},\"value\":{},{},\"dash\":{}}}",
+            axis_json(*axis),
+            json_float(*value),
+            stroke_json(*stroke),
+            dash_json(*style),
+        ),
+        ExportShape::Span {
+            axis,
+            interval,
+            clip,
+            color,
+            stroke,
+        } => format!(
+            "{{\"kind\":\"span\",\"axis\":{},\"start\":{},\"end\":{},\"clip_start\":{},\"clip_end\":{},\"color\":{},\"stroke\":{}}}",
+            axis_json(*axis),
+            json_float(interval.start),
+            json_float(interval.end),
+            json_float(clip.start),
+            json_float(clip.end),
+            json_color(*color),
+            stroke.map_or_else(
+                || "null".to_owned(),
+                |stroke| format!("{{{}}}", stroke_json(stroke))
+            ),
+        ),
+        ExportShape::Bars {
+            horizontal,
+            arguments,
+            values,
+            bases,
+            widths,
+            color,
+        } => format!(
+            "{{\"kind\":\"bars\",\"horizontal\":{horizontal},\"arguments\":{},\"values\":{},\"bases\":{},\"widths\":{},\"color\":{}}}",
+            json_array(arguments),
+            json_array(values),
+            json_array(bases),
+            json_array(widths),
+            json_color(*color),
+        ),
+        ExportShape::Text { x, y, text, color } => format!(
+            "{{\"kind\":\"text\",\"x\":{},\"y\":{},\"text\":{},\"color\":{}}}",
+            json_float(*x),
+            json_float(*y),
+            json_str(text),
+            json_color(*color),
+        ),
+    }
+}
+
+/// The `color` and `width` members of a stroke.
+fn stroke_json(stroke: Stroke) -> String {
+    format!(
+        "\"color\":{},\"width\":{}",
+        json_color(stroke.color),
+        json_float(f64::from(stroke.width))
+    )
+}
+
+fn axis_json(axis: Axis) -> &'static str {
+    match axis {
+        Axis::X => "\"x\"",
+        Axis::Y => "\"y\"",
+    }
+}
+
+/// A canvas `setLineDash` pattern, matching how the plot draws the line style.
+fn dash_json(style: LineStyle) -> String {
+    match style {
+        LineStyle::Solid => "[]".to_owned(),
+        LineStyle::Dotted { spacing } => json_array(&[1.0, f64::from(spacing)]),
+        LineStyle::Dashed { length } => {
+            let golden = (5.0_f64.sqrt() - 1.0) / 2.0;
+            json_array(&[f64::from(length), f64::from(length) * golden])
+        }
+    }
+}
+
+/// A JSON number, or `null` for NaN and infinities.
+fn json_float(value: f64) -> String {
+    if value.is_finite() {
+        format!("{value:?}")
+    } else {
+        "null".to_owned()
+    }
+}
+
+fn json_array(values: &[f64]) -> String {
+    let values: Vec<String> = values.iter().map(|&v| json_float(v)).collect();
+    format!("[{}]", values.join(","))
+}
+
+/// A JSON string literal that is also safe inside a `<script>` element.
+fn json_str(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    escaped.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '<' | '>' | '&' => escaped += &format!("\\u{:04x}", u32::from(c)),
+            c if c.is_control() => escaped += &format!("\\u{:04x}", u32::from(c)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// A CSS color string, or `null` for [`Color32::TRANSPARENT`] (auto-color).
+fn json_color(color: Color32) -> String {
+    if color == Color32::TRANSPARENT {
+        return "null".to_owned();
+    }
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    format!("\"rgba({r},{g},{b},{})\"", json_float(f64::from(a) / 255.0))
+}
+
+fn html_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[test]
+fn test_json_literals() {
+    assert_eq!(json_float(f64::NAN), "null");
+    assert_eq!(json_array(&[0.5, f64::INFINITY]), "[0.5,null]");
+    assert_eq!(
+        json_str("a \"b\"\n</script>"),
+        "\"a \\\"b\\\"\\n\\u003c/script\\u003e\""
+    );
+    assert_eq!(
+        json_color(Color32::from_rgb(255, 0, 16)),
+        "\"rgba(255,0,16,1.0)\""
+    );
+    assert_eq!(json_color(Color32::TRANSPARENT), "null");
+}
+
+#[test]
+fn test_html_page() {
+    use crate::{HLine, Line, PlotBounds};
+
+    let transform = PlotTransform::new(
+        egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0)),
+        PlotBounds::from_min_max([0.0, -1.0], [2.0, 1.0]),
+        false,
+    );
+    let items: Vec<Box<dyn PlotItem>> = vec![
+        Box::new(Line::new("sin", vec![[0.0, 0.0], [1.0, f64::NAN]]).color(Color32::RED)),
+        Box::new(HLine::new("", 0.5).color(Color32::BLUE)),
+    ];
+    let page = html_page(&items, &transform, "a < b", ["t", ""], false);
+    assert!(page.contains("<title>a &lt; b</title>"));
+    assert!(page.contains("\"bounds\":[0.0,2.0,-1.0,1.0]"));
+    assert!(page.contains("{\"kind\":\"line\",\"xs\":[0.0,1.0],\"ys\":[0.0,null],"));
+    assert!(page.contains("{\"kind\":\"rule\",\"axis\":\"y\",\"value\":0.5,"));
+}
@@ -0,0 +1,342 @@
+//! Export of a plot as a Python script drawing the same items with matplotlib.
+
+use egui::Color32;
+
+use super::ExportShape;
+use crate::{Axis, Interval, LineStyle, MarkerShape, PlotItem, PlotTransform};
+
+/// A Python script reproducing `items` with matplotlib, see [`crate::Plot::export_matplotlib`].
+pub(crate) fn matplotlib_script(
+    items: &[Box<dyn PlotItem + '_>],
+    transform: &PlotTransform,
+    title: &str,
+    [x_label, y_label]: [&str; 2],
+    decimated: bool,
+) -> String {
+    let mut script = String::from(
+        "import matplotlib.pyplot as plt\nimport numpy as np\n\nfig, ax = plt.subplots()\n",
+    );
+
+    // Limits first, so that items spanning the whole plot can refer to them:
+    let bounds = transform.bounds();
+    let inverted = transform.inverted();
+    let limits = |min: f64, max: f64, inverted: bool| {
+        if inverted {
+            format!("{}, {}", py_float(max), py_float(min))
+        } else {
+            format!("{}, {}", py_float(min), py_float(max))
+        }
+    };
+    script += &format!(
+        "ax.set_xlim({})\n",
+        limits(bounds.min()[0], bounds.max()[0], inverted.x)
+    );
+    script += &format!(
+        "ax.set_ylim({})\n",
+        limits(bounds.min()[1], bounds.max()[1], inverted.y)
+    );
+    if !title.is_empty() {
+        script += &format!("ax.set_title({})\n", py_str(title));
+    }
+    if !x_label.is_empty() {
+        script += &format!("ax.set_xlabel({})\n", py_str(x_label));
+    }
+    if !y_label.is_empty() {
+        script += &format!("ax.set_ylabel({})\n", py_str(y_label));
+    }
+    script.push('\n');
+
+    for item in items {
+        let Some(shapes) = super::item_shapes(&**item, transform, decimated) else {
+            script += &format!("# {} can't be exported\n", py_str(item.name()));
+            continue;
+        };
+        // One legend entry per item:
+        let mut label = item.name();
+        for shape in &shapes {
+            script += &shape_code(shape, label);
+            label = "";
+        }
+    }
+
+    if items.iter().any(|item| !item.name().is_empty()) {
+        script.push_str("\nax.legend()\n");
+    }
+    script.push_str("plt.show()\n");
+    script
+}
+
+/// The matplotlib call drawing `shape`.
+fn shape_code(shape: &ExportShape, label: &str) -> String {
+    match shape {
+        ExportShape::Line {
+            xs,
+            ys,
+            stroke,
+            style,
+            marker,
+        } => {
+            let mut kwargs = Kwargs::new(label, stroke.color);
+            kwargs.push("linestyle", py_str(line_style(*style)));
+            kwargs.push("linewidth", py_float(f64::from(stroke.width)));
+            if let Some(marker) = marker {
+                kwargs.push("marker", marker_code(*marker));
+            }
+            format!("ax.plot({}, {}{kwargs})\n", py_array(xs), py_array(ys))
+        }
+        ExportShape::Markers {
+            xs,
+            ys,
+            color,
+            shape,
+            radius,
+            filled,
+        } => {
+            let mut kwargs = Kwargs::new(label, *color);
+            kwargs.push("marker", marker_code(*shape));
+            // Marker area in points², the radius is in (egui) points:
+            kwargs.push("s", py_float(f64::from(radius * radius * 4.0)));
+            if !filled {
+                kwargs.push("facecolors", py_str("none"));
+                kwargs.push("edgecolors", py_color(*color));
+            }
+            format!("ax.scatter({}, {}{kwargs})\n", py_array(xs), py_array(ys))
+        }
+        ExportShape::FillBetween {
+            xs,
+            lower,
+            upper,
+            color,
+        } => format!(
+            "ax.fill_between({}, {}, {}{})\n",
+            py_array(xs),
+            py_array(lower),
+            py_array(upper),
+            Kwargs::new(label, *color),
+        ),
+        ExportShape::Polygon { xs, ys, color } => format!(
+            "ax.fill({}, {}{})\n",
+            py_array(xs),
+            py_array(ys),
+            Kwargs::new(label, *color),
+        ),
+        ExportShape::Rule {
+            axis,
+            value,
+            stroke,
+            style,
+        } => {
+            let mut kwargs = Kwargs::new(label, stroke.color);
+            kwargs.push("linestyle", py_str(line_style(*style)));
+            kwargs.push("linewidth", py_float(f64::from(stroke.width)));
+            let function = match axis {
+                Axis::X => "axvline",
+                Axis::Y => "axhline",
+            };
+            format!("ax.{function}({}{kwargs})\n", py_float(*value))
+        }
+        ExportShape::Span {
+            axis,
+            interval,
+            clip,
+            color,
+            stroke,
+        } => {
+            let mut kwargs = Kwargs::new(label, *color);
+            if let Some(stroke) = stroke {
+                kwargs.push("edgecolor", py_color(stroke.color));
+                kwargs.push("linewidth", py_float(f64::from(stroke.width)));
+            }
+            let (function, limits, clip_limits) = match axis {
+                Axis::X => ("axvspan", "ax.get_xlim()", "ax.get_ylim()"),
+                Axis::Y => ("axhspan", "ax.get_ylim()", "ax.get_xlim()"),
+            };
+            // Infinite ends reach the edge of the axes:
+            let end = |value: f64, limits: &str, edge: usize| {
+                if value.is_finite() {
+                    py_float(value)
+                } else {
+                    format!("{limits}[{edge}]")
+                }
+            };
+            let (start, stop) = (end(interval.start, limits, 0), end(interval.end, limits, 1));
+            if *clip == Interval::all() {
+                format!("ax.{function}({start}, {stop}{kwargs})\n")
+            } else {
+                // A rectangle, filled between the ends of `clip`:
+                let function = match axis {
+                    Axis::X => "fill_betweenx",
+                    Axis::Y => "fill_between",
+                };
+                format!(
+                    "ax.{function}([{}, {}], {start}, {stop}{kwargs})\n",
+                    end(clip.start, clip_limits, 0),
+                    end(clip.end, clip_limits, 1),
+                )
+            }
+        }
+        ExportShape::Bars {
+            horizontal,
+            arguments,
+            values,
+            bases,
+            widths,
+            color,
+        } => {
+            let mut kwargs = Kwargs::new(label, *color);
+            let (function, width, base) = if *horizontal {
+                ("barh", "height", "left")
+            } else {
+                ("bar", "width", "bottom")
+            };
+            kwargs.push(width, py_array(widths));
+            kwargs.push(base, py_array(bases));
+            format!(
+                "ax.{function}({}, {}{kwargs})\n",
+                py_array(arguments),
+                py_array(values)
+            )
+        }
+        ExportShape::Text { x, y, text, color } => format!(
+            "ax.text({}, {}, {}{})\n",
+            py_float(*x),
+            py_float(*y),
+            py_str(text),
+            Kwargs::new("", *color),
+        ),
+    }
+}
+
+/// Keyword arguments of a matplotlib call, starting with `color` and `label`.
+struct Kwargs(String);
+
+impl Kwargs {
+    fn new(label: &str, color: Color32) -> Self {
+        let mut kwargs = Self(String::new());
+        if color != Color32::TRANSPARENT {
+            kwargs.push("color", py_color(color));
+        }
+        if !label.is_empty() {
+            kwargs.push("label", py_str(label));
+        }
+        kwargs
+    }
+
+    fn push(&mut self, key: &str, value: impl std::fmt::Display) {
+        self.0 += &format!(", {key}={value}");
+    }
+}
+
+impl std::fmt::Display for Kwargs {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// A Python float literal.
+fn py_float(value: f64) -> String {
+    if value.is_nan() {
+        "np.nan".to_owned()
+    } else if value == f64::INFINITY {
+        "np.inf".to_owned()
+    } else if value == f64::NEG_INFINITY {
+        "-np.inf".to_owned()
+    } else {
+        format!("{value:?}")
+    }
+}
+
+/// A numpy array literal.
+fn py_array(values: &[f64]) -> String {
+    let values: Vec<String> = values.iter().map(|&v| py_float(v)).collect();
+    format!("np.array([{}])", values.join(", "))
+}
+
+/// A Python string literal.
+fn py_str(text: &str) -> String {
+    format!("{text:?}")
+}
+
+/// A matplotlib `#rrggbbaa` color.
+fn py_color(color: Color32) -> String {
+    let [r, g, b, a] = color.to_srgba_unmultiplied();
+    format!("\"#{r:02x}{g:02x}{b:02x}{a:02x}\"")
+}
+
+fn line_style(style: LineStyle) -> &'static str {
+    match style {
+        LineStyle::Solid => "-",
+        LineStyle::Dotted { .. } => ":",
+        LineStyle::Dashed { .. } => "--",
+    }
+}
+
+/// The matplotlib marker of a [`MarkerShape`].
+fn marker_code(shape: MarkerShape) -> String {
+    let code = match shape {
+        MarkerShape::Circle => "o",
+        MarkerShape::Diamond => "D",
+        MarkerShape::Square => "s",
+        MarkerShape::Cross => "x",
+        MarkerShape::Plus => "+",
+        MarkerShape::Up => "^",
+        MarkerShape::Down => "v",
+        MarkerShape::Left => "<",
+        MarkerShape::Right => ">",
+        MarkerShape::Asterisk => "*",
+        MarkerShape::Point => ".",
+        MarkerShape::Pixel => ",",
+        MarkerShape::PlusFilled => "P",
+        MarkerShape::XFilled => "X",
+        MarkerShape::ThinDiamond => "d",
+        MarkerShape::Pentagon => "p",
+        MarkerShape::Hexagon1 => "h",
+        MarkerShape::Hexagon2 => "H",
+        MarkerShape::Octagon => "8",
+        MarkerShape::VLine => "|",
+        MarkerShape::HLine => "_",
+        MarkerShape::RegularPolygon { n, angle_deg } => return format!("({n}, 0, {angle_deg})"),
+        MarkerShape::StarPolygon { n, angle_deg, .. } => return format!("({n}, 1, {angle_deg})"),
+    };
+    py_str(code)
+}
+
+#[test]
+fn test_python_literals() {
+    assert_eq!(py_float(1.0), "1.0");
+    assert_eq!(py_float(f64::NEG_INFINITY), "-np.inf");
+    assert_eq!(py_array(&[0.5, f64::NAN]), "np.array([0.5, np.nan])");
+    assert_eq!(py_str("a \"b\""), "\"a \\\"b\\\"\"");
+    assert_eq!(
+        py_color(Color32::from_rgb(255, 0, 16)),
+        "\"#ff0010ff\"".to_owned()
+    );
+    assert_eq!(
+        marker_code(MarkerShape::RegularPolygon { n: 5, angle_deg: 0 }),
+        "(5, 0, 0)"
+    );
+}
+
+#[test]
+fn test_matplotlib_script() {
+    use crate::{HLine, Line, PlotBounds};
+
+    let transform = PlotTransform::new(
+        egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0)),
+        PlotBounds::from_min_max([0.0, -1.0], [2.0, 1.0]),
+        false,
+    );
+    let items: Vec<Box<dyn PlotItem>> = vec![
+        Box::new(Line::new("sin", vec![[0.0, 0.0], [1.0, 0.5]]).color(Color32::RED)),
+        Box::new(HLine::new("", 0.5).color(Color32::BLUE)),
+    ];
+    let script = matplotlib_script(&items, &transform, "", ["t", ""], false);
+    assert!(script.contains("ax.set_xlim(0.0, 2.0)\n"));
+    assert!(script.contains("ax.set_xlabel(\"t\")\n"));
+    assert!(script.contains(
+        "ax.plot(np.array([0.0, 1.0]), np.array([0.0, 0.5]), color=\"#ff0000ff\", \
+         label=\"sin\", linestyle=\"-\", linewidth=1.5, marker=\"o\")\n"
+    ));
+    assert!(script.contains("ax.axhline(0.5, color=\"#0000ffff\", linestyle=\"-\""));
+    assert!(script.ends_with("ax.legend()\nplt.show()\n"));
+}
@@ -0,0 +1,251 @@
+//! Export of a plot to formats that can be used outside the app.
+//!
+//! Items describe themselves as [`ExportShape`]s, which the exporters turn into a matplotlib
+//! script or a standalone HTML page.
+
+mod html;
+mod matplotlib;
+
+use egui::{Color32, Stroke};
+
+use crate::{
+    Axis, Interval, LegendGlyph, LineStyle, MarkerShape, PlotGeometry, PlotItem, PlotTransform,
+};
+
+pub(crate) use html::html_page;
+pub(crate) use matplotlib::matplotlib_script;
+
+/// A plot item reduced to simple shapes in plot coordinates, see [`PlotItem::export_shapes`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum ExportShape {
+    /// A path through the points, broken at NaN values.
+    Line {
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        stroke: Stroke,
+        style: LineStyle,
+        marker: Option<MarkerShape>,
+    },
+
+    /// A marker at each point.
+    Markers {
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        color: Color32,
+        shape: MarkerShape,
+        radius: f32,
+        filled: bool,
+    },
+
+    /// The area between `lower` and `upper`.
+    FillBetween {
+        xs: Vec<f64>,
+        lower: Vec<f64>,
+        upper: Vec<f64>,
+        color: Color32,
+    },
+
+    /// A closed, filled polygon.
+    Polygon {
+        xs: Vec<f64>,
+        ys: Vec<f64>,
+        color: Color32,
+    },
+
+    /// A line across the whole plot at `value` on `axis`, e.g. a horizontal line for
+    /// [`Axis::Y`].
+    Rule {
+        axis: Axis,
+        value: f64,
+        stroke: Stroke,
+        style: LineStyle,
+    },
+
+    /// A shaded interval on `axis`, across the range `clip` of the other axis.
+    Span {
+        axis: Axis,
+        interval: Interval,
+        clip: Interval,
+        color: Color32,
+        stroke: Option<Stroke>,
+    },
+
+    /// Bars at `arguments`, from `bases` to `bases + values`.
+    Bars {
+        horizontal: bool,
+        arguments: Vec<f64>,
+        values: Vec<f64>,
+        bases: Vec<f64>,
+        widths: Vec<f64>,
+        color: Color32,
+    },
+
+    /// A text label at a point.
+    Text {
+        x: f64,
+        y: f64,
+        text: String,
+        color: Color32,
+    },
+}
+
+/// The points of an item as a line, markers or a polygon, depending on its legend glyph.
+///
+/// This is the default [`PlotItem::export_shapes`].
+pub(crate) fn from_geometry<I: PlotItem + ?Sized>(item: &I) -> Option<Vec<ExportShape>> {
+    let (xs, ys) = geometry_columns(&item.geometry())?;
+    let style = item.series_style();
+    let color = style.color.unwrap_or_else(|| item.color());
+    let shape = match item.legend_glyph() {
+        LegendGlyph::Line {
+            style: line_style,
+            marker,
+        } => ExportShape::Line {
+            xs,
+            ys,
+            stroke: Stroke::new(style.width.unwrap_or(1.0), color),
+            style: line_style,
+            marker,
+        },
+        LegendGlyph::Marker { shape, filled } => ExportShape::Markers {
+            xs,
+            ys,
+            color,
+            shape: style.marker.unwrap_or(shape),
+            radius: style.width.unwrap_or(1.0),
+            filled,
+        },
+        LegendGlyph::Area => ExportShape::Polygon { xs, ys, color },
+        LegendGlyph::Dot => ExportShape::Line {
+            xs,
+            ys,
+            stroke: Stroke::new(1.0, color),
+            style: LineStyle::Solid,
+            marker: None,
+        },
+    };
+    Some(vec![shape])
+}
+
+/// The points of `geometry` as owned columns, with a NaN between blocks so they are not
+/// connected. `None` for geometries without points.
+pub(crate) fn geometry_columns(geometry: &PlotGeometry<'_>) -> Option<(Vec<f64>, Vec<f64>)> {
+    Some(match geometry {
+        PlotGeometry::None | PlotGeometry::Rects => return None,
+        PlotGeometry::Points(points) => points.iter().map(|p| (p.x, p.y)).unzip(),
+        PlotGeometry::PointsXY { xs, ys } => (xs.to_vec(), ys.to_vec()),
+        PlotGeometry::BlocksXY {
+            xs_blocks,
+            ys_blocks,
+        } => {
+            // Separate the blocks with a NaN, so they are not connected:
+            let mut xs = Vec::new();
+            let mut ys = Vec::new();
+            for (bx, by) in xs_blocks.iter().zip(ys_blocks) {
+                if !xs.is_empty() {
+                    xs.push(f64::NAN);
+                    ys.push(f64::NAN);
+                }
+                xs.extend_from_slice(bx);
+                ys.extend_from_slice(by);
+            }
+            (xs, ys)
+        }
+    })
+}
+
+/// The shapes of `item` to export, see [`crate::Plot::export_decimated`].
+pub(crate) fn item_shapes(
+    item: &(dyn PlotItem + '_),
+    transform: &PlotTransform,
+    decimated: bool,
+) -> Option<Vec<ExportShape>> {
+    let mut shapes = item.export_shapes()?;
+    if decimated {
+        for shape in &mut shapes {
+            if let ExportShape::Line { xs, ys, .. } | ExportShape::Markers { xs, ys, .. } = shape {
+                (*xs, *ys) = decimate_to_view(xs, ys, transform);
+            }
+        }
+    }
+    Some(shapes)
+}
+
+/// Keep only what the plot shows in `transform`: the points in the x range of the view (and
+/// their neighbors, so lines reach the edges), and of those at most the first, lowest, highest
+/// and last in each column of one point on screen.
+///
+/// Points left out break the line with a NaN, like in [`ExportShape::Line`].
+fn decimate_to_view(xs: &[f64], ys: &[f64], transform: &PlotTransform) -> (Vec<f64>, Vec<f64>) {
+    let n = xs.len().min(ys.len());
+    let x_range = transform.bounds().range_x();
+    let visible = |i: usize| x_range.contains(&xs[i]);
+    let column = |i: usize| transform.position_from_point_x(xs[i]).floor();
+
+    let mut out = (Vec::new(), Vec::new());
+    let mut group: Vec<usize> = Vec::new();
+    let flush = |group: &mut Vec<usize>, out: &mut (Vec<f64>, Vec<f64>)| {
+        let (Some(&first), Some(&last)) = (group.first(), group.last()) else {
+            return;
+        };
+        let by_y = |a: &&usize, b: &&usize| ys[**a].total_cmp(&ys[**b]);
+        let lowest = *group.iter().min_by(by_y).unwrap_or(&first);
+        let highest = *group.iter().max_by(by_y).unwrap_or(&first);
+        let mut kept = [first, lowest, highest, last];
+        kept.sort_unstable();
+        let mut previous = None;
+        for i in kept {
+            if previous != Some(i) {
+                out.0.push(xs[i]);
+                out.1.push(ys[i]);
+            }
+            previous = Some(i);
+        }
+        group.clear();
+    };
+    let gap = |out: &mut (Vec<f64>, Vec<f64>)| {
+        if out.1.last().is_some_and(|y: &f64| !y.is_nan()) {
+            out.0.push(f64::NAN);
+            out.1.push(f64::NAN);
+        }
+    };
+
+    for i in 0..n {
+        let shown = visible(i) || (i > 0 && visible(i - 1)) || (i + 1 < n && visible(i + 1));
+        if !shown || xs[i].is_nan() || ys[i].is_nan() {
+            flush(&mut group, &mut out);
+            gap(&mut out);
+            continue;
+        }
+        if group.first().is_some_and(|&g| column(g) != column(i)) {
+            flush(&mut group, &mut out);
+        }
+        group.push(i);
+    }
+    flush(&mut group, &mut out);
+    if out.1.last().is_some_and(|y| y.is_nan()) {
+        out.0.pop();
+        out.1.pop();
+    }
+    out
+}
+
+#[test]
+fn test_decimate_to_view() {
+    use crate::PlotBounds;
+
+    // 8 points per column of the 100 points wide view, and as many outside of it:
+    let transform = PlotTransform::new(
+        egui::Rect::from_min_size(egui::Pos2::ZERO, egui::vec2(100.0, 100.0)),
+        PlotBounds::from_min_max([0.0, -1.0], [100.0, 1.0]),
+        egui::Vec2b::FALSE,
+    );
+    let xs: Vec<f64> = (0..1600).map(|i| i as f64 / 8.0).collect();
+    let ys: Vec<f64> = xs.iter().map(|x| (x * 7.0).sin()).collect();
+    let (dxs, dys) = decimate_to_view(&xs, &ys, &transform);
+    assert!(dxs.len() <= 4 * 101);
+    // Up to the first point past the view:
+    assert_eq!(dxs.last(), Some(&100.125));
+    let max = |ys: &[f64]| ys.iter().copied().fold(f64::MIN, f64::max);
+    assert_eq!(max(&dys), max(&ys[..=801]));
+}
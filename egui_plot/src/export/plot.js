@@ -0,0 +1,349 @@
+// Renderer of the pages written by `Plot::export_html`. Expects the data in `PLOT`.
+(function () {
+  "use strict";
+
+  const canvas = document.getElementById("plot");
+  const ctx = canvas.getContext("2d");
+  const palette = ["#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2"];
+  const margin = { left: 70, right: 20, top: PLOT.title ? 40 : 20, bottom: PLOT.xLabel ? 55 : 35 };
+
+  // [x_min, x_max, y_min, y_max]
+  const home = PLOT.bounds.slice();
+  let view = home.slice();
+  const hidden = new Set();
+  let legendBoxes = [];
+  let drag = null;
+
+  const seriesColor = (series, index) => {
+    for (const shape of series.shapes) {
+      if (shape.color) return shape.color;
+    }
+    return palette[index % palette.length];
+  };
+
+  function area() {
+    return {
+      x: margin.left,
+      y: margin.top,
+      w: Math.max(canvas.clientWidth - margin.left - margin.right, 1),
+      h: Math.max(canvas.clientHeight - margin.top - margin.bottom, 1),
+    };
+  }
+
+  function screenX(x) {
+    const a = area();
+    let t = (x - view[0]) / (view[1] - view[0]);
+    if (PLOT.inverted[0]) t = 1 - t;
+    return a.x + t * a.w;
+  }
+
+  function screenY(y) {
+    const a = area();
+    let t = (y - view[2]) / (view[3] - view[2]);
+    if (PLOT.inverted[1]) t = 1 - t;
+    return a.y + a.h - t * a.h;
+  }
+
+  function valueX(px) {
+    const a = area();
+    let t = (px - a.x) / a.w;
+    if (PLOT.inverted[0]) t = 1 - t;
+    return view[0] + t * (view[1] - view[0]);
+  }
+
+  function valueY(py) {
+    const a = area();
+    let t = (a.y + a.h - py) / a.h;
+    if (PLOT.inverted[1]) t = 1 - t;
+    return view[2] + t * (view[3] - view[2]);
+  }
+
+  function tickStep(range, count) {
+    const raw = range / count;
+    const magnitude = Math.pow(10, Math.floor(Math.log10(raw)));
+    for (const m of [1, 2, 5, 10]) {
+      if (m * magnitude >= raw) return m * magnitude;
+    }
+    return 10 * magnitude;
+  }
+
+  function ticks(min, max, count) {
+    const step = tickStep(max - min, count);
+    const decimals = Math.max(0, -Math.floor(Math.log10(step)));
+    const result = [];
+    for (let v = Math.ceil(min / step) * step; v <= max + step * 1e-9; v += step) {
+      result.push([v, (+v.toFixed(decimals)).toString()]);
+    }
+    return result;
+  }
+
+  // A path through the points, broken at missing values.
+  function tracePath(xs, ys) {
+    let pen = false;
+    for (let i = 0; i < xs.length; i++) {
+      if (xs[i] === null || ys[i] === null) {
+        pen = false;
+      } else if (pen) {
+        ctx.lineTo(screenX(xs[i]), screenY(ys[i]));
+      } else {
+        ctx.moveTo(screenX(xs[i]), screenY(ys[i]));
+        pen = true;
+      }
+    }
+  }
+
+  function dot(x, y, radius, color, filled) {
+    ctx.beginPath();
+    ctx.arc(screenX(x), screenY(y), radius, 0, 2 * Math.PI);
+    if (filled) {
+      ctx.fillStyle = color;
+      ctx.fill();
+    } else {
+      ctx.strokeStyle = color;
+      ctx.stroke();
+    }
+  }
+
+  function drawShape(shape, fallback) {
+    const a = area();
+    const color = shape.color || fallback;
+    ctx.setLineDash(shape.dash || []);
+    ctx.lineWidth = shape.width || 1;
+    switch (shape.kind) {
+      case "line":
+        ctx.strokeStyle = color;
+        ctx.beginPath();
+        tracePath(shape.xs, shape.ys);
+        ctx.stroke();
+        if (shape.markers) {
+          shape.xs.forEach((x, i) => x !== null && shape.ys[i] !== null && dot(x, shape.ys[i], 3, color, true));
+        }
+        break;
+      case "markers":
+        ctx.lineWidth = 1;
+        shape.xs.forEach((x, i) => x !== null && shape.ys[i] !== null && dot(x, shape.ys[i], shape.radius, color, shape.filled));
+        break;
+      case "fill": {
+        ctx.fillStyle = color;
+        // Fill each run of complete samples separately:
+        let run = [];
+        const flush = () => {
+          if (run.length > 1) {
+            ctx.beginPath();
+            run.forEach((i, k) => (k ? ctx.lineTo : ctx.moveTo).call(ctx, screenX(shape.xs[i]), screenY(shape.upper[i])));
+            run.slice().reverse().forEach((i) => ctx.lineTo(screenX(shape.xs[i]), screenY(shape.lower[i])));
+            ctx.closePath();
+            ctx.fill();
+          }
+          run = [];
+        };
+        shape.xs.forEach((x, i) => {
+          if (x === null || shape.lower[i] === null || shape.upper[i] === null) flush();
+          else run.push(i);
+        });
+        flush();
+        break;
+      }
+      case "polygon":
+        ctx.fillStyle = color;
+        ctx.beginPath();
+        tracePath(shape.xs, shape.ys);
+        ctx.closePath();
+        ctx.fill();
+        break;
+      case "rule":
+        ctx.strokeStyle = color;
+        ctx.beginPath();
+        if (shape.axis === "x") {
+          ctx.moveTo(screenX(shape.value), a.y);
+          ctx.lineTo(screenX(shape.value), a.y + a.h);
+        } else {
+          ctx.moveTo(a.x, screenY(shape.value));
+          ctx.lineTo(a.x + a.w, screenY(shape.value));
+        }
+        ctx.stroke();
+        break;
+      case "span": {
+        // Missing ends are infinite and reach the edge of the plot:
+        const ends = (start, end) => [start === null ? -1e300 : start, end === null ? 1e300 : end];
+        const clamp = (v, lo, hi) => Math.min(Math.max(v, lo), hi);
+        const across = ends(shape.start, shape.end);
+        const along = ends(shape.clip_start, shape.clip_end);
+        const [xs, ys] = shape.axis === "x" ? [across, along] : [along, across];
+        const [x0, x1] = xs.map((v) => clamp(screenX(v), a.x, a.x + a.w));
+        const [y0, y1] = ys.map((v) => clamp(screenY(v), a.y, a.y + a.h));
+        const rect = [Math.min(x0, x1), Math.min(y0, y1), Math.abs(x1 - x0), Math.abs(y1 - y0)];
+        ctx.fillStyle = color;
+        ctx.fillRect(...rect);
+        if (shape.stroke) {
+          ctx.strokeStyle = shape.stroke.color || color;
+          ctx.lineWidth = shape.stroke.width;
+          ctx.strokeRect(...rect);
+        }
+        break;
+      }
+      case "bars":
+        ctx.fillStyle = color;
+        shape.arguments.forEach((arg, i) => {
+          const [base, value, width] = [shape.bases[i] || 0, shape.values[i], shape.widths[i]];
+          if (arg === null || value === null || width === null) return;
+          let [x0, x1, y0, y1] = [arg - width / 2, arg + width / 2, base, base + value];
+          if (shape.horizontal) [x0, x1, y0, y1] = [y0, y1, x0, x1];
+          const [sx0, sx1, sy0, sy1] = [screenX(x0), screenX(x1), screenY(y0), screenY(y1)];
+          ctx.fillRect(Math.min(sx0, sx1), Math.min(sy0, sy1), Math.abs(sx1 - sx0), Math.abs(sy1 - sy0));
+        });
+        break;
+      case "text":
+        ctx.fillStyle = color;
+        ctx.font = "13px sans-serif";
+        ctx.textAlign = "center";
+        ctx.textBaseline = "middle";
+        ctx.fillText(shape.text, screenX(shape.x), screenY(shape.y));
+        break;
+    }
+    ctx.setLineDash([]);
+  }
+
+  function drawAxes() {
+    const a = area();
+    ctx.font = "12px sans-serif";
+    ctx.fillStyle = "#444";
+    ctx.strokeStyle = "#e4e4e4";
+    ctx.lineWidth = 1;
+    ctx.textAlign = "center";
+    ctx.textBaseline = "top";
+    for (const [v, text] of ticks(Math.min(view[0], view[1]), Math.max(view[0], view[1]), a.w / 80)) {
+      const x = Math.round(screenX(v)) + 0.5;
+      ctx.beginPath();
+      ctx.moveTo(x, a.y);
+      ctx.lineTo(x, a.y + a.h);
+      ctx.stroke();
+      ctx.fillText(text, x, a.y + a.h + 6);
+    }
+    ctx.textAlign = "right";
+    ctx.textBaseline = "middle";
+    for (const [v, text] of ticks(Math.min(view[2], view[3]), Math.max(view[2], view[3]), a.h / 50)) {
+      const y = Math.round(screenY(v)) + 0.5;
+      ctx.beginPath();
+      ctx.moveTo(a.x, y);
+      ctx.lineTo(a.x + a.w, y);
+      ctx.stroke();
+      ctx.fillText(text, a.x - 6, y);
+    }
+    ctx.strokeStyle = "#888";
+    ctx.strokeRect(a.x + 0.5, a.y + 0.5, a.w, a.h);
+
+    ctx.fillStyle = "#222";
+    ctx.textAlign = "center";
+    ctx.textBaseline = "bottom";
+    if (PLOT.xLabel) ctx.fillText(PLOT.xLabel, a.x + a.w / 2, a.y + a.h + 45);
+    if (PLOT.title) {
+      ctx.font = "16px sans-serif";
+      ctx.fillText(PLOT.title, a.x + a.w / 2, a.y - 12);
+    }
+    if (PLOT.yLabel) {
+      ctx.save();
+      ctx.font = "12px sans-serif";
+      ctx.translate(16, a.y + a.h / 2);
+      ctx.rotate(-Math.PI / 2);
+      ctx.textBaseline = "middle";
+      ctx.fillText(PLOT.yLabel, 0, 0);
+      ctx.restore();
+    }
+  }
+
+  function drawLegend() {
+    const a = area();
+    const entries = PLOT.series.map((series, i) => [series, i]).filter(([series]) => series.name);
+    legendBoxes = [];
+    if (!entries.length) return;
+    ctx.font = "12px sans-serif";
+    const width = Math.max(...entries.map(([series]) => ctx.measureText(series.name).width)) + 34;
+    const x = a.x + a.w - width - 8;
+    let y = a.y + 8;
+    ctx.fillStyle = "rgba(255,255,255,0.85)";
+    ctx.fillRect(x, y, width, entries.length * 18 + 6);
+    ctx.textAlign = "left";
+    ctx.textBaseline = "middle";
+    for (const [series, i] of entries) {
+      const off = hidden.has(i);
+      ctx.fillStyle = off ? "#bbb" : seriesColor(series, i);
+      ctx.fillRect(x + 6, y + 8, 16, 8);
+      ctx.fillStyle = off ? "#999" : "#222";
+      ctx.fillText(series.name, x + 28, y + 12);
+      legendBoxes.push({ index: i, x, y: y + 3, w: width, h: 18 });
+      y += 18;
+    }
+  }
+
+  function draw() {
+    const a = area();
+    ctx.clearRect(0, 0, canvas.clientWidth, canvas.clientHeight);
+    ctx.fillStyle = "#fff";
+    ctx.fillRect(0, 0, canvas.clientWidth, canvas.clientHeight);
+    drawAxes();
+    ctx.save();
+    ctx.beginPath();
+    ctx.rect(a.x, a.y, a.w, a.h);
+    ctx.clip();
+    PLOT.series.forEach((series, i) => {
+      if (hidden.has(i)) return;
+      const fallback = palette[i % palette.length];
+      series.shapes.forEach((shape) => drawShape(shape, fallback));
+    });
+    ctx.restore();
+    drawLegend();
+  }
+
+  function resize() {
+    const ratio = window.devicePixelRatio || 1;
+    canvas.width = canvas.clientWidth * ratio;
+    canvas.height = canvas.clientHeight * ratio;
+    ctx.setTransform(ratio, 0, 0, ratio, 0, 0);
+    draw();
+  }
+
+  const legendBoxAt = (x, y) => legendBoxes.find((b) => x >= b.x && x <= b.x + b.w && y >= b.y && y <= b.y + b.h);
+
+  canvas.addEventListener("wheel", (event) => {
+    event.preventDefault();
+    const [x, y] = [valueX(event.offsetX), valueY(event.offsetY)];
+    const factor = Math.exp(event.deltaY * 0.002);
+    view = [x + (view[0] - x) * factor, x + (view[1] - x) * factor, y + (view[2] - y) * factor, y + (view[3] - y) * factor];
+    draw();
+  }, { passive: false });
+
+  canvas.addEventListener("mousedown", (event) => {
+    const box = legendBoxAt(event.offsetX, event.offsetY);
+    if (box) {
+      if (hidden.has(box.index)) hidden.delete(box.index);
+      else hidden.add(box.index);
+      draw();
+      return;
+    }
+    drag = { x: event.clientX, y: event.clientY, view: view.slice() };
+  });
+
+  window.addEventListener("mousemove", (event) => {
+    if (!drag) return;
+    const a = area();
+    let dx = ((event.clientX - drag.x) / a.w) * (drag.view[1] - drag.view[0]);
+    let dy = ((event.clientY - drag.y) / a.h) * (drag.view[3] - drag.view[2]);
+    if (PLOT.inverted[0]) dx = -dx;
+    if (PLOT.inverted[1]) dy = -dy;
+    view = [drag.view[0] - dx, drag.view[1] - dx, drag.view[2] + dy, drag.view[3] + dy];
+    draw();
+  });
+
+  window.addEventListener("mouseup", () => {
+    drag = null;
+  });
+
+  canvas.addEventListener("dblclick", () => {
+    view = home.slice();
+    draw();
+  });
+
+  window.addEventListener("resize", resize);
+  resize();
+})();
@@ -0,0 +1,453 @@
+//! Series computed from user-entered expressions over other series, like `"a - 2*b"`.
+
+use std::fmt;
+
+use crate::{PlotGeometry, PlotItem, PlotPoint};
+
+/// Why an expression could not be evaluated, see [`crate::PlotUi::expression_points`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ExpressionError {
+    /// The expression is malformed. `position` is the byte offset of the problem.
+    Syntax { position: usize, message: String },
+
+    /// No series of this name was added to the plot before the expression.
+    UnknownSeries(String),
+
+    /// A function that doesn't exist, or was called with the wrong number of arguments.
+    UnknownFunction(String),
+
+    /// The expression references no series, so there are no x positions to evaluate it at.
+    NoSeries,
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Syntax { position, message } => write!(f, "{message} at position {position}"),
+            Self::UnknownSeries(name) => write!(f, "unknown series {name:?}"),
+            Self::UnknownFunction(name) => write!(f, "unknown function {name:?}"),
+            Self::NoSeries => write!(f, "the expression references no series"),
+        }
+    }
+}
+
+impl std::error::Error for ExpressionError {}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Function {
+    Abs,
+    Sqrt,
+    Exp,
+    Ln,
+    Log10,
+    Sin,
+    Cos,
+    Tan,
+    Min,
+    Max,
+}
+
+impl Function {
+    fn from_name(name: &str) -> Option<(Self, usize)> {
+        Some(match name {
+            "abs" => (Self::Abs, 1),
+            "sqrt" => (Self::Sqrt, 1),
+            "exp" => (Self::Exp, 1),
+            "ln" => (Self::Ln, 1),
+            "log10" => (Self::Log10, 1),
+            "sin" => (Self::Sin, 1),
+            "cos" => (Self::Cos, 1),
+            "tan" => (Self::Tan, 1),
+            "min" => (Self::Min, 2),
+            "max" => (Self::Max, 2),
+            _ => return None,
+        })
+    }
+
+    fn apply(self, args: &[f64]) -> f64 {
+        match self {
+            Self::Abs => args[0].abs(),
+            Self::Sqrt => args[0].sqrt(),
+            Self::Exp => args[0].exp(),
+            Self::Ln => args[0].ln(),
+            Self::Log10 => args[0].log10(),
+            Self::Sin => args[0].sin(),
+            Self::Cos => args[0].cos(),
+            Self::Tan => args[0].tan(),
+            Self::Min => args[0].min(args[1]),
+            Self::Max => args[0].max(args[1]),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Node {
+    Number(f64),
+
+    /// The x position the expression is evaluated at.
+    X,
+
+    /// Index into [`Expression::series`].
+    Series(usize),
+
+    Neg(Box<Node>),
+    Binary(char, Box<Node>, Box<Node>),
+    Call(Function, Vec<Node>),
+}
+
+/// A parsed expression over named series.
+///
+/// Supported are numbers, `+ - * / ^`, parentheses, `x` (the x position), `pi`, the functions
+/// `abs sqrt exp ln log10 sin cos tan min max`, and series names. Names that are not plain
+/// identifiers can be quoted: `"f1 = sin(1.2x)" * 2`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Expression {
+    root: Node,
+
+    /// The referenced series, in order of first use.
+    series: Vec<String>,
+}
+
+impl Expression {
+    /// Parse an expression.
+    ///
+    /// # Errors
+    /// [`ExpressionError::Syntax`] if the text is malformed or nested more than 256 levels deep,
+    /// or [`ExpressionError::UnknownFunction`] for calls to functions that don't exist.
+    pub fn parse(text: &str) -> Result<Self, ExpressionError> {
+        let mut parser = Parser {
+            text,
+            position: 0,
+            depth: 0,
+            series: Vec::new(),
+        };
+        let root = parser.sum()?;
+        parser.skip_whitespace();
+        if parser.position < text.len() {
+            return Err(parser.error("unexpected input"));
+        }
+        Ok(Self {
+            root,
+            series: parser.series,
+        })
+    }
+
+    /// The names of the referenced series, in order of first use.
+    pub fn series(&self) -> &[String] {
+        &self.series
+    }
+
+    /// The value at `x`, where `values[i]` is the value of [`Self::series`]`[i]`.
+    pub fn evaluate(&self, x: f64, values: &[f64]) -> f64 {
+        eval(&self.root, x, values)
+    }
+
+    /// Evaluate at the x positions of the first referenced series, with the other series linearly
+    /// interpolated there.
+    pub(crate) fn points(
+        &self,
+        items: &[&dyn PlotItem],
+    ) -> Result<Vec<PlotPoint>, ExpressionError> {
+        let columns = self
+            .series
+            .iter()
+            .map(|name| {
+                items
+                    .iter()
+                    .find(|item| item.name() == name)
+                    .map(|item| sorted_points(&item.geometry()))
+                    .ok_or_else(|| ExpressionError::UnknownSeries(name.clone()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let Some(first) = columns.first() else {
+            return Err(ExpressionError::NoSeries);
+        };
+
+        let mut values = vec![0.0; columns.len()];
+        Ok(first
+            .iter()
+            .map(|point| {
+                for (value, column) in values.iter_mut().zip(&columns) {
+                    *value = interpolate(column, point.x);
+                }
+                PlotPoint::new(point.x, self.evaluate(point.x, &values))
+            })
+            .collect())
+    }
+}
+
+fn eval(node: &Node, x: f64, values: &[f64]) -> f64 {
+    match node {
+        Node::Number(value) => *value,
+        Node::X => x,
+        Node::Series(index) => values[*index],
+        Node::Neg(node) => -eval(node, x, values),
+        Node::Binary(op, a, b) => {
+            let (a, b) = (eval(a, x, values), eval(b, x, values));
+            match op {
+                '+' => a + b,
+                '-' => a - b,
+                '*' => a * b,
+                '/' => a / b,
+                _ => a.powf(b),
+            }
+        }
+        Node::Call(function, args) => {
+            let args: Vec<f64> = args.iter().map(|arg| eval(arg, x, values)).collect();
+            function.apply(&args)
+        }
+    }
+}
+
+/// The points of an item, sorted by x.
+fn sorted_points(geometry: &PlotGeometry<'_>) -> Vec<PlotPoint> {
+    let mut points = geometry.points();
+    points.retain(|point| !point.x.is_nan());
+    points.sort_by(|a, b| a.x.total_cmp(&b.x));
+    points
+}
+
+/// The value of the polyline `points` (sorted by x) at `x`, NaN outside of it.
+fn interpolate(points: &[PlotPoint], x: f64) -> f64 {
+    let i = points.partition_point(|point| point.x < x);
+    match (i.checked_sub(1).map(|i| points[i]), points.get(i)) {
+        (_, Some(b)) if b.x == x => b.y,
+        (Some(a), Some(b)) => a.y + (b.y - a.y) * (x - a.x) / (b.x - a.x),
+        _ => f64::NAN,
+    }
+}
+
+/// Deepest expression tree that is parsed, so that malformed input can't overflow the stack.
+const MAX_DEPTH: usize = 256;
+
+struct Parser<'t> {
+    text: &'t str,
+    position: usize,
+
+    /// Depth in the expression tree of the node being parsed.
+    depth: usize,
+
+    series: Vec<String>,
+}
+
+impl Parser<'_> {
+    fn error(&self, message: &str) -> ExpressionError {
+        ExpressionError::Syntax {
+            position: self.position,
+            message: message.to_owned(),
+        }
+    }
+
+    fn rest(&self) -> &str {
+        &self.text[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    /// Go one level deeper into the expression tree.
+    fn descend(&mut self) -> Result<(), ExpressionError> {
+        if self.depth >= MAX_DEPTH {
+            return Err(self.error("too deeply nested"));
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Consume `c` if it comes next.
+    fn eat(&mut self, c: char) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    /// `product (('+' | '-') product)*`
+    fn sum(&mut self) -> Result<Node, ExpressionError> {
+        let depth = self.depth;
+        let mut node = self.product()?;
+        loop {
+            let op = if self.eat('+') {
+                '+'
+            } else if self.eat('-') {
+                '-'
+            } else {
+                self.depth = depth;
+                return Ok(node);
+            };
+            // Each operand of a chain nests the tree one level deeper:
+            self.descend()?;
+            node = Node::Binary(op, Box::new(node), Box::new(self.product()?));
+        }
+    }
+
+    /// `unary (('*' | '/') unary)*`
+    fn product(&mut self) -> Result<Node, ExpressionError> {
+        let depth = self.depth;
+        let mut node = self.unary()?;
+        loop {
+            let op = if self.eat('*') {
+                '*'
+            } else if self.eat('/') {
+                '/'
+            } else {
+                self.depth = depth;
+                return Ok(node);
+            };
+            // Each operand of a chain nests the tree one level deeper:
+            self.descend()?;
+            node = Node::Binary(op, Box::new(node), Box::new(self.unary()?));
+        }
+    }
+
+    /// `'-' unary | power`
+    fn unary(&mut self) -> Result<Node, ExpressionError> {
+        let depth = self.depth;
+        self.descend()?;
+        let node = if self.eat('-') {
+            Node::Neg(Box::new(self.unary()?))
+        } else {
+            self.power()?
+        };
+        self.depth = depth;
+        Ok(node)
+    }
+
+    /// `atom ('^' unary)?`, right associative.
+    fn power(&mut self) -> Result<Node, ExpressionError> {
+        let base = self.atom()?;
+        if self.eat('^') {
+            Ok(Node::Binary('^', Box::new(base), Box::new(self.unary()?)))
+        } else {
+            Ok(base)
+        }
+    }
+
+    fn atom(&mut self) -> Result<Node, ExpressionError> {
+        self.skip_whitespace();
+        let start = self.position;
+        let Some(c) = self.rest().chars().next() else {
+            return Err(self.error("unexpected end"));
+        };
+
+        if self.eat('(') {
+            let node = self.sum()?;
+            return if self.eat(')') {
+                Ok(node)
+            } else {
+                Err(self.error("expected ')'"))
+            };
+        }
+
+        if c == '"' {
+            let Some(end) = self.rest()[1..].find('"') else {
+                return Err(self.error("unterminated name"));
+            };
+            let name = self.rest()[1..=end].to_owned();
+            self.position += end + 2;
+            return Ok(self.series_node(name));
+        }
+
+        if c.is_ascii_digit() || c == '.' {
+            let len = self
+                .rest()
+                .find(|c: char| !(c.is_ascii_digit() || c == '.' || c == 'e' || c == 'E'))
+                .unwrap_or(self.rest().len());
+            let literal = &self.rest()[..len];
+            let value = literal
+                .parse()
+                .map_err(|_err| self.error("invalid number"))?;
+            self.position += len;
+            return Ok(Node::Number(value));
+        }
+
+        if c.is_alphabetic() || c == '_' {
+            let len = self
+                .rest()
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(self.rest().len());
+            let name = self.rest()[..len].to_owned();
+            self.position += len;
+
+            if self.eat('(') {
+                let mut args = vec![self.sum()?];
+                while self.eat(',') {
+                    args.push(self.sum()?);
+                }
+                if !self.eat(')') {
+                    return Err(self.error("expected ')'"));
+                }
+                return match Function::from_name(&name) {
+                    Some((function, arity)) if arity == args.len() => {
+                        Ok(Node::Call(function, args))
+                    }
+                    _ => Err(ExpressionError::UnknownFunction(name)),
+                };
+            }
+            return Ok(match name.as_str() {
+                "x" => Node::X,
+                "pi" => Node::Number(std::f64::consts::PI),
+                _ => self.series_node(name),
+            });
+        }
+
+        self.position = start;
+        Err(self.error("unexpected character"))
+    }
+
+    fn series_node(&mut self, name: String) -> Node {
+        let index = self
+            .series
+            .iter()
+            .position(|series| *series == name)
+            .unwrap_or_else(|| {
+                self.series.push(name);
+                self.series.len() - 1
+            });
+        Node::Series(index)
+    }
+}
+
+#[test]
+fn test_expression() {
+    let expression = Expression::parse("a - 2*\"b c\" ^ 2 + max(a, x) / -2").unwrap();
+    assert_eq!(expression.series(), ["a", "b c"]);
+    // 3 - 2 * 4 + max(3, 5) / -2
+    assert_eq!(expression.evaluate(5.0, &[3.0, 2.0]), -7.5);
+
+    assert_eq!(
+        Expression::parse("a +"),
+        Err(ExpressionError::Syntax {
+            position: 3,
+            message: "unexpected end".to_owned()
+        })
+    );
+    assert_eq!(
+        Expression::parse("foo(a)"),
+        Err(ExpressionError::UnknownFunction("foo".to_owned()))
+    );
+
+    let nested = format!("{}1{}", "(".repeat(100), ")".repeat(100));
+    assert_eq!(Expression::parse(&nested).unwrap().evaluate(0.0, &[]), 1.0);
+    for deep in [
+        format!("{}1", "-".repeat(100_000)),
+        format!("{}1{}", "(".repeat(100_000), ")".repeat(100_000)),
+        format!("{}1", "1+".repeat(100_000)),
+        format!("{}1", "2^".repeat(100_000)),
+    ] {
+        assert!(matches!(
+            Expression::parse(&deep),
+            Err(ExpressionError::Syntax { message, .. }) if message == "too deeply nested"
+        ));
+    }
+
+    let line = [PlotPoint::new(0.0, 0.0), PlotPoint::new(2.0, 4.0)];
+    assert_eq!(interpolate(&line, 0.5), 1.0);
+    assert_eq!(interpolate(&line, 2.0), 4.0);
+    assert!(interpolate(&line, 3.0).is_nan());
+}
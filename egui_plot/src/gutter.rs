@@ -0,0 +1,119 @@
+//! Room for custom widgets next to the axes, aligned with the plot, see
+//! [`crate::Plot::axis_gutter`].
+
+use std::ops::RangeInclusive;
+
+use egui::{Rect, Ui, UiBuilder};
+
+use crate::{Axis, Placement, PlotTransform};
+
+/// Space between a gutter and the rest of the plot.
+pub(crate) const GUTTER_GAP: f32 = 4.0;
+
+type GutterFn<'a> = dyn FnMut(&mut Ui, &AxisGutter) + 'a;
+
+/// A gutter added with [`crate::Plot::axis_gutter`], before it is laid out.
+pub(crate) struct GutterConfig<'a> {
+    pub(crate) axis: Axis,
+    pub(crate) placement: Placement,
+    pub(crate) size: f32,
+    pub(crate) add_contents: Box<GutterFn<'a>>,
+}
+
+impl GutterConfig<'_> {
+    /// Take room for the gutter from the side of `rect`, and return the range it covers across
+    /// the axis.
+    pub(crate) fn reserve(&self, rect: &mut Rect) -> RangeInclusive<f32> {
+        match (self.axis, self.placement) {
+            (Axis::X, Placement::LeftBottom) => {
+                let size = self.size.min(rect.height() / 3.0);
+                *rect.bottom_mut() -= size + GUTTER_GAP;
+                rect.bottom() + GUTTER_GAP..=rect.bottom() + GUTTER_GAP + size
+            }
+            (Axis::X, Placement::RightTop) => {
+                let size = self.size.min(rect.height() / 3.0);
+                *rect.top_mut() += size + GUTTER_GAP;
+                rect.top() - GUTTER_GAP - size..=rect.top() - GUTTER_GAP
+            }
+            (Axis::Y, Placement::LeftBottom) => {
+                let size = self.size.min(rect.width() / 3.0);
+                *rect.left_mut() += size + GUTTER_GAP;
+                rect.left() - GUTTER_GAP - size..=rect.left() - GUTTER_GAP
+            }
+            (Axis::Y, Placement::RightTop) => {
+                let size = self.size.min(rect.width() / 3.0);
+                *rect.right_mut() -= size + GUTTER_GAP;
+                rect.right() + GUTTER_GAP..=rect.right() + GUTTER_GAP + size
+            }
+        }
+    }
+
+    /// Show the widgets of the gutter in the `strip` reserved for it, along the plot frame.
+    pub(crate) fn ui(
+        &mut self,
+        ui: &mut Ui,
+        strip: RangeInclusive<f32>,
+        transform: &PlotTransform,
+    ) {
+        let frame = transform.frame();
+        let rect = match self.axis {
+            Axis::X => Rect::from_x_y_ranges(frame.x_range(), strip),
+            Axis::Y => Rect::from_x_y_ranges(strip, frame.y_range()),
+        };
+        let gutter = AxisGutter {
+            axis: self.axis,
+            rect,
+            transform: *transform,
+        };
+        let mut child = ui.new_child(UiBuilder::new().max_rect(rect));
+        child.set_clip_rect(rect.intersect(ui.clip_rect()));
+        (self.add_contents)(&mut child, &gutter);
+    }
+}
+
+/// A strip next to an axis for custom widgets, like the mute buttons of the channels of a
+/// plot, see [`crate::Plot::axis_gutter`].
+///
+/// Place widgets with [`Ui::put`] at the rects given here, so they line up with the plot.
+#[derive(Clone, Copy, Debug)]
+pub struct AxisGutter {
+    axis: Axis,
+    rect: Rect,
+    transform: PlotTransform,
+}
+
+impl AxisGutter {
+    /// The axis the gutter runs along.
+    pub fn axis(&self) -> Axis {
+        self.axis
+    }
+
+    /// The whole gutter.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// The transform of the plot this frame.
+    pub fn transform(&self) -> &PlotTransform {
+        &self.transform
+    }
+
+    /// The screen position along the gutter of `value` on its axis.
+    pub fn position(&self, value: f64) -> f32 {
+        match self.axis {
+            Axis::X => self.transform.position_from_point_x(value),
+            Axis::Y => self.transform.position_from_point_y(value),
+        }
+    }
+
+    /// The part of the gutter next to the values in `range` on its axis, like the row of a
+    /// channel.
+    pub fn span(&self, range: RangeInclusive<f64>) -> Rect {
+        let [a, b] = [*range.start(), *range.end()].map(|value| self.position(value));
+        let along = a.min(b)..=a.max(b);
+        match self.axis {
+            Axis::X => Rect::from_x_y_ranges(along, self.rect.y_range()),
+            Axis::Y => Rect::from_x_y_ranges(self.rect.x_range(), along),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+//! A small plot inside the plot frame, magnifying a region of the data.
+
+use egui::{Align2, CursorIcon, Id, Rect, Sense, Shape, Ui, Vec2, Vec2b, vec2};
+
+use crate::{PlotBounds, PlotItem, PlotPoint, PlotTransform};
+
+/// Space between the inset and the edges of the plot frame.
+const INSET_MARGIN: f32 = 8.0;
+
+/// A picture-in-picture view of a region of the plot, e.g. to magnify a detail while showing
+/// the whole data.
+///
+/// The inset shows the same items as the plot, anchored in a corner of the plot frame, and a
+/// rectangle on the plot marks the region it shows. When it is interactive, dragging the inset
+/// or the rectangle moves the region. Add it with [`crate::Plot::inset`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Inset {
+    pub(crate) region: PlotBounds,
+    pub(crate) anchor: Align2,
+    pub(crate) size: Vec2,
+    pub(crate) show_extent: bool,
+    pub(crate) interactive: bool,
+}
+
+impl Inset {
+    /// An inset showing `region` of the plot.
+    ///
+    /// This is where the inset starts: once the user moves it, the plot remembers the moved
+    /// region until it is reset.
+    pub fn new(region: PlotBounds) -> Self {
+        Self {
+            region,
+            anchor: Align2::RIGHT_TOP,
+            size: vec2(160.0, 100.0),
+            show_extent: true,
+            interactive: true,
+        }
+    }
+
+    /// The corner or edge of the plot frame to put the inset in. Default: [`Align2::RIGHT_TOP`].
+    #[inline]
+    pub fn anchor(mut self, anchor: Align2) -> Self {
+        self.anchor = anchor;
+        self
+    }
+
+    /// Size of the inset in points, at most half the plot frame. Default: `160 × 100`.
+    #[inline]
+    pub fn size(mut self, size: impl Into<Vec2>) -> Self {
+        self.size = size.into().max(Vec2::splat(16.0));
+        self
+    }
+
+    /// Mark the region of the inset with a rectangle on the plot. Default: `true`.
+    #[inline]
+    pub fn show_extent(mut self, show: bool) -> Self {
+        self.show_extent = show;
+        self
+    }
+
+    /// Let the user move the region by dragging the inset or its rectangle on the plot.
+    /// Default: `true`.
+    #[inline]
+    pub fn interactive(mut self, interactive: bool) -> Self {
+        self.interactive = interactive;
+        self
+    }
+
+    /// The transform of the inset in the plot with the transform `main`, showing `region`.
+    pub(crate) fn transform(&self, main: &PlotTransform, region: PlotBounds) -> PlotTransform {
+        let frame = main.frame().shrink(INSET_MARGIN);
+        let rect = self
+            .anchor
+            .align_size_within_rect(self.size.min(0.5 * frame.size()), frame);
+        let mut transform = PlotTransform::new(rect, region, Vec2b::FALSE);
+        transform.set_inverted(main.inverted());
+        transform
+    }
+
+    /// Let the user drag the inset or its rectangle on the plot, and return the moved region.
+    pub(crate) fn interact(
+        &self,
+        ui: &Ui,
+        id: Id,
+        main: &PlotTransform,
+        region: PlotBounds,
+    ) -> PlotBounds {
+        if !self.interactive {
+            return region;
+        }
+        let inset = self.transform(main, region);
+        let mut moved = region;
+
+        // The inset is on top of the plot, so it takes the drags there first:
+        let response = ui.interact(*inset.frame(), id, Sense::drag());
+        let delta = response.drag_delta();
+        moved.translate((
+            -delta.x as f64 * inset.dvalue_dpos()[0],
+            -delta.y as f64 * inset.dvalue_dpos()[1],
+        ));
+
+        if self.show_extent {
+            let extent = extent_rect(main, &region);
+            if !extent.intersects(*inset.frame()) {
+                let response = ui.interact(extent, id.with("extent"), Sense::drag());
+                let delta = response.drag_delta();
+                moved.translate((
+                    delta.x as f64 * main.dvalue_dpos()[0],
+                    delta.y as f64 * main.dvalue_dpos()[1],
+                ));
+                grab_cursor(ui, &response);
+            }
+        }
+        grab_cursor(ui, &response);
+        moved
+    }
+
+    /// Paint the inset with the `item_shapes`, and the rectangle of its region on the plot.
+    pub(crate) fn paint(
+        &self,
+        ui: &Ui,
+        main: &PlotTransform,
+        inset: &PlotTransform,
+        item_shapes: Vec<Shape>,
+    ) {
+        let visuals = ui.visuals();
+        let stroke = visuals.selection.stroke;
+        if self.show_extent {
+            ui.painter().with_clip_rect(*main.frame()).rect_stroke(
+                extent_rect(main, inset.bounds()),
+                0,
+                stroke,
+                egui::StrokeKind::Inside,
+            );
+        }
+
+        let rect = *inset.frame();
+        let painter = ui.painter().with_clip_rect(rect);
+        painter.rect(
+            rect,
+            2,
+            visuals.extreme_bg_color,
+            visuals.widgets.noninteractive.bg_stroke,
+            egui::StrokeKind::Inside,
+        );
+        painter.extend(item_shapes);
+        painter.rect_stroke(rect, 2, stroke, egui::StrokeKind::Inside);
+    }
+}
+
+/// The shapes of `items` in the inset with the transform `inset`.
+pub(crate) fn item_shapes(
+    ui: &Ui,
+    inset: &PlotTransform,
+    items: &[Box<dyn PlotItem + '_>],
+) -> Vec<Shape> {
+    let mut shapes = Vec::new();
+    for item in items {
+        item.shapes(ui, inset, &mut shapes);
+    }
+    shapes
+}
+
+/// The rectangle on the plot marking `region`.
+fn extent_rect(main: &PlotTransform, region: &PlotBounds) -> Rect {
+    let [min, max] = [region.min(), region.max()].map(|[x, y]| PlotPoint::new(x, y));
+    main.rect_from_values(&min, &max)
+}
+
+fn grab_cursor(ui: &Ui, response: &egui::Response) {
+    if response.dragged() {
+        ui.ctx().set_cursor_icon(CursorIcon::Grabbing);
+    } else if response.hovered() {
+        ui.ctx().set_cursor_icon(CursorIcon::Grab);
+    }
+}
+
+#[test]
+fn test_inset_transform() {
+    let frame = Rect::from_min_size(egui::pos2(0.0, 0.0), vec2(400.0, 300.0));
+    let main = PlotTransform::new(
+        frame,
+        PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]),
+        Vec2b::FALSE,
+    );
+    let region = PlotBounds::from_min_max([2.0, 2.0], [3.0, 3.0]);
+
+    // In the top right corner, inside the margin:
+    let inset = Inset::new(region).transform(&main, region);
+    assert_eq!(
+        *inset.frame(),
+        Rect::from_min_max(egui::pos2(232.0, 8.0), egui::pos2(392.0, 108.0))
+    );
+    assert_eq!(inset.bounds(), &region);
+
+    // At most half the frame:
+    let inset = Inset::new(region)
+        .size([1000.0, 1000.0])
+        .anchor(Align2::LEFT_BOTTOM)
+        .transform(&main, region);
+    assert_eq!(
+        *inset.frame(),
+        Rect::from_min_max(egui::pos2(8.0, 150.0), egui::pos2(200.0, 292.0))
+    );
+}
@@ -18,44 +18,65 @@
 //
 // plot_ui.band(band);
 // ```
+//
+// Use `Band::from_columns(&x, &y_min, &y_max)` to borrow the data instead of copying it,
+// or `Band::around(&x, &y, &std)` to skip computing `y_min` and `y_max`.
 
-use std::ops::RangeInclusive;
+use std::{borrow::Cow, ops::RangeInclusive};
 
-use egui::{Color32, Mesh, Shape, Ui};
+use egui::{Color32, Mesh, Pos2, Shape, Stroke, Ui};
 
-use super::{PlotGeometry, PlotItem, PlotItemBase, PlotPoint};
-use crate::{PlotBounds, PlotTransform};
+use super::{PaintStage, PlotGeometry, PlotItem, PlotItemBase, PlotPoint, geom_helpers};
+use crate::{FillPattern, LegendGlyph, LineStyle, PlotBounds, PlotTransform, export::ExportShape};
 
 /// A shaded area between two curves  ``y_min(x) `` and  ``y_max(x) ``.
 #[derive(Clone, Debug)]
-pub struct Band {
+pub struct Band<'a> {
     ///  plot-item metadata (name, id, highlight, hover).
     base: PlotItemBase,
 
     /// Base color for the fill
     color: Color32,
 
+    /// Stroke of the `y_min` and `y_max` edges, if any.
+    outline: Option<Stroke>,
+
+    /// Pattern drawn on top of the fill.
+    pattern: Option<FillPattern>,
+
+    /// Center line of a band created with [`Self::around`], drawn if `center_stroke` is set.
+    center: Option<Cow<'a, [f64]>>,
+    center_stroke: Option<Stroke>,
+
+    /// Whether NaN samples break the band, or the samples around them are joined.
+    gap_on_nan: bool,
+
     /// Sampled x-coordinates.
-    xs: Vec<f64>,
+    xs: Cow<'a, [f64]>,
     /// Lower envelope `` y_min(x) ``.
-    y_min: Vec<f64>,
+    y_min: Cow<'a, [f64]>,
     /// Upper envelope  ``y_max(x) ``.
-    y_max: Vec<f64>,
+    y_max: Cow<'a, [f64]>,
 }
-impl Default for Band {
+impl Default for Band<'_> {
     fn default() -> Self {
         let default = Color32::from_rgba_unmultiplied(64, 160, 255, 96);
         Self {
-            base: PlotItemBase::new(String::new()),
+            base: PlotItemBase::new(String::new()).paint_stage(PaintStage::UnderData),
             color: default,
-            xs: Vec::new(),
-            y_min: Vec::new(),
-            y_max: Vec::new(),
+            outline: None,
+            pattern: None,
+            center: None,
+            center_stroke: None,
+            gap_on_nan: true,
+            xs: Cow::Borrowed(&[]),
+            y_min: Cow::Borrowed(&[]),
+            y_max: Cow::Borrowed(&[]),
         }
     }
 }
 
-impl Band {
+impl<'a> Band<'a> {
     /// Create an empty band
     ///
     /// Use [`Self::with_series`] to provide data and optionally [`Self::with_color`]
@@ -85,36 +106,98 @@ impl Band {
         self
     }
 
-    /// Provide series data. All inputs must have identical length.
+    /// Draw a pattern on top of the fill, e.g. [`FillPattern::hatch`], in the band color.
+    #[inline]
+    pub fn pattern(mut self, pattern: FillPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Draw the `y_min` and `y_max` edges with the given stroke.
     ///
-    /// NaN/non-finite samples are skipped segment-wise during tessellation.
-    pub fn with_series(mut self, xs: &[f64], y_min: &[f64], y_max: &[f64]) -> Self {
-        assert_eq!(
-            xs.len(),
-            y_min.len(),
-            "Band: xs and y_min must have the same length"
-        );
+    /// A transparent stroke color means the opaque band color.
+    #[inline]
+    pub fn outline(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.outline = Some(stroke.into());
+        self
+    }
+
+    /// Break the band at NaN or infinite samples, leaving a gap for the missing data, or join
+    /// the samples around them if `false`. Default: `true`.
+    #[inline]
+    pub fn gap_on_nan(mut self, gap: bool) -> Self {
+        self.gap_on_nan = gap;
+        self
+    }
+
+    /// A band of `center ± spread`, e.g. mean ± standard deviation.
+    /// All inputs must have identical length.
+    ///
+    /// Draw the center line as well with [`Self::center_line`].
+    pub fn around(xs: &'a [f64], center: &'a [f64], spread: &'a [f64]) -> Self {
+        Self::around_asymmetric(xs, center, spread, spread)
+    }
+
+    /// A band from `center - lower` to `center + upper`. All inputs must have identical length.
+    pub fn around_asymmetric(
+        xs: &'a [f64],
+        center: &'a [f64],
+        lower: &'a [f64],
+        upper: &'a [f64],
+    ) -> Self {
+        assert_same_len(xs, lower, upper);
         assert_eq!(
             xs.len(),
-            y_max.len(),
-            "Band: xs and y_max must have the same length"
+            center.len(),
+            "Band: xs and center must have the same length"
         );
+        let y_min = center.iter().zip(lower).map(|(c, d)| c - d).collect();
+        let y_max = center.iter().zip(upper).map(|(c, d)| c + d).collect();
+        Self {
+            xs: Cow::Borrowed(xs),
+            y_min: Cow::Owned(y_min),
+            y_max: Cow::Owned(y_max),
+            center: Some(Cow::Borrowed(center)),
+            ..Self::default()
+        }
+    }
 
-        let n = xs.len();
-        self.xs.clear();
-        self.y_min.clear();
-        self.y_max.clear();
-
-        self.xs.reserve_exact(n);
-        self.y_min.reserve_exact(n);
-        self.y_max.reserve_exact(n);
+    /// Draw the center line of a band created with [`Self::around`].
+    ///
+    /// A transparent stroke color means the opaque band color.
+    #[inline]
+    pub fn center_line(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.center_stroke = Some(stroke.into());
+        self
+    }
 
-        self.xs.extend_from_slice(xs);
-        self.y_min.extend_from_slice(y_min);
-        self.y_max.extend_from_slice(y_max);
+    /// Provide series data, copying it. All inputs must have identical length.
+    ///
+    /// NaN/non-finite samples leave a gap, see [`Self::gap_on_nan`].
+    /// See [`Self::from_columns`] to borrow the data instead.
+    pub fn with_series(mut self, xs: &[f64], y_min: &[f64], y_max: &[f64]) -> Self {
+        assert_same_len(xs, y_min, y_max);
+        self.xs = Cow::Owned(xs.to_vec());
+        self.y_min = Cow::Owned(y_min.to_vec());
+        self.y_max = Cow::Owned(y_max.to_vec());
+        self.center = None;
         self
     }
 
+    /// A band borrowing its data, like [`ColumnarSeries`](crate::ColumnarSeries).
+    /// All inputs must have identical length.
+    ///
+    /// NaN/non-finite samples leave a gap, see [`Self::gap_on_nan`].
+    pub fn from_columns(xs: &'a [f64], y_min: &'a [f64], y_max: &'a [f64]) -> Self {
+        assert_same_len(xs, y_min, y_max);
+        Self {
+            xs: Cow::Borrowed(xs),
+            y_min: Cow::Borrowed(y_min),
+            y_max: Cow::Borrowed(y_max),
+            ..Self::default()
+        }
+    }
+
     /// Compute data bounds for auto-scaling.
     fn compute_bounds(&self) -> Option<PlotBounds> {
         if self.xs.is_empty() {
@@ -153,34 +236,27 @@ impl Band {
     }
 
     /// Build a filled triangle mesh for the band in screen space.
-    fn build_mesh(&self, transform: &PlotTransform) -> Mesh {
-        let n = self.xs.len();
-        let n_segs = n.saturating_sub(1);
-
-        let mut mesh = Mesh::default();
-
-        mesh.vertices.reserve_exact(n_segs * 4);
-        mesh.indices.reserve_exact(n_segs * 6);
-
-        let fill = self.color;
-
-        for i in 0..self.xs.len().saturating_sub(1) {
-            let x0 = self.xs[i];
-            let x1 = self.xs[i + 1];
-            let yl0 = self.y_min[i];
-            let yl1 = self.y_min[i + 1];
-            let yu0 = self.y_max[i];
-            let yu1 = self.y_max[i + 1];
-
-            if !(x0.is_finite()
-                && x1.is_finite()
-                && yl0.is_finite()
-                && yl1.is_finite()
-                && yu0.is_finite()
-                && yu1.is_finite())
-            {
+    /// The screen corners of each segment between finite samples, counter-clockwise from the
+    /// lower left.
+    fn segment_quads(&self, transform: &PlotTransform) -> Vec<[Pos2; 4]> {
+        let mut quads = Vec::with_capacity(self.xs.len().saturating_sub(1));
+        let mut previous = None;
+        for i in 0..self.xs.len() {
+            if !(self.xs[i].is_finite() && self.y_min[i].is_finite() && self.y_max[i].is_finite()) {
+                if self.gap_on_nan {
+                    previous = None;
+                }
                 continue;
             }
+            let Some(j) = previous.replace(i) else {
+                continue;
+            };
+            let x0 = self.xs[j];
+            let x1 = self.xs[i];
+            let yl0 = self.y_min[j];
+            let yl1 = self.y_min[i];
+            let yu0 = self.y_max[j];
+            let yu1 = self.y_max[i];
 
             let (a0, b0) = if yl0 <= yu0 { (yl0, yu0) } else { (yu0, yl0) };
             let (a1, b1) = if yl1 <= yu1 { (yl1, yu1) } else { (yu1, yl1) };
@@ -190,11 +266,20 @@ impl Band {
             let p_ur = PlotPoint::new(x1, b1);
             let p_ul = PlotPoint::new(x0, b0);
 
-            let ll = transform.position_from_point(&p_ll);
-            let lr = transform.position_from_point(&p_lr);
-            let ur = transform.position_from_point(&p_ur);
-            let ul = transform.position_from_point(&p_ul);
+            quads.push([p_ll, p_lr, p_ur, p_ul].map(|p| transform.position_from_point(&p)));
+        }
+        quads
+    }
+
+    fn build_mesh(&self, quads: &[[Pos2; 4]]) -> Mesh {
+        let mut mesh = Mesh::default();
 
+        mesh.vertices.reserve_exact(quads.len() * 4);
+        mesh.indices.reserve_exact(quads.len() * 6);
+
+        let fill = self.color;
+
+        for &[ll, lr, ur, ul] in quads {
             let i0 = mesh.vertices.len() as u32;
             mesh.colored_vertex(ll, fill);
             let i1 = mesh.vertices.len() as u32;
@@ -210,17 +295,75 @@ impl Band {
 
         mesh
     }
+
+    /// Push one edge as lines, broken at non-finite samples unless `gap_on_nan` is off.
+    fn edge_shapes(
+        &self,
+        ys: &[f64],
+        stroke: Stroke,
+        transform: &PlotTransform,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let positions = self
+            .xs
+            .iter()
+            .zip(ys)
+            .map(|(&x, &y)| transform.position_from_point(&PlotPoint::new(x, y)));
+        for edge in geom_helpers::line_runs(positions, self.gap_on_nan) {
+            shapes.push(Shape::line(edge, stroke));
+        }
+    }
+
+    builder_methods_for_base!();
+}
+
+fn assert_same_len(xs: &[f64], y_min: &[f64], y_max: &[f64]) {
+    assert_eq!(
+        xs.len(),
+        y_min.len(),
+        "Band: xs and y_min must have the same length"
+    );
+    assert_eq!(
+        xs.len(),
+        y_max.len(),
+        "Band: xs and y_max must have the same length"
+    );
 }
 
-impl PlotItem for Band {
+impl PlotItem for Band<'_> {
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         if self.xs.len() < 2 {
             return;
         }
-        let mesh = self.build_mesh(transform);
+        let quads = self.segment_quads(transform);
+        let mesh = self.build_mesh(&quads);
         if !mesh.indices.is_empty() {
             shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
         }
+        if let Some(pattern) = &self.pattern {
+            for quad in &quads {
+                pattern.shapes(quad, *transform.frame(), self.color.to_opaque(), shapes);
+            }
+        }
+        if let Some(mut stroke) = self.outline {
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = self.color.to_opaque();
+            }
+            if self.base.highlight {
+                stroke.width *= 2.0;
+            }
+            self.edge_shapes(&self.y_min, stroke, transform, shapes);
+            self.edge_shapes(&self.y_max, stroke, transform, shapes);
+        }
+        if let (Some(center), Some(mut stroke)) = (&self.center, self.center_stroke) {
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = self.color.to_opaque();
+            }
+            if self.base.highlight {
+                stroke.width *= 2.0;
+            }
+            self.edge_shapes(center, stroke, transform, shapes);
+        }
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -233,10 +376,41 @@ impl PlotItem for Band {
         PlotGeometry::None
     }
 
+    fn screen_extent(&self) -> f32 {
+        [self.outline, self.center_stroke]
+            .into_iter()
+            .flatten()
+            .map(|stroke| 0.5 * stroke.width)
+            .fold(0.0, f32::max)
+    }
+
     fn bounds(&self) -> PlotBounds {
         self.compute_bounds().unwrap_or(PlotBounds::NOTHING)
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Area
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        let mut shapes = vec![ExportShape::FillBetween {
+            xs: self.xs.to_vec(),
+            lower: self.y_min.to_vec(),
+            upper: self.y_max.to_vec(),
+            color: self.color,
+        }];
+        if let (Some(center), Some(stroke)) = (&self.center, self.center_stroke) {
+            shapes.push(ExportShape::Line {
+                xs: self.xs.to_vec(),
+                ys: center.to_vec(),
+                stroke: Stroke::new(stroke.width, self.color.to_opaque()),
+                style: LineStyle::Solid,
+                marker: None,
+            });
+        }
+        Some(shapes)
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -2,7 +2,7 @@ use egui::emath::NumExt as _;
 use egui::epaint::{Color32, CornerRadius, RectShape, Shape, Stroke};
 
 use super::{Orientation, PlotConfig, RectElement, add_rulers_and_text, highlighted_color};
-use crate::{BarChart, Cursor, PlotPoint, PlotTransform};
+use crate::{BarChart, Cursor, PlotPoint, PlotTransform, transform::ease_in_out};
 
 /// One bar in a [`BarChart`]. Potentially floating, allowing stacked bar charts.
 /// Width can be changed to allow variable-width histograms.
@@ -201,3 +201,93 @@ impl RectElement for Bar {
         crate::format_number(self.value, decimals)
     }
 }
+
+// ----------------------------------------------------------------------------
+
+/// The base and value of each bar of an animated [`BarChart`], retained between frames.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) struct BarAnimation {
+    from: Vec<[f64; 2]>,
+    to: Vec<[f64; 2]>,
+
+    /// In seconds, like [`egui::InputState::time`].
+    start: f64,
+}
+
+impl BarAnimation {
+    /// Move `bars` from where the animation stored at `id` left them towards their values, over
+    /// `duration` seconds. Returns whether they are still moving.
+    pub(crate) fn tween(
+        ctx: &egui::Context,
+        id: egui::Id,
+        bars: &mut [Bar],
+        duration: f64,
+    ) -> bool {
+        let time = ctx.input(|i| i.time);
+        let target: Vec<[f64; 2]> = bars
+            .iter()
+            .map(|bar| [bar.base_offset.unwrap_or(0.0), bar.value])
+            .collect();
+        let previous: Option<Self> = ctx.data(|data| data.get_temp(id));
+        let animation = match previous {
+            Some(animation) if animation.to == target => animation,
+            Some(animation) => {
+                // Start from where the bars are now; new bars grow from their base:
+                let mut from = animation.at(time, duration);
+                from.resize(target.len(), [0.0; 2]);
+                for (from, to) in from.iter_mut().zip(&target).skip(animation.to.len()) {
+                    *from = [to[0], 0.0];
+                }
+                Self {
+                    from,
+                    to: target,
+                    start: time,
+                }
+            }
+            None => Self {
+                from: target.clone(),
+                to: target,
+                start: time,
+            },
+        };
+
+        let moving = time < animation.start + duration;
+        if moving {
+            for (bar, [base, value]) in bars.iter_mut().zip(animation.at(time, duration)) {
+                if bar.base_offset.is_some() || base != 0.0 {
+                    bar.base_offset = Some(base);
+                }
+                bar.value = value;
+            }
+        }
+        ctx.data_mut(|data| data.insert_temp(id, animation));
+        moving
+    }
+
+    fn at(&self, time: f64, duration: f64) -> Vec<[f64; 2]> {
+        let t = if duration > 0.0 {
+            ((time - self.start) / duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let eased = ease_in_out(t);
+        self.from
+            .iter()
+            .zip(&self.to)
+            .map(|(from, to)| [0, 1].map(|i| from[i] + (to[i] - from[i]) * eased))
+            .collect()
+    }
+}
+
+#[test]
+fn test_bar_animation() {
+    let animation = BarAnimation {
+        from: vec![[0.0, 2.0], [1.0, 0.0]],
+        to: vec![[0.0, 4.0], [3.0, 2.0]],
+        start: 1.0,
+    };
+    assert_eq!(animation.at(1.0, 2.0), animation.from);
+    assert_eq!(animation.at(2.0, 2.0), vec![[0.0, 3.0], [2.0, 1.0]]);
+    assert_eq!(animation.at(5.0, 2.0), animation.to);
+    assert_eq!(animation.at(1.0, 0.0), animation.to);
+}
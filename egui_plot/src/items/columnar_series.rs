@@ -2,6 +2,7 @@
 use crate::transform::PlotBounds;
 use core::fmt;
 use core::ops::{Bound, RangeBounds};
+use std::sync::Arc;
 
 /// A zero-copy Series of `(x, y)`.
 ///
@@ -127,6 +128,47 @@ impl<'a> ColumnarSeries<'a> {
         }
     }
 
+    /// Reduce the series to fewer samples with the Douglas-Peucker algorithm, so that a line
+    /// through them is nowhere farther than `tolerance` (in plot units) from the line through
+    /// all of them.
+    ///
+    /// Meant for static high-density curves: simplify once and keep the result, rather than
+    /// every frame like [`crate::Line::simplify`]. Distances mix x and y, so with very
+    /// different units on the axes, scale them first. Non-finite samples are kept, so gaps
+    /// stay gaps.
+    ///
+    /// ```
+    /// # use egui_plot::ColumnarSeries;
+    /// let xs: Vec<f64> = (0..10_000).map(|i| i as f64 * 1e-3).collect();
+    /// let ys: Vec<f64> = xs.iter().map(|x| x.sin()).collect();
+    /// let (sxs, sys) = ColumnarSeries::new(&xs, &ys).simplify(1e-3);
+    /// assert!(sxs.len() < xs.len() / 10);
+    /// let simplified = ColumnarSeries::new(&sxs, &sys);
+    /// ```
+    pub fn simplify(&self, tolerance: f64) -> (Vec<f64>, Vec<f64>) {
+        let mut keep = Vec::new();
+        let mut start = 0;
+        while start < self.len() {
+            let finite = |i: usize| self.xs[i].is_finite() && self.ys[i].is_finite();
+            if !finite(start) {
+                keep.push(start);
+                start += 1;
+                continue;
+            }
+            let end = (start..self.len())
+                .find(|&i| !finite(i))
+                .unwrap_or(self.len());
+            let point = |i: usize| [self.xs[start + i], self.ys[start + i]];
+            keep.extend(
+                crate::items::geom_helpers::simplify_indices(end - start, point, tolerance)
+                    .into_iter()
+                    .map(|i| start + i),
+            );
+            start = end;
+        }
+        keep.iter().map(|&i| (self.xs[i], self.ys[i])).unzip()
+    }
+
     /// Estimate numeric bounds over all finite points in the series.
     ///
     /// Non-finite values (`NaN`, `±∞`) are **ignored**. If no finite values
@@ -213,3 +255,64 @@ impl<'a> From<(&'a [f64], &'a [f64])> for ColumnarSeries<'a> {
         Self::new(tup.0, tup.1)
     }
 }
+
+/// An x column that many owned series can share, like the time base of hundreds of channels
+/// recorded together, see [`crate::Line::new_shared_xs`].
+///
+/// Cloning it is cheap: the values are reference counted, not copied.
+#[derive(Clone, PartialEq)]
+pub struct SharedXs(Arc<[f64]>);
+
+impl SharedXs {
+    /// Share `xs` between series.
+    #[inline]
+    pub fn new(xs: impl Into<Arc<[f64]>>) -> Self {
+        Self(xs.into())
+    }
+
+    /// Borrow the values.
+    #[inline]
+    pub fn as_slice(&self) -> &[f64] {
+        &self.0
+    }
+
+    /// Number of values.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Is the column empty?
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Whether both handles share the same values, rather than equal copies.
+    #[inline]
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl fmt::Debug for SharedXs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedXs")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl From<Vec<f64>> for SharedXs {
+    #[inline]
+    fn from(xs: Vec<f64>) -> Self {
+        Self::new(xs)
+    }
+}
+
+impl From<Arc<[f64]>> for SharedXs {
+    #[inline]
+    fn from(xs: Arc<[f64]>) -> Self {
+        Self(xs)
+    }
+}
@@ -0,0 +1,231 @@
+//! Mean line with a min/max envelope, the usual view of a downsampled signal.
+
+use std::{borrow::Cow, ops::RangeInclusive};
+
+use egui::{Color32, Pos2, Shape, Stroke, Ui, emath::Float as _};
+
+use super::{
+    Band, ClosestElem, Cursor, LabelFormatter, PlotConfig, PlotGeometry, PlotItem, PlotItemBase,
+    PlotPoint, show_tooltip_text,
+};
+use crate::{LegendGlyph, LineStyle, PlotBounds, PlotTransform, export::ExportShape};
+
+/// Opacity of the envelope, relative to the color of the mean line.
+const ENVELOPE_OPACITY: f32 = 0.25;
+
+/// The mean of a signal with its min/max envelope, drawn as one item with one legend entry.
+///
+/// The tooltip shows the min and max below the label of the mean at the hovered sample.
+///
+/// ```
+/// # use egui_plot::EnvelopeLine;
+/// let xs: Vec<f64> = (0..100).map(|i| i as f64).collect();
+/// let mean: Vec<f64> = xs.iter().map(|x| (x * 0.1).sin()).collect();
+/// let min: Vec<f64> = mean.iter().map(|y| y - 0.2).collect();
+/// let max: Vec<f64> = mean.iter().map(|y| y + 0.3).collect();
+///
+/// let envelope = EnvelopeLine::new("signal", &xs, &min, &mean, &max);
+/// ```
+#[derive(Clone, Debug)]
+pub struct EnvelopeLine<'a> {
+    base: PlotItemBase,
+
+    /// Stroke of the mean line. The envelope is filled in a lighter shade of its color.
+    stroke: Stroke,
+
+    xs: Cow<'a, [f64]>,
+    min: Cow<'a, [f64]>,
+    mean: Cow<'a, [f64]>,
+    max: Cow<'a, [f64]>,
+}
+
+impl<'a> EnvelopeLine<'a> {
+    /// All columns must have the same length.
+    pub fn new(
+        name: impl Into<String>,
+        xs: impl Into<Cow<'a, [f64]>>,
+        min: impl Into<Cow<'a, [f64]>>,
+        mean: impl Into<Cow<'a, [f64]>>,
+        max: impl Into<Cow<'a, [f64]>>,
+    ) -> Self {
+        let (xs, min, mean, max) = (xs.into(), min.into(), mean.into(), max.into());
+        assert!(
+            min.len() == xs.len() && mean.len() == xs.len() && max.len() == xs.len(),
+            "EnvelopeLine: xs, min, mean and max must have the same length"
+        );
+        Self {
+            base: PlotItemBase::new(name.into()),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            xs,
+            min,
+            mean,
+            max,
+        }
+    }
+
+    /// Color of the mean line and the envelope.
+    /// Default: [`Color32::TRANSPARENT`], which means a color is picked automatically.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Width of the mean line. Default: `1.5`.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    fn envelope_color(&self) -> Color32 {
+        self.stroke
+            .color
+            .to_opaque()
+            .gamma_multiply(ENVELOPE_OPACITY)
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for EnvelopeLine<'_> {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        Band::from_columns(&self.xs, &self.min, &self.max)
+            .with_color(self.envelope_color())
+            .shapes(ui, transform, shapes);
+
+        let mut stroke = self.stroke;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+        }
+        let mut line = Vec::with_capacity(self.xs.len());
+        for (&x, &y) in self.xs.iter().zip(self.mean.iter()) {
+            if x.is_finite() && y.is_finite() {
+                line.push(transform.position_from_point(&PlotPoint::new(x, y)));
+            } else if !line.is_empty() {
+                shapes.push(Shape::line(std::mem::take(&mut line), stroke));
+            }
+        }
+        if !line.is_empty() {
+            shapes.push(Shape::line(line, stroke));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::PointsXY {
+            xs: &self.xs,
+            ys: &self.mean,
+        }
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for ys in [&self.min, &self.mean, &self.max] {
+            for (&x, &y) in self.xs.iter().zip(ys.iter()) {
+                if x.is_finite() && y.is_finite() {
+                    bounds.extend_with(&PlotPoint::new(x, y));
+                }
+            }
+        }
+        bounds
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line {
+            style: LineStyle::Solid,
+            marker: None,
+        }
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        Some(vec![
+            ExportShape::FillBetween {
+                xs: self.xs.to_vec(),
+                lower: self.min.to_vec(),
+                upper: self.max.to_vec(),
+                color: self.envelope_color(),
+            },
+            ExportShape::Line {
+                xs: self.xs.to_vec(),
+                ys: self.mean.to_vec(),
+                stroke: self.stroke,
+                style: LineStyle::Solid,
+                marker: None,
+            },
+        ])
+    }
+
+    /// The sample whose min–max range passes closest to the pointer.
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        (0..self.xs.len())
+            .filter(|&index| self.xs[index].is_finite() && self.mean[index].is_finite())
+            .map(|index| {
+                let x = self.xs[index];
+                let [min, max] = [self.min[index], self.max[index]].map(|y| {
+                    let y = if y.is_finite() { y } else { self.mean[index] };
+                    transform.position_from_point(&PlotPoint::new(x, y))
+                });
+                let (top, bottom) = (min.y.min(max.y), min.y.max(max.y));
+                let closest = Pos2::new(min.x, point.y.clamp(top, bottom));
+                ClosestElem {
+                    index,
+                    dist_sq: point.distance_sq(closest),
+                }
+            })
+            .min_by_key(|elem| elem.dist_sq.ord())
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let i = elem.index;
+        let (x, min, mean, max) = (self.xs[i], self.min[i], self.mean[i], self.max[i]);
+
+        for y in [min, mean, max] {
+            if y.is_finite() {
+                let pos = plot.transform.position_from_point(&PlotPoint::new(x, y));
+                shapes.push(Shape::circle_filled(pos, 3.0, self.stroke.color));
+            }
+        }
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: mean });
+        }
+
+        // The label of the mean, custom or not, followed by the envelope:
+        let label = if let Some(custom_label) = label_formatter {
+            custom_label(self.name(), &PlotPoint::new(x, mean))
+        } else {
+            let [x, mean] = plot.format_values(x, mean);
+            format!("{}\nx = {x}\nmean = {mean}", self.name())
+        };
+        let [max, min] = [max, min].map(|y| {
+            let [_, y] = plot.format_values(x, y);
+            y
+        });
+        let text = format!("{label}\nmax = {max}\nmin = {min}");
+        show_tooltip_text(plot_area_response, text);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
@@ -0,0 +1,251 @@
+//! Fan chart item: several nested envelopes, e.g. the 50/80/95% quantiles of a forecast.
+
+use std::{borrow::Cow, ops::RangeInclusive};
+
+use egui::{Color32, Shape, Stroke, Ui};
+
+use super::{Band, PaintStage, PlotGeometry, PlotItem, PlotItemBase, PlotPoint};
+use crate::{LegendGlyph, LineStyle, PlotBounds, PlotTransform, export::ExportShape};
+
+/// Combined opacity of the innermost level, where all levels overlap.
+const TOTAL_OPACITY: f32 = 0.75;
+
+/// Nested shaded envelopes around a series, drawn as one item with one legend entry.
+///
+/// Each level is filled with the same color; wider levels are drawn first and the fills add up,
+/// so the narrowest envelope is the most opaque.
+///
+/// ```
+/// # use egui_plot::FanBand;
+/// let xs: Vec<f64> = (0..50).map(|i| i as f64).collect();
+/// let median: Vec<f64> = xs.iter().map(|x| x.sqrt()).collect();
+/// let spread = |q: f64| -> (Vec<f64>, Vec<f64>) {
+///     let lo = median.iter().zip(&xs).map(|(m, x)| m - q * x * 0.05).collect();
+///     let hi = median.iter().zip(&xs).map(|(m, x)| m + q * x * 0.05).collect();
+///     (lo, hi)
+/// };
+/// let (lo_95, hi_95) = spread(2.0);
+/// let (lo_50, hi_50) = spread(0.7);
+///
+/// let fan = FanBand::new("forecast", &xs)
+///     .level(&lo_95, &hi_95)
+///     .level(&lo_50, &hi_50)
+///     .center(&median, (1.5, egui::Color32::TRANSPARENT));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FanBand<'a> {
+    base: PlotItemBase,
+
+    /// Base color of the fills and the center line.
+    color: Color32,
+
+    xs: Cow<'a, [f64]>,
+
+    /// Lower and upper edge of each envelope, in the order they were added.
+    levels: Vec<(Cow<'a, [f64]>, Cow<'a, [f64]>)>,
+
+    /// Center line, e.g. the median.
+    center: Option<(Cow<'a, [f64]>, Stroke)>,
+}
+
+impl<'a> FanBand<'a> {
+    /// A fan chart without levels over the sample positions `xs`. Add envelopes with
+    /// [`Self::level`].
+    pub fn new(name: impl Into<String>, xs: impl Into<Cow<'a, [f64]>>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()).paint_stage(PaintStage::UnderData),
+            color: Color32::TRANSPARENT,
+            xs: xs.into(),
+            levels: Vec::new(),
+            center: None,
+        }
+    }
+
+    /// Add an envelope from `lower` to `upper`. Both must have the same length as `xs`.
+    ///
+    /// Levels may be added in any order, they are drawn from the widest to the narrowest.
+    pub fn level(
+        mut self,
+        lower: impl Into<Cow<'a, [f64]>>,
+        upper: impl Into<Cow<'a, [f64]>>,
+    ) -> Self {
+        let (lower, upper) = (lower.into(), upper.into());
+        assert_eq!(
+            self.xs.len(),
+            lower.len(),
+            "FanBand: xs and lower must have the same length"
+        );
+        assert_eq!(
+            self.xs.len(),
+            upper.len(),
+            "FanBand: xs and upper must have the same length"
+        );
+        self.levels.push((lower, upper));
+        self
+    }
+
+    /// Draw a center line, e.g. the median, on top of the envelopes.
+    ///
+    /// A transparent stroke color means the color of the fan.
+    pub fn center(mut self, values: impl Into<Cow<'a, [f64]>>, stroke: impl Into<Stroke>) -> Self {
+        let values = values.into();
+        assert_eq!(
+            self.xs.len(),
+            values.len(),
+            "FanBand: xs and center must have the same length"
+        );
+        self.center = Some((values, stroke.into()));
+        self
+    }
+
+    /// Base color of the fan. The opacity of the levels is derived from it.
+    /// Default: [`Color32::TRANSPARENT`], which means a color is picked automatically.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// The levels ordered from the widest to the narrowest, by mean width.
+    fn levels_by_width(&self) -> Vec<&(Cow<'a, [f64]>, Cow<'a, [f64]>)> {
+        let mean_width = |(lower, upper): &(Cow<'a, [f64]>, Cow<'a, [f64]>)| {
+            let (sum, count) = lower
+                .iter()
+                .zip(upper.iter())
+                .map(|(lo, hi)| (hi - lo).abs())
+                .filter(|width| width.is_finite())
+                .fold((0.0, 0_usize), |(sum, count), width| {
+                    (sum + width, count + 1)
+                });
+            if count == 0 { 0.0 } else { sum / count as f64 }
+        };
+        let mut levels: Vec<_> = self.levels.iter().collect();
+        levels.sort_by(|a, b| mean_width(b).total_cmp(&mean_width(a)));
+        levels
+    }
+
+    builder_methods_for_base!();
+}
+
+/// Fill opacity of each of `count` overlapping levels, so that all of them together reach
+/// [`TOTAL_OPACITY`].
+fn level_opacity(count: usize) -> f32 {
+    if count == 0 {
+        return 0.0;
+    }
+    1.0 - (1.0 - TOTAL_OPACITY).powf(1.0 / count as f32)
+}
+
+impl PlotItem for FanBand<'_> {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let fill = self
+            .color
+            .to_opaque()
+            .gamma_multiply(level_opacity(self.levels.len()));
+        for (lower, upper) in self.levels_by_width() {
+            Band::from_columns(&self.xs, lower, upper)
+                .with_color(fill)
+                .shapes(ui, transform, shapes);
+        }
+        if let Some((center, stroke)) = &self.center {
+            let mut stroke = *stroke;
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = self.color.to_opaque();
+            }
+            if self.base.highlight {
+                stroke.width *= 2.0;
+            }
+            let mut line = Vec::with_capacity(self.xs.len());
+            for (&x, &y) in self.xs.iter().zip(center.iter()) {
+                if x.is_finite() && y.is_finite() {
+                    line.push(transform.position_from_point(&PlotPoint::new(x, y)));
+                } else if !line.is_empty() {
+                    shapes.push(Shape::line(std::mem::take(&mut line), stroke));
+                }
+            }
+            if !line.is_empty() {
+                shapes.push(Shape::line(line, stroke));
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        let columns = self
+            .levels
+            .iter()
+            .flat_map(|(lower, upper)| [lower, upper])
+            .chain(self.center.as_ref().map(|(center, _)| center));
+        for ys in columns {
+            for (&x, &y) in self.xs.iter().zip(ys.iter()) {
+                if x.is_finite() && y.is_finite() {
+                    bounds.extend_with(&PlotPoint::new(x, y));
+                }
+            }
+        }
+        bounds
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Area
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        let fill = self
+            .color
+            .to_opaque()
+            .gamma_multiply(level_opacity(self.levels.len()));
+        let mut shapes: Vec<_> = self
+            .levels_by_width()
+            .into_iter()
+            .map(|(lower, upper)| ExportShape::FillBetween {
+                xs: self.xs.to_vec(),
+                lower: lower.to_vec(),
+                upper: upper.to_vec(),
+                color: fill,
+            })
+            .collect();
+        if let Some((center, stroke)) = &self.center {
+            let mut stroke = *stroke;
+            if stroke.color == Color32::TRANSPARENT {
+                stroke.color = self.color.to_opaque();
+            }
+            shapes.push(ExportShape::Line {
+                xs: self.xs.to_vec(),
+                ys: center.to_vec(),
+                stroke,
+                style: LineStyle::Solid,
+                marker: None,
+            });
+        }
+        Some(shapes)
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[test]
+fn test_level_opacity() {
+    for count in 1..5 {
+        let opacity = level_opacity(count);
+        let combined = 1.0 - (1.0 - opacity).powi(count as i32);
+        assert!((combined - TOTAL_OPACITY).abs() < 1e-5);
+    }
+    assert_eq!(level_opacity(0), 0.0);
+}
@@ -0,0 +1,188 @@
+//! Event flags: labeled markers at x positions, anchored to the bottom of the plot.
+
+use std::{ops::RangeInclusive, sync::Arc};
+
+use egui::{
+    Color32, CornerRadius, Galley, Id, Pos2, Rect, Shape, Stroke, StrokeKind, TextStyle, Ui,
+    epaint::RectShape, pos2, vec2,
+};
+
+use super::{ItemInteraction, PaintStage, PlotGeometry, PlotItem, PlotItemBase};
+use crate::{PlotBounds, PlotTransform};
+
+/// Gap between stacked flags, and between a flag's text and its border.
+const FLAG_PADDING: f32 = 2.0;
+
+/// A single flag of an [`EventFlags`] item.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventFlag {
+    id: Id,
+    x: f64,
+    label: String,
+    color: Option<Color32>,
+}
+
+impl EventFlag {
+    /// A flag at `x` with the given label. `id` is reported when the flag is clicked.
+    pub fn new(id: impl Into<Id>, x: f64, label: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            x,
+            label: label.into(),
+            color: None,
+        }
+    }
+
+    /// Color of this flag. Defaults to the color of the [`EventFlags`] item.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// Labeled flags at given x positions (e.g. deploys or alarms on a time axis).
+///
+/// The flags are anchored to the bottom of the plot, so they stay visible however the y-axis is
+/// panned or zoomed. Overlapping labels are stacked on top of each other. Clicking a flag emits
+/// [`PlotEvent::FlagClicked`](crate::PlotEvent::FlagClicked).
+#[derive(Clone, Debug)]
+pub struct EventFlags {
+    base: PlotItemBase,
+    flags: Vec<EventFlag>,
+    color: Color32,
+}
+
+impl EventFlags {
+    /// An item showing the given flags.
+    pub fn new(name: impl Into<String>, flags: Vec<EventFlag>) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()).paint_stage(PaintStage::OverData),
+            flags,
+            color: Color32::TRANSPARENT,
+        }
+    }
+
+    /// Default color of the flags.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Screen rectangles and galleys of the visible flag labels, by index into `self.flags`.
+    fn layout(&self, ui: &Ui, transform: &PlotTransform) -> Vec<(usize, Rect, Arc<Galley>)> {
+        let frame = transform.frame();
+        let font_id = TextStyle::Small.resolve(ui.style());
+
+        // Left to right on screen (which is not the x order if the axis is inverted):
+        let mut order: Vec<(usize, f32)> = (0..self.flags.len())
+            .filter(|&i| self.flags[i].x.is_finite())
+            .map(|i| (i, transform.position_from_point_x(self.flags[i].x)))
+            .collect();
+        order.sort_by(|(_, a), (_, b)| a.total_cmp(b));
+
+        // Right edge of the last label in each row, bottom row first:
+        let mut rows: Vec<f32> = Vec::new();
+        let mut placed = Vec::with_capacity(order.len());
+        for (i, x) in order {
+            let flag = &self.flags[i];
+            if x < frame.left() || x > frame.right() {
+                continue;
+            }
+            let galley = ui.fonts(|f| {
+                f.layout_no_wrap(flag.label.clone(), font_id.clone(), Color32::PLACEHOLDER)
+            });
+            let size = galley.size() + vec2(2.0 * FLAG_PADDING, 2.0 * FLAG_PADDING);
+
+            let row = rows
+                .iter()
+                .position(|&right| right + FLAG_PADDING < x)
+                .unwrap_or(rows.len());
+            if row == rows.len() {
+                rows.push(f32::NEG_INFINITY);
+            }
+            rows[row] = x + size.x;
+
+            let bottom = frame.bottom() - row as f32 * (size.y + FLAG_PADDING);
+            let rect = Rect::from_min_size(pos2(x, bottom - size.y), size);
+            placed.push((i, rect, galley));
+        }
+        placed
+    }
+
+    /// The id of the flag under `pointer`, if any, the last one on top.
+    pub(crate) fn flag_at(&self, ui: &Ui, pointer: Pos2, transform: &PlotTransform) -> Option<Id> {
+        self.layout(ui, transform)
+            .into_iter()
+            .rev()
+            .find(|(_, rect, _)| rect.contains(pointer))
+            .map(|(i, _, _)| self.flags[i].id)
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for EventFlags {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let frame = transform.frame();
+        let text_color = ui.visuals().strong_text_color();
+        let width = if self.base.highlight { 2.0 } else { 1.0 };
+
+        for (i, rect, galley) in self.layout(ui, transform) {
+            let color = self.flags[i].color.unwrap_or(self.color);
+            shapes.push(Shape::line_segment(
+                [
+                    pos2(rect.left(), frame.top()),
+                    pos2(rect.left(), rect.bottom()),
+                ],
+                Stroke::new(width, color),
+            ));
+            shapes.push(Shape::Rect(RectShape::new(
+                rect,
+                CornerRadius::same(2),
+                color.gamma_multiply(0.35),
+                Stroke::new(width, color),
+                StrokeKind::Inside,
+            )));
+            shapes.push(Shape::galley(
+                rect.min + vec2(FLAG_PADDING, FLAG_PADDING),
+                galley,
+                text_color,
+            ));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::None
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for flag in &self.flags {
+            if flag.x.is_finite() {
+                bounds.extend_with_x(flag.x);
+            }
+        }
+        bounds
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+
+    fn interaction(&self) -> ItemInteraction<'_> {
+        ItemInteraction::Flags(self)
+    }
+}
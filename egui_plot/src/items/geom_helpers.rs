@@ -29,15 +29,15 @@ pub fn push_polygon_at(
     out: &mut Vec<Shape>,
     center: Pos2,
     local_pts: Vec<Vec2>,
-    color: Color32,
-    stroke: Stroke,
+    fill: Color32,
+    outline: Stroke,
     filled: bool,
 ) {
     let pts: Vec<Pos2> = local_pts.into_iter().map(|v| center + v).collect();
     if filled {
-        out.push(Shape::convex_polygon(pts, color, Stroke::NONE));
+        out.push(Shape::convex_polygon(pts, fill, outline));
     } else {
-        out.push(Shape::closed_line(pts, Stroke::new(stroke.width, color)));
+        out.push(Shape::closed_line(pts, outline));
     }
 }
 // #[inline]
@@ -45,3 +45,170 @@ pub fn push_polygon_at(
 // pub fn degree_to_radius(d: i16) -> f32 {
 //     (d as f32) * PI / 180.0
 // }
+
+/// Keep the first, lowest, highest and last of each run of positions in the same pixel column.
+///
+/// A line through these looks the same as through all positions when there are many more of them
+/// than columns. Non-finite positions are kept as they are.
+pub fn decimate_columns(positions: impl Iterator<Item = Pos2>) -> Vec<Pos2> {
+    fn flush(out: &mut Vec<Pos2>, [first, min, max, last]: [Pos2; 4]) {
+        let (a, b) = if min.x <= max.x {
+            (min, max)
+        } else {
+            (max, min)
+        };
+        for pos in [first, a, b, last] {
+            if out.last() != Some(&pos) {
+                out.push(pos);
+            }
+        }
+    }
+
+    let mut out = Vec::new();
+    // The pixel column and its first, lowest, highest and last positions:
+    let mut column: Option<(f32, [Pos2; 4])> = None;
+    for pos in positions {
+        if !pos.x.is_finite() || !pos.y.is_finite() {
+            if let Some((_, run)) = column.take() {
+                flush(&mut out, run);
+            }
+            out.push(pos);
+            continue;
+        }
+        match &mut column {
+            Some((x, run)) if *x == pos.x.floor() => {
+                if pos.y < run[1].y {
+                    run[1] = pos;
+                }
+                if pos.y > run[2].y {
+                    run[2] = pos;
+                }
+                run[3] = pos;
+            }
+            _ => {
+                if let Some((_, run)) = column.take() {
+                    flush(&mut out, run);
+                }
+                column = Some((pos.x.floor(), [pos; 4]));
+            }
+        }
+    }
+    if let Some((_, run)) = column {
+        flush(&mut out, run);
+    }
+    out
+}
+
+/// Split `positions` into runs of finite positions, broken at the non-finite ones if
+/// `gap_on_nan`, or skipping them otherwise.
+pub fn line_runs(positions: impl Iterator<Item = Pos2>, gap_on_nan: bool) -> Vec<Vec<Pos2>> {
+    let mut runs = vec![Vec::new()];
+    for pos in positions {
+        if pos.is_finite() {
+            runs.last_mut().expect("never empty").push(pos);
+        } else if gap_on_nan && runs.last().is_some_and(|run| !run.is_empty()) {
+            runs.push(Vec::new());
+        }
+    }
+    runs.retain(|run| !run.is_empty());
+    runs
+}
+
+/// The indices of the points to keep of the `len` points given by `point`, so the polyline
+/// through them is nowhere farther than `tolerance` from the one through all of them
+/// (Douglas-Peucker). The first and last points are always kept.
+pub fn simplify_indices(
+    len: usize,
+    point: impl Fn(usize) -> [f64; 2],
+    tolerance: f64,
+) -> Vec<usize> {
+    if len <= 2 {
+        return (0..len).collect();
+    }
+    let mut keep = vec![false; len];
+    keep[0] = true;
+    keep[len - 1] = true;
+    let mut spans = vec![(0, len - 1)];
+    while let Some((first, last)) = spans.pop() {
+        let [ax, ay] = point(first);
+        let [bx, by] = point(last);
+        let (dx, dy) = (bx - ax, by - ay);
+        let length_sq = dx * dx + dy * dy;
+        let mut farthest = (0.0, first);
+        for i in first + 1..last {
+            let [px, py] = point(i);
+            let t = if length_sq > 0.0 {
+                (((px - ax) * dx + (py - ay) * dy) / length_sq).clamp(0.0, 1.0)
+            } else {
+                0.0
+            };
+            let distance = (px - ax - t * dx).hypot(py - ay - t * dy);
+            if distance > farthest.0 {
+                farthest = (distance, i);
+            }
+        }
+        let (distance, index) = farthest;
+        if distance > tolerance {
+            keep[index] = true;
+            spans.push((first, index));
+            spans.push((index, last));
+        }
+    }
+    (0..len).filter(|&i| keep[i]).collect()
+}
+
+/// Simplify `positions` to within `tolerance` points, see [`simplify_indices`].
+pub fn simplify_positions(positions: &[Pos2], tolerance: f32) -> Vec<Pos2> {
+    let point = |i: usize| [positions[i].x as f64, positions[i].y as f64];
+    simplify_indices(positions.len(), point, tolerance as f64)
+        .into_iter()
+        .map(|i| positions[i])
+        .collect()
+}
+
+#[test]
+fn test_decimate_columns() {
+    // 100 positions over two pixel columns, with a spike in the first one:
+    let positions = (0..100).map(|i| {
+        let x = i as f32 / 50.0;
+        let y = if i == 20 { 50.0 } else { x };
+        Pos2::new(x, y)
+    });
+    let decimated = decimate_columns(positions);
+    let ys: Vec<f32> = decimated.iter().map(|pos| pos.y).collect();
+    assert_eq!(ys, [0.0, 50.0, 49.0 / 50.0, 1.0, 99.0 / 50.0]);
+
+    // Gaps are kept:
+    let gap = [
+        Pos2::new(0.0, 0.0),
+        Pos2::new(0.5, f32::NAN),
+        Pos2::new(0.6, 1.0),
+    ];
+    assert_eq!(decimate_columns(gap.into_iter()).len(), 3);
+}
+
+#[test]
+fn test_line_runs() {
+    let nan = Pos2::new(f32::NAN, 0.0);
+    let [a, b, c] = [0.0, 1.0, 2.0].map(|x| Pos2::new(x, x));
+    let positions = [nan, a, b, nan, nan, c, nan];
+    assert_eq!(
+        line_runs(positions.into_iter(), true),
+        vec![vec![a, b], vec![c]]
+    );
+    assert_eq!(line_runs(positions.into_iter(), false), vec![vec![a, b, c]]);
+}
+
+#[test]
+fn test_simplify_indices() {
+    // A flat line with a small wobble and a spike:
+    let ys = [0.0, 0.05, 0.0, -0.05, 0.0, 3.0, 0.0, 0.0];
+    let point = |i: usize| [i as f64, ys[i]];
+    assert_eq!(simplify_indices(ys.len(), point, 0.1), vec![0, 4, 5, 6, 7]);
+    // Point 2 is on the line from point 1 to point 3:
+    assert_eq!(
+        simplify_indices(ys.len(), point, 0.01),
+        vec![0, 1, 3, 4, 5, 6, 7]
+    );
+    assert_eq!(simplify_indices(ys.len(), point, 10.0), vec![0, 7]);
+}
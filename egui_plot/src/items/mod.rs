@@ -11,41 +11,59 @@ use egui::{
     pos2, vec2,
 };
 
-use super::{Cursor, LabelFormatter, PlotBounds, PlotTransform};
+use super::{
+    Axis, Cursor, Interval, LabelFormatter, LegendGlyph, PlotBounds, PlotTransform, PointEdit,
+    SeriesStyle, ValueFormat,
+};
 
+use crate::FillPattern;
+use crate::export::{self, ExportShape};
 use crate::items::scatter::MarkerColor;
 pub use crate::items::tooltip::HitPoint;
 pub use crate::items::tooltip::PinnedPoints;
 pub use crate::items::tooltip::TooltipOptions;
+pub(crate) use crate::items::tooltip::band_hits;
 pub use band::Band;
 pub use bar::Bar;
+pub(crate) use bar::BarAnimation;
 pub use box_elem::{BoxElem, BoxSpread};
-pub use columnar_series::ColumnarSeries;
+pub use columnar_series::{ColumnarSeries, SharedXs};
 use emath::Float as _;
+pub use envelope_line::EnvelopeLine;
+pub use fan_band::FanBand;
+pub use flags::{EventFlag, EventFlags};
 use rect_elem::{RectElement, highlighted_color};
 pub use scatter::Marker;
 pub use scatter::Scatter;
-pub use scatter::ScatterEncodings;
+pub use scatter::{LabelOverlap, RadiusBy, RadiusScale, ScatterEncodings};
+pub use spline::{Spline, SplineKind};
+pub use stacked_area::StackedArea;
+pub use streaming::{Epoch, StreamingSeries};
 pub use values::{
-    ClosestElem, LineStyle, MarkerShape, Orientation, PlotGeometry, PlotPoint, PlotPoints,
+    ClosestElem, LineStyle, MarkerShape, Orientation, PickHit, PlotGeometry, PlotPoint, PlotPoints,
+    PointMetadata,
 };
-mod band;
-mod bar;
-mod box_elem;
-mod columnar_series;
-pub(crate) mod geom_helpers;
-mod rect_elem;
-mod scatter;
-mod tooltip;
-mod values;
 const DEFAULT_FILL_ALPHA: f32 = 0.05;
 
+/// Custom tooltip of an item, see [`Line::on_hover`].
+type HoverUiFn<'a> = dyn Fn(&mut Ui, &PickHit) + 'a;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct PlotItemBase {
     name: String,
     id: Id,
     highlight: bool,
+
+    /// Draw faster at a lower fidelity this frame, see [`crate::Plot::idle_detail`].
+    ///
+    /// Only [`Line`] has a faster way to draw for now.
+    pub(crate) draft: bool,
+
     allow_hover: bool,
+    z_order: i32,
+    stage: PaintStage,
+    clip: bool,
+    include_in_auto_fit: bool,
 }
 
 impl PlotItemBase {
@@ -55,9 +73,120 @@ impl PlotItemBase {
             name,
             id,
             highlight: false,
+            draft: false,
             allow_hover: true,
+            z_order: 0,
+            stage: PaintStage::Data,
+            clip: true,
+            include_in_auto_fit: true,
         }
     }
+
+    /// Set the drawing order of the item, see [`PlotItem::z_order`].
+    #[inline]
+    pub fn z_order(mut self, z: i32) -> Self {
+        self.z_order = z;
+        self
+    }
+
+    /// Set the stage the item is painted in, see [`PlotItem::stage`].
+    #[inline]
+    pub fn paint_stage(mut self, stage: PaintStage) -> Self {
+        self.stage = stage;
+        self
+    }
+
+    /// Set whether the item is clipped to the plot frame, see [`PlotItem::clip`].
+    #[inline]
+    pub fn clip(mut self, clip: bool) -> Self {
+        self.clip = clip;
+        self
+    }
+
+    /// Set whether auto-fit includes the item, see [`PlotItem::include_in_auto_fit`].
+    #[inline]
+    pub fn include_in_auto_fit(mut self, include: bool) -> Self {
+        self.include_in_auto_fit = include;
+        self
+    }
+}
+
+/// What the user can do with an item besides hovering it, see [`PlotItem::interaction`].
+///
+/// Public but not exported, so the plot can ask any item while only the items of this crate can
+/// answer.
+#[derive(Clone, Copy)]
+pub enum ItemInteraction<'a> {
+    None,
+
+    /// The points can be dragged, see [`PlotEvent::PointEdited`](crate::PlotEvent::PointEdited).
+    EditPoints(PointEdit),
+
+    /// The whole item can be dragged along `axis`, on which it covers `interval`. See
+    /// [`PlotEvent::ItemDragged`](crate::PlotEvent::ItemDragged).
+    Drag {
+        axis: Axis,
+        interval: Interval,
+    },
+
+    /// The flags can be clicked, see [`PlotEvent::FlagClicked`](crate::PlotEvent::FlagClicked).
+    Flags(&'a EventFlags),
+}
+
+/// The stages a plot is painted in, see [`PlotItem::stage`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PaintStage {
+    /// Below the grid.
+    Background,
+
+    /// Above the grid, below the data. The default of spans and bands.
+    UnderData,
+
+    #[default]
+    Data,
+
+    /// Above the data. The default of texts, arrows and event flags.
+    OverData,
+
+    /// Above everything else, including the cursors and the hovered points.
+    Overlay,
+}
+
+/// The unit of the line widths, marker radii and text sizes of the items, see
+/// [`crate::Plot::item_scale`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum ItemScale {
+    /// Points, like the rest of egui, so the items keep their size on high-DPI displays.
+    #[default]
+    Points,
+
+    /// Points times a factor, e.g. to make a dashboard on a wall display readable from afar.
+    Factor(f32),
+
+    /// Physical pixels, so the items get thinner and smaller on high-DPI displays.
+    Pixels,
+}
+
+impl ItemScale {
+    /// How many points one unit of this scale is, with `pixels_per_point` physical pixels in a
+    /// point.
+    pub(crate) fn points(self, pixels_per_point: f32) -> f32 {
+        match self {
+            Self::Points => 1.0,
+            Self::Factor(factor) => factor.max(0.0),
+            Self::Pixels => 1.0 / pixels_per_point,
+        }
+    }
+}
+
+/// Multiply the width of `item` by `factor`, see [`SeriesStyle::width`].
+pub(crate) fn scale_item(item: &mut dyn PlotItem, factor: f32) {
+    let mut style = item.series_style();
+    if let Some(width) = style.width.as_mut() {
+        *width *= factor;
+        item.set_series_style(&style);
+    }
 }
 
 macro_rules! builder_methods_for_base {
@@ -92,19 +221,82 @@ macro_rules! builder_methods_for_base {
         ///
         /// By default the id is determined from the name, but it can be explicitly set to a different value.
         #[inline]
-        pub fn id(mut self, id: impl Into<Id>) -> Self {
+        pub fn id(mut self, id: impl Into<egui::Id>) -> Self {
             self.base_mut().id = id.into();
             self
         }
+
+        /// Drawing order of this item. Items with a higher `z` are drawn on top of items with a
+        /// lower one, items with the same `z` in the order they were added. Default: `0`.
+        #[inline]
+        pub fn z(mut self, z: i32) -> Self {
+            self.base_mut().z_order = z;
+            self
+        }
+
+        /// The stage this item is painted in, see [`PlotItem::stage`](crate::PlotItem::stage).
+        #[inline]
+        pub fn paint_stage(mut self, stage: crate::PaintStage) -> Self {
+            self.base_mut().stage = stage;
+            self
+        }
+
+        /// Whether to clip this item to the plot frame, see
+        /// [`PlotItem::clip`](crate::PlotItem::clip). Default: `true`.
+        #[inline]
+        pub fn clip(mut self, clip: bool) -> Self {
+            self.base_mut().clip = clip;
+            self
+        }
+
+        /// Whether fitting the bounds to the data includes this item, see
+        /// [`PlotItem::include_in_auto_fit`](crate::PlotItem::include_in_auto_fit).
+        /// Default: `true`.
+        #[inline]
+        pub fn include_in_auto_fit(mut self, include: bool) -> Self {
+            self.base_mut().include_in_auto_fit = include;
+            self
+        }
     };
 }
 
+mod band;
+mod bar;
+mod box_elem;
+mod columnar_series;
+mod envelope_line;
+mod fan_band;
+mod flags;
+pub(crate) mod geom_helpers;
+mod rect_elem;
+mod scatter;
+mod spline;
+mod stacked_area;
+mod streaming;
+mod tooltip;
+mod values;
+
 /// Container to pass-through several parameters related to plot visualization
 pub struct PlotConfig<'a> {
     pub ui: &'a Ui,
     pub transform: &'a PlotTransform,
     pub show_x: bool,
     pub show_y: bool,
+    pub value_format: &'a ValueFormat,
+}
+
+impl PlotConfig<'_> {
+    /// Format `x` and `y` for a tooltip, with as many decimals as tell apart points a pixel
+    /// apart unless the [`ValueFormat`] sets its own precision.
+    pub fn format_values(&self, x: f64, y: f64) -> [String; 2] {
+        let scale = self.transform.dvalue_dpos();
+        [
+            self.value_format
+                .format(x, crate::value_format::decimals_for(scale[0])),
+            self.value_format
+                .format(y, crate::value_format::decimals_for(scale[1])),
+        ]
+    }
 }
 
 /// Trait shared by things that can be drawn in the plot.
@@ -137,6 +329,36 @@ pub trait PlotItem {
 
     fn bounds(&self) -> PlotBounds;
 
+    /// The bounds of the data with x in `x_range`, for
+    /// [`crate::Plot::auto_fit_y_to_visible_x`].
+    ///
+    /// By default from the points of [`Self::geometry`], or all of [`Self::bounds`] for items
+    /// without points.
+    fn bounds_in_x_range(&self, x_range: &RangeInclusive<f64>) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        let mut extend = |x: f64, y: f64| {
+            if x_range.contains(&x) && y.is_finite() {
+                bounds.extend_with(&PlotPoint::new(x, y));
+            }
+        };
+        match self.geometry() {
+            PlotGeometry::Points(points) => points.iter().for_each(|p| extend(p.x, p.y)),
+            PlotGeometry::PointsXY { xs, ys } => {
+                xs.iter().zip(ys).for_each(|(&x, &y)| extend(x, y));
+            }
+            PlotGeometry::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => {
+                for (xs, ys) in xs_blocks.iter().zip(&ys_blocks) {
+                    xs.iter().zip(*ys).for_each(|(&x, &y)| extend(x, y));
+                }
+            }
+            PlotGeometry::None | PlotGeometry::Rects => return self.bounds(),
+        }
+        bounds
+    }
+
     fn base(&self) -> &PlotItemBase;
 
     fn base_mut(&mut self) -> &mut PlotItemBase;
@@ -145,6 +367,99 @@ pub trait PlotItem {
         self.base().id
     }
 
+    /// Drawing order: items with a higher value are drawn on top.
+    ///
+    /// Only orders the items within their [`Self::stage`].
+    fn z_order(&self) -> i32 {
+        self.base().z_order
+    }
+
+    /// The stage this item is painted in, so that e.g. spans go under the lines and
+    /// annotations above their markers whatever order they are added in.
+    fn stage(&self) -> PaintStage {
+        self.base().stage
+    }
+
+    /// How far the shapes of this item reach beyond its data points, in points, like the radius
+    /// of its markers or half the width of its lines.
+    ///
+    /// [`crate::Plot::fit_item_extents`] leaves room for this when fitting the bounds to the
+    /// data.
+    fn screen_extent(&self) -> f32 {
+        0.0
+    }
+
+    /// Whether fitting the bounds to the data includes this item.
+    ///
+    /// Leave reference lines, spans and annotations out, so they don't widen the automatic
+    /// view. They are still shown when the view reaches them.
+    fn include_in_auto_fit(&self) -> bool {
+        self.base().include_in_auto_fit
+    }
+
+    /// Whether the shapes of this item are clipped to the plot frame.
+    ///
+    /// Annotations and labels that aren't clipped can reach into the axes and the margins of the
+    /// plot, e.g. to label the last value of a line next to the y axis.
+    fn clip(&self) -> bool {
+        self.base().clip
+    }
+
+    /// Custom content for the tooltip of this item, shown instead of the default text when one
+    /// of its points is hovered, see [`Line::on_hover`].
+    fn hover_ui(&self) -> Option<&(dyn Fn(&mut Ui, &PickHit) + '_)> {
+        None
+    }
+
+    /// The payload attached to the element `index` of this item, surfaced in picks, hovers and
+    /// clicks. See [`crate::Scatter::with_metadata`].
+    fn point_metadata(&self, _index: usize) -> Option<PointMetadata> {
+        None
+    }
+
+    /// What the user can do with this item besides hovering it: drag its points, drag the whole
+    /// item or click its flags.
+    ///
+    /// Only the items of this crate support these; others can't name the returned type.
+    #[doc(hidden)]
+    fn interaction(&self) -> ItemInteraction<'_> {
+        ItemInteraction::None
+    }
+
+    /// The glyph shown next to the name of this item in the legend, with
+    /// [`Legend::item_glyphs`](crate::Legend::item_glyphs).
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Dot
+    }
+
+    /// The style the user can edit from the legend, see
+    /// [`Legend::style_editing`](crate::Legend::style_editing). By default only the name.
+    fn series_style(&self) -> SeriesStyle {
+        SeriesStyle {
+            name: self.name().to_owned(),
+            color: None,
+            width: None,
+            marker: None,
+        }
+    }
+
+    /// Simple shapes describing this item for [`Plot::export_matplotlib`] and
+    /// [`Plot::export_html`], or `None` if it can't be exported.
+    ///
+    /// By default the points of the item become a line, markers or a filled area, depending on
+    /// its [`Self::legend_glyph`].
+    ///
+    /// [`Plot::export_matplotlib`]: crate::Plot::export_matplotlib
+    /// [`Plot::export_html`]: crate::Plot::export_html
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        export::from_geometry(self)
+    }
+
+    /// Apply a style edited from the legend, see [`Self::series_style`].
+    fn set_series_style(&mut self, style: &SeriesStyle) {
+        self.base_mut().name.clone_from(&style.name);
+    }
+
     fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
         match self.geometry() {
             PlotGeometry::None => None,
@@ -273,14 +588,33 @@ pub trait PlotItem {
         let pointer = plot.transform.position_from_point(&value);
         shapes.push(Shape::circle_filled(pointer, 3.0, line_color));
 
-        rulers_and_tooltip_at_value(
-            plot_area_response,
-            value,
-            self.name(),
-            plot,
-            cursors,
-            label_formatter,
-        );
+        if let Some(hover_ui) = self.hover_ui() {
+            if plot.show_x {
+                cursors.push(Cursor::Vertical { x: value.x });
+            }
+            if plot.show_y {
+                cursors.push(Cursor::Horizontal { y: value.y });
+            }
+            let hit = PickHit {
+                item: self.id(),
+                name: self.name().to_owned(),
+                index: elem.index,
+                distance: elem.dist_sq.sqrt(),
+                metadata: self.point_metadata(elem.index),
+            };
+            show_tooltip_ui_at(plot_area_response, PopupAnchor::Pointer, |ui| {
+                hover_ui(ui, &hit);
+            });
+        } else {
+            rulers_and_tooltip_at_value(
+                plot_area_response,
+                value,
+                self.name(),
+                plot,
+                cursors,
+                label_formatter,
+            );
+        }
     }
 }
 
@@ -293,6 +627,8 @@ pub struct HLine {
     pub(super) y: f64,
     pub(super) stroke: Stroke,
     pub(super) style: LineStyle,
+    pub(super) label: Option<String>,
+    pub(super) draggable: bool,
 }
 
 impl HLine {
@@ -302,6 +638,8 @@ impl HLine {
             y: y.into(),
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             style: LineStyle::Solid,
+            label: None,
+            draggable: false,
         }
     }
 
@@ -333,16 +671,35 @@ impl HLine {
         self
     }
 
+    /// Let the user drag the line up and down. Default: `false`.
+    ///
+    /// The plot doesn't move the line itself; apply
+    /// [`PlotEvent::ItemDragged`](crate::PlotEvent::ItemDragged) to your data instead.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Text drawn above the line next to the y axis, e.g. `"threshold = 0.8"`.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
     builder_methods_for_base!();
 }
 
 impl PlotItem for HLine {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             base,
             y,
             stroke,
             style,
+            label,
             ..
         } = self;
 
@@ -350,6 +707,10 @@ impl PlotItem for HLine {
             transform.position_from_point(&PlotPoint::new(transform.bounds().min[0], *y)),
             transform.position_from_point(&PlotPoint::new(transform.bounds().max[0], *y)),
         ];
+        if let Some(label) = label {
+            let anchor = pos2(points[0].x + 4.0, points[0].y - 2.0);
+            push_line_label(ui, label, anchor, stroke.color, shapes);
+        }
         style.style_line(
             points,
             PathStroke::new(stroke.width, stroke.color),
@@ -364,6 +725,28 @@ impl PlotItem for HLine {
         self.stroke.color
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line {
+            style: self.style,
+            marker: None,
+        }
+    }
+
+    fn series_style(&self) -> SeriesStyle {
+        SeriesStyle {
+            name: self.name().to_owned(),
+            color: Some(self.stroke.color),
+            width: Some(self.stroke.width),
+            marker: None,
+        }
+    }
+
+    fn set_series_style(&mut self, style: &SeriesStyle) {
+        self.base.name.clone_from(&style.name);
+        self.stroke.color = style.color.unwrap_or(self.stroke.color);
+        self.stroke.width = style.width.unwrap_or(self.stroke.width);
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -382,6 +765,57 @@ impl PlotItem for HLine {
         bounds.max[1] = self.y;
         bounds
     }
+
+    fn interaction(&self) -> ItemInteraction<'_> {
+        if self.draggable {
+            ItemInteraction::Drag {
+                axis: Axis::Y,
+                interval: Interval::new(self.y, self.y),
+            }
+        } else {
+            ItemInteraction::None
+        }
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        Some(vec![ExportShape::Rule {
+            axis: Axis::Y,
+            value: self.y,
+            stroke: self.stroke,
+            style: self.style,
+        }])
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let dist = point.y - transform.position_from_point_y(self.y);
+        Some(ClosestElem {
+            index: 0,
+            dist_sq: dist * dist,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        _elem: ClosestElem,
+        _shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let Some(pointer) = plot_area_response.hover_pos() else {
+            return;
+        };
+        let value = PlotPoint::new(plot.transform.value_from_position(pointer).x, self.y);
+        rulers_and_tooltip_at_value(
+            plot_area_response,
+            value,
+            self.name(),
+            plot,
+            cursors,
+            label_formatter,
+        );
+    }
 }
 
 /// A vertical line in a plot, filling the full width
@@ -391,6 +825,8 @@ pub struct VLine {
     pub(super) x: f64,
     pub(super) stroke: Stroke,
     pub(super) style: LineStyle,
+    pub(super) label: Option<String>,
+    pub(super) draggable: bool,
 }
 
 impl VLine {
@@ -400,6 +836,8 @@ impl VLine {
             x: x.into(),
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             style: LineStyle::Solid,
+            label: None,
+            draggable: false,
         }
     }
 
@@ -431,16 +869,35 @@ impl VLine {
         self
     }
 
+    /// Let the user drag the line left and right. Default: `false`.
+    ///
+    /// The plot doesn't move the line itself; apply
+    /// [`PlotEvent::ItemDragged`](crate::PlotEvent::ItemDragged) to your data instead.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
+
+    /// Text drawn next to the line above the x axis, e.g. `"deadline"`.
+    #[allow(clippy::needless_pass_by_value)]
+    #[inline]
+    pub fn label(mut self, label: impl ToString) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
     builder_methods_for_base!();
 }
 
 impl PlotItem for VLine {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
         let Self {
             base,
             x,
             stroke,
             style,
+            label,
             ..
         } = self;
 
@@ -448,6 +905,10 @@ impl PlotItem for VLine {
             transform.position_from_point(&PlotPoint::new(*x, transform.bounds().min[1])),
             transform.position_from_point(&PlotPoint::new(*x, transform.bounds().max[1])),
         ];
+        if let Some(label) = label {
+            let anchor = pos2(points[0].x + 4.0, points[0].y - 2.0);
+            push_line_label(ui, label, anchor, stroke.color, shapes);
+        }
         style.style_line(
             points,
             PathStroke::new(stroke.width, stroke.color),
@@ -462,6 +923,28 @@ impl PlotItem for VLine {
         self.stroke.color
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line {
+            style: self.style,
+            marker: None,
+        }
+    }
+
+    fn series_style(&self) -> SeriesStyle {
+        SeriesStyle {
+            name: self.name().to_owned(),
+            color: Some(self.stroke.color),
+            width: Some(self.stroke.width),
+            marker: None,
+        }
+    }
+
+    fn set_series_style(&mut self, style: &SeriesStyle) {
+        self.base.name.clone_from(&style.name);
+        self.stroke.color = style.color.unwrap_or(self.stroke.color);
+        self.stroke.width = style.width.unwrap_or(self.stroke.width);
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -480,6 +963,72 @@ impl PlotItem for VLine {
         bounds.max[0] = self.x;
         bounds
     }
+
+    fn interaction(&self) -> ItemInteraction<'_> {
+        if self.draggable {
+            ItemInteraction::Drag {
+                axis: Axis::X,
+                interval: Interval::new(self.x, self.x),
+            }
+        } else {
+            ItemInteraction::None
+        }
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        Some(vec![ExportShape::Rule {
+            axis: Axis::X,
+            value: self.x,
+            stroke: self.stroke,
+            style: self.style,
+        }])
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let dist = point.x - transform.position_from_point_x(self.x);
+        Some(ClosestElem {
+            index: 0,
+            dist_sq: dist * dist,
+        })
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        _elem: ClosestElem,
+        _shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let Some(pointer) = plot_area_response.hover_pos() else {
+            return;
+        };
+        let value = PlotPoint::new(self.x, plot.transform.value_from_position(pointer).y);
+        rulers_and_tooltip_at_value(
+            plot_area_response,
+            value,
+            self.name(),
+            plot,
+            cursors,
+            label_formatter,
+        );
+    }
+}
+
+/// Draw the label of an [`HLine`] or [`VLine`] with its bottom left corner at `anchor`.
+fn push_line_label(ui: &Ui, label: &str, anchor: Pos2, color: Color32, shapes: &mut Vec<Shape>) {
+    let font_id = TextStyle::Small.resolve(ui.style());
+    ui.fonts(|f| {
+        shapes.push(Shape::text(
+            f,
+            anchor,
+            Align2::LEFT_BOTTOM,
+            label,
+            font_id,
+            color,
+        ));
+    });
 }
 
 pub struct LineBlocks<'a> {
@@ -490,6 +1039,8 @@ pub struct LineBlocks<'a> {
 pub struct Line<'a> {
     base: PlotItemBase,
     pub(super) columnar: Option<ColumnarSeries<'a>>,
+    /// Owned ys over an x column shared with other series.
+    pub(super) shared: Option<(SharedXs, Vec<f64>)>,
     pub(super) series: Option<PlotPoints<'a>>,
 
     pub(super) stroke: Stroke,
@@ -500,8 +1051,18 @@ pub struct Line<'a> {
     pub(super) style: LineStyle,
     // segmentation
     pub(super) blocks_xy: Option<LineBlocks<'a>>,
+    /// Whether NaN samples break the line, or the samples around them are connected.
+    pub(super) gap_on_nan: bool,
+    /// Tolerance in points to simplify the line to before tessellation, if any.
+    pub(super) simplify: Option<f32>,
+    /// Bounds already known by the source of the data, like a [`StreamingSeries`].
+    pub(super) known_bounds: Option<PlotBounds>,
 
     pub(super) markers: Option<Marker>,
+
+    pub(super) edit: Option<PointEdit>,
+
+    pub(super) hover_ui: Option<Box<HoverUiFn<'a>>>,
 }
 impl Line<'_> {
     pub fn markers(mut self, m: Marker) -> Self {
@@ -545,6 +1106,7 @@ impl<'a> Line<'a> {
         Self {
             base: PlotItemBase::new(name.into()),
             columnar: Some(series),
+            shared: None,
             series: None,
             stroke: Stroke::new(1.5, Color32::TRANSPARENT),
             fill: None,
@@ -553,9 +1115,55 @@ impl<'a> Line<'a> {
             gradient_fill: false,
             style: LineStyle::Solid,
             blocks_xy: None,
+            gap_on_nan: true,
+            simplify: None,
+            known_bounds: None,
             markers: Some(Marker::default()),
+            edit: None,
+            hover_ui: None,
+        }
+    }
+
+    /// A line owning its `ys`, over an x column shared with other series.
+    ///
+    /// Unlike [`Self::new`], the x values aren't copied into each series, which saves a lot of
+    /// memory when many channels share one time base:
+    ///
+    /// ```
+    /// # use egui_plot::{Line, SharedXs};
+    /// let time = SharedXs::new((0..10_000).map(|i| i as f64 * 1e-3).collect::<Vec<_>>());
+    /// let lines: Vec<Line<'static>> = (0..500)
+    ///     .map(|channel| {
+    ///         let ys = time.as_slice().iter().map(|t| (t + channel as f64).sin()).collect();
+    ///         Line::new_shared_xs(format!("channel {channel}"), time.clone(), ys)
+    ///     })
+    ///     .collect();
+    /// ```
+    ///
+    /// # Panics
+    /// Panics if `xs.len() != ys.len()`.
+    pub fn new_shared_xs(name: impl Into<String>, xs: SharedXs, ys: Vec<f64>) -> Self {
+        assert!(
+            xs.len() == ys.len(),
+            "Line::new_shared_xs: xs and ys must have equal length (got {} vs {})",
+            xs.len(),
+            ys.len()
+        );
+        Self {
+            columnar: None,
+            shared: Some((xs, ys)),
+            ..Self::from_series(name, ColumnarSeries::EMPTY)
         }
     }
+
+    /// The columnar data of the line, borrowed or over shared xs.
+    fn columns(&self) -> Option<ColumnarSeries<'_>> {
+        self.columnar.or_else(|| {
+            self.shared
+                .as_ref()
+                .map(|(xs, ys)| ColumnarSeries::new(xs.as_slice(), ys))
+        })
+    }
 }
 
 impl<'a> Line<'a> {
@@ -573,6 +1181,7 @@ impl<'a> Line<'a> {
         Self {
             base: PlotItemBase::new(name.into()),
             columnar: None,
+            shared: None,
             series: None,
             stroke: Stroke::new(1.5, Color32::TRANSPARENT),
             fill: None,
@@ -584,13 +1193,19 @@ impl<'a> Line<'a> {
                 xs: xs_blocks,
                 ys: ys_blocks,
             }),
+            gap_on_nan: true,
+            simplify: None,
+            known_bounds: None,
             markers: Some(Marker::default()),
+            edit: None,
+            hover_ui: None,
         }
     }
     pub fn new(name: impl Into<String>, series: impl Into<PlotPoints<'a>>) -> Self {
         Self {
             base: PlotItemBase::new(name.into()),
             columnar: None,
+            shared: None,
             series: Some(series.into()),
             stroke: Stroke::new(1.5, Color32::TRANSPARENT),
             fill: None,
@@ -599,10 +1214,39 @@ impl<'a> Line<'a> {
             gradient_fill: false,
             style: LineStyle::Solid,
             blocks_xy: None,
+            gap_on_nan: true,
+            simplify: None,
+            known_bounds: None,
             markers: Some(Marker::default()),
+            edit: None,
+            hover_ui: None,
         }
     }
 
+    /// Show `add_contents` in the tooltip when a point of this line is hovered, instead of the
+    /// default text, e.g. a thumbnail or the metadata of the point.
+    ///
+    /// The [`PickHit::index`] is the index of the hovered point in the data of the line.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Line, Plot};
+    /// let labels = ["start", "peak", "end"];
+    /// Plot::new("custom_hover").show(ui, |plot_ui| {
+    ///     let line = Line::new("events", vec![[0.0, 0.0], [1.0, 3.0], [2.0, 1.0]])
+    ///         .on_hover(move |ui, hit| {
+    ///             ui.strong(labels[hit.index]);
+    ///         });
+    ///     plot_ui.line(line);
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn on_hover(mut self, add_contents: impl Fn(&mut Ui, &PickHit) + 'a) -> Self {
+        self.hover_ui = Some(Box::new(add_contents));
+        self
+    }
+
     /// Add a stroke.
     #[inline]
     pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
@@ -662,9 +1306,68 @@ impl<'a> Line<'a> {
         self
     }
 
+    /// Break the line at NaN or infinite samples, leaving a gap for the missing data, or
+    /// connect the samples around them if `false`. Default: `true`.
+    #[inline]
+    pub fn gap_on_nan(mut self, gap: bool) -> Self {
+        self.gap_on_nan = gap;
+        self
+    }
+
+    /// Draw the line through fewer points, dropping those less than `tolerance` points from
+    /// it (Douglas-Peucker), to tessellate dense curves faster. Markers are still drawn at
+    /// every sample.
+    ///
+    /// Unlike the per-pixel decimation of [`crate::Plot::idle_detail`], this keeps the shape
+    /// of the line within `tolerance` at any zoom. It runs every frame; to simplify static data
+    /// once, see [`ColumnarSeries::simplify`].
+    #[inline]
+    pub fn simplify(mut self, tolerance: f32) -> Self {
+        self.simplify = Some(tolerance);
+        self
+    }
+
+    /// Let the user drag the points of this line.
+    ///
+    /// Edits are reported as [`PlotEvent::PointEdited`](crate::PlotEvent::PointEdited).
+    #[inline]
+    pub fn editable(mut self, edit: PointEdit) -> Self {
+        self.edit = Some(edit);
+        self
+    }
+
     builder_methods_for_base!();
 }
 
+/// The positions to draw a line through, fewer of them in `draft`.
+fn line_positions<'p>(
+    positions: impl Iterator<Item = Pos2> + 'p,
+    draft: bool,
+) -> Box<dyn Iterator<Item = Pos2> + 'p> {
+    if draft {
+        Box::new(geom_helpers::decimate_columns(positions).into_iter())
+    } else {
+        Box::new(positions)
+    }
+}
+
+/// The `runs` of a line simplified to within `tolerance`, if any.
+fn simplify_runs(runs: Vec<Vec<Pos2>>, tolerance: Option<f32>) -> Vec<Vec<Pos2>> {
+    let Some(tolerance) = tolerance else {
+        return runs;
+    };
+    runs.iter()
+        .map(|run| geom_helpers::simplify_positions(run, tolerance))
+        .collect()
+}
+
+/// The consecutive positions within each of the `runs`.
+fn run_segments(runs: &[Vec<Pos2>]) -> impl Iterator<Item = (Pos2, Pos2)> + '_ {
+    runs.iter()
+        .flat_map(|run| run.windows(2))
+        .map(|pair| (pair[0], pair[1]))
+}
+
 /// Returns the x-coordinate of a possible intersection between a line segment from `p1` to `p2` and
 /// a horizontal line at the given y-coordinate.
 fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
@@ -675,9 +1378,9 @@ fn y_intersection(p1: &Pos2, p2: &Pos2, y: f32) -> Option<f32> {
 impl PlotItem for Line<'_> {
     #[allow(clippy::too_many_lines)]
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let columnar = self.columns();
         let Self {
             base,
-            columnar,
             series,
             stroke,
             fill,
@@ -686,6 +1389,8 @@ impl PlotItem for Line<'_> {
             gradient_fill,
             style,
             blocks_xy,
+            gap_on_nan,
+            simplify,
             ..
         } = self;
 
@@ -837,6 +1542,10 @@ impl PlotItem for Line<'_> {
                     transform.position_from_point(&v)
                 };
 
+                let runs = simplify_runs(
+                    geom_helpers::line_runs((0..len).map(get_pos), *gap_on_nan),
+                    *simplify,
+                );
                 if len >= 2 {
                     if let Some(y_ref) = fill {
                         let mut fill_alpha = *self_fill_alpha;
@@ -855,9 +1564,7 @@ impl PlotItem for Line<'_> {
                         mesh.reserve_triangles(len.saturating_sub(1) * 2);
                         mesh.reserve_vertices(len * 2 + expected_intersections);
 
-                        let mut p0 = get_pos(0);
-                        for i in 0..(len - 1) {
-                            let p1 = get_pos(i + 1);
+                        for (p0, p1) in run_segments(&runs) {
                             #[allow(clippy::excessive_nesting)]
                             if *gradient_fill {
                                 if let Some(grad) = gradient_color.as_ref() {
@@ -886,14 +1593,8 @@ impl PlotItem for Line<'_> {
                                 mesh.add_triangle(base_idx, base_idx + 1, base_idx + 2);
                                 mesh.add_triangle(base_idx + 1, base_idx + 2, base_idx + 3);
                             }
-
-                            p0 = p1;
                         }
 
-                        let last = get_pos(len - 1);
-                        mesh.colored_vertex(last, fill_color);
-                        mesh.colored_vertex(pos2(last.x, y_line), fill_color);
-
                         shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
                     }
 
@@ -902,13 +1603,15 @@ impl PlotItem for Line<'_> {
                             != egui::epaint::ColorMode::Solid(Color32::TRANSPARENT);
                     if draw_stroke {
                         let mut scratch: Vec<Pos2> = Vec::new();
-                        style.style_line_iter(
-                            (0..len).map(get_pos),
-                            final_stroke.clone(),
-                            base.highlight,
-                            shapes,
-                            &mut scratch,
-                        );
+                        for run in &runs {
+                            style.style_line_iter(
+                                line_positions(run.iter().copied(), base.draft),
+                                final_stroke.clone(),
+                                base.highlight,
+                                shapes,
+                                &mut scratch,
+                            );
+                        }
                     }
 
                     if let Some(marker) = &self.markers {
@@ -980,6 +1683,10 @@ impl PlotItem for Line<'_> {
             }
         };
 
+        let runs = simplify_runs(
+            geom_helpers::line_runs((0..len).map(get_pos), *gap_on_nan),
+            *simplify,
+        );
         if len < 2 {
             fill = None;
         }
@@ -1003,10 +1710,7 @@ impl PlotItem for Line<'_> {
             mesh.reserve_triangles(len.saturating_sub(1) * 2);
             mesh.reserve_vertices(len * 2 + expected_intersections);
 
-            let mut p0 = get_pos(0);
-            for i in 0..(len - 1) {
-                let p1 = get_pos(i + 1);
-
+            for (p0, p1) in run_segments(&runs) {
                 if *gradient_fill {
                     if let Some(grad) = gradient_color.as_ref() {
                         fill_color = Rgba::from(grad(transform.value_from_position(p1)))
@@ -1033,14 +1737,8 @@ impl PlotItem for Line<'_> {
                     mesh.add_triangle(base_idx, base_idx + 1, base_idx + 2);
                     mesh.add_triangle(base_idx + 1, base_idx + 2, base_idx + 3);
                 }
-
-                p0 = p1;
             }
 
-            let last = get_pos(len - 1);
-            mesh.colored_vertex(last, fill_color);
-            mesh.colored_vertex(pos2(last.x, y_line), fill_color);
-
             shapes.push(Shape::Mesh(std::sync::Arc::new(mesh)));
         }
 
@@ -1048,13 +1746,15 @@ impl PlotItem for Line<'_> {
             && final_stroke.color != egui::epaint::ColorMode::Solid(Color32::TRANSPARENT);
         if draw_stroke {
             let mut scratch: Vec<Pos2> = Vec::new();
-            style.style_line_iter(
-                (0..len).map(get_pos),
-                final_stroke,
-                base.highlight,
-                shapes,
-                &mut scratch,
-            );
+            for run in &runs {
+                style.style_line_iter(
+                    line_positions(run.iter().copied(), base.draft),
+                    final_stroke.clone(),
+                    base.highlight,
+                    shapes,
+                    &mut scratch,
+                );
+            }
         }
 
         if let Some(marker) = &self.markers {
@@ -1106,6 +1806,31 @@ impl PlotItem for Line<'_> {
         self.stroke.color
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line {
+            style: self.style,
+            marker: self.markers.as_ref().map(|marker| marker.shape),
+        }
+    }
+
+    fn series_style(&self) -> SeriesStyle {
+        SeriesStyle {
+            name: self.name().to_owned(),
+            color: Some(self.stroke.color),
+            width: Some(self.stroke.width),
+            marker: self.markers.as_ref().map(|marker| marker.shape),
+        }
+    }
+
+    fn set_series_style(&mut self, style: &SeriesStyle) {
+        self.base.name.clone_from(&style.name);
+        self.stroke.color = style.color.unwrap_or(self.stroke.color);
+        self.stroke.width = style.width.unwrap_or(self.stroke.width);
+        if let (Some(marker), Some(shape)) = (&mut self.markers, style.marker) {
+            marker.shape = shape;
+        }
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -1114,13 +1839,22 @@ impl PlotItem for Line<'_> {
         &mut self.base
     }
 
+    fn interaction(&self) -> ItemInteraction<'_> {
+        self.edit
+            .map_or(ItemInteraction::None, ItemInteraction::EditPoints)
+    }
+
+    fn hover_ui(&self) -> Option<&(dyn Fn(&mut Ui, &PickHit) + '_)> {
+        self.hover_ui.as_deref()
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         if let Some(b) = &self.blocks_xy {
             PlotGeometry::BlocksXY {
                 xs_blocks: b.xs.clone(),
                 ys_blocks: b.ys.clone(),
             }
-        } else if let Some(cs) = &self.columnar {
+        } else if let Some(cs) = self.columns() {
             PlotGeometry::PointsXY {
                 xs: cs.xs(),
                 ys: cs.ys(),
@@ -1132,7 +1866,18 @@ impl PlotItem for Line<'_> {
         }
     }
 
+    fn screen_extent(&self) -> f32 {
+        let markers = self
+            .markers
+            .as_ref()
+            .map_or(0.0, |marker| marker.radius + 0.5 * marker.stroke.width);
+        markers.max(0.5 * self.stroke.width)
+    }
+
     fn bounds(&self) -> PlotBounds {
+        if let Some(bounds) = self.known_bounds {
+            return bounds;
+        }
         if let Some(b) = &self.blocks_xy {
             let mut out = PlotBounds::NOTHING;
             for (xs, ys) in b.xs.iter().zip(&b.ys) {
@@ -1145,7 +1890,7 @@ impl PlotItem for Line<'_> {
             }
             return out;
         }
-        if let Some(cs) = &self.columnar {
+        if let Some(cs) = self.columns() {
             cs.bounds()
         } else if let Some(series) = &self.series {
             series.bounds()
@@ -1161,6 +1906,7 @@ pub struct Polygon<'a> {
     pub(super) series: PlotPoints<'a>,
     pub(super) stroke: Stroke,
     pub(super) fill_color: Option<Color32>,
+    pub(super) pattern: Option<FillPattern>,
     pub(super) style: LineStyle,
 }
 
@@ -1171,6 +1917,7 @@ impl<'a> Polygon<'a> {
             series: series.into(),
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             fill_color: None,
+            pattern: None,
             style: LineStyle::Solid,
         }
     }
@@ -1196,6 +1943,13 @@ impl<'a> Polygon<'a> {
         self
     }
 
+    /// Draw a pattern on top of the fill, e.g. [`FillPattern::hatch`], in the stroke color.
+    #[inline]
+    pub fn pattern(mut self, pattern: FillPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
     /// Set the outline's style. Default is `LineStyle::Solid`.
     #[inline]
     pub fn style(mut self, style: LineStyle) -> Self {
@@ -1213,6 +1967,7 @@ impl PlotItem for Polygon<'_> {
             series,
             stroke,
             fill_color,
+            pattern,
             style,
             ..
         } = self;
@@ -1227,6 +1982,9 @@ impl PlotItem for Polygon<'_> {
 
         let shape = Shape::convex_polygon(values_tf.clone(), fill_color, Stroke::NONE);
         shapes.push(shape);
+        if let Some(pattern) = pattern {
+            pattern.shapes(&values_tf, *transform.frame(), stroke.color, shapes);
+        }
 
         if let Some(first) = values_tf.first() {
             values_tf.push(*first); // close the polygon
@@ -1252,10 +2010,18 @@ impl PlotItem for Polygon<'_> {
         PlotGeometry::Points(self.series.points())
     }
 
+    fn screen_extent(&self) -> f32 {
+        0.5 * self.stroke.width
+    }
+
     fn bounds(&self) -> PlotBounds {
         self.series.bounds()
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Area
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -1278,7 +2044,7 @@ pub struct Text {
 impl Text {
     pub fn new(name: impl Into<String>, position: PlotPoint, text: impl Into<WidgetText>) -> Self {
         Self {
-            base: PlotItemBase::new(name.into()),
+            base: PlotItemBase::new(name.into()).paint_stage(PaintStage::OverData),
             text: text.into(),
             position,
             color: Color32::TRANSPARENT,
@@ -1349,6 +2115,15 @@ impl PlotItem for Text {
         bounds
     }
 
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        Some(vec![ExportShape::Text {
+            x: self.position.x,
+            y: self.position.y,
+            text: self.text.text().to_owned(),
+            color: self.color,
+        }])
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -1376,6 +2151,8 @@ pub struct Points<'a> {
     pub(super) radius: f32,
 
     pub(super) stems: Option<f32>,
+
+    pub(super) hover_ui: Option<Box<HoverUiFn<'a>>>,
 }
 
 impl<'a> Points<'a> {
@@ -1388,6 +2165,7 @@ impl<'a> Points<'a> {
             filled: true,
             radius: 1.0,
             stems: None,
+            hover_ui: None,
         }
     }
 
@@ -1426,16 +2204,19 @@ impl<'a> Points<'a> {
         self
     }
 
+    /// Show `add_contents` in the tooltip when a point is hovered, instead of the default text,
+    /// see [`Line::on_hover`].
+    #[inline]
+    pub fn on_hover(mut self, add_contents: impl Fn(&mut Ui, &PickHit) + 'a) -> Self {
+        self.hover_ui = Some(Box::new(add_contents));
+        self
+    }
+
     builder_methods_for_base!();
 }
 
 impl PlotItem for Points<'_> {
-    #[allow(clippy::too_many_lines)] // TODO(emilk): shorten this function
     fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        let sqrt_3 = 3_f32.sqrt();
-        let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
-        let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
-
         let Self {
             base,
             series,
@@ -1471,97 +2252,12 @@ impl PlotItem for Points<'_> {
             .iter()
             .map(|value| transform.position_from_point(value))
             .for_each(|center| {
-                let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
-
                 if let Some(y) = y_reference {
                     let stem = Shape::line_segment([center, pos2(center.x, y)], stem_stroke);
                     shapes.push(stem);
                 }
 
-                match shape {
-                    MarkerShape::Circle => {
-                        shapes.push(Shape::Circle(CircleShape {
-                            center,
-                            radius,
-                            fill,
-                            stroke,
-                        }));
-                    }
-                    MarkerShape::Diamond => {
-                        let points = vec![
-                            tf(0.0, 1.0),  // bottom
-                            tf(-1.0, 0.0), // left
-                            tf(0.0, -1.0), // top
-                            tf(1.0, 0.0),  // right
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Square => {
-                        let points = vec![
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Cross => {
-                        let diagonal1 = [
-                            tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        let diagonal2 = [
-                            tf(frac_1_sqrt_2, -frac_1_sqrt_2),
-                            tf(-frac_1_sqrt_2, frac_1_sqrt_2),
-                        ];
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                    MarkerShape::Plus => {
-                        let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        shapes.push(Shape::line_segment(horizontal, default_stroke));
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                    }
-                    MarkerShape::Up => {
-                        let points =
-                            vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Down => {
-                        let points = vec![
-                            tf(0.0, 1.0),
-                            tf(-0.5 * sqrt_3, -0.5),
-                            tf(0.5 * sqrt_3, -0.5),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Left => {
-                        let points =
-                            vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Right => {
-                        let points = vec![
-                            tf(1.0, 0.0),
-                            tf(-0.5, 0.5 * sqrt_3),
-                            tf(-0.5, -0.5 * sqrt_3),
-                        ];
-                        shapes.push(Shape::convex_polygon(points, fill, stroke));
-                    }
-                    MarkerShape::Asterisk => {
-                        let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
-                        let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
-                        let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
-                        shapes.push(Shape::line_segment(vertical, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal1, default_stroke));
-                        shapes.push(Shape::line_segment(diagonal2, default_stroke));
-                    }
-                    #[allow(clippy::unimplemented)]
-                    _ => {
-                        unimplemented!()
-                    }
-                }
+                push_marker(*shape, center, radius, fill, stroke, default_stroke, shapes);
             });
     }
 
@@ -1573,14 +2269,45 @@ impl PlotItem for Points<'_> {
         self.color
     }
 
+    fn hover_ui(&self) -> Option<&(dyn Fn(&mut Ui, &PickHit) + '_)> {
+        self.hover_ui.as_deref()
+    }
+
     fn geometry(&self) -> PlotGeometry<'_> {
         PlotGeometry::Points(self.series.points())
     }
 
+    fn screen_extent(&self) -> f32 {
+        self.radius
+    }
+
     fn bounds(&self) -> PlotBounds {
         self.series.bounds()
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Marker {
+            shape: self.shape,
+            filled: self.filled,
+        }
+    }
+
+    fn series_style(&self) -> SeriesStyle {
+        SeriesStyle {
+            name: self.name().to_owned(),
+            color: Some(self.color),
+            width: Some(self.radius),
+            marker: Some(self.shape),
+        }
+    }
+
+    fn set_series_style(&mut self, style: &SeriesStyle) {
+        self.base.name.clone_from(&style.name);
+        self.color = style.color.unwrap_or(self.color);
+        self.radius = style.width.unwrap_or(self.radius);
+        self.shape = style.marker.unwrap_or(self.shape);
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -1606,7 +2333,7 @@ impl<'a> Arrows<'a> {
         tips: impl Into<PlotPoints<'a>>,
     ) -> Self {
         Self {
-            base: PlotItemBase::new(name.into()),
+            base: PlotItemBase::new(name.into()).paint_stage(PaintStage::OverData),
             origins: origins.into(),
             tips: tips.into(),
             tip_length: None,
@@ -1869,6 +2596,8 @@ pub struct BarChart {
 
     /// A custom element formatter
     pub(super) element_formatter: Option<Box<dyn Fn(&Bar, &BarChart) -> String>>,
+
+    pub(crate) animation: Option<std::time::Duration>,
 }
 
 impl BarChart {
@@ -1879,9 +2608,23 @@ impl BarChart {
             bars,
             default_color: Color32::TRANSPARENT,
             element_formatter: None,
+            animation: None,
         }
     }
 
+    /// Animate changes of the bar values, moving the bars to their new heights over `duration`.
+    ///
+    /// The previous values are retained per chart [`id`](Self::id) (by default its name), so
+    /// live dashboards can rebuild the chart every frame with new values. Bars added to the
+    /// chart grow from their base. Stacked charts that change in the same frame stay stacked.
+    ///
+    /// Default: no animation.
+    #[inline]
+    pub fn animate(mut self, duration: std::time::Duration) -> Self {
+        self.animation = Some(duration);
+        self
+    }
+
     /// Set the default color. It is set on all elements that do not already have a specific color.
     /// This is the color that shows up in the legend.
     /// It can be overridden at the bar level (see [[`Bar`]]).
@@ -2010,6 +2753,25 @@ impl PlotItem for BarChart {
         bar.add_rulers_and_text(self, plot, shapes, cursors);
     }
 
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        let column = |f: fn(&Bar) -> f64| self.bars.iter().map(f).collect();
+        Some(vec![ExportShape::Bars {
+            horizontal: self
+                .bars
+                .first()
+                .is_some_and(|bar| bar.orientation == Orientation::Horizontal),
+            arguments: column(|bar| bar.argument),
+            values: column(|bar| bar.value),
+            bases: column(|bar| bar.base_offset.unwrap_or(0.0)),
+            widths: column(|bar| bar.bar_width),
+            color: self.default_color,
+        }])
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Area
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -2137,6 +2899,10 @@ impl PlotItem for BoxPlot {
         box_plot.add_rulers_and_text(self, plot, shapes, cursors);
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Area
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -2149,6 +2915,105 @@ impl PlotItem for BoxPlot {
 // ----------------------------------------------------------------------------
 // Helper functions
 
+/// Push the shapes of a single marker centered at `center`.
+///
+/// `stroke` outlines filled shapes, `line_stroke` draws shapes made of lines only.
+pub(crate) fn push_marker(
+    shape: MarkerShape,
+    center: Pos2,
+    radius: f32,
+    fill: Color32,
+    stroke: Stroke,
+    line_stroke: Stroke,
+    shapes: &mut Vec<Shape>,
+) {
+    let sqrt_3 = 3_f32.sqrt();
+    let frac_sqrt_3_2 = 3_f32.sqrt() / 2.0;
+    let frac_1_sqrt_2 = 1.0 / 2_f32.sqrt();
+    let default_stroke = line_stroke;
+    let tf = |dx: f32, dy: f32| -> Pos2 { center + radius * vec2(dx, dy) };
+
+    match shape {
+        MarkerShape::Diamond => {
+            let points = vec![
+                tf(0.0, 1.0),  // bottom
+                tf(-1.0, 0.0), // left
+                tf(0.0, -1.0), // top
+                tf(1.0, 0.0),  // right
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Square => {
+            let points = vec![
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Cross => {
+            let diagonal1 = [
+                tf(-frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            let diagonal2 = [
+                tf(frac_1_sqrt_2, -frac_1_sqrt_2),
+                tf(-frac_1_sqrt_2, frac_1_sqrt_2),
+            ];
+            shapes.push(Shape::line_segment(diagonal1, default_stroke));
+            shapes.push(Shape::line_segment(diagonal2, default_stroke));
+        }
+        MarkerShape::Plus => {
+            let horizontal = [tf(-1.0, 0.0), tf(1.0, 0.0)];
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            shapes.push(Shape::line_segment(horizontal, default_stroke));
+            shapes.push(Shape::line_segment(vertical, default_stroke));
+        }
+        MarkerShape::Up => {
+            let points = vec![tf(0.0, -1.0), tf(0.5 * sqrt_3, 0.5), tf(-0.5 * sqrt_3, 0.5)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Down => {
+            let points = vec![
+                tf(0.0, 1.0),
+                tf(-0.5 * sqrt_3, -0.5),
+                tf(0.5 * sqrt_3, -0.5),
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Left => {
+            let points = vec![tf(-1.0, 0.0), tf(0.5, -0.5 * sqrt_3), tf(0.5, 0.5 * sqrt_3)];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Right => {
+            let points = vec![
+                tf(1.0, 0.0),
+                tf(-0.5, 0.5 * sqrt_3),
+                tf(-0.5, -0.5 * sqrt_3),
+            ];
+            shapes.push(Shape::convex_polygon(points, fill, stroke));
+        }
+        MarkerShape::Asterisk => {
+            let vertical = [tf(0.0, -1.0), tf(0.0, 1.0)];
+            let diagonal1 = [tf(-frac_sqrt_3_2, 0.5), tf(frac_sqrt_3_2, -0.5)];
+            let diagonal2 = [tf(-frac_sqrt_3_2, -0.5), tf(frac_sqrt_3_2, 0.5)];
+            shapes.push(Shape::line_segment(vertical, default_stroke));
+            shapes.push(Shape::line_segment(diagonal1, default_stroke));
+            shapes.push(Shape::line_segment(diagonal2, default_stroke));
+        }
+        // Circles, and shapes without a dedicated drawing yet:
+        _ => {
+            shapes.push(Shape::Circle(CircleShape {
+                center,
+                radius,
+                fill,
+                stroke,
+            }));
+        }
+    }
+}
+
 pub(crate) fn rulers_color(ui: &Ui) -> Color32 {
     if ui.visuals().dark_mode {
         Color32::from_gray(100).additive()
@@ -2267,7 +3132,19 @@ pub(super) fn rulers_and_tooltip_at_value(
         cursors.push(Cursor::Horizontal { y: value.y });
     }
 
-    let text = if let Some(custom_label) = label_formatter {
+    let text = tooltip_text(value, name, plot, label_formatter);
+    show_tooltip_text(plot_area_response, text);
+}
+
+/// The tooltip for `value` of the item `name`, `x = …` and `y = …` unless `label_formatter` is
+/// custom.
+pub(super) fn tooltip_text(
+    value: PlotPoint,
+    name: &str,
+    plot: &PlotConfig<'_>,
+    label_formatter: &LabelFormatter<'_>,
+) -> String {
+    if let Some(custom_label) = label_formatter {
         custom_label(name, &value)
     } else {
         let prefix = if name.is_empty() {
@@ -2275,38 +3152,84 @@ pub(super) fn rulers_and_tooltip_at_value(
         } else {
             format!("{name}\n")
         };
-        let scale = plot.transform.dvalue_dpos();
-        let x_decimals = ((-scale[0].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
-        let y_decimals = ((-scale[1].abs().log10()).ceil().at_least(0.0) as usize).clamp(1, 6);
+        let [x, y] = plot.format_values(value.x, value.y);
         if plot.show_x && plot.show_y {
-            format!(
-                "{}x = {:.*}\ny = {:.*}",
-                prefix, x_decimals, value.x, y_decimals, value.y
-            )
+            format!("{prefix}x = {x}\ny = {y}")
         } else if plot.show_x {
-            format!("{}x = {:.*}", prefix, x_decimals, value.x)
+            format!("{prefix}x = {x}")
         } else if plot.show_y {
-            format!("{}y = {:.*}", prefix, y_decimals, value.y)
+            format!("{prefix}y = {y}")
         } else {
-            unreachable!()
+            prefix.trim_end().to_owned()
         }
-    };
+    }
+}
+
+/// Shows `text` in a tooltip next to the pointer while the plot area is hovered.
+pub(super) fn show_tooltip_text(plot_area_response: &egui::Response, text: String) {
+    show_tooltip_text_at(plot_area_response, PopupAnchor::Pointer, text);
+}
 
+/// Shows `text` in a tooltip at `anchor`.
+pub(super) fn show_tooltip_text_at(
+    plot_area_response: &egui::Response,
+    anchor: PopupAnchor,
+    text: String,
+) {
+    show_tooltip_ui_at(plot_area_response, anchor, |ui| {
+        ui.label(text);
+    });
+}
+
+/// The `items` with an element within `radius_px` points of `screen_pos`, nearest first, see
+/// [`crate::PlotUi::pick`].
+pub(crate) fn pick<'i, 'a: 'i>(
+    items: impl Iterator<Item = &'i (dyn PlotItem + 'a)>,
+    transform: &PlotTransform,
+    screen_pos: Pos2,
+    radius_px: f32,
+) -> Vec<PickHit> {
+    let mut hits: Vec<PickHit> = items
+        .filter(|item| item.allow_hover())
+        .filter_map(|item| {
+            let closest = item.find_closest(screen_pos, transform)?;
+            let distance = closest.dist_sq.sqrt();
+            (distance <= radius_px).then(|| PickHit {
+                item: item.id(),
+                name: item.name().to_owned(),
+                index: closest.index,
+                distance,
+                metadata: item.point_metadata(closest.index),
+            })
+        })
+        .collect();
+    hits.sort_by(|a, b| a.distance.total_cmp(&b.distance));
+    hits
+}
+
+/// Shows the contents of `add_contents` in a tooltip at `anchor`.
+pub(super) fn show_tooltip_ui_at(
+    plot_area_response: &egui::Response,
+    anchor: PopupAnchor,
+    add_contents: impl FnOnce(&mut Ui),
+) {
     // We show the tooltip as soon as we're hovering the plot area:
     let mut tooltip = egui::Tooltip::always_open(
         plot_area_response.ctx.clone(),
         plot_area_response.layer_id,
         plot_area_response.id,
-        PopupAnchor::Pointer,
+        anchor,
     );
 
+    tooltip =
+        crate::PlotStyle::style_tooltip(&plot_area_response.ctx, plot_area_response.id, tooltip);
     let tooltip_width = plot_area_response.ctx.style().spacing.tooltip_width;
 
     tooltip.popup = tooltip.popup.width(tooltip_width);
 
     tooltip.gap(12.0).show(|ui| {
         ui.set_max_width(tooltip_width);
-        ui.label(text);
+        add_contents(ui);
     });
 }
 
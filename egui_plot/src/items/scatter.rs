@@ -1,13 +1,20 @@
 //! scatter.rs – Zero-copy scatter plot API.
 
+use std::ops::RangeInclusive;
+
 use crate::{
-    MarkerShape, PlotBounds, PlotPoint, PlotTransform,
+    ColorScale, Colormap, LegendGlyph, MarkerShape, PickHit, PlotBounds, PlotPoint, PlotTransform,
+    PointEdit,
     items::{
-        ColumnarSeries, PlotGeometry, PlotItem, PlotItemBase,
+        ColumnarSeries, HoverUiFn, ItemInteraction, PlotGeometry, PlotItem, PlotItemBase,
+        PointMetadata,
         geom_helpers::{push_polygon_at, regular_ngon, star_ngon},
     },
 };
-use egui::{Color32, Pos2, Shape, Stroke, StrokeKind, Ui, Vec2, epaint::CircleShape, pos2, vec2};
+use egui::{
+    Color32, Pos2, Rect, Shape, Stroke, StrokeKind, TextStyle, Ui, Vec2, epaint::CircleShape, pos2,
+    vec2,
+};
 
 /// Per-series uniform marker style (presentation only).
 #[derive(Clone, Debug)]
@@ -15,10 +22,22 @@ pub struct Marker {
     pub shape: MarkerShape,
     pub filled: bool,
     pub radius: f32,
+
+    /// The stroke of the stems, and of the outline of filled markers unless `outline` is set.
     pub stroke: Stroke,
     /// None = auto color from Plot palette.
     pub color: Option<Color32>,
 
+    /// The inside of filled markers. None = `color`.
+    pub fill: Option<Color32>,
+
+    /// The outline of filled markers. None = `stroke`.
+    pub outline: Option<Stroke>,
+
+    /// Multiplies the alpha of the fill, the outline and the stems, from `0.0` (invisible) to
+    /// `1.0`. Defaults to 1.
+    pub opacity: f32,
+
     pub color_mode: MarkerColor,
     ///draw only every Nth point (1 = all). Defaults to 1.
     pub every_nth: std::num::NonZeroUsize,
@@ -41,6 +60,9 @@ impl Default for Marker {
             radius: 2.5,
             stroke: Stroke::new(1.0, Color32::TRANSPARENT),
             color: None,
+            fill: None,
+            outline: None,
+            opacity: 1.0,
             color_mode: MarkerColor::Auto,
             every_nth: std::num::NonZeroUsize::new(1).expect("n must be non-zero"),
         }
@@ -56,19 +78,191 @@ impl Marker {
         self.every_nth = std::num::NonZeroUsize::new(n.max(1)).expect("n must be non-zero");
         self
     }
+
+    pub fn fill(mut self, fill: Color32) -> Self {
+        self.fill = Some(fill);
+        self
+    }
+
+    pub fn outline(mut self, outline: impl Into<Stroke>) -> Self {
+        self.outline = Some(outline.into());
+        self
+    }
+
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
 }
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ScatterEncodings<'a> {
     pub per_point_colors: Option<&'a [Color32]>,
     pub per_point_radii: Option<&'a [f32]>,
+
+    /// Outline colors of filled markers, over the one of [`Marker::outline`].
+    pub per_point_outline_colors: Option<&'a [Color32]>,
+
+    /// Opacities, multiplied with [`Marker::opacity`].
+    pub per_point_opacity: Option<&'a [f32]>,
+
+    /// Radii computed from a value column, for bubble charts. `per_point_radii` take
+    /// precedence.
+    pub radius_by: Option<RadiusBy<'a>>,
+}
+
+impl<'a> ScatterEncodings<'a> {
+    /// Size the markers by `values`, from `min_px` for the smallest value to `max_px` for the
+    /// largest, see [`RadiusScale`].
+    #[inline]
+    pub fn radius_by(
+        mut self,
+        values: &'a [f64],
+        scale: RadiusScale,
+        min_px: f32,
+        max_px: f32,
+    ) -> Self {
+        self.radius_by = Some(RadiusBy {
+            values,
+            scale,
+            min_px,
+            max_px,
+        });
+        self
+    }
+}
+
+/// How the values of [`ScatterEncodings::radius_by`] map to marker sizes.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum RadiusScale {
+    /// The area of the markers grows linearly with the value, so a value twice as large looks
+    /// twice as large. The right choice for bubble charts.
+    #[default]
+    Sqrt,
+
+    /// The radius grows linearly with the value, which exaggerates the large values.
+    Linear,
+}
+
+/// Marker radii from a value column, see [`ScatterEncodings::radius_by`].
+#[derive(Clone, Copy, Debug)]
+pub struct RadiusBy<'a> {
+    pub values: &'a [f64],
+    pub scale: RadiusScale,
+    pub min_px: f32,
+    pub max_px: f32,
+}
+
+impl RadiusBy<'_> {
+    /// The smallest and largest finite values.
+    fn value_range(&self) -> [f64; 2] {
+        self.values
+            .iter()
+            .filter(|value| value.is_finite())
+            .fold([f64::INFINITY, f64::NEG_INFINITY], |[min, max], &value| {
+                [min.min(value), max.max(value)]
+            })
+    }
+
+    /// The radius of the marker of `value`, with the values spanning `range`.
+    fn radius(&self, value: f64, [min, max]: [f64; 2]) -> Option<f32> {
+        if !value.is_finite() {
+            return None;
+        }
+        let t = if max > min {
+            ((value - min) / (max - min)) as f32
+        } else {
+            1.0
+        };
+        Some(match self.scale {
+            RadiusScale::Sqrt => {
+                let [min_area, max_area] = [self.min_px, self.max_px].map(|r| r * r);
+                (min_area + t * (max_area - min_area)).sqrt()
+            }
+            RadiusScale::Linear => self.min_px + t * (self.max_px - self.min_px),
+        })
+    }
+}
+
+/// What to do with the labels of [`Scatter::labels`] that would overlap others.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LabelOverlap {
+    /// Draw every label to the right of its point, overlapping or not.
+    ShowAll,
+
+    /// Try the other sides of the point, and leave the label out if none is free.
+    #[default]
+    Hide,
+
+    /// Like [`Self::Hide`], but first try spots farther away, with a line from the point to the
+    /// label.
+    LeaderLines,
+}
+
+/// Where to draw a label of `size` for a marker of `radius` at `point`, without overlapping the
+/// labels `placed` before, and whether it needs a leader line.
+fn place_label(
+    point: Pos2,
+    radius: f32,
+    size: Vec2,
+    placed: &[Rect],
+    overlap: LabelOverlap,
+) -> Option<(Rect, bool)> {
+    const GAP: f32 = 2.0;
+    let near = radius + GAP;
+    let at = |direction: Vec2, distance: f32| {
+        let center = point + direction * (distance + 0.5 * (direction * size).length());
+        Rect::from_center_size(center, size)
+    };
+    let sides = [
+        Vec2::RIGHT,
+        Vec2::LEFT,
+        Vec2::UP,
+        Vec2::DOWN,
+        vec2(1.0, -1.0).normalized(),
+        vec2(-1.0, -1.0).normalized(),
+        vec2(1.0, 1.0).normalized(),
+        vec2(-1.0, 1.0).normalized(),
+    ];
+    let is_free = |rect: &Rect| !placed.iter().any(|other| other.intersects(*rect));
+    match overlap {
+        LabelOverlap::ShowAll => Some((at(Vec2::RIGHT, near), false)),
+        LabelOverlap::Hide => sides[..4]
+            .iter()
+            .map(|side| at(*side, near))
+            .find(is_free)
+            .map(|rect| (rect, false)),
+        LabelOverlap::LeaderLines => sides[..4]
+            .iter()
+            .map(|side| (at(*side, near), false))
+            .chain((1..=3).flat_map(|ring| {
+                let distance = near + ring as f32 * 1.5 * size.y;
+                sides.iter().map(move |side| (at(*side, distance), true))
+            }))
+            .find(|(rect, _)| is_free(rect)),
+    }
 }
 
+type MetadataFn<'a> = dyn Fn(usize) -> Option<PointMetadata> + 'a;
+
 pub struct Scatter<'a> {
     base: PlotItemBase,
     series: ColumnarSeries<'a>,
-    marker: Marker,
+    pub(crate) marker: Marker,
     enc: ScatterEncodings<'a>,
+
+    /// Values to color the points by, and their scale.
+    color_values: Option<(&'a [f64], ColorScale)>,
+
+    /// Whether [`crate::PlotUi::scatter`] adds a colorbar for `color_values`.
+    colorbar: bool,
     stems_y: Option<f32>,
+    labels: Option<&'a [String]>,
+    label_overlap: LabelOverlap,
+    edit: Option<PointEdit>,
+    metadata: Option<Box<MetadataFn<'a>>>,
+    hover_ui: Option<Box<HoverUiFn<'a>>>,
 }
 
 impl<'a> Scatter<'a> {
@@ -78,7 +272,14 @@ impl<'a> Scatter<'a> {
             series: ColumnarSeries::EMPTY,
             marker: Marker::default(),
             enc: ScatterEncodings::default(),
+            color_values: None,
+            colorbar: true,
             stems_y: None,
+            labels: None,
+            label_overlap: LabelOverlap::default(),
+            edit: None,
+            metadata: None,
+            hover_ui: None,
         }
     }
 
@@ -125,6 +326,40 @@ impl<'a> Scatter<'a> {
         self
     }
 
+    /// The inside of filled markers, instead of [`Self::color`].
+    #[inline]
+    pub fn fill_color(mut self, c: Color32) -> Self {
+        self.marker.fill = Some(c);
+        self
+    }
+
+    /// The outline of filled markers, instead of [`Self::stroke`], which then only draws the
+    /// stems.
+    ///
+    /// ```
+    /// # use egui::{Color32, Stroke};
+    /// # use egui_plot::{ColumnarSeries, MarkerShape, Scatter};
+    /// let (xs, ys) = ([1.0, 2.0, 3.0], [0.5, 0.1, 0.9]);
+    /// let markers = Scatter::from_series("samples", ColumnarSeries::new(&xs, &ys))
+    ///     .marker_shape(MarkerShape::Square)
+    ///     .fill_color(Color32::LIGHT_BLUE)
+    ///     .outline(Stroke::new(1.5, Color32::DARK_BLUE))
+    ///     .opacity(0.6);
+    /// ```
+    #[inline]
+    pub fn outline(mut self, outline: impl Into<Stroke>) -> Self {
+        self.marker.outline = Some(outline.into());
+        self
+    }
+
+    /// The opacity of the markers and stems, from `0.0` (invisible) to `1.0`, e.g. to see
+    /// overlapping points. Default: `1.0`.
+    #[inline]
+    pub fn opacity(mut self, opacity: f32) -> Self {
+        self.marker.opacity = opacity.clamp(0.0, 1.0);
+        self
+    }
+
     #[inline]
     pub fn encodings(mut self, enc: ScatterEncodings<'a>) -> Self {
         self.enc = enc;
@@ -136,18 +371,222 @@ impl<'a> Scatter<'a> {
         self.enc.per_point_colors = Some(colors);
         self
     }
+    /// Color point `i` by `values[i]`, from the start of `colormap` at the start of `range` to
+    /// its end at the end of `range`, like `c=z` in matplotlib.
+    ///
+    /// Added with [`crate::PlotUi::scatter`], the plot shows a [`crate::Colorbar`] of the scale,
+    /// see [`Self::colorbar`]. Colors set with [`Self::per_point_colors`] take precedence.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Colormap, ColumnarSeries, Plot, Scatter};
+    /// let (xs, ys) = ([1.0, 2.0, 3.0], [0.5, 0.1, 0.9]);
+    /// let temperatures = [12.0, 18.5, 25.0];
+    /// Plot::new("stations").show(ui, |plot_ui| {
+    ///     plot_ui.scatter(
+    ///         Scatter::from_series("stations", ColumnarSeries::new(&xs, &ys))
+    ///             .color_by(&temperatures, Colormap::Coolwarm, 10.0..=30.0),
+    ///     );
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn color_by(
+        self,
+        values: &'a [f64],
+        colormap: Colormap,
+        range: RangeInclusive<f64>,
+    ) -> Self {
+        self.color_by_scale(values, ColorScale::new(colormap, range))
+    }
+
+    /// Color point `i` by `values[i]` on `scale`, e.g. with a logarithmic
+    /// [`Normalization`](crate::Normalization). See [`Self::color_by`].
+    #[inline]
+    pub fn color_by_scale(mut self, values: &'a [f64], scale: ColorScale) -> Self {
+        self.color_values = Some((values, scale));
+        self
+    }
+
+    /// Whether [`crate::PlotUi::scatter`] shows a [`crate::Colorbar`] for the colors set with
+    /// [`Self::color_by`]. Default: `true`.
+    #[inline]
+    pub fn colorbar(mut self, show: bool) -> Self {
+        self.colorbar = show;
+        self
+    }
+
+    /// The scale of the colors set with [`Self::color_by`], e.g. to show a
+    /// [`crate::Colorbar`] next to the plot.
+    pub fn color_scale(&self) -> Option<&ColorScale> {
+        self.color_values.as_ref().map(|(_, scale)| scale)
+    }
+
+    /// The colorbar [`crate::PlotUi::scatter`] adds, if any.
+    pub(crate) fn auto_colorbar(&self) -> Option<crate::Colorbar> {
+        let scale = self.color_scale().filter(|_| self.colorbar)?;
+        Some(crate::Colorbar::new(scale.clone()).label(self.base.name.clone()))
+    }
+
     #[inline]
     pub fn per_point_radii(mut self, radii: &'a [f32]) -> Self {
         self.enc.per_point_radii = Some(radii);
         self
     }
 
+    #[inline]
+    pub fn per_point_outline_colors(mut self, colors: &'a [Color32]) -> Self {
+        self.enc.per_point_outline_colors = Some(colors);
+        self
+    }
+
+    #[inline]
+    pub fn per_point_opacity(mut self, opacity: &'a [f32]) -> Self {
+        self.enc.per_point_opacity = Some(opacity);
+        self
+    }
+
+    /// Size the markers by `values`, for a bubble chart, see [`ScatterEncodings::radius_by`].
+    ///
+    /// ```
+    /// # use egui_plot::{ColumnarSeries, RadiusScale, Scatter};
+    /// let (xs, ys) = ([1.0, 2.0, 3.0], [0.5, 0.1, 0.9]);
+    /// let population = [1.2e6, 8.9e6, 0.4e6];
+    /// let bubbles = Scatter::from_series("cities", ColumnarSeries::new(&xs, &ys))
+    ///     .radius_by(&population, RadiusScale::Sqrt, 3.0, 20.0);
+    /// ```
+    #[inline]
+    pub fn radius_by(
+        mut self,
+        values: &'a [f64],
+        scale: RadiusScale,
+        min_px: f32,
+        max_px: f32,
+    ) -> Self {
+        self.enc = self.enc.radius_by(values, scale, min_px, max_px);
+        self
+    }
+
     #[inline]
     pub fn stems(mut self, y_reference: f32) -> Self {
         self.stems_y = Some(y_reference);
         self
     }
 
+    /// Write `labels[i]` next to point `i`, e.g. the names of the entities of an embedding.
+    ///
+    /// Labels that would overlap others are moved or left out, see [`Self::label_overlap`]. The
+    /// earlier labels are placed first, so put the important ones first. Empty labels are
+    /// skipped.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{ColumnarSeries, LabelOverlap, Plot, Scatter};
+    /// let (xs, ys) = ([0.1, 0.12, 0.8], [0.5, 0.52, 0.3]);
+    /// let words = ["king".to_owned(), "queen".to_owned(), "apple".to_owned()];
+    /// Plot::new("embedding").show(ui, |plot_ui| {
+    ///     plot_ui.add(
+    ///         Scatter::from_series("words", ColumnarSeries::new(&xs, &ys))
+    ///             .labels(&words)
+    ///             .label_overlap(LabelOverlap::LeaderLines),
+    ///     );
+    /// });
+    /// # });
+    /// ```
+    #[inline]
+    pub fn labels(mut self, labels: &'a [String]) -> Self {
+        self.labels = Some(labels);
+        self
+    }
+
+    /// What to do with the [`Self::labels`] that would overlap others. Default:
+    /// [`LabelOverlap::Hide`].
+    #[inline]
+    pub fn label_overlap(mut self, overlap: LabelOverlap) -> Self {
+        self.label_overlap = overlap;
+        self
+    }
+
+    /// Draw the [`Self::labels`] of the markers at `markers`, with their radii.
+    fn label_shapes(&self, ui: &Ui, markers: &[(Pos2, f32)], out: &mut Vec<Shape>) {
+        let Some(labels) = self.labels else {
+            return;
+        };
+        let font_id = TextStyle::Small.resolve(ui.style());
+        let color = ui.visuals().text_color();
+        let leader = Stroke::new(0.5, color.gamma_multiply(0.6));
+        let mut placed = Vec::new();
+        for (label, &(point, radius)) in labels.iter().zip(markers) {
+            if label.is_empty() || !point.is_finite() {
+                continue;
+            }
+            let galley = ui
+                .painter()
+                .layout_no_wrap(label.clone(), font_id.clone(), color);
+            let Some((rect, needs_leader)) =
+                place_label(point, radius, galley.size(), &placed, self.label_overlap)
+            else {
+                continue;
+            };
+            if needs_leader {
+                let towards = rect.clamp(point);
+                out.push(Shape::line_segment([point, towards], leader));
+            }
+            out.push(Shape::galley(rect.min, galley, color));
+            placed.push(rect);
+        }
+    }
+
+    /// Let the user drag the points of this scatter.
+    ///
+    /// Edits are reported as [`PlotEvent::PointEdited`](crate::PlotEvent::PointEdited).
+    #[inline]
+    pub fn editable(mut self, edit: PointEdit) -> Self {
+        self.edit = Some(edit);
+        self
+    }
+
+    /// Attach `metadata[i]` to point `i`, like the key of its database record.
+    ///
+    /// It is surfaced as [`PickHit::metadata`] in [`crate::PlotUi::pick`] and
+    /// [`Self::on_hover`], and in [`PlotEvent::ItemClicked`](crate::PlotEvent::ItemClicked), so
+    /// a marker can be mapped back to its record without keeping track of indices. For an id
+    /// column, pass the ids.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{ColumnarSeries, Plot, PlotEvent, Scatter};
+    /// let (xs, ys) = ([1.0, 2.0, 3.0], [0.5, 0.1, 0.9]);
+    /// let record_ids: [u64; 3] = [1041, 1042, 1077];
+    /// let response = Plot::new("records").show(ui, |plot_ui| {
+    ///     plot_ui.add(
+    ///         Scatter::from_series("records", ColumnarSeries::new(&xs, &ys))
+    ///             .with_metadata(&record_ids),
+    ///     );
+    /// });
+    /// for event in &response.events {
+    ///     if let PlotEvent::ItemClicked { metadata: Some(metadata), .. } = event {
+    ///         let record = metadata.downcast_ref::<u64>();
+    ///     }
+    /// }
+    /// # });
+    /// ```
+    #[inline]
+    pub fn with_metadata<T: Clone + Send + Sync + 'static>(mut self, metadata: &'a [T]) -> Self {
+        self.metadata = Some(Box::new(move |index| {
+            metadata.get(index).cloned().map(PointMetadata::new)
+        }));
+        self
+    }
+
+    /// Show `add_contents` in the tooltip when a point is hovered, instead of the default text,
+    /// see [`crate::Line::on_hover`].
+    #[inline]
+    pub fn on_hover(mut self, add_contents: impl Fn(&mut Ui, &PickHit) + 'a) -> Self {
+        self.hover_ui = Some(Box::new(add_contents));
+        self
+    }
+
     #[inline]
     fn resolve_color(&self, idx: usize, auto: Color32) -> Color32 {
         if let Some(colors) = self.enc.per_point_colors {
@@ -155,18 +594,59 @@ impl<'a> Scatter<'a> {
                 return colors[idx];
             }
         }
+        if let Some(value) = self
+            .color_values
+            .as_ref()
+            .and_then(|(values, scale)| Some(scale.color(*values.get(idx)?)))
+        {
+            return value;
+        }
         self.marker.color.unwrap_or(auto)
     }
 
+    /// The opacity of point `idx`.
     #[inline]
-    fn resolve_radius(&self, idx: usize) -> f32 {
+    fn resolve_opacity(&self, idx: usize) -> f32 {
+        let per_point = self
+            .enc
+            .per_point_opacity
+            .and_then(|opacity| opacity.get(idx).copied())
+            .unwrap_or(1.0);
+        (self.marker.opacity * per_point).clamp(0.0, 1.0)
+    }
+
+    /// The outline of filled marker `idx`.
+    #[inline]
+    fn resolve_outline(&self, idx: usize) -> Stroke {
+        let mut outline = self.marker.outline.unwrap_or(self.marker.stroke);
+        if let Some(color) = self
+            .enc
+            .per_point_outline_colors
+            .and_then(|colors| colors.get(idx))
+        {
+            outline.color = *color;
+        }
+        outline
+    }
+
+    /// The radius of point `idx`, with the values of [`ScatterEncodings::radius_by`] spanning
+    /// `value_range`.
+    #[inline]
+    fn resolve_radius(&self, idx: usize, value_range: Option<[f64; 2]>) -> f32 {
         if let Some(r) = self.enc.per_point_radii {
             if idx < r.len() {
                 return r[idx];
             }
         }
+        if let (Some(by), Some(range)) = (self.enc.radius_by, value_range) {
+            if let Some(radius) = by.values.get(idx).and_then(|v| by.radius(*v, range)) {
+                return radius;
+            }
+        }
         self.marker.radius
     }
+
+    builder_methods_for_base!();
 }
 
 impl PlotItem for Scatter<'_> {
@@ -185,21 +665,38 @@ impl PlotItem for Scatter<'_> {
         let stems_y_screen = self
             .stems_y
             .map(|y| transform.position_from_point(&PlotPoint::new(0.0, y)).y);
+        let radius_range = self.enc.radius_by.map(|by| by.value_range());
+        let mut labeled = Vec::new();
 
         for i in 0..n {
             let (x, y) = self.series.get(i).unwrap_or_default();
             let pos = transform.position_from_point(&PlotPoint::new(x, y));
+            let opacity = self.resolve_opacity(i);
 
             if let Some(y_screen) = stems_y_screen {
+                let stem = self.marker.stroke;
                 out.push(Shape::line_segment(
                     [Pos2::new(pos.x, y_screen), pos],
-                    self.marker.stroke,
+                    Stroke::new(stem.width, stem.color.gamma_multiply(opacity)),
                 ));
             }
 
-            let color = self.resolve_color(i, auto_color);
-            let radius = self.resolve_radius(i);
+            let color = self.resolve_color(i, auto_color).gamma_multiply(opacity);
+            let fill = self
+                .marker
+                .fill
+                .map_or(color, |fill| fill.gamma_multiply(opacity));
+            let radius = self.resolve_radius(i, radius_range);
             let stroke = self.marker.stroke;
+            if self.labels.is_some() {
+                labeled.push((pos, radius));
+            }
+            let outline = if self.marker.filled {
+                let outline = self.resolve_outline(i);
+                Stroke::new(outline.width, outline.color.gamma_multiply(opacity))
+            } else {
+                Stroke::new(stroke.width, color)
+            };
 
             match self.marker.shape {
                 MarkerShape::Circle => {
@@ -207,33 +704,29 @@ impl PlotItem for Scatter<'_> {
                         center: pos,
                         radius,
                         fill: if self.marker.filled {
-                            color
+                            fill
                         } else {
                             Color32::TRANSPARENT
                         },
-                        stroke: if self.marker.filled {
-                            stroke
-                        } else {
-                            Stroke::new(stroke.width, color)
-                        },
+                        stroke: outline,
                     }));
                 }
 
                 MarkerShape::Point => {
-                    out.push(Shape::circle_filled(pos, (radius * 0.4).max(0.5), color));
+                    out.push(Shape::circle_filled(pos, (radius * 0.4).max(0.5), fill));
                 }
                 MarkerShape::Pixel => {
                     let r = (radius * 0.25).max(0.5);
                     let rect = egui::Rect::from_center_size(pos, Vec2::splat(2.0 * r));
-                    out.push(Shape::rect_filled(rect, 0.0, color));
+                    out.push(Shape::rect_filled(rect, 0.0, fill));
                 }
                 MarkerShape::PlusFilled => {
                     let w = radius * 0.6;
                     let t = stroke.width.max(1.0).max(radius * 0.6);
                     let rect_h = egui::Rect::from_center_size(pos, Vec2::new(2.0 * w, t));
                     let rect_v = egui::Rect::from_center_size(pos, Vec2::new(t, 2.0 * w));
-                    out.push(Shape::rect_filled(rect_h, 0.0, color));
-                    out.push(Shape::rect_filled(rect_v, 0.0, color));
+                    out.push(Shape::rect_filled(rect_h, 0.0, fill));
+                    out.push(Shape::rect_filled(rect_v, 0.0, fill));
                 }
 
                 MarkerShape::XFilled => {
@@ -255,7 +748,7 @@ impl PlotItem for Scatter<'_> {
                             .into_iter()
                             .map(|p: egui::Pos2| p - egui::pos2(0.0, 0.0)) // Pos2 -> Vec2
                             .collect();
-                    push_polygon_at(out, pos, pts_local, color, stroke, self.marker.filled);
+                    push_polygon_at(out, pos, pts_local, fill, outline, self.marker.filled);
                 }
                 MarkerShape::StarPolygon {
                     n,
@@ -271,13 +764,10 @@ impl PlotItem for Scatter<'_> {
                     if self.marker.filled {
                         out.push(egui::Shape::closed_line(
                             path.clone(),
-                            egui::Stroke::new(1.0, color),
+                            egui::Stroke::new(1.0, fill),
                         ));
                     }
-                    out.push(egui::Shape::closed_line(
-                        path,
-                        egui::Stroke::new(stroke.width, color),
-                    ));
+                    out.push(egui::Shape::closed_line(path, outline));
                 }
 
                 MarkerShape::Square => {
@@ -287,19 +777,12 @@ impl PlotItem for Scatter<'_> {
                         rect,
                         0.0,
                         if self.marker.filled {
-                            color
+                            fill
                         } else {
                             Color32::TRANSPARENT
                         },
                     ));
-                    if !self.marker.filled {
-                        out.push(Shape::rect_stroke(
-                            rect,
-                            0.0,
-                            Stroke::new(stroke.width, color),
-                            StrokeKind::Outside,
-                        ));
-                    }
+                    out.push(Shape::rect_stroke(rect, 0.0, outline, StrokeKind::Outside));
                 }
                 MarkerShape::Diamond => {
                     let r = radius;
@@ -312,15 +795,11 @@ impl PlotItem for Scatter<'_> {
                     out.push(Shape::convex_polygon(
                         pts.clone(),
                         if self.marker.filled {
-                            color
+                            fill
                         } else {
                             Color32::TRANSPARENT
                         },
-                        if self.marker.filled {
-                            Stroke::NONE
-                        } else {
-                            Stroke::new(stroke.width, color)
-                        },
+                        outline,
                     ));
                 }
                 MarkerShape::Cross => {
@@ -367,7 +846,7 @@ impl PlotItem for Scatter<'_> {
                         Vec2::new(0.5 * radius, -0.5 * s3 * radius),
                         Vec2::new(0.5 * radius, 0.5 * s3 * radius),
                     ];
-                    push_polygon_at(out, pos, pts, color, stroke, self.marker.filled);
+                    push_polygon_at(out, pos, pts, fill, outline, self.marker.filled);
                 }
                 MarkerShape::Down => {
                     let s3 = 3f32.sqrt();
@@ -376,7 +855,7 @@ impl PlotItem for Scatter<'_> {
                         Vec2::new(-0.5 * s3 * radius, -0.5 * radius),
                         Vec2::new(0.5 * s3 * radius, -0.5 * radius),
                     ];
-                    push_polygon_at(out, pos, pts, color, stroke, self.marker.filled);
+                    push_polygon_at(out, pos, pts, fill, outline, self.marker.filled);
                 }
                 MarkerShape::Up => {
                     let s3 = 3f32.sqrt();
@@ -385,7 +864,7 @@ impl PlotItem for Scatter<'_> {
                         Vec2::new(0.5 * s3 * radius, 0.5 * radius),
                         Vec2::new(-0.5 * s3 * radius, 0.5 * radius),
                     ];
-                    push_polygon_at(out, pos, pts, color, stroke, self.marker.filled);
+                    push_polygon_at(out, pos, pts, fill, outline, self.marker.filled);
                 }
                 MarkerShape::Plus => {
                     let r = radius;
@@ -405,19 +884,16 @@ impl PlotItem for Scatter<'_> {
                         center: pos,
                         radius,
                         fill: if self.marker.filled {
-                            color
+                            fill
                         } else {
                             Color32::TRANSPARENT
                         },
-                        stroke: if self.marker.filled {
-                            stroke
-                        } else {
-                            Stroke::new(stroke.width, color)
-                        },
+                        stroke: outline,
                     }));
                 }
             }
         }
+        self.label_shapes(ui, &labeled, out);
     }
 
     fn initialize(&mut self, _x_range: std::ops::RangeInclusive<f64>) {}
@@ -433,14 +909,118 @@ impl PlotItem for Scatter<'_> {
         }
     }
 
+    fn screen_extent(&self) -> f32 {
+        let radius = match (self.enc.per_point_radii, self.enc.radius_by) {
+            (Some(radii), _) => radii.iter().copied().fold(self.marker.radius, f32::max),
+            (None, Some(by)) => by.min_px.max(by.max_px),
+            (None, None) => self.marker.radius,
+        };
+        let outline = self.marker.outline.unwrap_or(self.marker.stroke);
+        radius + 0.5 * outline.width.max(self.marker.stroke.width)
+    }
+
     fn bounds(&self) -> PlotBounds {
         self.series.bounds()
     }
 
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Marker {
+            shape: self.marker.shape,
+            filled: self.marker.filled,
+        }
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
     fn base_mut(&mut self) -> &mut PlotItemBase {
         &mut self.base
     }
+
+    fn interaction(&self) -> ItemInteraction<'_> {
+        self.edit
+            .map_or(ItemInteraction::None, ItemInteraction::EditPoints)
+    }
+
+    fn point_metadata(&self, index: usize) -> Option<PointMetadata> {
+        self.metadata.as_ref().and_then(|metadata| metadata(index))
+    }
+
+    fn hover_ui(&self) -> Option<&(dyn Fn(&mut Ui, &PickHit) + '_)> {
+        self.hover_ui.as_deref()
+    }
+}
+
+#[test]
+fn test_radius_by() {
+    let values = [0.0, 1.0, 4.0, f64::NAN];
+    let by = ScatterEncodings::default()
+        .radius_by(&values, RadiusScale::Sqrt, 0.0, 10.0)
+        .radius_by
+        .expect("set");
+    let range = by.value_range();
+    assert_eq!(range, [0.0, 4.0]);
+    assert_eq!(by.radius(4.0, range), Some(10.0));
+    assert_eq!(by.radius(1.0, range), Some(5.0));
+    assert_eq!(by.radius(f64::NAN, range), None);
+
+    let linear = RadiusBy {
+        scale: RadiusScale::Linear,
+        ..by
+    };
+    assert_eq!(linear.radius(1.0, range), Some(2.5));
+}
+
+#[test]
+fn test_marker_outline() {
+    let (xs, ys) = ([0.0, 1.0], [0.0, 1.0]);
+    let outline_colors = [Color32::RED];
+    let opacity = [0.5];
+    let scatter = Scatter::from_series("s", ColumnarSeries::new(&xs, &ys))
+        .stroke(Stroke::new(3.0, Color32::GRAY))
+        .outline(Stroke::new(1.0, Color32::BLUE))
+        .opacity(0.5)
+        .per_point_outline_colors(&outline_colors)
+        .per_point_opacity(&opacity);
+    assert_eq!(scatter.resolve_outline(0), Stroke::new(1.0, Color32::RED));
+    assert_eq!(scatter.resolve_outline(1), Stroke::new(1.0, Color32::BLUE));
+    assert_eq!(scatter.resolve_opacity(0), 0.25);
+    assert_eq!(scatter.resolve_opacity(1), 0.5);
+
+    // Without an outline, the stroke of the stems outlines the markers too:
+    let scatter = Scatter::new("s").stroke(Stroke::new(3.0, Color32::GRAY));
+    assert_eq!(scatter.resolve_outline(0), Stroke::new(3.0, Color32::GRAY));
+}
+
+#[test]
+fn test_place_label() {
+    let size = vec2(20.0, 10.0);
+    let point = pos2(100.0, 100.0);
+    let (first, leader) = place_label(point, 3.0, size, &[], LabelOverlap::Hide).expect("free");
+    assert!(!leader);
+    assert_eq!(first.left_center(), pos2(105.0, 100.0));
+
+    // The right side is taken, so the label goes to the left:
+    let (second, _) = place_label(point, 3.0, size, &[first], LabelOverlap::Hide).expect("free");
+    assert_eq!(second.right_center(), pos2(95.0, 100.0));
+
+    // Labels above and below too:
+    let crowded = [
+        first,
+        second,
+        first.translate(-vec2(20.0, 13.0)).expand2(vec2(30.0, 0.0)),
+        first.translate(vec2(-20.0, 13.0)).expand2(vec2(30.0, 0.0)),
+    ];
+    assert_eq!(
+        place_label(point, 3.0, size, &crowded, LabelOverlap::Hide),
+        None
+    );
+    let (far, leader) =
+        place_label(point, 3.0, size, &crowded, LabelOverlap::LeaderLines).expect("free");
+    assert!(leader);
+    assert!(!crowded.iter().any(|rect| rect.intersects(far)));
+    assert_eq!(
+        place_label(point, 3.0, size, &crowded, LabelOverlap::ShowAll),
+        Some((first, false))
+    );
 }
@@ -0,0 +1,290 @@
+//! Smooth curve item through (or controlled by) a list of knots.
+
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Shape, Stroke, Ui, epaint::CircleShape};
+
+use super::{
+    ItemInteraction, LineStyle, PlotGeometry, PlotItem, PlotItemBase, PlotPoint, PointEdit,
+};
+use crate::{LegendGlyph, PlotBounds, PlotTransform, SeriesStyle};
+
+/// Number of line segments drawn per spline segment.
+const DEFAULT_SAMPLES_PER_SEGMENT: usize = 32;
+
+/// How the knots of a [`Spline`] define the curve.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SplineKind {
+    /// Uniform Catmull-Rom spline passing through every knot.
+    CatmullRom,
+
+    /// Piecewise cubic Bezier curve. The knots are
+    /// `[anchor, control, control, anchor, control, control, anchor, ...]`.
+    CubicBezier,
+}
+
+/// A smooth curve defined by knots, for instance for animation curves and easing editors.
+///
+/// # Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, PointEdit, Spline};
+///
+/// let spline = Spline::catmull_rom("easing", vec![[0.0, 0.0], [0.3, 0.1], [0.7, 0.9], [1.0, 1.0]])
+///     .editable(PointEdit::new());
+/// let response = Plot::new("easing_editor").show(ui, |plot_ui| plot_ui.spline(spline));
+/// // Apply `PlotEvent::PointEdited` from `response.events` to your knots.
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct Spline {
+    base: PlotItemBase,
+    kind: SplineKind,
+    knots: Vec<PlotPoint>,
+    stroke: Stroke,
+    samples_per_segment: usize,
+    edit: Option<PointEdit>,
+}
+
+impl Spline {
+    /// A Catmull-Rom spline passing through all `knots`.
+    pub fn catmull_rom(name: impl Into<String>, knots: Vec<impl Into<PlotPoint>>) -> Self {
+        Self::new(name, SplineKind::CatmullRom, knots)
+    }
+
+    /// A piecewise cubic Bezier curve; see [`SplineKind::CubicBezier`] for the knot layout.
+    pub fn cubic_bezier(name: impl Into<String>, knots: Vec<impl Into<PlotPoint>>) -> Self {
+        Self::new(name, SplineKind::CubicBezier, knots)
+    }
+
+    /// A spline of the given kind through (or controlled by) `knots`.
+    pub fn new(
+        name: impl Into<String>,
+        kind: SplineKind,
+        knots: Vec<impl Into<PlotPoint>>,
+    ) -> Self {
+        Self {
+            base: PlotItemBase::new(name.into()),
+            kind,
+            knots: knots.into_iter().map(Into::into).collect(),
+            stroke: Stroke::new(1.5, Color32::TRANSPARENT),
+            samples_per_segment: DEFAULT_SAMPLES_PER_SEGMENT,
+            edit: None,
+        }
+    }
+
+    /// Add a stroke.
+    #[inline]
+    pub fn stroke(mut self, stroke: impl Into<Stroke>) -> Self {
+        self.stroke = stroke.into();
+        self
+    }
+
+    /// Stroke width. A high value means the plot thickens.
+    #[inline]
+    pub fn width(mut self, width: impl Into<f32>) -> Self {
+        self.stroke.width = width.into();
+        self
+    }
+
+    /// Stroke color. Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.stroke.color = color.into();
+        self
+    }
+
+    /// Number of line segments used to draw each curve segment. Default: `32`.
+    #[inline]
+    pub fn samples_per_segment(mut self, samples: usize) -> Self {
+        self.samples_per_segment = samples.max(1);
+        self
+    }
+
+    /// Show the knots (and Bezier control handles) and let the user drag them.
+    ///
+    /// Edits are reported as [`PlotEvent::PointEdited`](crate::PlotEvent::PointEdited), where
+    /// `index` is the index into the knots.
+    #[inline]
+    pub fn editable(mut self, edit: PointEdit) -> Self {
+        self.edit = Some(edit);
+        self
+    }
+
+    /// The knots of the spline.
+    #[inline]
+    pub fn knots(&self) -> &[PlotPoint] {
+        &self.knots
+    }
+
+    /// Evaluate the curve, with `samples_per_segment` line segments per curve segment.
+    ///
+    /// The result starts at the first and ends at the last anchor.
+    pub fn sample(&self, samples_per_segment: usize) -> Vec<PlotPoint> {
+        let n = samples_per_segment.max(1);
+        let segments = self.segments();
+        let mut points = Vec::with_capacity(segments.len() * n + 1);
+        for (i, [p0, p1, p2, p3]) in segments.iter().enumerate() {
+            // Each segment starts where the previous one ended:
+            let start = usize::from(i != 0);
+            for s in start..=n {
+                let t = s as f64 / n as f64;
+                points.push(match self.kind {
+                    SplineKind::CatmullRom => catmull_rom(*p0, *p1, *p2, *p3, t),
+                    SplineKind::CubicBezier => cubic_bezier(*p0, *p1, *p2, *p3, t),
+                });
+            }
+        }
+        if points.is_empty() {
+            points.extend(self.knots.first().copied());
+        }
+        points
+    }
+
+    /// The four points defining each curve segment.
+    fn segments(&self) -> Vec<[PlotPoint; 4]> {
+        let k = &self.knots;
+        match self.kind {
+            SplineKind::CatmullRom => (0..k.len().saturating_sub(1))
+                .map(|i| {
+                    // Duplicate the end points so the curve reaches the first and last knot:
+                    let p0 = k[i.saturating_sub(1)];
+                    let p3 = k[(i + 2).min(k.len() - 1)];
+                    [p0, k[i], k[i + 1], p3]
+                })
+                .collect(),
+            SplineKind::CubicBezier => k
+                .windows(4)
+                .step_by(3)
+                .map(|w| [w[0], w[1], w[2], w[3]])
+                .collect(),
+        }
+    }
+
+    builder_methods_for_base!();
+}
+
+fn catmull_rom(p0: PlotPoint, p1: PlotPoint, p2: PlotPoint, p3: PlotPoint, t: f64) -> PlotPoint {
+    let (t2, t3) = (t * t, t * t * t);
+    let f = |a: f64, b: f64, c: f64, d: f64| {
+        0.5 * (2.0 * b
+            + (c - a) * t
+            + (2.0 * a - 5.0 * b + 4.0 * c - d) * t2
+            + (3.0 * b - a - 3.0 * c + d) * t3)
+    };
+    PlotPoint::new(f(p0.x, p1.x, p2.x, p3.x), f(p0.y, p1.y, p2.y, p3.y))
+}
+
+fn cubic_bezier(p0: PlotPoint, p1: PlotPoint, p2: PlotPoint, p3: PlotPoint, t: f64) -> PlotPoint {
+    let u = 1.0 - t;
+    let f = |a: f64, b: f64, c: f64, d: f64| {
+        u * u * u * a + 3.0 * u * u * t * b + 3.0 * u * t * t * c + t * t * t * d
+    };
+    PlotPoint::new(f(p0.x, p1.x, p2.x, p3.x), f(p0.y, p1.y, p2.y, p3.y))
+}
+
+impl PlotItem for Spline {
+    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        let mut stroke = self.stroke;
+        if self.base.highlight {
+            stroke.width *= 2.0;
+        }
+        let curve: Vec<_> = self
+            .sample(self.samples_per_segment)
+            .iter()
+            .map(|p| transform.position_from_point(p))
+            .collect();
+        shapes.push(Shape::line(curve, stroke));
+
+        if self.edit.is_none() {
+            return;
+        }
+        let handle_stroke = Stroke::new(1.0, self.stroke.color);
+        for (i, knot) in self.knots.iter().enumerate() {
+            let pos = transform.position_from_point(knot);
+            let is_control = self.kind == SplineKind::CubicBezier && i % 3 != 0;
+            if is_control {
+                // Connect the control point to its anchor:
+                let anchor = if i % 3 == 1 { i - 1 } else { i + 1 };
+                if let Some(anchor) = self.knots.get(anchor) {
+                    let anchor = transform.position_from_point(anchor);
+                    shapes.push(Shape::line_segment([anchor, pos], handle_stroke));
+                }
+                shapes.push(Shape::Circle(CircleShape::stroke(pos, 3.5, handle_stroke)));
+            } else {
+                shapes.push(Shape::circle_filled(pos, 3.5, self.stroke.color));
+            }
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.stroke.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::Points(&self.knots)
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for point in self.sample(self.samples_per_segment) {
+            bounds.extend_with(&point);
+        }
+        if self.edit.is_some() {
+            for knot in &self.knots {
+                bounds.extend_with(knot);
+            }
+        }
+        bounds
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Line {
+            style: LineStyle::Solid,
+            marker: None,
+        }
+    }
+
+    fn series_style(&self) -> SeriesStyle {
+        SeriesStyle {
+            name: self.name().to_owned(),
+            color: Some(self.stroke.color),
+            width: Some(self.stroke.width),
+            marker: None,
+        }
+    }
+
+    fn set_series_style(&mut self, style: &SeriesStyle) {
+        self.base.name.clone_from(&style.name);
+        self.stroke.color = style.color.unwrap_or(self.stroke.color);
+        self.stroke.width = style.width.unwrap_or(self.stroke.width);
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+
+    fn interaction(&self) -> ItemInteraction<'_> {
+        self.edit
+            .map_or(ItemInteraction::None, ItemInteraction::EditPoints)
+    }
+}
+
+#[test]
+fn test_spline_passes_through_knots() {
+    let knots = vec![[0.0, 0.0], [1.0, 2.0], [2.0, 1.0]];
+    let samples = Spline::catmull_rom("s", knots).sample(4);
+    assert_eq!(samples.len(), 9);
+    assert_eq!((samples[4].x, samples[4].y), (1.0, 2.0));
+    assert_eq!((samples[8].x, samples[8].y), (2.0, 1.0));
+
+    let bezier = Spline::cubic_bezier("b", vec![[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]);
+    let mid = bezier.sample(2)[1];
+    assert_eq!((mid.x, mid.y), (0.5, 0.75));
+}
@@ -0,0 +1,338 @@
+//! Stacked area item: a filled area between the cumulative sum of the series below it and the
+//! cumulative sum including itself.
+
+use std::ops::RangeInclusive;
+
+use egui::{Color32, Pos2, Shape, Stroke, Ui, pos2};
+
+use super::{
+    Band, ClosestElem, Cursor, LabelFormatter, PlotConfig, PlotGeometry, PlotItem, PlotItemBase,
+    PlotPoint, geom_helpers, show_tooltip_text,
+};
+use crate::{FillPattern, LegendGlyph, PlotBounds, PlotTransform, export::ExportShape};
+
+/// One series of a stacked area chart.
+///
+/// Use [`StackedArea::stack`] to stack a group of series on top of each other, or
+/// [`StackedArea::stack_normalized`] to scale every x position of the stack to `1.0`
+/// (a "100% stacked" chart).
+///
+/// # Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, StackedArea};
+///
+/// let xs = [0.0, 1.0, 2.0, 3.0];
+/// let mut areas = vec![
+///     StackedArea::new("coal", &xs, &[4.0, 3.0, 2.0, 1.0]),
+///     StackedArea::new("solar", &xs, &[1.0, 2.0, 4.0, 6.0]),
+/// ];
+/// StackedArea::stack_normalized(&mut areas);
+/// Plot::new("energy_mix").show(ui, |plot_ui| {
+///     for area in areas {
+///         plot_ui.stacked_area(area);
+///     }
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct StackedArea {
+    base: PlotItemBase,
+    color: Color32,
+
+    /// Pattern drawn on top of the fill.
+    pattern: Option<FillPattern>,
+
+    /// Whether NaN samples break the area, or the samples around them are joined.
+    gap_on_nan: bool,
+
+    /// Sampled x-coordinates.
+    xs: Vec<f64>,
+
+    /// Absolute values of this series.
+    values: Vec<f64>,
+
+    /// Plotted lower edge, i.e. the top of the series below.
+    lower: Vec<f64>,
+
+    /// Plotted upper edge.
+    upper: Vec<f64>,
+
+    /// Sum of all series of the stack at each x, if this area has been stacked.
+    totals: Option<Vec<f64>>,
+}
+
+impl StackedArea {
+    /// Create an area filled between `0` and `values`.
+    ///
+    /// If `xs` and `values` differ in length, the longer one is truncated.
+    /// Values are expected to be non-negative; NaN/non-finite samples leave a gap, see
+    /// [`Self::gap_on_nan`].
+    pub fn new(name: impl Into<String>, xs: &[f64], values: &[f64]) -> Self {
+        let n = xs.len().min(values.len());
+        let (xs, values) = (&xs[..n], &values[..n]);
+        Self {
+            base: PlotItemBase::new(name.into()),
+            color: Color32::TRANSPARENT,
+            pattern: None,
+            gap_on_nan: true,
+            xs: xs.to_vec(),
+            values: values.to_vec(),
+            lower: vec![0.0; xs.len()],
+            upper: values.to_vec(),
+            totals: None,
+        }
+    }
+
+    /// Stroke color of the upper edge; the fill uses a translucent version of it.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Draw a pattern on top of the fill, e.g. [`FillPattern::hatch`], in the area color.
+    #[inline]
+    pub fn pattern(mut self, pattern: FillPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Break the area at NaN or infinite samples, leaving a gap for the missing data, or join
+    /// the samples around them if `false`. Default: `true`.
+    #[inline]
+    pub fn gap_on_nan(mut self, gap: bool) -> Self {
+        self.gap_on_nan = gap;
+        self
+    }
+
+    /// Stack the areas on top of each other, in order (the first one is at the bottom).
+    ///
+    /// The areas are assumed to share the same `xs`; any with more samples than the others are
+    /// truncated. Non-finite samples leave a gap in their own area, and count as `0` for the areas
+    /// above it.
+    pub fn stack(areas: &mut [Self]) {
+        Self::stack_impl(areas, false);
+    }
+
+    /// Like [`Self::stack`], but scale each x position so the whole stack sums to `1.0`.
+    ///
+    /// Tooltips still show the absolute value of each series next to its percentage.
+    pub fn stack_normalized(areas: &mut [Self]) {
+        Self::stack_impl(areas, true);
+    }
+
+    fn stack_impl(areas: &mut [Self], normalize: bool) {
+        let Some(n) = areas.iter().map(|a| a.xs.len()).min() else {
+            return;
+        };
+        for area in areas.iter_mut() {
+            area.xs.truncate(n);
+            area.values.truncate(n);
+            area.lower.truncate(n);
+            area.upper.truncate(n);
+        }
+
+        // Missing samples don't lift the areas above them:
+        let finite = |v: f64| if v.is_finite() { v } else { 0.0 };
+        let totals: Vec<f64> = (0..n)
+            .map(|i| areas.iter().map(|a| finite(a.values[i])).sum())
+            .collect();
+        let mut base = vec![0.0; n];
+        for area in areas.iter_mut() {
+            for i in 0..n {
+                let scale = if normalize && totals[i] != 0.0 {
+                    1.0 / totals[i]
+                } else {
+                    1.0
+                };
+                area.lower[i] = base[i];
+                area.upper[i] = base[i] + area.values[i] * scale;
+                base[i] += finite(area.values[i]) * scale;
+            }
+            area.totals = Some(totals.clone());
+        }
+    }
+
+    /// Index of the sample closest to the given screen x-coordinate.
+    fn closest_index(&self, pointer_x: f32, transform: &PlotTransform) -> Option<usize> {
+        self.xs
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| x.is_finite())
+            .min_by(|(_, a), (_, b)| {
+                let da = (transform.position_from_point_x(**a) - pointer_x).abs();
+                let db = (transform.position_from_point_x(**b) - pointer_x).abs();
+                da.total_cmp(&db)
+            })
+            .map(|(i, _)| i)
+    }
+
+    builder_methods_for_base!();
+}
+
+impl PlotItem for StackedArea {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if self.xs.len() < 2 {
+            return;
+        }
+        let fill = self.color.gamma_multiply(0.5);
+        let mut band = Band::from_columns(&self.xs, &self.lower, &self.upper)
+            .with_color(fill)
+            .gap_on_nan(self.gap_on_nan);
+        if let Some(pattern) = self.pattern {
+            band = band.pattern(pattern);
+        }
+        band.shapes(ui, transform, shapes);
+
+        let width = if self.base.highlight { 2.0 } else { 1.0 };
+        let stroke = Stroke::new(width, self.color);
+        let positions = self
+            .xs
+            .iter()
+            .zip(&self.upper)
+            .map(|(&x, &y)| transform.position_from_point(&PlotPoint::new(x, y)));
+        for edge in geom_helpers::line_runs(positions, self.gap_on_nan) {
+            shapes.push(Shape::line(edge, stroke));
+        }
+    }
+
+    fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
+
+    fn color(&self) -> Color32 {
+        self.color
+    }
+
+    fn geometry(&self) -> PlotGeometry<'_> {
+        PlotGeometry::PointsXY {
+            xs: &self.xs,
+            ys: &self.upper,
+        }
+    }
+
+    fn screen_extent(&self) -> f32 {
+        1.0
+    }
+
+    fn bounds(&self) -> PlotBounds {
+        let mut bounds = PlotBounds::NOTHING;
+        for i in 0..self.xs.len() {
+            let (x, lo, hi) = (self.xs[i], self.lower[i], self.upper[i]);
+            if x.is_finite() && lo.is_finite() && hi.is_finite() {
+                bounds.extend_with(&PlotPoint::new(x, lo));
+                bounds.extend_with(&PlotPoint::new(x, hi));
+            }
+        }
+        bounds
+    }
+
+    fn find_closest(&self, point: Pos2, transform: &PlotTransform) -> Option<ClosestElem> {
+        let index = self.closest_index(point.x, transform)?;
+        let x = transform.position_from_point_x(self.xs[index]);
+        let y0 = transform.position_from_point_y(self.lower[index]);
+        let y1 = transform.position_from_point_y(self.upper[index]);
+        let y = point.y.clamp(y0.min(y1), y0.max(y1));
+        Some(ClosestElem {
+            index,
+            dist_sq: point.distance_sq(pos2(x, y)),
+        })
+    }
+
+    fn on_hover(
+        &self,
+        plot_area_response: &egui::Response,
+        elem: ClosestElem,
+        shapes: &mut Vec<Shape>,
+        cursors: &mut Vec<Cursor>,
+        plot: &PlotConfig<'_>,
+        label_formatter: &LabelFormatter<'_>,
+    ) {
+        let i = elem.index;
+        let x = self.xs[i];
+        let value = self.values[i];
+
+        let top = PlotPoint::new(x, self.upper[i]);
+        let pointer = plot.transform.position_from_point(&top);
+        shapes.push(Shape::circle_filled(
+            pointer,
+            3.0,
+            super::rulers_color(plot.ui),
+        ));
+
+        if plot.show_x {
+            cursors.push(Cursor::Vertical { x });
+        }
+        if plot.show_y {
+            cursors.push(Cursor::Horizontal { y: top.y });
+        }
+
+        let text = if let Some(custom_label) = label_formatter {
+            custom_label(self.name(), &PlotPoint::new(x, value))
+        } else {
+            let [x, y] = plot.format_values(x, value);
+            let mut text = format!("{}\nx = {x}\ny = {y}", self.name());
+            let total = self.totals.as_ref().map_or(0.0, |t| t[i]);
+            if total != 0.0 {
+                text.push_str(&format!(" ({:.1}%)", 100.0 * value / total));
+            }
+            text
+        };
+        show_tooltip_text(plot_area_response, text);
+    }
+
+    fn legend_glyph(&self) -> LegendGlyph {
+        LegendGlyph::Area
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        Some(vec![ExportShape::FillBetween {
+            xs: self.xs.clone(),
+            lower: self.lower.clone(),
+            upper: self.upper.clone(),
+            color: self.color,
+        }])
+    }
+
+    fn base(&self) -> &PlotItemBase {
+        &self.base
+    }
+
+    fn base_mut(&mut self) -> &mut PlotItemBase {
+        &mut self.base
+    }
+}
+
+#[test]
+fn test_stack_normalized() {
+    let xs = [0.0, 1.0];
+    let mut areas = vec![
+        StackedArea::new("a", &xs, &[1.0, 3.0]),
+        StackedArea::new("b", &xs, &[3.0, 1.0]),
+    ];
+    StackedArea::stack_normalized(&mut areas);
+    assert_eq!(areas[0].upper, vec![0.25, 0.75]);
+    assert_eq!(areas[1].lower, vec![0.25, 0.75]);
+    assert_eq!(areas[1].upper, vec![1.0, 1.0]);
+    assert_eq!(areas[1].values, vec![3.0, 1.0]);
+}
+
+#[test]
+fn test_stack_missing_samples() {
+    let xs = [0.0, 1.0, 2.0];
+    let mut areas = vec![
+        StackedArea::new("a", &xs, &[1.0, f64::NAN, 1.0]),
+        StackedArea::new("b", &xs, &[2.0, 2.0, 2.0, 2.0]),
+        StackedArea::new("c", &xs[..2], &[1.0, 1.0]),
+    ];
+    assert_eq!(areas[1].xs.len(), 3);
+
+    StackedArea::stack(&mut areas);
+    // The gap stays in its own area, and the areas above it start from the samples below:
+    assert!(areas[0].upper[1].is_nan());
+    assert_eq!(areas[1].lower, vec![1.0, 0.0]);
+    assert_eq!(areas[1].upper, vec![3.0, 2.0]);
+    assert_eq!(areas[2].upper, vec![4.0, 3.0]);
+    assert_eq!(areas[2].totals, Some(vec![4.0, 3.0]));
+    assert!(areas.iter().all(|a| a.xs.len() == 2));
+}
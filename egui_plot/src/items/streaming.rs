@@ -0,0 +1,159 @@
+//! Data appended while it is being plotted, in chunks, see [`StreamingSeries`].
+
+use super::{ColumnarSeries, Line};
+use crate::PlotBounds;
+
+/// When a [`StreamingSeries`] last changed, as returned by [`StreamingSeries::append_chunk`].
+///
+/// Epochs only grow, so keep the one your cache was built at and compare: if it is older,
+/// update the cache with just [`StreamingSeries::since`] rather than rebuilding it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Epoch(u64);
+
+/// An `(x, y)` series that grows in chunks while it is shown, like samples from a device.
+///
+/// The samples are kept contiguous, and the bounds are extended with each chunk instead of being
+/// computed over all the samples every frame, which keeps high-rate streaming smooth.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui_plot::{Plot, StreamingSeries};
+/// let mut series = StreamingSeries::new();
+/// let epoch = series.append_chunk(&[0.0, 1.0, 2.0], &[0.5, 0.7, 0.2]);
+/// series.append_chunk(&[3.0, 4.0], &[0.1, 0.4]);
+/// assert_eq!(series.since(epoch).xs(), &[3.0, 4.0]);
+///
+/// Plot::new("streaming").show(ui, |plot_ui| {
+///     plot_ui.line(series.line("signal"));
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct StreamingSeries {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    bounds: PlotBounds,
+    epoch: Epoch,
+
+    /// The epoch of each chunk, with the index of its first sample.
+    chunks: Vec<(Epoch, usize)>,
+}
+
+impl Default for StreamingSeries {
+    fn default() -> Self {
+        Self {
+            xs: Vec::new(),
+            ys: Vec::new(),
+            bounds: PlotBounds::NOTHING,
+            epoch: Epoch::default(),
+            chunks: Vec::new(),
+        }
+    }
+}
+
+impl StreamingSeries {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append the samples of `xs` and `ys`, and return the new epoch of the series.
+    ///
+    /// # Panics
+    /// Panics if `xs.len() != ys.len()`.
+    pub fn append_chunk(&mut self, xs: &[f64], ys: &[f64]) -> Epoch {
+        assert!(
+            xs.len() == ys.len(),
+            "StreamingSeries::append_chunk: xs and ys must have equal length (got {} vs {})",
+            xs.len(),
+            ys.len()
+        );
+        let chunk = ColumnarSeries::new(xs, ys).bounds();
+        if chunk.is_finite_x() {
+            self.bounds.merge_x(&chunk);
+        }
+        if chunk.is_finite_y() {
+            self.bounds.merge_y(&chunk);
+        }
+        self.epoch = Epoch(self.epoch.0 + 1);
+        self.chunks.push((self.epoch, self.xs.len()));
+        self.xs.extend_from_slice(xs);
+        self.ys.extend_from_slice(ys);
+        self.epoch
+    }
+
+    /// Remove all the samples. This is a new epoch too, after which caches must be rebuilt.
+    pub fn clear(&mut self) -> Epoch {
+        self.xs.clear();
+        self.ys.clear();
+        self.chunks.clear();
+        self.bounds = PlotBounds::NOTHING;
+        self.epoch = Epoch(self.epoch.0 + 1);
+        self.epoch
+    }
+
+    /// The epoch of the last change.
+    #[inline]
+    pub fn epoch(&self) -> Epoch {
+        self.epoch
+    }
+
+    /// All the samples.
+    #[inline]
+    pub fn series(&self) -> ColumnarSeries<'_> {
+        ColumnarSeries::new(&self.xs, &self.ys)
+    }
+
+    /// The samples appended after `epoch`.
+    ///
+    /// If the series was [cleared](Self::clear) since, this is everything.
+    pub fn since(&self, epoch: Epoch) -> ColumnarSeries<'_> {
+        let start = self
+            .chunks
+            .iter()
+            .find(|(chunk_epoch, _)| *chunk_epoch > epoch)
+            .map_or(self.xs.len(), |(_, start)| *start);
+        self.series().slice(start..)
+    }
+
+    /// The bounds of all the finite samples.
+    #[inline]
+    pub fn bounds(&self) -> PlotBounds {
+        self.bounds
+    }
+
+    /// Number of samples.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    /// A line through the samples, using the bounds kept by the series.
+    pub fn line(&self, name: impl Into<String>) -> Line<'_> {
+        let mut line = Line::from_series(name, self.series());
+        line.known_bounds = Some(self.bounds);
+        line
+    }
+}
+
+#[test]
+fn test_streaming_series() {
+    let mut series = StreamingSeries::new();
+    let first = series.append_chunk(&[0.0, 1.0], &[5.0, f64::NAN]);
+    let second = series.append_chunk(&[2.0], &[-1.0]);
+    assert!(first < second);
+    assert_eq!(series.since(Epoch::default()).len(), 3);
+    assert_eq!(series.since(first).xs(), &[2.0]);
+    assert!(series.since(second).is_empty());
+    assert_eq!(series.bounds().min(), [0.0, -1.0]);
+    assert_eq!(series.bounds().max(), [2.0, 5.0]);
+
+    let cleared = series.clear();
+    assert!(cleared > second);
+    series.append_chunk(&[7.0], &[7.0]);
+    assert_eq!(series.since(second).xs(), &[7.0]);
+}
@@ -44,7 +44,7 @@ use egui::{
     TextStyle,
 };
 
-use crate::{PlotPoint, PlotUi, items::PlotGeometry};
+use crate::{PlotItem, PlotPoint, PlotTransform, PlotUi, items::PlotGeometry};
 
 /// One selected  anchor per series, found inside the vertical band.
 ///
@@ -220,175 +220,15 @@ impl PlotUi<'_> {
         if band_max_x <= band_min_x {
             return;
         }
-        let radius_px = options.radius_px;
 
         // Collect per-series closest point inside the band:
-        let mut hits: Vec<HitPoint> = Vec::new();
-        let pointer_plot = transform.value_from_position(pointer_screen);
-        let mut best_value_pointsxy: Option<PlotPoint> = None;
-
-        for item in self.actions.iter_items() {
-            if !item.allow_hover() {
-                continue;
-            }
-
-            let base_color = {
-                let c = item.color();
-                if c == Color32::TRANSPARENT {
-                    visuals.text_color()
-                } else {
-                    c
-                }
-            };
-
-            let (mut best_ix, mut best_dx, mut best_pos) = (None, f32::INFINITY, Pos2::ZERO);
-            let mut best_value_blocksxy: Option<PlotPoint> = None;
-            match item.geometry() {
-                PlotGeometry::Points(points) => {
-                    for (ix, v) in points.iter().enumerate() {
-                        let p = transform.position_from_point(v);
-                        let dx = (p.x - pointer_screen.x).abs();
-                        if dx <= radius_px && dx < best_dx {
-                            best_ix = Some(ix);
-                            best_dx = dx;
-                            best_pos = p;
-                        }
-                    }
-                }
-
-                PlotGeometry::PointsXY { xs, ys } => {
-                    let n = xs.len().min(ys.len());
-                    if n == 0 {
-                        // nothing
-                    } else if n == 1 {
-                        // single point
-                        let value = PlotPoint { x: xs[0], y: ys[0] };
-                        let p = transform.position_from_point(&value);
-                        let dx = (p.x - pointer_screen.x).abs();
-                        if dx <= radius_px && dx < best_dx {
-                            best_ix = Some(0);
-                            best_dx = dx;
-                            best_pos = p;
-                            best_value_pointsxy = Some(value);
-                        }
-                    } else {
-                        //
-                        if pointer_plot.x >= xs[0] && pointer_plot.x <= xs[n - 1] {
-                            let j = xs.partition_point(|x| *x < pointer_plot.x).clamp(1, n - 1);
-                            let i = j - 1;
-
-                            let (x0, y0) = (xs[i], ys[i]);
-                            let (x1, y1) = (xs[j], ys[j]);
-                            let t = if x1 > x0 {
-                                (pointer_plot.x - x0) / (x1 - x0)
-                            } else {
-                                0.0
-                            };
-                            let y = y0 + t * (y1 - y0);
-
-                            let value = PlotPoint {
-                                x: pointer_plot.x,
-                                y,
-                            };
-                            let py = transform.position_from_point(&value).y;
-                            let p = Pos2::new(pointer_screen.x, py);
-
-                            if best_dx > 0.0 || radius_px >= 0.0 {
-                                best_ix = Some(i);
-                                best_dx = 0.0;
-                                best_pos = p;
-                                best_value_pointsxy = Some(value);
-                            }
-                        }
-                    }
-                }
-
-                PlotGeometry::BlocksXY {
-                    xs_blocks,
-                    ys_blocks,
-                } => {
-                    let nb = xs_blocks.len().min(ys_blocks.len());
-                    for b in 0..nb {
-                        let xs = xs_blocks[b];
-                        let ys = ys_blocks[b];
-                        let n = xs.len().min(ys.len());
-                        if n < 2 {
-                            continue;
-                        }
-
-                        if pointer_plot.x < xs[0] || pointer_plot.x > xs[n - 1] {
-                            continue;
-                        }
-
-                        let j = xs.partition_point(|x| *x < pointer_plot.x).clamp(1, n - 1);
-                        let i = j - 1;
-
-                        let x0 = xs[i];
-                        let y0 = ys[i];
-                        let x1 = xs[j];
-                        let y1 = ys[j];
-                        let t = if x1 > x0 {
-                            (pointer_plot.x - x0) / (x1 - x0)
-                        } else {
-                            0.0
-                        };
-                        let y = y0 + t * (y1 - y0);
-
-                        let value = PlotPoint {
-                            x: pointer_plot.x,
-                            y,
-                        };
-
-                        let py = transform.position_from_point(&value).y;
-                        let p = Pos2::new(pointer_screen.x, py);
-
-                        let dx = 0.0;
-                        if dx <= radius_px && dx < best_dx {
-                            best_ix = Some(i);
-                            best_dx = dx;
-                            best_pos = p;
-                            best_value_blocksxy = Some(value);
-                        }
-                    }
-                }
-
-                PlotGeometry::Rects | PlotGeometry::None => {}
-            }
-
-            let value = match item.geometry() {
-                PlotGeometry::Points(points) => {
-                    let Some(ix) = best_ix else { continue };
-                    points[ix]
-                }
-                PlotGeometry::PointsXY { xs, ys } => {
-                    if let Some(v) = best_value_pointsxy {
-                        v
-                    } else {
-                        let Some(ix) = best_ix else { continue };
-                        PlotPoint {
-                            x: xs[ix],
-                            y: ys[ix],
-                        }
-                    }
-                }
-                PlotGeometry::BlocksXY { .. } => {
-                    if let Some(v) = best_value_blocksxy {
-                        v
-                    } else {
-                        continue;
-                    }
-                }
-                PlotGeometry::Rects | PlotGeometry::None => continue,
-            };
-
-            hits.push(HitPoint {
-                series_name: item.name().to_owned(),
-                color: base_color,
-                value,
-                screen_pos: best_pos,
-                screen_dx: best_dx,
-            });
-        }
+        let hits = band_hits(
+            self.actions.iter_items().map(|item| &**item),
+            &transform,
+            pointer_screen,
+            options.radius_px,
+            visuals.text_color(),
+        );
 
         if hits.is_empty() {
             if self.response.hovered() {
@@ -405,13 +245,6 @@ impl PlotUi<'_> {
             return;
         }
 
-        hits.sort_by(|a, b| {
-            a.screen_dx
-                .partial_cmp(&b.screen_dx)
-                .unwrap_or(std::cmp::Ordering::Equal)
-                .then_with(|| a.series_name.cmp(&b.series_name))
-        });
-
         if options.highlight_hovered_lines {
             let names: ahash::AHashSet<&str> =
                 hits.iter().map(|h| h.series_name.as_str()).collect();
@@ -478,6 +311,7 @@ impl PlotUi<'_> {
             self.response.id.with("band_tooltip"),
             egui::PopupAnchor::Pointer,
         );
+        tooltip = crate::PlotStyle::style_tooltip(&ctx, self.response.id, tooltip);
         let tooltip_width = ctx.style().spacing.tooltip_width;
         tooltip.popup = tooltip.popup.width(tooltip_width);
 
@@ -488,6 +322,193 @@ impl PlotUi<'_> {
     }
 }
 
+/// The closest point of each of the `items` to `pointer_screen` in a vertical band `radius_px`
+/// wide, closest first. Lines are interpolated at the pointer.
+///
+/// Items without a color get `text_color`.
+pub(crate) fn band_hits<'i, 'a: 'i>(
+    items: impl Iterator<Item = &'i (dyn PlotItem + 'a)>,
+    transform: &PlotTransform,
+    pointer_screen: Pos2,
+    radius_px: f32,
+    text_color: Color32,
+) -> Vec<HitPoint> {
+    let mut hits: Vec<HitPoint> = Vec::new();
+    let pointer_plot = transform.value_from_position(pointer_screen);
+    let mut best_value_pointsxy: Option<PlotPoint> = None;
+
+    for item in items {
+        if !item.allow_hover() {
+            continue;
+        }
+
+        let base_color = {
+            let c = item.color();
+            if c == Color32::TRANSPARENT {
+                text_color
+            } else {
+                c
+            }
+        };
+
+        let (mut best_ix, mut best_dx, mut best_pos) = (None, f32::INFINITY, Pos2::ZERO);
+        let mut best_value_blocksxy: Option<PlotPoint> = None;
+        match item.geometry() {
+            PlotGeometry::Points(points) => {
+                for (ix, v) in points.iter().enumerate() {
+                    let p = transform.position_from_point(v);
+                    let dx = (p.x - pointer_screen.x).abs();
+                    if dx <= radius_px && dx < best_dx {
+                        best_ix = Some(ix);
+                        best_dx = dx;
+                        best_pos = p;
+                    }
+                }
+            }
+
+            PlotGeometry::PointsXY { xs, ys } => {
+                let n = xs.len().min(ys.len());
+                if n == 0 {
+                    // nothing
+                } else if n == 1 {
+                    // single point
+                    let value = PlotPoint { x: xs[0], y: ys[0] };
+                    let p = transform.position_from_point(&value);
+                    let dx = (p.x - pointer_screen.x).abs();
+                    if dx <= radius_px && dx < best_dx {
+                        best_ix = Some(0);
+                        best_dx = dx;
+                        best_pos = p;
+                        best_value_pointsxy = Some(value);
+                    }
+                } else {
+                    //
+                    if pointer_plot.x >= xs[0] && pointer_plot.x <= xs[n - 1] {
+                        let j = xs.partition_point(|x| *x < pointer_plot.x).clamp(1, n - 1);
+                        let i = j - 1;
+
+                        let (x0, y0) = (xs[i], ys[i]);
+                        let (x1, y1) = (xs[j], ys[j]);
+                        let t = if x1 > x0 {
+                            (pointer_plot.x - x0) / (x1 - x0)
+                        } else {
+                            0.0
+                        };
+                        let y = y0 + t * (y1 - y0);
+
+                        let value = PlotPoint {
+                            x: pointer_plot.x,
+                            y,
+                        };
+                        let py = transform.position_from_point(&value).y;
+                        let p = Pos2::new(pointer_screen.x, py);
+
+                        if best_dx > 0.0 || radius_px >= 0.0 {
+                            best_ix = Some(i);
+                            best_dx = 0.0;
+                            best_pos = p;
+                            best_value_pointsxy = Some(value);
+                        }
+                    }
+                }
+            }
+
+            PlotGeometry::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => {
+                let nb = xs_blocks.len().min(ys_blocks.len());
+                for b in 0..nb {
+                    let xs = xs_blocks[b];
+                    let ys = ys_blocks[b];
+                    let n = xs.len().min(ys.len());
+                    if n < 2 {
+                        continue;
+                    }
+
+                    if pointer_plot.x < xs[0] || pointer_plot.x > xs[n - 1] {
+                        continue;
+                    }
+
+                    let j = xs.partition_point(|x| *x < pointer_plot.x).clamp(1, n - 1);
+                    let i = j - 1;
+
+                    let x0 = xs[i];
+                    let y0 = ys[i];
+                    let x1 = xs[j];
+                    let y1 = ys[j];
+                    let t = if x1 > x0 {
+                        (pointer_plot.x - x0) / (x1 - x0)
+                    } else {
+                        0.0
+                    };
+                    let y = y0 + t * (y1 - y0);
+
+                    let value = PlotPoint {
+                        x: pointer_plot.x,
+                        y,
+                    };
+
+                    let py = transform.position_from_point(&value).y;
+                    let p = Pos2::new(pointer_screen.x, py);
+
+                    let dx = 0.0;
+                    if dx <= radius_px && dx < best_dx {
+                        best_ix = Some(i);
+                        best_dx = dx;
+                        best_pos = p;
+                        best_value_blocksxy = Some(value);
+                    }
+                }
+            }
+
+            PlotGeometry::Rects | PlotGeometry::None => {}
+        }
+
+        let value = match item.geometry() {
+            PlotGeometry::Points(points) => {
+                let Some(ix) = best_ix else { continue };
+                points[ix]
+            }
+            PlotGeometry::PointsXY { xs, ys } => {
+                if let Some(v) = best_value_pointsxy {
+                    v
+                } else {
+                    let Some(ix) = best_ix else { continue };
+                    PlotPoint {
+                        x: xs[ix],
+                        y: ys[ix],
+                    }
+                }
+            }
+            PlotGeometry::BlocksXY { .. } => {
+                if let Some(v) = best_value_blocksxy {
+                    v
+                } else {
+                    continue;
+                }
+            }
+            PlotGeometry::Rects | PlotGeometry::None => continue,
+        };
+
+        hits.push(HitPoint {
+            series_name: item.name().to_owned(),
+            color: base_color,
+            value,
+            screen_pos: best_pos,
+            screen_dx: best_dx,
+        });
+    }
+
+    hits.sort_by(|a, b| {
+        a.screen_dx
+            .partial_cmp(&b.screen_dx)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| a.series_name.cmp(&b.series_name))
+    });
+    hits
+}
+
 /// Draws **all pin overlays**: a vertical rail per pin and markers at each pinned point.
 ///
 /// Pins are stored in plot-space; this function transforms them back to screen
@@ -1,4 +1,8 @@
-use std::ops::{Bound, RangeBounds, RangeInclusive};
+use std::{
+    any::Any,
+    ops::{Bound, RangeBounds, RangeInclusive},
+    sync::Arc,
+};
 
 use egui::{
     Pos2, Rect, Shape, Stroke, Vec2,
@@ -16,6 +20,7 @@ use crate::transform::PlotBounds;
     note = "PlotPoint is deprecated. Use ColumnarSeries<'a> and Line::from_series / Line::new_xy."
 )]
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct PlotPoint {
     /// This is often something monotonically increasing, such as time, but doesn't have to be.
     /// Goes from left to right.
@@ -440,6 +445,7 @@ impl<'a> PlotPoints<'a> {
 /// Circle, Diamond, Square, Cross, …
 /// see `<https://matplotlib.org/stable/api/markers_api.html>` for reference
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub enum MarkerShape {
     Circle,
     Diamond,
@@ -522,6 +528,30 @@ pub enum PlotGeometry<'a> {
     }, // todo: document this later
 }
 
+impl PlotGeometry<'_> {
+    /// All points in order, none for geometries without points.
+    pub(crate) fn points(&self) -> Vec<PlotPoint> {
+        match self {
+            Self::None | Self::Rects => Vec::new(),
+            Self::Points(points) => points.to_vec(),
+            Self::PointsXY { xs, ys } => xs
+                .iter()
+                .zip(ys.iter())
+                .map(|(&x, &y)| PlotPoint::new(x, y))
+                .collect(),
+            Self::BlocksXY {
+                xs_blocks,
+                ys_blocks,
+            } => xs_blocks
+                .iter()
+                .zip(ys_blocks)
+                .flat_map(|(xs, ys)| xs.iter().zip(ys.iter()))
+                .map(|(&x, &y)| PlotPoint::new(x, y))
+                .collect(),
+        }
+    }
+}
+
 // ----------------------------------------------------------------------------
 
 /// Describes a function y = f(x) with an optional range for x and a number of points.
@@ -584,3 +614,53 @@ pub struct ClosestElem {
     /// Squared distance from the mouse cursor (needed to compare against other `PlotItems`, which might be nearer)
     pub dist_sq: f32,
 }
+
+/// An item near a screen position, found by [`crate::PlotUi::pick`] or hovered, see
+/// [`crate::Line::on_hover`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct PickHit {
+    /// The id of the item, see [`super::PlotItem::id`].
+    pub item: egui::Id,
+
+    /// The name of the item.
+    pub name: String,
+
+    /// The closest point (or bar/box/…) of the item to the position.
+    pub index: usize,
+
+    /// Distance in points from the position to that element.
+    pub distance: f32,
+
+    /// The payload attached to that element, see [`crate::Scatter::with_metadata`].
+    pub metadata: Option<PointMetadata>,
+}
+
+/// A payload attached to a point, like the key of its database record, see
+/// [`crate::Scatter::with_metadata`].
+///
+/// Two payloads are equal if they are the same value, not equal copies.
+#[derive(Clone)]
+pub struct PointMetadata(Arc<dyn Any + Send + Sync>);
+
+impl PointMetadata {
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+
+    /// The payload, if it is a `T`.
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref()
+    }
+}
+
+impl std::fmt::Debug for PointMetadata {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PointMetadata(..)")
+    }
+}
+
+impl PartialEq for PointMetadata {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
@@ -0,0 +1,507 @@
+//! Which buttons, modifiers and keys control a plot, see [`PlotInputConfig`] and [`PlotKeymap`].
+
+use egui::{
+    Event, Id, InputState, Key, KeyboardShortcut, Modifiers, PointerButton, Vec2, Vec2b, vec2,
+};
+
+use crate::{PlotBounds, PlotItem};
+
+/// What double-clicking the plot does, see [`PlotInputConfig::double_click`].
+///
+/// Except for [`Self::None`], double-clicks are also reported as
+/// [`PlotEvent::DoubleClicked`](crate::PlotEvent::DoubleClicked), with the item under the
+/// pointer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DoubleClickAction {
+    /// Nothing.
+    None,
+
+    /// Only report the double-click, e.g. to isolate the trace that was double-clicked.
+    EmitOnly,
+
+    /// Go back to automatic bounds on both axes.
+    ResetView,
+
+    /// Go back to automatic bounds on the x axis only, keeping the y range.
+    FitX,
+
+    /// Go back to automatic bounds on the y axis only, keeping the x range.
+    FitY,
+
+    /// Zoom in two times around the pointer, on the axes that allow zooming.
+    ZoomIn,
+
+    /// Fit the view to the item under the pointer, or go back to automatic bounds if there is
+    /// none.
+    FitItem,
+}
+
+impl DoubleClickAction {
+    /// The axes to go back to automatic bounds on.
+    pub(crate) fn auto_bounds(self) -> Vec2b {
+        match self {
+            Self::None | Self::EmitOnly | Self::ZoomIn | Self::FitItem => Vec2b::FALSE,
+            Self::ResetView => Vec2b::TRUE,
+            Self::FitX => Vec2b::new(true, false),
+            Self::FitY => Vec2b::new(false, true),
+        }
+    }
+}
+
+/// What the scroll wheel does over the plot, see [`PlotInputConfig::scroll`].
+///
+/// Pinching on a touch screen zooms in all of them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ScrollBehavior {
+    /// The wheel pans vertically, and horizontally while holding shift. It zooms at the pointer
+    /// while holding ctrl (⌘ on Mac), see [`egui::InputOptions::zoom_modifier`].
+    #[default]
+    Pan,
+
+    /// The wheel zooms at the pointer, without holding anything.
+    Zoom,
+
+    /// The plot ignores the wheel, so it scrolls an enclosing [`egui::ScrollArea`] instead.
+    Disabled,
+}
+
+/// When the plot takes drags and the scroll wheel, see [`PlotInputConfig::capture`].
+///
+/// Until then they go to an enclosing [`egui::ScrollArea`], so the page can be scrolled past
+/// the plot. Clicks, hovering and the keyboard work either way.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InputCapture {
+    /// Always.
+    #[default]
+    Always,
+
+    /// While holding these modifiers, e.g. [`Modifiers::COMMAND`].
+    Modifiers(Modifiers),
+
+    /// While the plot has keyboard focus, after clicking it.
+    Focused,
+}
+
+impl InputCapture {
+    /// Whether the plot takes drags and the scroll wheel this frame.
+    pub(crate) fn captures(self, modifiers: Modifiers, has_focus: bool) -> bool {
+        match self {
+            Self::Always => true,
+            Self::Modifiers(required) => modifiers.contains(required),
+            Self::Focused => has_focus,
+        }
+    }
+}
+
+/// The buttons, modifiers and keys of all the interactions with a plot, see
+/// [`crate::Plot::input_config`].
+///
+/// Whether an interaction is allowed at all is still set on the plot, with
+/// [`crate::Plot::allow_drag`], [`crate::Plot::allow_boxed_zoom`] and so on.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotInputConfig {
+    /// Drag with this button to pan, and to move editable points, lines and spans.
+    /// Default: [`PointerButton::Primary`].
+    pub pan_button: PointerButton,
+
+    /// Drag out a box with this button to zoom into it. Default: [`PointerButton::Secondary`].
+    pub box_zoom_button: PointerButton,
+
+    /// Modifiers to hold when starting a box zoom, so it can share its button with panning.
+    /// Default: [`Modifiers::NONE`].
+    pub box_zoom_modifiers: Modifiers,
+
+    /// Drag an axis with this button to zoom along it, see [`crate::Plot::allow_axis_zoom_drag`].
+    /// Default: [`PointerButton::Primary`].
+    pub axis_zoom_button: PointerButton,
+
+    /// Pan only horizontally while holding any of these. Default: [`Modifiers::NONE`].
+    pub horizontal_pan_modifier: Modifiers,
+
+    /// Pan only vertically while holding any of these. Default: [`Modifiers::NONE`].
+    pub vertical_pan_modifier: Modifiers,
+
+    /// Zoom only horizontally while holding any of these. Default: [`Modifiers::SHIFT`].
+    ///
+    /// The modifier to zoom with the scroll wheel at all is set by egui, see
+    /// [`egui::InputOptions::zoom_modifier`].
+    pub horizontal_zoom_modifier: Modifiers,
+
+    /// Zoom only vertically while holding any of these. Default: [`Modifiers::ALT`].
+    pub vertical_zoom_modifier: Modifiers,
+
+    /// Zoom and pan only horizontally while holding this key. Default: [`Key::X`].
+    ///
+    /// To never change the y range, use [`crate::Plot::allow_zoom`] and
+    /// [`crate::Plot::allow_drag`] instead.
+    pub x_only_key: Option<Key>,
+
+    /// Zoom and pan only vertically while holding this key. Default: [`Key::Y`].
+    pub y_only_key: Option<Key>,
+
+    /// Default: [`DoubleClickAction::ResetView`].
+    pub double_click: DoubleClickAction,
+
+    /// Default: [`ScrollBehavior::Pan`].
+    pub scroll: ScrollBehavior,
+
+    /// Default: [`InputCapture::Always`].
+    pub capture: InputCapture,
+
+    /// Keys to navigate the plot with while it has keyboard focus.
+    pub keymap: PlotKeymap,
+}
+
+impl Default for PlotInputConfig {
+    fn default() -> Self {
+        Self {
+            pan_button: PointerButton::Primary,
+            box_zoom_button: PointerButton::Secondary,
+            box_zoom_modifiers: Modifiers::NONE,
+            axis_zoom_button: PointerButton::Primary,
+            horizontal_pan_modifier: Modifiers::NONE,
+            vertical_pan_modifier: Modifiers::NONE,
+            horizontal_zoom_modifier: Modifiers::SHIFT,
+            vertical_zoom_modifier: Modifiers::ALT,
+            x_only_key: Some(Key::X),
+            y_only_key: Some(Key::Y),
+            double_click: DoubleClickAction::ResetView,
+            scroll: ScrollBehavior::Pan,
+            capture: InputCapture::Always,
+            keymap: PlotKeymap::default(),
+        }
+    }
+}
+
+impl PlotInputConfig {
+    /// The axes a zoom may change this frame, given the held modifiers and keys.
+    pub(crate) fn zoom_axes(&self, input: &InputState) -> Vec2b {
+        self.unlocked_axes(
+            input,
+            self.horizontal_zoom_modifier,
+            self.vertical_zoom_modifier,
+        )
+    }
+
+    /// The axes a pan may change this frame, given the held modifiers and keys.
+    pub(crate) fn pan_axes(&self, input: &InputState) -> Vec2b {
+        self.unlocked_axes(
+            input,
+            self.horizontal_pan_modifier,
+            self.vertical_pan_modifier,
+        )
+    }
+
+    fn unlocked_axes(
+        &self,
+        input: &InputState,
+        horizontal: Modifiers,
+        vertical: Modifiers,
+    ) -> Vec2b {
+        let held = |key: Option<Key>| key.is_some_and(|key| input.key_down(key));
+        let only_x = held(self.x_only_key) || input.modifiers.matches_any(horizontal);
+        let only_y = held(self.y_only_key) || input.modifiers.matches_any(vertical);
+        if only_x == only_y {
+            Vec2b::TRUE
+        } else {
+            Vec2b::new(only_x, only_y)
+        }
+    }
+
+    /// The zoom factor of the scroll wheel or pinch this frame, alike on both axes.
+    pub(crate) fn uniform_zoom_delta(&self, input: &InputState) -> f32 {
+        match self.scroll {
+            ScrollBehavior::Pan => input.zoom_delta(),
+            ScrollBehavior::Zoom => {
+                let wheel = input.smooth_scroll_delta.x + input.smooth_scroll_delta.y;
+                input.zoom_delta() * (wheel / 200.0).exp()
+            }
+            ScrollBehavior::Disabled => {
+                if input.multi_touch().is_some() {
+                    input.zoom_delta()
+                } else {
+                    1.0
+                }
+            }
+        }
+    }
+
+    /// The scroll this frame that pans the plot, in screen points.
+    pub(crate) fn scroll_pan(&self, input: &InputState) -> Vec2 {
+        match self.scroll {
+            ScrollBehavior::Pan => input.smooth_scroll_delta,
+            ScrollBehavior::Zoom | ScrollBehavior::Disabled => Vec2::ZERO,
+        }
+    }
+
+    /// The zoom factor of the scroll wheel or pinch this frame, per axis.
+    pub(crate) fn zoom_delta(&self, input: &InputState) -> Vec2 {
+        let mut zoom = if input.multi_touch().is_some() {
+            input.zoom_delta_2d()
+        } else {
+            Vec2::splat(self.uniform_zoom_delta(input))
+        };
+        let axes = self.zoom_axes(input);
+        if !axes.x {
+            zoom.x = 1.0;
+        }
+        if !axes.y {
+            zoom.y = 1.0;
+        }
+        zoom
+    }
+}
+
+/// Keys to navigate a plot with while it has keyboard focus, see [`crate::Plot::keymap`] and
+/// [`PlotInputConfig::keymap`].
+///
+/// The plot gets the focus when it is clicked, or with the tab key. Set a key to `None` to
+/// disable its action, or use [`Self::disabled`] to turn off keyboard navigation.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlotKeymap {
+    /// Show more of the plot to the left. Default: [`Key::ArrowLeft`].
+    pub pan_left: Option<Key>,
+
+    /// Default: [`Key::ArrowRight`].
+    pub pan_right: Option<Key>,
+
+    /// Default: [`Key::ArrowUp`].
+    pub pan_up: Option<Key>,
+
+    /// Default: [`Key::ArrowDown`].
+    pub pan_down: Option<Key>,
+
+    /// Zoom in around the center. Default: [`Key::Plus`].
+    pub zoom_in: Option<Key>,
+
+    /// Default: [`Key::Minus`].
+    pub zoom_out: Option<Key>,
+
+    /// Move to the next data point of the focused series, with the tooltip following.
+    ///
+    /// The focused series is the last hovered one, or else the first one with points.
+    /// Moving the pointer over the plot goes back to the tooltip at the pointer.
+    /// Default: [`Key::PageDown`].
+    pub next_point: Option<Key>,
+
+    /// Default: [`Key::PageUp`].
+    pub previous_point: Option<Key>,
+
+    /// Copy the x of the cursor and the y of each series there, like the "Copy values at cursor"
+    /// entry of the context menu, in the [`crate::Plot::copy_format`].
+    /// Default: [`Modifiers::COMMAND`] + [`Key::C`].
+    ///
+    /// Integrations like `eframe` send this default as an [`Event::Copy`] rather than a key
+    /// press, which copies the values too.
+    pub copy_values: Option<KeyboardShortcut>,
+
+    /// Go back to the view before the last pan or zoom, like [`crate::PlotUi::undo_view`].
+    /// Default: [`Key::OpenBracket`].
+    pub undo_view: Option<Key>,
+
+    /// Go forward to the view undone last, like [`crate::PlotUi::redo_view`].
+    /// Default: [`Key::CloseBracket`].
+    pub redo_view: Option<Key>,
+
+    /// How far to pan per key press, as a fraction of the plot size. Default: `0.1`.
+    pub pan_fraction: f32,
+
+    /// How much to zoom in per key press. Default: `1.25`.
+    pub zoom_factor: f32,
+}
+
+impl Default for PlotKeymap {
+    fn default() -> Self {
+        Self {
+            pan_left: Some(Key::ArrowLeft),
+            pan_right: Some(Key::ArrowRight),
+            pan_up: Some(Key::ArrowUp),
+            pan_down: Some(Key::ArrowDown),
+            zoom_in: Some(Key::Plus),
+            zoom_out: Some(Key::Minus),
+            next_point: Some(Key::PageDown),
+            previous_point: Some(Key::PageUp),
+            copy_values: Some(KeyboardShortcut::new(Modifiers::COMMAND, Key::C)),
+            undo_view: Some(Key::OpenBracket),
+            redo_view: Some(Key::CloseBracket),
+            pan_fraction: 0.1,
+            zoom_factor: 1.25,
+        }
+    }
+}
+
+impl PlotKeymap {
+    /// No keyboard navigation.
+    pub fn disabled() -> Self {
+        Self {
+            pan_left: None,
+            pan_right: None,
+            pan_up: None,
+            pan_down: None,
+            zoom_in: None,
+            zoom_out: None,
+            next_point: None,
+            previous_point: None,
+            copy_values: None,
+            undo_view: None,
+            redo_view: None,
+            ..Default::default()
+        }
+    }
+
+    /// Whether the arrow keys are used, so they shouldn't move the focus away from the plot.
+    pub(crate) fn uses_arrows(&self) -> bool {
+        [self.pan_left, self.pan_right, self.pan_up, self.pan_down]
+            .iter()
+            .flatten()
+            .any(|key| {
+                matches!(
+                    key,
+                    Key::ArrowLeft | Key::ArrowRight | Key::ArrowUp | Key::ArrowDown
+                )
+            })
+    }
+
+    /// The pan in screen points for the keys pressed this frame, for a plot of `size`.
+    pub(crate) fn pan(&self, input: &InputState, size: Vec2) -> Vec2 {
+        let step = size * self.pan_fraction;
+        let count = |key: Option<Key>| key.map_or(0.0, |key| input.num_presses(key) as f32);
+        vec2(
+            (count(self.pan_right) - count(self.pan_left)) * step.x,
+            // Up shows larger values, which are higher on the screen:
+            (count(self.pan_down) - count(self.pan_up)) * step.y,
+        )
+    }
+
+    /// The zoom factor for the keys pressed this frame, above 1 to zoom in.
+    pub(crate) fn zoom(&self, input: &InputState) -> f32 {
+        let count = |key: Option<Key>| key.map_or(0, |key| input.num_presses(key) as i32);
+        self.zoom_factor
+            .powi(count(self.zoom_in) - count(self.zoom_out))
+    }
+
+    /// How many points to step forward (or back, if negative) this frame.
+    pub(crate) fn step(&self, input: &InputState) -> isize {
+        let count = |key: Option<Key>| key.map_or(0, |key| input.num_presses(key) as isize);
+        count(self.next_point) - count(self.previous_point)
+    }
+
+    /// How many views to go back (or forward, if negative) this frame.
+    pub(crate) fn view_steps(&self, input: &InputState) -> isize {
+        let count = |key: Option<Key>| key.map_or(0, |key| input.num_presses(key) as isize);
+        count(self.undo_view) - count(self.redo_view)
+    }
+
+    /// Whether [`Self::copy_values`] was pressed this frame.
+    pub(crate) fn copy_pressed(&self, input: &mut InputState) -> bool {
+        let Some(shortcut) = self.copy_values else {
+            return false;
+        };
+        let copy_event = shortcut == KeyboardShortcut::new(Modifiers::COMMAND, Key::C)
+            && input
+                .events
+                .iter()
+                .any(|event| matches!(event, Event::Copy));
+        copy_event || input.consume_shortcut(&shortcut)
+    }
+}
+
+/// The point `step` points after `current` in the focused series. A new series starts at the
+/// first visible point when stepping forward, at the last one when stepping back.
+pub(crate) fn step_point(
+    items: &[Box<dyn PlotItem + '_>],
+    current: Option<(Id, usize)>,
+    last_hovered: Option<Id>,
+    step: isize,
+    bounds: &PlotBounds,
+) -> Option<(Id, usize)> {
+    let has_points = |item: &&dyn PlotItem| !item.geometry().points().is_empty();
+    let mut items = items.iter().map(|item| &**item);
+    let item = current
+        .map(|(id, _)| id)
+        .or(last_hovered)
+        .and_then(|id| items.clone().find(|item| item.id() == id))
+        .filter(has_points)
+        .or_else(|| items.find(has_points))?;
+    let points = item.geometry().points();
+
+    let index = match current {
+        Some((id, index)) if id == item.id() => {
+            index.saturating_add_signed(step).min(points.len() - 1)
+        }
+        _ => {
+            let visible = |point: &crate::PlotPoint| bounds.range_x().contains(&point.x);
+            if step > 0 {
+                points.iter().position(visible).unwrap_or(0)
+            } else {
+                points.iter().rposition(visible).unwrap_or(points.len() - 1)
+            }
+        }
+    };
+    Some((item.id(), index))
+}
+
+#[test]
+fn test_step_point() {
+    let line = |name: &str| -> Box<dyn PlotItem> {
+        Box::new(crate::Line::new(
+            name,
+            vec![[0.0, 0.0], [1.0, 1.0], [2.0, 4.0], [3.0, 9.0]],
+        ))
+    };
+    let items = [line("a"), line("b")];
+    let (a, b) = (items[0].id(), items[1].id());
+    let bounds = PlotBounds::from_min_max([0.5, 0.0], [2.5, 10.0]);
+
+    // Start at the first visible point of the last hovered series, or the first series:
+    assert_eq!(step_point(&items, None, Some(b), 1, &bounds), Some((b, 1)));
+    assert_eq!(step_point(&items, None, None, -1, &bounds), Some((a, 2)));
+
+    // Then step, up to the ends:
+    assert_eq!(
+        step_point(&items, Some((b, 1)), None, 1, &bounds),
+        Some((b, 2))
+    );
+    assert_eq!(
+        step_point(&items, Some((b, 2)), None, 5, &bounds),
+        Some((b, 3))
+    );
+    assert_eq!(
+        step_point(&items, Some((b, 1)), None, -5, &bounds),
+        Some((b, 0))
+    );
+}
+
+#[test]
+fn test_scroll_behavior() {
+    let mut input = InputState::default();
+    input.smooth_scroll_delta = vec2(0.0, 20.0);
+    let config = |scroll| PlotInputConfig {
+        scroll,
+        ..Default::default()
+    };
+
+    let pan = config(ScrollBehavior::Pan);
+    assert_eq!(pan.scroll_pan(&input), vec2(0.0, 20.0));
+    assert_eq!(pan.uniform_zoom_delta(&input), 1.0);
+
+    let zoom = config(ScrollBehavior::Zoom);
+    assert_eq!(zoom.scroll_pan(&input), Vec2::ZERO);
+    assert!(zoom.uniform_zoom_delta(&input) > 1.0);
+
+    let disabled = config(ScrollBehavior::Disabled);
+    assert_eq!(disabled.scroll_pan(&input), Vec2::ZERO);
+    assert_eq!(disabled.uniform_zoom_delta(&input), 1.0);
+}
+
+#[test]
+fn test_input_capture() {
+    assert!(InputCapture::Always.captures(Modifiers::NONE, false));
+
+    let ctrl = InputCapture::Modifiers(Modifiers::CTRL);
+    assert!(!ctrl.captures(Modifiers::NONE, true));
+    assert!(ctrl.captures(Modifiers::CTRL | Modifiers::SHIFT, false));
+
+    assert!(!InputCapture::Focused.captures(Modifiers::CTRL, false));
+    assert!(InputCapture::Focused.captures(Modifiers::NONE, true));
+}
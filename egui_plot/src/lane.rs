@@ -0,0 +1,149 @@
+//! A strip next to the plot area showing discrete events (e.g. log lines) on the shared x-axis.
+
+use egui::{Color32, Id, Rect, Response, Sense, Shape, Stroke, Ui, pos2};
+
+use crate::{PlotTransform, VPlacement};
+
+/// How close (in points) the pointer must be to an event to show its details.
+const HOVER_DISTANCE: f32 = 4.0;
+
+/// Space between the lane and the rest of the plot.
+pub(crate) const LANE_GAP: f32 = 4.0;
+
+/// Severity of a [`LaneEvent`], which determines its color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Debug,
+    Info,
+    Warning,
+    Error,
+    Critical,
+}
+
+impl Severity {
+    /// The color events of this severity are drawn with.
+    pub fn color(self) -> Color32 {
+        match self {
+            Self::Debug => Color32::GRAY,
+            Self::Info => Color32::from_rgb(80, 160, 255),
+            Self::Warning => Color32::from_rgb(255, 190, 40),
+            Self::Error => Color32::from_rgb(240, 70, 60),
+            Self::Critical => Color32::from_rgb(200, 40, 200),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Debug => "Debug",
+            Self::Info => "Info",
+            Self::Warning => "Warning",
+            Self::Error => "Error",
+            Self::Critical => "Critical",
+        }
+    }
+}
+
+/// A single event in an [`EventLane`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct LaneEvent {
+    pub x: f64,
+    pub severity: Severity,
+    pub message: String,
+}
+
+impl LaneEvent {
+    /// An event at `x` with a message shown on hover.
+    pub fn new(x: f64, severity: Severity, message: impl Into<String>) -> Self {
+        Self {
+            x,
+            severity,
+            message: message.into(),
+        }
+    }
+}
+
+/// A strip above or below the plot area with discrete events at x positions, colored by
+/// [`Severity`]. Hovering an event shows its message.
+///
+/// The lane shares the x-axis of the plot, so events line up with the plotted signals while
+/// panning and zooming. Add it with [`crate::Plot::event_lane`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct EventLane {
+    pub(crate) events: Vec<LaneEvent>,
+    pub(crate) placement: VPlacement,
+    pub(crate) height: f32,
+}
+
+impl EventLane {
+    /// A lane showing the given events.
+    pub fn new(events: Vec<LaneEvent>) -> Self {
+        Self {
+            events,
+            placement: VPlacement::Top,
+            height: 16.0,
+        }
+    }
+
+    /// Whether to show the lane above or below the plot area. Default: `VPlacement::Top`.
+    #[inline]
+    pub fn placement(mut self, placement: VPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Height of the lane in points. Default: `16.0`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height.max(1.0);
+        self
+    }
+
+    /// Paint the lane into `rect`, whose x-range must match the plot frame.
+    pub(crate) fn ui(&self, ui: &Ui, id: Id, rect: Rect, transform: &PlotTransform) -> Response {
+        let response = ui.interact(rect, id, Sense::hover());
+        let painter = ui.painter().with_clip_rect(rect);
+        painter.rect(
+            rect,
+            2,
+            ui.visuals().extreme_bg_color,
+            ui.visuals().widgets.noninteractive.bg_stroke,
+            egui::StrokeKind::Inside,
+        );
+
+        let hovered = response.hover_pos().and_then(|pointer| {
+            self.events
+                .iter()
+                .map(|event| {
+                    (
+                        event,
+                        (transform.position_from_point_x(event.x) - pointer.x).abs(),
+                    )
+                })
+                .filter(|(_, distance)| *distance <= HOVER_DISTANCE)
+                .min_by(|(a, da), (b, db)| da.total_cmp(db).then(b.severity.cmp(&a.severity)))
+                .map(|(event, _)| event)
+        });
+
+        // Draw more severe events on top:
+        let mut order: Vec<&LaneEvent> = self.events.iter().filter(|e| e.x.is_finite()).collect();
+        order.sort_by_key(|event| event.severity);
+        let shapes = order.into_iter().map(|event| {
+            let x = transform.position_from_point_x(event.x);
+            let width = if hovered == Some(event) { 4.0 } else { 2.0 };
+            Shape::line_segment(
+                [pos2(x, rect.top() + 2.0), pos2(x, rect.bottom() - 2.0)],
+                Stroke::new(width, event.severity.color()),
+            )
+        });
+        painter.extend(shapes);
+
+        if let Some(event) = hovered {
+            response.clone().on_hover_ui_at_pointer(|ui| {
+                ui.colored_label(event.severity.color(), event.severity.name());
+                ui.label(format!("x = {}", crate::format_number(event.x, 3)));
+                ui.label(&event.message);
+            });
+        }
+        response
+    }
+}
@@ -1,11 +1,13 @@
 use std::{collections::BTreeMap, string::String};
 
 use egui::{
-    Align, Color32, Direction, Frame, Id, Layout, PointerButton, Rect, Response, Sense, Shadow,
-    Shape, TextStyle, Ui, Widget, WidgetInfo, WidgetType, epaint::CircleShape, pos2, vec2,
+    Align, Color32, ComboBox, Direction, Frame, Grid, Id, Layout, PointerButton, Rect, Response,
+    ScrollArea, Sense, Shadow, Shape, Slider, Stroke, StrokeKind, TextStyle, Ui, Vec2, Widget,
+    WidgetInfo, WidgetType, epaint::CircleShape, pos2, vec2,
 };
 
-use super::items::PlotItem;
+use super::items::{LineStyle, MarkerShape, PlotGeometry, PlotItem, PlotPoint, push_marker};
+use crate::{PlotTransform, Unit, ValueFormat};
 
 /// Where to place the plot legend.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +32,143 @@ impl Corner {
     }
 }
 
+/// Whether the legend is drawn on top of the plot or next to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum LegendPlacement {
+    /// Inside the plot frame, in the corner given by [`Legend::position`].
+    Inside,
+
+    /// In a panel to the right of the plot frame.
+    Right,
+
+    /// In a panel below the plot frame, with as many columns as fit the width.
+    Bottom,
+}
+
+/// The symbol drawn next to the name of an item in the legend.
+///
+/// Items pick the glyph that matches how they are drawn, see [`PlotItem::legend_glyph`]. It is
+/// only shown with [`Legend::item_glyphs`], otherwise all items get a [`LegendGlyph::Dot`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LegendGlyph {
+    /// A filled dot in the color of the item.
+    Dot,
+
+    /// A short line segment in the style of the item, with an optional marker in its middle.
+    Line {
+        style: LineStyle,
+        marker: Option<MarkerShape>,
+    },
+
+    /// A single marker.
+    Marker { shape: MarkerShape, filled: bool },
+
+    /// A filled square, for items covering an area.
+    Area,
+}
+
+/// The user-editable style of an item, see [`Legend::style_editing`].
+///
+/// Fields that are `None` can't be changed for that kind of item.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct SeriesStyle {
+    /// The name shown in the legend.
+    pub name: String,
+
+    pub color: Option<Color32>,
+
+    /// Line width, or marker radius for items made of markers.
+    pub width: Option<f32>,
+
+    pub marker: Option<MarkerShape>,
+}
+
+/// Which statistics of the visible part of each item to show in the legend, see
+/// [`Legend::show_stats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct LegendStats {
+    pub min: bool,
+    pub max: bool,
+    pub mean: bool,
+
+    /// The last visible value.
+    pub last: bool,
+
+    /// Significant digits of the values. Default: `3`.
+    pub digits: usize,
+}
+
+impl Default for LegendStats {
+    fn default() -> Self {
+        Self {
+            min: true,
+            max: true,
+            mean: true,
+            last: true,
+            digits: 3,
+        }
+    }
+}
+
+impl LegendStats {
+    fn format(&self, stats: &SeriesStats, unit: Option<&Unit>) -> String {
+        let values = [
+            (self.min, "min", stats.min),
+            (self.max, "max", stats.max),
+            (self.mean, "mean", stats.mean),
+            (self.last, "last", stats.last),
+        ];
+        values
+            .iter()
+            .filter(|(show, _, _)| *show)
+            .map(|(_, name, value)| {
+                let value = unit.map_or(*value, |unit| unit.convert(*value));
+                format!("{name} {}", crate::format_number(value, self.digits))
+            })
+            .collect::<Vec<_>>()
+            .join("  ")
+    }
+}
+
+/// Statistics of the points of an item within the visible x range, in the unit of the data.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct SeriesStats {
+    min: f64,
+    max: f64,
+    mean: f64,
+    last: f64,
+}
+
+impl SeriesStats {
+    /// `None` if none of the points are visible.
+    pub(crate) fn of_visible(
+        geometry: &PlotGeometry<'_>,
+        x_range: &std::ops::RangeInclusive<f64>,
+    ) -> Option<Self> {
+        let mut points = geometry_points(geometry)?.filter(|(x, _)| x_range.contains(x));
+        let (_, first) = points.next()?;
+        let mut stats = Self {
+            min: first,
+            max: first,
+            mean: first,
+            last: first,
+        };
+        let mut count = 1.0;
+        for (_, y) in points {
+            stats.min = stats.min.min(y);
+            stats.max = stats.max.max(y);
+            stats.mean += y;
+            stats.last = y;
+            count += 1.0;
+        }
+        stats.mean /= count;
+        Some(stats)
+    }
+}
+
 /// How to handle multiple conflicting color for a legend item.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -46,8 +185,17 @@ pub struct Legend {
     pub text_style: TextStyle,
     pub background_alpha: f32,
     pub position: Corner,
+    pub placement: LegendPlacement,
     pub title: Option<String>,
 
+    max_columns: usize,
+    show_values: bool,
+    stats: Option<LegendStats>,
+    pub(crate) hover_highlight: bool,
+    pub(crate) smart_corner: bool,
+    style_editing: bool,
+    item_glyphs: bool,
+
     follow_insertion_order: bool,
     color_conflict_handling: ColorConflictHandling,
 
@@ -61,7 +209,15 @@ impl Default for Legend {
             text_style: TextStyle::Body,
             background_alpha: 0.75,
             position: Corner::RightTop,
+            placement: LegendPlacement::Inside,
             title: None,
+            max_columns: 3,
+            show_values: false,
+            stats: None,
+            hover_highlight: false,
+            smart_corner: false,
+            style_editing: false,
+            item_glyphs: false,
             follow_insertion_order: false,
             color_conflict_handling: ColorConflictHandling::RemoveColor,
             hidden_items: None,
@@ -85,12 +241,97 @@ impl Legend {
     }
 
     /// In which corner to place the legend. Default: `Corner::RightTop`.
+    ///
+    /// Only used with [`LegendPlacement::Inside`]; see [`Self::placement`] to move the legend
+    /// out of the plot frame.
     #[inline]
     pub fn position(mut self, corner: Corner) -> Self {
         self.position = corner;
         self
     }
 
+    /// Move the legend to the corner with the least data underneath it. Default: `false`.
+    ///
+    /// The corner is picked again whenever the bounds come to rest, so the legend doesn't jump
+    /// around while panning or zooming. [`Self::position`] wins ties.
+    #[inline]
+    pub fn smart_corner(mut self, enabled: bool) -> Self {
+        self.smart_corner = enabled;
+        self
+    }
+
+    /// Whether to draw the legend inside the plot frame or in a panel next to it.
+    /// Default: `LegendPlacement::Inside`.
+    ///
+    /// Outside legends wrap into several columns and scroll when there are many entries, so they
+    /// never cover the data.
+    #[inline]
+    pub fn placement(mut self, placement: LegendPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Maximum number of columns of a [`LegendPlacement::Right`] legend before it starts
+    /// scrolling. Default: `3`.
+    #[inline]
+    pub fn max_columns(mut self, max_columns: usize) -> Self {
+        self.max_columns = max_columns.max(1);
+        self
+    }
+
+    /// Show the value of each item next to its name. Default: `false`.
+    ///
+    /// While the plot is hovered this is the value closest to the pointer on the x axis,
+    /// otherwise the latest value of the item.
+    #[inline]
+    pub fn show_values(mut self, show: bool) -> Self {
+        self.show_values = show;
+        self
+    }
+
+    /// Show statistics of the visible part of each item next to its name. Default: `None`.
+    ///
+    /// The statistics are only updated once the bounds have settled, so they don't flicker
+    /// while panning or zooming.
+    #[inline]
+    pub fn show_stats(mut self, stats: impl Into<Option<LegendStats>>) -> Self {
+        self.stats = stats.into();
+        self
+    }
+
+    /// Dim all other items while an entry of the legend is hovered. Default: `false`.
+    ///
+    /// The hovered item is always highlighted, this makes it easier to find among many
+    /// overlapping lines.
+    #[inline]
+    pub fn hover_highlight(mut self, enabled: bool) -> Self {
+        self.hover_highlight = enabled;
+        self
+    }
+
+    /// Let the user change the name, color, width and marker of an item from the context menu
+    /// of its legend entry. Default: `false`.
+    ///
+    /// The changes are kept in the plot memory and reported as
+    /// [`PlotEvent::SeriesStyleChanged`](crate::PlotEvent::SeriesStyleChanged), so they can be
+    /// persisted by the application.
+    #[inline]
+    pub fn style_editing(mut self, enabled: bool) -> Self {
+        self.style_editing = enabled;
+        self
+    }
+
+    /// Draw each entry with the glyph of its item, like a short line in the style of a
+    /// [`Line`](crate::Line) or a marker of a [`Scatter`](crate::Scatter), instead of a dot.
+    /// Default: `false`.
+    ///
+    /// See [`PlotItem::legend_glyph`] for the glyph of each item.
+    #[inline]
+    pub fn item_glyphs(mut self, enabled: bool) -> Self {
+        self.item_glyphs = enabled;
+        self
+    }
+
     /// Set the title of the legend. Default: `None`.
     #[inline]
     pub fn title(mut self, title: &str) -> Self {
@@ -135,33 +376,64 @@ struct LegendEntry {
     id: Id,
     name: String,
     color: Color32,
+    glyph: LegendGlyph,
+    value: Option<String>,
+    stats: Option<String>,
     checked: bool,
     hovered: bool,
+
+    /// The style of the item if it can be edited, and whether it was edited this frame.
+    style: Option<SeriesStyle>,
+    style_edited: bool,
 }
 
 impl LegendEntry {
-    fn new(id: Id, name: String, color: Color32, checked: bool) -> Self {
+    fn new(id: Id, name: String, color: Color32, glyph: LegendGlyph, checked: bool) -> Self {
         Self {
             id,
             name,
             color,
+            glyph,
+            value: None,
+            stats: None,
             checked,
             hovered: false,
+            style: None,
+            style_edited: false,
         }
     }
 
+    /// The name, followed by the value readout and the statistics if there are any.
+    fn label(&self) -> String {
+        let mut label = self.name.clone();
+        if let Some(value) = &self.value {
+            label += &format!("  {value}");
+        }
+        if let Some(stats) = &self.stats {
+            label += &format!("  ({stats})");
+        }
+        label
+    }
+
+    /// Size of the icon and label, as allocated by [`Self::ui`].
+    fn desired_size(&self, ui: &Ui, text_style: &TextStyle) -> Vec2 {
+        let font_id = text_style.resolve(ui.style());
+        let galley = ui.fonts(|f| f.layout_delayed_color(self.label(), font_id, f32::INFINITY));
+        let icon_size = galley.size().y;
+        vec2(icon_size + icon_size / 5.0, 0.0) + galley.size()
+    }
+
     fn ui(&self, ui: &mut Ui, text_style: &TextStyle) -> Response {
         let Self {
-            id: _,
-            name,
             color,
+            glyph,
             checked,
-            hovered: _,
+            ..
         } = self;
 
         let font_id = text_style.resolve(ui.style());
 
-        let galley = ui.fonts(|f| f.layout_delayed_color(name.clone(), font_id, f32::INFINITY));
+        let galley = ui.fonts(|f| f.layout_delayed_color(self.label(), font_id, f32::INFINITY));
 
         let icon_size = galley.size().y;
         let icon_spacing = icon_size / 5.0;
@@ -192,25 +464,37 @@ impl LegendEntry {
 
         let painter = ui.painter();
 
-        // Gray background, for interaction effects, and to sow something if we're disabled:
-        painter.add(CircleShape {
-            center: icon_rect.center(),
-            radius: icon_size * 0.35,
-            fill: visuals.bg_fill,
-            stroke: visuals.bg_stroke,
-        });
+        let fill = if *color == Color32::TRANSPARENT {
+            ui.visuals().noninteractive().fg_stroke.color
+        } else {
+            *color
+        };
+        if *glyph == LegendGlyph::Dot {
+            // Gray background, for interaction effects, and to show something if we're disabled:
+            painter.add(CircleShape {
+                center: icon_rect.center(),
+                radius: icon_size * 0.35,
+                fill: visuals.bg_fill,
+                stroke: visuals.bg_stroke,
+            });
 
-        if *checked {
-            let fill = if *color == Color32::TRANSPARENT {
-                ui.visuals().noninteractive().fg_stroke.color
+            if *checked {
+                painter.add(Shape::circle_filled(
+                    icon_rect.center(),
+                    icon_size * 0.25,
+                    fill,
+                ));
+            }
+        } else {
+            // Hidden items keep their glyph, but grayed out:
+            let fill = if *checked {
+                fill
             } else {
-                *color
+                ui.visuals().weak_text_color()
             };
-            painter.add(Shape::circle_filled(
-                icon_rect.center(),
-                icon_size * 0.25,
-                fill,
-            ));
+            let mut shapes = Vec::new();
+            glyph_shapes(*glyph, icon_rect, fill, &mut shapes);
+            painter.extend(shapes);
         }
 
         let text_position_x = if label_on_the_left {
@@ -278,7 +562,21 @@ impl LegendWidget {
                     .or_insert_with(|| {
                         let color = item.color();
                         let checked = !hidden_items.contains(&item.id());
-                        LegendEntry::new(item.id(), item.name().to_owned(), color, checked)
+                        let mut entry = LegendEntry::new(
+                            item.id(),
+                            item.name().to_owned(),
+                            color,
+                            if config.item_glyphs {
+                                item.legend_glyph()
+                            } else {
+                                LegendGlyph::Dot
+                            },
+                            checked,
+                        );
+                        if config.style_editing {
+                            entry.style = Some(item.series_style());
+                        }
+                        entry
                     });
             });
         (!entries.is_empty()).then_some(Self {
@@ -288,6 +586,59 @@ impl LegendWidget {
         })
     }
 
+    /// Update the value readouts of the entries, if enabled.
+    ///
+    /// Uses the point closest to `pointer_x` on the x axis, or the last point if the plot is not
+    /// hovered. Items without points have no readout. The values are formatted like the y values
+    /// of the tooltips, with `value_format`.
+    pub(super) fn update_values<'a>(
+        &mut self,
+        items: &[Box<dyn PlotItem + 'a>],
+        pointer_x: Option<f64>,
+        unit: Option<&Unit>,
+        transform: &PlotTransform,
+        value_format: &ValueFormat,
+    ) {
+        if !self.config.show_values {
+            return;
+        }
+        let scale = transform.dvalue_dpos()[1];
+        let scale = unit.map_or(scale, |unit| unit.convert(scale) - unit.convert(0.0));
+        let decimals = crate::value_format::decimals_for(scale);
+        for entry in &mut self.entries {
+            entry.value = items
+                .iter()
+                .find(|item| item.id() == entry.id)
+                .and_then(|item| value_at(&item.geometry(), pointer_x))
+                .map(|value| unit.map_or(value, |unit| unit.convert(value)))
+                .map(|value| value_format.format(value, decimals));
+        }
+    }
+
+    /// Whether the entries show statistics, see [`Legend::show_stats`].
+    pub(super) fn shows_stats(&self) -> bool {
+        self.config.stats.is_some()
+    }
+
+    /// Show `stats` next to the names of the entries, if enabled.
+    pub(super) fn update_stats(
+        &mut self,
+        stats: &ahash::HashMap<Id, SeriesStats>,
+        unit: Option<&Unit>,
+    ) {
+        let Some(format) = self.config.stats else {
+            return;
+        };
+        for entry in &mut self.entries {
+            entry.stats = stats.get(&entry.id).map(|stats| format.format(stats, unit));
+        }
+    }
+
+    /// Override the corner of an inside legend, see [`Legend::smart_corner`].
+    pub(super) fn set_corner(&mut self, corner: Corner) {
+        self.config.position = corner;
+    }
+
     // Get the names of the hidden items.
     pub fn hidden_items(&self) -> ahash::HashSet<Id> {
         self.entries
@@ -296,6 +647,17 @@ impl LegendWidget {
             .collect()
     }
 
+    /// The styles edited in the context menu of the entries this frame.
+    pub fn edited_styles(&self) -> impl Iterator<Item = (Id, &SeriesStyle)> {
+        self.entries.iter().filter_map(|entry| {
+            entry
+                .style
+                .as_ref()
+                .filter(|_| entry.style_edited)
+                .map(|style| (entry.id, style))
+        })
+    }
+
     // Get the name of the hovered items.
     pub fn hovered_item(&self) -> Option<Id> {
         self.entries
@@ -304,8 +666,96 @@ impl LegendWidget {
     }
 }
 
+impl LegendWidget {
+    /// Whether the legend is drawn next to the plot rather than on top of it.
+    pub fn is_outside(&self) -> bool {
+        self.config.placement != LegendPlacement::Inside
+    }
+
+    /// Legend in a panel next to the plot, laid out in a grid that scrolls if needed.
+    ///
+    /// The returned response covers the whole legend panel.
+    fn ui_outside(&mut self, ui: &mut Ui) -> Response {
+        let Self {
+            rect,
+            entries,
+            config,
+        } = self;
+
+        let inner_margin = vec2(8.0, 4.0);
+        let spacing = ui.spacing().item_spacing;
+        let entry_size = entries
+            .iter()
+            .map(|entry| entry.desired_size(ui, &config.text_style))
+            .fold(Vec2::ZERO, |a, b| a.max(b));
+        let cell = entry_size + spacing;
+        let available = rect.size() - 2.0 * inner_margin;
+
+        let columns = match config.placement {
+            LegendPlacement::Right => {
+                let rows = ((available.y / cell.y).floor() as usize).max(1);
+                entries.len().div_ceil(rows).clamp(1, config.max_columns)
+            }
+            LegendPlacement::Bottom | LegendPlacement::Inside => {
+                ((available.x / cell.x).floor() as usize).clamp(1, entries.len().max(1))
+            }
+        };
+
+        let mut legend_ui = ui.new_child(
+            egui::UiBuilder::new()
+                .max_rect(*rect)
+                .layout(Layout::top_down(Align::LEFT)),
+        );
+        let background_frame = Frame {
+            inner_margin: inner_margin.into(),
+            corner_radius: legend_ui.style().visuals.window_corner_radius,
+            shadow: Shadow::NONE,
+            fill: legend_ui.style().visuals.extreme_bg_color,
+            stroke: legend_ui.style().visuals.window_stroke(),
+            ..Default::default()
+        }
+        .multiply_with_opacity(config.background_alpha);
+
+        background_frame
+            .show(&mut legend_ui, |ui| {
+                if let Some(title) = &config.title {
+                    ui.heading(title);
+                }
+                let mut focus_on_item = None;
+                ScrollArea::vertical()
+                    .id_salt("legend")
+                    .max_height(ui.available_height().max(cell.y))
+                    .show(ui, |ui| {
+                        Grid::new("legend_grid")
+                            .num_columns(columns)
+                            .min_col_width(entry_size.x)
+                            .show(ui, |ui| {
+                                for (i, entry) in entries.iter_mut().enumerate() {
+                                    let response = entry.ui(ui, &config.text_style);
+                                    handle_interaction_on_legend_item(&response, entry);
+                                    if response.clicked() && ui.input(|r| r.modifiers.alt) {
+                                        focus_on_item = Some(entry.id);
+                                    }
+                                    if (i + 1) % columns == 0 {
+                                        ui.end_row();
+                                    }
+                                }
+                            });
+                    });
+                if let Some(focus_on_item) = focus_on_item {
+                    handle_focus_on_legend_item(&focus_on_item, entries);
+                }
+            })
+            .response
+    }
+}
+
 impl Widget for &mut LegendWidget {
     fn ui(self, ui: &mut Ui) -> Response {
+        if self.is_outside() {
+            return self.ui_outside(ui);
+        }
+
         let LegendWidget {
             rect,
             entries,
@@ -385,6 +835,46 @@ impl Widget for &mut LegendWidget {
 fn handle_interaction_on_legend_item(response: &Response, entry: &mut LegendEntry) {
     entry.checked ^= response.clicked_by(PointerButton::Primary);
     entry.hovered = response.hovered();
+    if let Some(style) = &mut entry.style {
+        response.context_menu(|ui| {
+            entry.style_edited |= style_menu_ui(ui, style);
+        });
+    }
+}
+
+/// The context menu of an entry with [`Legend::style_editing`]. Returns `true` if the style
+/// was changed.
+fn style_menu_ui(ui: &mut Ui, style: &mut SeriesStyle) -> bool {
+    let mut changed = false;
+    Grid::new("series_style").num_columns(2).show(ui, |ui| {
+        ui.label("Name");
+        changed |= ui.text_edit_singleline(&mut style.name).changed();
+        ui.end_row();
+        if let Some(color) = &mut style.color {
+            ui.label("Color");
+            changed |= ui.color_edit_button_srgba(color).changed();
+            ui.end_row();
+        }
+        if let Some(width) = &mut style.width {
+            ui.label("Width");
+            changed |= ui.add(Slider::new(width, 0.5..=10.0)).changed();
+            ui.end_row();
+        }
+        if let Some(marker) = &mut style.marker {
+            ui.label("Marker");
+            ComboBox::from_id_salt("series_marker")
+                .selected_text(format!("{marker:?}"))
+                .show_ui(ui, |ui| {
+                    for shape in MarkerShape::all() {
+                        changed |= ui
+                            .selectable_value(marker, shape, format!("{shape:?}"))
+                            .changed();
+                    }
+                });
+            ui.end_row();
+        }
+    });
+    changed
 }
 
 /// Handle alt-click interaction (which may affect all entries).
@@ -399,3 +889,191 @@ fn handle_focus_on_legend_item(clicked_entry: &Id, entries: &mut [LegendEntry])
         entry.checked = is_focus_item_only_visible || clicked_entry == &entry.id;
     }
 }
+
+/// Shapes of a legend glyph, filling `rect`.
+fn glyph_shapes(glyph: LegendGlyph, rect: Rect, color: Color32, shapes: &mut Vec<Shape>) {
+    let center = rect.center();
+    let stroke = Stroke::new(1.5, color);
+    match glyph {
+        LegendGlyph::Dot => {
+            shapes.push(Shape::circle_filled(center, rect.height() * 0.25, color));
+        }
+        LegendGlyph::Line { style, marker } => {
+            let line = vec![pos2(rect.left(), center.y), pos2(rect.right(), center.y)];
+            match style {
+                LineStyle::Solid => shapes.push(Shape::line(line, stroke)),
+                LineStyle::Dotted { .. } => {
+                    shapes.extend(Shape::dotted_line(&line, color, rect.width() / 4.0, 1.0));
+                }
+                LineStyle::Dashed { .. } => {
+                    let dash = rect.width() / 3.5;
+                    shapes.extend(Shape::dashed_line(&line, stroke, dash, dash / 2.0));
+                }
+            }
+            if let Some(shape) = marker {
+                let radius = rect.height() * 0.2;
+                push_marker(shape, center, radius, color, Stroke::NONE, stroke, shapes);
+            }
+        }
+        LegendGlyph::Marker { shape, filled } => {
+            let radius = rect.height() * 0.3;
+            let (fill, outline) = if filled {
+                (color, Stroke::NONE)
+            } else {
+                (Color32::TRANSPARENT, stroke)
+            };
+            push_marker(shape, center, radius, fill, outline, stroke, shapes);
+        }
+        LegendGlyph::Area => {
+            let rect = rect.shrink(rect.height() * 0.2);
+            shapes.push(Shape::rect_filled(rect, 1.0, color.gamma_multiply(0.5)));
+            shapes.push(Shape::rect_stroke(rect, 1.0, stroke, StrokeKind::Inside));
+        }
+    }
+}
+
+/// The corner of `frame` with the fewest points of `items` underneath it, preferring
+/// `preferred` and then the order of [`Corner::all`] on ties.
+pub(super) fn least_covered_corner<'a>(
+    items: &[Box<dyn PlotItem + 'a>],
+    transform: &PlotTransform,
+    preferred: Corner,
+) -> Corner {
+    // Rough room a legend takes up:
+    let frame = *transform.frame();
+    let size = frame.size() / 3.0;
+    let corner_rect = |corner: Corner| {
+        let (x, y) = match corner {
+            Corner::LeftTop => (frame.left(), frame.top()),
+            Corner::RightTop => (frame.right() - size.x, frame.top()),
+            Corner::LeftBottom => (frame.left(), frame.bottom() - size.y),
+            Corner::RightBottom => (frame.right() - size.x, frame.bottom() - size.y),
+        };
+        Rect::from_min_size(pos2(x, y), size)
+    };
+    let corners: Vec<Corner> = std::iter::once(preferred)
+        .chain(Corner::all().filter(|corner| *corner != preferred))
+        .collect();
+    let rects: Vec<Rect> = corners.iter().map(|corner| corner_rect(*corner)).collect();
+
+    let mut counts = vec![0_usize; corners.len()];
+    for item in items {
+        let geometry = item.geometry();
+        let Some(points) = geometry_points(&geometry) else {
+            continue;
+        };
+        for (x, y) in points {
+            let pos = transform.position_from_point(&PlotPoint::new(x, y));
+            for (count, rect) in counts.iter_mut().zip(&rects) {
+                *count += usize::from(rect.contains(pos));
+            }
+        }
+    }
+    counts
+        .iter()
+        .zip(corners)
+        .min_by_key(|(count, _)| **count)
+        .map_or(preferred, |(_, corner)| corner)
+}
+
+/// The finite points of an item, if it is made of points.
+fn geometry_points<'g>(
+    geometry: &'g PlotGeometry<'_>,
+) -> Option<Box<dyn Iterator<Item = (f64, f64)> + 'g>> {
+    let points: Box<dyn Iterator<Item = (f64, f64)> + 'g> = match geometry {
+        PlotGeometry::None | PlotGeometry::Rects => return None,
+        PlotGeometry::Points(points) => Box::new(points.iter().map(|p| (p.x, p.y))),
+        PlotGeometry::PointsXY { xs, ys } => Box::new(xs.iter().copied().zip(ys.iter().copied())),
+        PlotGeometry::BlocksXY {
+            xs_blocks,
+            ys_blocks,
+        } => Box::new(
+            xs_blocks
+                .iter()
+                .zip(ys_blocks)
+                .flat_map(|(xs, ys)| xs.iter().copied().zip(ys.iter().copied())),
+        ),
+    };
+    Some(Box::new(
+        points.filter(|(px, py)| px.is_finite() && py.is_finite()),
+    ))
+}
+
+/// The y value of the point closest to `x`, or of the last point if `x` is `None`.
+fn value_at(geometry: &PlotGeometry<'_>, x: Option<f64>) -> Option<f64> {
+    let points = geometry_points(geometry)?;
+    match x {
+        Some(x) => points
+            .min_by(|a, b| (a.0 - x).abs().total_cmp(&(b.0 - x).abs()))
+            .map(|(_, y)| y),
+        None => points.last().map(|(_, y)| y),
+    }
+}
+
+#[test]
+fn test_value_at() {
+    let points = [
+        PlotPoint::new(0.0, 1.0),
+        PlotPoint::new(1.0, 2.0),
+        PlotPoint::new(2.0, f64::NAN),
+    ];
+    let geometry = PlotGeometry::Points(&points);
+    assert_eq!(value_at(&geometry, Some(0.3)), Some(1.0));
+    assert_eq!(value_at(&geometry, Some(5.0)), Some(2.0));
+    assert_eq!(value_at(&geometry, None), Some(2.0));
+    assert_eq!(value_at(&PlotGeometry::Rects, None), None);
+}
+
+#[test]
+fn test_least_covered_corner() {
+    use crate::{Line, PlotBounds};
+
+    let transform = PlotTransform::new(
+        Rect::from_min_size(pos2(0.0, 0.0), egui::vec2(300.0, 300.0)),
+        PlotBounds::from_min_max([0.0, 0.0], [3.0, 3.0]),
+        false,
+    );
+    // A rising line covers the bottom-left and top-right corners:
+    let items: Vec<Box<dyn PlotItem>> = vec![Box::new(Line::new(
+        "line",
+        vec![[0.2, 0.2], [1.5, 1.5], [2.8, 2.8]],
+    ))];
+    assert_eq!(
+        least_covered_corner(&items, &transform, Corner::RightTop),
+        Corner::LeftTop
+    );
+    assert_eq!(
+        least_covered_corner(&items, &transform, Corner::RightBottom),
+        Corner::RightBottom
+    );
+}
+
+#[test]
+fn test_series_stats() {
+    let points = [
+        PlotPoint::new(0.0, 10.0),
+        PlotPoint::new(1.0, 2.0),
+        PlotPoint::new(2.0, 4.0),
+        PlotPoint::new(3.0, f64::NAN),
+        PlotPoint::new(4.0, 3.0),
+        PlotPoint::new(5.0, -7.0),
+    ];
+    let geometry = PlotGeometry::Points(&points);
+    let stats = SeriesStats::of_visible(&geometry, &(0.5..=4.5)).unwrap();
+    assert_eq!(
+        stats,
+        SeriesStats {
+            min: 2.0,
+            max: 4.0,
+            mean: 3.0,
+            last: 3.0,
+        }
+    );
+    assert_eq!(SeriesStats::of_visible(&geometry, &(6.0..=7.0)), None);
+
+    let format = LegendStats {
+        mean: false,
+        ..Default::default()
+    };
+    assert_eq!(format.format(&stats, None), "min 2  max 4  last 3");
+}
@@ -9,50 +9,122 @@
 //!
 #![allow(deprecated)]
 mod axis;
+mod axis_breaks;
+mod axis_scale;
 mod bound;
 mod collect_events;
+mod colormap;
+mod context_menu;
+mod data_table;
+mod edit;
+mod envelope;
+mod export;
+#[cfg(feature = "expressions")]
+mod expression;
+mod gutter;
+mod inset;
 mod items;
+mod keymap;
+mod lane;
 mod legend;
 mod memory;
+mod minimap;
+mod palette;
+mod pattern;
+mod plot_grid;
+mod plot_style;
 mod plot_ui;
+mod plugin;
+mod range_slider;
+mod reference;
+mod replay;
 mod span;
 mod span_utils;
+#[cfg(feature = "testing")]
+mod testing;
+mod time_axis;
 mod transform;
+mod transition;
+mod unit;
+mod validate;
+mod value_format;
+mod view_history;
+mod weibull;
 use std::{cmp::Ordering, ops::RangeInclusive, sync::Arc};
 mod action;
 pub use crate::action::PlotEvent;
 pub use crate::action::{ActionExecutor, ActionQueue};
-pub use crate::action::{BoundsChangeCause, InputInfo, PinSnapshot};
+pub use crate::action::{BoundsChangeCause, EventMask, InputInfo, PinSnapshot};
 
 pub use crate::{
-    axis::{Axis, AxisHints, HPlacement, Placement, VPlacement},
+    axis::{Axis, AxisHints, HPlacement, Placement, StackPosition, TickLabelOverlap, VPlacement},
+    edit::PointEdit,
+    export::ExportShape,
     items::{
         Arrows, Band, Bar, BarChart, BoxElem, BoxPlot, BoxSpread, ClosestElem, ColumnarSeries,
-        HLine, HitPoint, Line, LineStyle, Marker, MarkerShape, Orientation, PinnedPoints,
-        PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotItemBase, PlotPoint, PlotPoints, Points,
-        Polygon, Scatter, ScatterEncodings, Text, TooltipOptions, VLine,
+        EnvelopeLine, Epoch, EventFlag, EventFlags, FanBand, HLine, HitPoint, ItemScale,
+        LabelOverlap, Line, LineStyle, Marker, MarkerShape, Orientation, PaintStage, PickHit,
+        PinnedPoints, PlotConfig, PlotGeometry, PlotImage, PlotItem, PlotItemBase, PlotPoint,
+        PlotPoints, PointMetadata, Points, Polygon, RadiusBy, RadiusScale, Scatter,
+        ScatterEncodings, SharedXs, Spline, SplineKind, StackedArea, StreamingSeries, Text,
+        TooltipOptions, VLine,
+    },
+    legend::{
+        ColorConflictHandling, Corner, Legend, LegendGlyph, LegendPlacement, LegendStats,
+        SeriesStyle,
     },
-    legend::{ColorConflictHandling, Corner, Legend},
     memory::PlotMemory,
     plot_ui::PlotUi,
-    transform::{PlotBounds, PlotTransform},
+    plugin::{PlotPlugin, PluginContext},
+    transform::{PlotBounds, PlotTransform, TransformViolation},
 };
 use ahash::HashMap;
+pub use axis_breaks::AxisBreaks;
+pub use axis_scale::{AxisScale, AxisScaleFn};
+pub use colormap::{ColorScale, Colorbar, Colormap, Normalization};
+pub use context_menu::{CopyFormat, PlotMenuContext};
+pub use data_table::DataTableMode;
 use egui::{
     Align2, Color32, CursorIcon, Id, Layout, NumExt as _, PointerButton, Pos2, Rangef, Rect,
-    Response, Sense, Shape, Stroke, TextStyle, Ui, Vec2, Vec2b, WidgetText, epaint, remap_clamp,
-    vec2,
+    Response, Sense, Shape, Stroke, TextStyle, Ui, Vec2, Vec2b, WidgetText, epaint, pos2,
+    remap_clamp, vec2,
 };
+pub use envelope::EnvelopeEditor;
+#[cfg(feature = "expressions")]
+pub use expression::{Expression, ExpressionError};
+pub use gutter::AxisGutter;
+pub use inset::Inset;
+pub use keymap::{DoubleClickAction, InputCapture, PlotInputConfig, PlotKeymap, ScrollBehavior};
+pub use lane::{EventLane, LaneEvent, Severity};
+pub use minimap::Minimap;
+pub use palette::Palette;
+pub use pattern::FillPattern;
+pub use plot_grid::{GridCell, PlotGrid};
+pub use plot_style::PlotStyle;
+pub use range_slider::XRangeSlider;
+pub use replay::{PlotRecorder, PlotReplayer, RecordedEvent};
 pub use span::{HSpan, VSpan};
 pub use span_utils::interval_to_screen_x;
 pub use span_utils::interval_to_screen_y;
-
-pub use bound::Interval;
+#[cfg(feature = "testing")]
+pub use testing::PlotHarness;
+pub use time_axis::{TimeHeader, TimeUnit, TradingCalendar};
+pub use unit::Unit;
+pub use validate::PlotConfigIssue;
+pub use value_format::ValueFormat;
+pub use weibull::WeibullPlot;
+
+pub use bound::{Interval, IntervalSet};
 use emath::Float as _;
 
 use axis::AxisWidget;
-use items::{horizontal_line, rulers_color, vertical_line};
+use context_menu::{ContextMenuFn, MenuCommand};
+use gutter::GutterConfig;
+use items::{band_hits, horizontal_line, rulers_color, vertical_line};
 use legend::LegendWidget;
+use reference::Reference;
+use transform::{AxisMaps, BoundsAnimation};
+use transition::ItemTransitions;
 
 type LabelFormatterFn<'a> = dyn Fn(&str, &PlotPoint) -> String + 'a;
 pub type LabelFormatter<'a> = Option<Box<LabelFormatterFn<'a>>>;
@@ -60,6 +132,12 @@ pub type LabelFormatter<'a> = Option<Box<LabelFormatterFn<'a>>>;
 type GridSpacerFn<'a> = dyn Fn(GridInput) -> Vec<GridMark> + 'a;
 type GridSpacer<'a> = Box<GridSpacerFn<'a>>;
 
+/// Vertical space between the plot title and the rest of the plot, in ui points.
+const TITLE_GAP: f32 = 4.0;
+
+/// Opacity of the other items while a legend entry is hovered, see [`Legend::hover_highlight`].
+const DIMMED_ALPHA: f32 = 0.25;
+
 type CoordinatesFormatterFn<'a> = dyn Fn(&PlotPoint, &PlotBounds) -> String + 'a;
 
 /// Specifies the coordinates formatting when passed to [`Plot::coordinates_formatter`].
@@ -84,6 +162,23 @@ impl<'a> CoordinatesFormatter<'a> {
         }
     }
 
+    /// Format the coordinates with a [`ValueFormat`], e.g. in scientific notation.
+    ///
+    /// Unless the format sets its own precision, the decimals follow the size of the bounds.
+    pub fn with_format(format: ValueFormat) -> Self {
+        Self {
+            function: Box::new(move |value, bounds| {
+                // The bounds are shown on a plot of a few hundred points:
+                let decimals = |width: f64| value_format::decimals_for(width / 500.0);
+                format!(
+                    "x: {}\ny: {}",
+                    format.format(value.x, decimals(bounds.width())),
+                    format.format(value.y, decimals(bounds.height()))
+                )
+            }),
+        }
+    }
+
     fn format(&self, value: &PlotPoint, bounds: &PlotBounds) -> String {
         (self.function)(value, bounds)
     }
@@ -115,13 +210,19 @@ struct PlotFrameCursors {
 struct CursorLinkGroups(HashMap<Id, Vec<PlotFrameCursors>>);
 
 #[derive(Clone)]
-struct LinkedBounds {
-    bounds: PlotBounds,
-    auto_bounds: Vec2b,
+pub(crate) struct LinkedBounds {
+    pub(crate) bounds: PlotBounds,
+    pub(crate) auto_bounds: Vec2b,
+
+    /// The axes linked by the plot that saved the bounds last.
+    pub(crate) axes: Vec2b,
+
+    /// The bounds of the items of the plot that saved the bounds last.
+    pub(crate) data_bounds: PlotBounds,
 }
 
 #[derive(Default, Clone)]
-struct BoundsLinkGroups(HashMap<Id, LinkedBounds>);
+pub(crate) struct BoundsLinkGroups(pub(crate) HashMap<Id, LinkedBounds>);
 
 // ----------------------------------------------------------------------------
 
@@ -146,6 +247,15 @@ pub struct PlotResponse<R> {
     /// All interaction events produced this frame
     /// empty when no events occurred.
     pub events: Vec<PlotEvent>,
+
+    /// The plot as a matplotlib script, if requested with [`Plot::export_matplotlib`].
+    pub matplotlib_script: Option<String>,
+
+    /// The plot as a standalone HTML page, if requested with [`Plot::export_html`].
+    pub html: Option<String>,
+
+    /// Whether the [`Plot::data_version`] changed since the last frame.
+    pub data_changed: bool,
 }
 
 // ----------------------------------------------------------------------------
@@ -171,17 +281,27 @@ pub struct Plot<'a> {
     id: Option<Id>,
 
     center_axis: Vec2b,
+    invert_axis: Vec2b,
+    bounds_limits: [Interval; 2],
+    extent_limits: [Interval; 2],
+    hidden_items: Option<ahash::HashSet<Id>>,
+    select_items: bool,
     allow_zoom: Vec2b,
     allow_drag: Vec2b,
     allow_axis_zoom_drag: Vec2b,
+    axis_zoom_zone: Option<f32>,
+    axis_zoom_highlight: bool,
     allow_scroll: Vec2b,
-    allow_double_click_reset: bool,
-    allow_boxed_zoom: bool,
+    allow_boxed_zoom: Vec2b,
     default_auto_bounds: Vec2b,
     min_auto_bounds: PlotBounds,
+
+    /// The axes with bounds set by [`Self::default_x_bounds`] or [`Self::default_y_bounds`].
+    default_bounds: Vec2b,
     margin_fraction: Vec2,
-    boxed_zoom_pointer_button: PointerButton,
-    linked_axes: Option<(Id, Vec2b)>,
+    fit_item_extents: bool,
+    auto_fit_y_to_visible_x: bool,
+    linked_axes: Vec<(Id, Vec2b)>,
     linked_cursors: Option<(Id, Vec2b)>,
 
     min_size: Vec2,
@@ -192,9 +312,39 @@ pub struct Plot<'a> {
 
     reset: bool,
 
+    title: WidgetText,
+    title_text_style: TextStyle,
+    event_lane: Option<EventLane>,
+    minimap: Option<Minimap>,
+    inset: Option<Inset>,
+    axis_scales: [AxisScale; 2],
+    axis_breaks: [AxisBreaks; 2],
+    gutters: Vec<GutterConfig<'a>>,
+    time_origin: Option<f64>,
+    units: [Vec<Unit>; 2],
+    input: PlotInputConfig,
+    data_version: Option<u64>,
+    idle_detail: Option<std::time::Duration>,
+    item_transitions: Option<std::time::Duration>,
+    item_scale: ItemScale,
+    palette: Palette,
+    style: PlotStyle,
+    export_matplotlib: bool,
+    export_html: bool,
+    export_decimated: bool,
+    event_mask: EventMask,
+    coalesce_events: bool,
+    show_context_menu: bool,
+    show_config_issues: bool,
+    context_menu: Option<ContextMenuFn<'a>>,
+    copy_format: CopyFormat,
+    data_table: DataTableMode,
+    stack_position: Option<StackPosition>,
+
     show_x: bool,
     show_y: bool,
     label_formatter: LabelFormatter<'a>,
+    value_format: ValueFormat,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'a>)>,
     x_axes: Vec<AxisHints<'a>>, // default x axes
     y_axes: Vec<AxisHints<'a>>, // default y axes
@@ -209,6 +359,7 @@ pub struct Plot<'a> {
     clamp_grid: bool,
 
     sense: Sense,
+    plugins: Vec<&'a mut dyn PlotPlugin>,
 }
 
 impl<'a> Plot<'a> {
@@ -219,17 +370,25 @@ impl<'a> Plot<'a> {
             id: None,
 
             center_axis: false.into(),
+            invert_axis: false.into(),
+            bounds_limits: [Interval::all(); 2],
+            extent_limits: [Interval::all(); 2],
+            hidden_items: None,
+            select_items: false,
             allow_zoom: true.into(),
             allow_drag: true.into(),
             allow_axis_zoom_drag: true.into(),
+            axis_zoom_zone: None,
+            axis_zoom_highlight: true,
             allow_scroll: true.into(),
-            allow_double_click_reset: true,
-            allow_boxed_zoom: true,
+            allow_boxed_zoom: true.into(),
             default_auto_bounds: true.into(),
             min_auto_bounds: PlotBounds::NOTHING,
+            default_bounds: Vec2b::FALSE,
             margin_fraction: Vec2::splat(0.05),
-            boxed_zoom_pointer_button: PointerButton::Secondary,
-            linked_axes: None,
+            fit_item_extents: false,
+            auto_fit_y_to_visible_x: false,
+            linked_axes: Vec::new(),
             linked_cursors: None,
 
             min_size: Vec2::splat(64.0),
@@ -240,9 +399,39 @@ impl<'a> Plot<'a> {
 
             reset: false,
 
+            title: WidgetText::default(),
+            title_text_style: TextStyle::Heading,
+            event_lane: None,
+            minimap: None,
+            inset: None,
+            axis_scales: Default::default(),
+            axis_breaks: Default::default(),
+            gutters: Vec::new(),
+            time_origin: None,
+            units: Default::default(),
+            input: PlotInputConfig::default(),
+            data_version: None,
+            idle_detail: None,
+            item_transitions: None,
+            item_scale: ItemScale::Points,
+            palette: Palette::default(),
+            style: PlotStyle::default(),
+            export_matplotlib: false,
+            export_html: false,
+            export_decimated: false,
+            event_mask: EventMask::ALL,
+            coalesce_events: false,
+            show_context_menu: true,
+            show_config_issues: false,
+            context_menu: None,
+            copy_format: CopyFormat::Tsv,
+            data_table: DataTableMode::Off,
+            stack_position: None,
+
             show_x: true,
             show_y: true,
             label_formatter: None,
+            value_format: ValueFormat::new(),
             coordinates_formatter: None,
             x_axes: vec![AxisHints::new(Axis::X)],
             y_axes: vec![AxisHints::new(Axis::Y)],
@@ -257,6 +446,7 @@ impl<'a> Plot<'a> {
             clamp_grid: false,
 
             sense: egui::Sense::click_and_drag(),
+            plugins: Vec::new(),
         }
     }
 
@@ -273,7 +463,10 @@ impl<'a> Plot<'a> {
 
     /// width / height ratio of the data.
     /// For instance, it can be useful to set this to `1.0` for when the two axes show the same
-    /// unit.
+    /// unit, e.g. for trajectories, maps or geometric shapes.
+    ///
+    /// The ratio is kept during auto-fit, panning, zooming and box zooming; zooming is always
+    /// uniform in both axes while this is set.
     /// By default the plot window's aspect ratio is used.
     #[inline]
     pub fn data_aspect(mut self, data_aspect: f32) -> Self {
@@ -342,9 +535,79 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Flip the X-axis so that values grow to the left. Default: `false`.
+    ///
+    /// Panning, zooming, box zoom and tooltips all follow the flipped direction.
+    #[inline]
+    pub fn invert_x(mut self, on: bool) -> Self {
+        self.invert_axis.x = on;
+        self
+    }
+
+    /// Flip the Y-axis so that values grow downwards. Default: `false`.
+    ///
+    /// Panning, zooming, box zoom and tooltips all follow the flipped direction.
+    #[inline]
+    pub fn invert_y(mut self, on: bool) -> Self {
+        self.invert_axis.y = on;
+        self
+    }
+
+    /// Restrict panning and zooming to the given x and y ranges, e.g. `Interval::above(0.0)` to
+    /// never show negative time.
+    ///
+    /// This is applied to every change of the bounds: user interaction, auto-fit and
+    /// programmatic changes through [`PlotUi`]. A NaN end is unbounded, and an empty range is
+    /// no limit. Default: [`Interval::all`] for both axes.
+    #[inline]
+    pub fn bounds_limits(mut self, x: Interval, y: Interval) -> Self {
+        self.bounds_limits = [x, y].map(transform::sanitize_bounds_limits);
+        self
+    }
+
+    /// Restrict how far the user can zoom: the visible width must lie in `x` and the visible
+    /// height in `y`, both in data units.
+    ///
+    /// For instance `Interval::new(1e-3, 1e3)` for both axes. Negative sizes count as `0`, a NaN
+    /// end is unbounded, and an empty range is no limit. Default: [`Interval::all`] for both axes.
+    #[inline]
+    pub fn extent_limits(mut self, x: Interval, y: Interval) -> Self {
+        self.extent_limits = [x, y].map(transform::sanitize_extent_limits);
+        self
+    }
+
+    /// Hide exactly these items, overriding the visibility stored in the plot memory.
+    ///
+    /// Use this to drive (and persist) series visibility from your own UI. Legend clicks and
+    /// [`PlotUi::set_item_visible`] still work for the current frame and are reported as
+    /// [`PlotEvent::LegendItemToggled`], which you should apply to your set.
+    #[inline]
+    pub fn hidden_items<S>(mut self, hidden_items: &std::collections::HashSet<Id, S>) -> Self {
+        self.hidden_items = Some(hidden_items.iter().copied().collect());
+        self
+    }
+
+    /// Select items by clicking them, and ctrl-clicking to add or remove more. Clicking next to
+    /// the items clears the selection. Default: `false`.
+    ///
+    /// The selected items are highlighted, and reported as [`PlotEvent::SelectionChanged`] and
+    /// by [`PlotUi::selected_items`], which also has operations on them, like
+    /// [`PlotUi::hide_selected`].
+    #[inline]
+    pub fn select_items(mut self, select: bool) -> Self {
+        self.select_items = select;
+        self
+    }
+
     /// Whether to allow zooming in the plot. Default: `true`.
     ///
-    /// Note: Allowing zoom in one axis but not the other may lead to unexpected results if used in combination with `data_aspect`.
+    /// Takes a [`Vec2b`] to only allow it on one axis, like `allow_zoom([true, false])` to keep
+    /// the y range of a time series fixed. To lock an axis only while a key or modifier is held,
+    /// see [`PlotInputConfig::x_only_key`]. To change it from frame to frame, see
+    /// [`PlotUi::set_allow_zoom`].
+    ///
+    /// Note: If [`Self::data_aspect`] is set, zooming always affects both axes and is enabled if
+    /// it is allowed in either of them.
     #[inline]
     pub fn allow_zoom<T>(mut self, on: T) -> Self
     where
@@ -355,6 +618,8 @@ impl<'a> Plot<'a> {
     }
 
     /// Whether to allow scrolling in the plot. Default: `true`.
+    ///
+    /// Like [`Self::allow_zoom`], this can be allowed on one axis only.
     #[inline]
     pub fn allow_scroll<T>(mut self, on: T) -> Self
     where
@@ -364,40 +629,113 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// What the scroll wheel does over the plot. Default: [`ScrollBehavior::Pan`].
+    ///
+    /// Shorthand for setting [`PlotInputConfig::scroll`]. Use [`ScrollBehavior::Disabled`] for
+    /// plots in a [`egui::ScrollArea`], so the wheel scrolls past them.
+    #[inline]
+    pub fn scroll_behavior(mut self, scroll: ScrollBehavior) -> Self {
+        self.input.scroll = scroll;
+        self
+    }
+
+    /// When the plot takes drags and the scroll wheel. Default: [`InputCapture::Always`].
+    ///
+    /// Shorthand for setting [`PlotInputConfig::capture`]. For plots in a [`egui::ScrollArea`],
+    /// [`InputCapture::Modifiers`] or [`InputCapture::Focused`] let the page scroll past them.
+    #[inline]
+    pub fn capture_input(mut self, capture: InputCapture) -> Self {
+        self.input.capture = capture;
+        self
+    }
+
     /// Whether to allow double clicking to reset the view.
     /// Default: `true`.
+    ///
+    /// Shorthand for setting [`PlotInputConfig::double_click`] to
+    /// [`DoubleClickAction::ResetView`] or [`DoubleClickAction::None`].
     #[inline]
     pub fn allow_double_click_reset(mut self, on: bool) -> Self {
-        self.allow_double_click_reset = on;
+        self.input.double_click = if on {
+            DoubleClickAction::ResetView
+        } else {
+            DoubleClickAction::None
+        };
         self
     }
 
-    /// Set the side margin as a fraction of the plot size. Only used for auto bounds.
+    /// Set the side margin as a fraction of the data extent per axis. Only used for auto bounds.
     ///
-    /// For instance, a value of `0.1` will add 10% space on both sides.
+    /// For instance, `vec2(0.0, 0.1)` fits the x-range tightly to the data and adds 10% of the
+    /// data height above and below it. The bounds reported in [`PlotEvent::AutoFitApplied`]
+    /// include this margin. Default: `0.05` for both axes.
+    /// See also [`Self::auto_bounds_margin`].
     #[inline]
     pub fn set_margin_fraction(mut self, margin_fraction: Vec2) -> Self {
         self.margin_fraction = margin_fraction;
         self
     }
 
+    /// Padding that auto-fit leaves around the data, as a fraction of the data extent per axis.
+    ///
+    /// Same as [`Self::set_margin_fraction`] with `vec2(x_frac, y_frac)`.
+    #[inline]
+    pub fn auto_bounds_margin(self, x_frac: f32, y_frac: f32) -> Self {
+        self.set_margin_fraction(vec2(x_frac, y_frac))
+    }
+
+    /// Leave room for markers and wide lines when fitting the bounds to the data, so they aren't
+    /// cut off at the edges of the plot. Default: `false`.
+    ///
+    /// After adding the [`Self::set_margin_fraction`], the bounds are widened by the largest
+    /// [`PlotItem::screen_extent`] of the items, like the radius of the markers of a
+    /// [`Scatter`], in screen points.
+    #[inline]
+    pub fn fit_item_extents(mut self, fit: bool) -> Self {
+        self.fit_item_extents = fit;
+        self
+    }
+
+    /// Keep fitting the y axis to the data within the visible x range, like trading and telemetry
+    /// charts: panning and zooming then only change the x range, and the y axis follows the
+    /// data. Default: `false`.
+    ///
+    /// Items left out with [`PlotItem::include_in_auto_fit`] are ignored, and the
+    /// [`Self::set_margin_fraction`] applies. Items without points, like bar charts, are fitted
+    /// whole, see [`PlotItem::bounds_in_x_range`].
+    #[inline]
+    pub fn auto_fit_y_to_visible_x(mut self, fit: bool) -> Self {
+        self.auto_fit_y_to_visible_x = fit;
+        self
+    }
+
     /// Whether to allow zooming in the plot by dragging out a box with the secondary mouse button.
     ///
     /// Default: `true`.
+    ///
+    /// Like [`Self::allow_zoom`], this can be allowed on one axis only. With
+    /// `allow_boxed_zoom([true, false])` the box selects an x range and keeps the y range.
     #[inline]
-    pub fn allow_boxed_zoom(mut self, on: bool) -> Self {
-        self.allow_boxed_zoom = on;
+    pub fn allow_boxed_zoom<T>(mut self, on: T) -> Self
+    where
+        T: Into<Vec2b>,
+    {
+        self.allow_boxed_zoom = on.into();
         self
     }
 
     /// Config the button pointer to use for boxed zooming. Default: [`Secondary`](PointerButton::Secondary)
+    ///
+    /// Shorthand for setting [`PlotInputConfig::box_zoom_button`].
     #[inline]
     pub fn boxed_zoom_pointer_button(mut self, boxed_zoom_pointer_button: PointerButton) -> Self {
-        self.boxed_zoom_pointer_button = boxed_zoom_pointer_button;
+        self.input.box_zoom_button = boxed_zoom_pointer_button;
         self
     }
 
     /// Whether to allow dragging in the plot to move the bounds. Default: `true`.
+    ///
+    /// Like [`Self::allow_zoom`], this can be allowed on one axis only.
     #[inline]
     pub fn allow_drag<T>(mut self, on: T) -> Self
     where
@@ -408,6 +746,8 @@ impl<'a> Plot<'a> {
     }
 
     /// Whether to allow dragging in the axis areas to zoom the plot. Default: `true`.
+    ///
+    /// Like [`Self::allow_zoom`], this can be allowed on one axis only.
     #[inline]
     pub fn allow_axis_zoom_drag<T>(mut self, on: T) -> Self
     where
@@ -417,6 +757,26 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Only start an axis zoom drag in a strip of this thickness along the plot frame, instead
+    /// of anywhere in the axis area. Default: the whole axis area.
+    ///
+    /// This leaves the rest of the axis, e.g. the axis label, free for clicks.
+    #[inline]
+    pub fn axis_zoom_zone(mut self, thickness: f32) -> Self {
+        self.axis_zoom_zone = Some(thickness.max(0.0));
+        self
+    }
+
+    /// Highlight the axis zoom zone while the pointer is over it or it is being dragged.
+    /// Default: `true`.
+    ///
+    /// The cursor changes in the zone either way, see [`Self::allow_axis_zoom_drag`].
+    #[inline]
+    pub fn axis_zoom_highlight(mut self, highlight: bool) -> Self {
+        self.axis_zoom_highlight = highlight;
+        self
+    }
+
     /// Provide a function to customize the on-hover label for the x and y axis
     ///
     /// ```
@@ -446,6 +806,18 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// How to format the values in the default tooltips and the value readouts of the
+    /// [`Legend`], e.g. with significant digits, scientific notation or thousands separators.
+    /// A custom [`Self::label_formatter`] replaces
+    /// the label of the point, but not the extra values some items add to it.
+    ///
+    /// Default: as many decimals as tell apart points a pixel apart.
+    #[inline]
+    pub fn value_format(mut self, format: ValueFormat) -> Self {
+        self.value_format = format;
+        self
+    }
+
     /// Show the pointer coordinates in the plot.
     pub fn coordinates_formatter(
         mut self,
@@ -541,6 +913,7 @@ impl<'a> Plot<'a> {
             "`min` must be less than `max` in `default_x_bounds`"
         );
         self.default_auto_bounds.x = false;
+        self.default_bounds.x = true;
         self.min_auto_bounds.min[0] = min;
         self.min_auto_bounds.max[0] = max;
         self
@@ -557,6 +930,7 @@ impl<'a> Plot<'a> {
             "`min` must be less than `max` in `default_y_bounds`"
         );
         self.default_auto_bounds.y = false;
+        self.default_bounds.y = true;
         self.min_auto_bounds.min[1] = min;
         self.min_auto_bounds.max[1] = max;
         self
@@ -639,10 +1013,16 @@ impl<'a> Plot<'a> {
     }
 
     /// Add this plot to an axis link group so that this plot will share the bounds with other plots in the
-    /// same group. A plot cannot belong to more than one axis group.
+    /// same group.
+    ///
+    /// A plot can belong to one group per axis, e.g. share its x axis with the plots in its column
+    /// and its y axis with the plots in its row, like in a [`PlotGrid`]. Calling this again with
+    /// the same group replaces its axes.
     #[inline]
     pub fn link_axis(mut self, group_id: impl Into<Id>, link: impl Into<Vec2b>) -> Self {
-        self.linked_axes = Some((group_id.into(), link.into()));
+        let group_id = group_id.into();
+        self.linked_axes.retain(|(id, _)| *id != group_id);
+        self.linked_axes.push((group_id, link.into()));
         self
     }
 
@@ -691,6 +1071,387 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Set a title shown centered above the plot.
+    ///
+    /// The title takes up space inside the plot widget, so the plot area shrinks to make room.
+    /// Use a [`egui::RichText`] to control the font and size directly.
+    ///
+    /// Default: no title.
+    #[inline]
+    pub fn title(mut self, title: impl Into<WidgetText>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// Text style used for the plot title, unless the title has its own font.
+    ///
+    /// Default: `TextStyle::Heading`.
+    #[inline]
+    pub fn title_text_style(mut self, style: TextStyle) -> Self {
+        self.title_text_style = style;
+        self
+    }
+
+    /// Show discrete events (e.g. log lines) in a strip above or below the plot area.
+    ///
+    /// The lane takes up space inside the plot widget and shares the x-axis with the plot.
+    ///
+    /// Default: no lane.
+    #[inline]
+    pub fn event_lane(mut self, lane: EventLane) -> Self {
+        self.event_lane = Some(lane);
+        self
+    }
+
+    /// Add a strip of custom widgets next to the axes of `axis`, on the side given by
+    /// `placement`, `size` points thick.
+    ///
+    /// `add_contents` is called every frame once the bounds are known, with an [`AxisGutter`]
+    /// that tells where values of the axis are. Can be called several times, the first gutter
+    /// goes closest to the axes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Axis, HPlacement, Plot};
+    /// let mut muted = [false; 4];
+    /// Plot::new("mixer")
+    ///     .axis_gutter(Axis::Y, HPlacement::Left, 24.0, |ui, gutter| {
+    ///         // One button for each channel, drawn in the rows y ∈ [i, i + 1]:
+    ///         for (i, muted) in muted.iter_mut().enumerate() {
+    ///             let row = gutter.span(i as f64..=i as f64 + 1.0);
+    ///             ui.put(row.shrink(2.0), egui::Checkbox::without_text(muted));
+    ///         }
+    ///     })
+    ///     .show(ui, |_plot_ui| {});
+    /// # });
+    /// ```
+    #[inline]
+    pub fn axis_gutter(
+        mut self,
+        axis: Axis,
+        placement: impl Into<Placement>,
+        size: f32,
+        add_contents: impl FnMut(&mut Ui, &AxisGutter) + 'a,
+    ) -> Self {
+        self.gutters.push(GutterConfig {
+            axis,
+            placement: placement.into(),
+            size,
+            add_contents: Box::new(add_contents),
+        });
+        self
+    }
+
+    /// Show a miniature of all the data above or below the plot area, with a brush to pan and
+    /// zoom the x-axis with.
+    ///
+    /// Default: no minimap.
+    #[inline]
+    pub fn minimap(mut self, minimap: Minimap) -> Self {
+        self.minimap = Some(minimap);
+        self
+    }
+
+    /// Show a small view of a region of the plot inside the plot frame, e.g. a magnified detail.
+    ///
+    /// Default: no inset.
+    #[inline]
+    pub fn inset(mut self, inset: Inset) -> Self {
+        self.inset = Some(inset);
+        self
+    }
+
+    /// Label the X-axis relative to a t₀ marker (`T−10 s`, `T+3 s`), e.g. for countdown views.
+    ///
+    /// The data stays in absolute time; `t0` is the initial position of the marker, which the
+    /// user can drag. Move it from code with [`PlotUi::set_time_origin`]. Every move is reported
+    /// as [`PlotEvent::TimeOriginMoved`].
+    ///
+    /// Default: absolute x values.
+    #[inline]
+    pub fn time_origin(mut self, t0: f64) -> Self {
+        self.time_origin = Some(t0);
+        self
+    }
+
+    /// Units the user can pick for the X-axis from its context menu, the first of which is the
+    /// unit of the data and shown initially.
+    ///
+    /// The ticks, the hover label, the coordinates and the legend values are shown in the picked
+    /// unit; the data and the bounds stay in the unit of the data. A single unit like
+    /// `vec![Unit::si("Hz")]` labels the axis with SI prefixes that follow the zoom.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Plot, Unit};
+    ///
+    /// Plot::new("temperature")
+    ///     .y_units(vec![Unit::data("°C"), Unit::linear("°F", 1.8, 32.0)])
+    ///     .show(ui, |_plot_ui| {});
+    /// # });
+    /// ```
+    #[inline]
+    pub fn x_units(mut self, units: Vec<Unit>) -> Self {
+        self.units[0] = units;
+        self
+    }
+
+    /// Units the user can pick for the Y-axis, see [`Self::x_units`].
+    #[inline]
+    pub fn y_units(mut self, units: Vec<Unit>) -> Self {
+        self.units[1] = units;
+        self
+    }
+
+    /// Keys to pan, zoom and step through the data points with while the plot has keyboard focus.
+    ///
+    /// Default: [`PlotKeymap::default`], with the arrow keys, `+`/`-` and page up/down.
+    ///
+    /// Shorthand for setting [`PlotInputConfig::keymap`].
+    #[inline]
+    pub fn keymap(mut self, keymap: PlotKeymap) -> Self {
+        self.input.keymap = keymap;
+        self
+    }
+
+    /// The buttons, modifiers and keys of all interactions with the plot.
+    ///
+    /// Default: [`PlotInputConfig::default`], panning with the primary button and zooming into a
+    /// box with the secondary one.
+    ///
+    /// ```
+    /// # use egui_plot::{DoubleClickAction, Plot, PlotInputConfig};
+    /// // Pan with the middle button, and box zoom with the primary one:
+    /// let plot = Plot::new("my_plot").input_config(PlotInputConfig {
+    ///     pan_button: egui::PointerButton::Middle,
+    ///     box_zoom_button: egui::PointerButton::Primary,
+    ///     double_click: DoubleClickAction::FitX,
+    ///     ..Default::default()
+    /// });
+    /// ```
+    #[inline]
+    pub fn input_config(mut self, input: PlotInputConfig) -> Self {
+        self.input = input;
+        self
+    }
+
+    /// A version or hash of the data, for apps that only repaint when something changes.
+    ///
+    /// When it differs from the last frame, the plot requests one more repaint so that
+    /// everything that settles a frame later, like linked axes and the legend statistics,
+    /// catches up with the new data. It also reports the change in
+    /// [`PlotResponse::data_changed`]. Default: `None`, the plot never requests repaints.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Line, Plot};
+    /// let samples = vec![[0.0, 1.0], [1.0, 3.0]];
+    /// Plot::new("dashboard")
+    ///     .data_version(samples.len())
+    ///     .show(ui, |plot_ui| plot_ui.line(Line::new("samples", samples)));
+    /// # });
+    /// ```
+    #[inline]
+    pub fn data_version(mut self, version: impl std::hash::Hash) -> Self {
+        self.data_version = Some(epaint::util::hash(version));
+        self
+    }
+
+    /// Draw lines at a lower fidelity while the user pans and zooms, and at full fidelity once
+    /// there has been no interaction for `delay`. Default: `None`, always at full fidelity.
+    ///
+    /// Meanwhile, lines are drawn with at most four points per pixel column, their first, lowest,
+    /// highest and last ones. This looks the same for lines much denser than the screen, and
+    /// keeps panning smooth through millions of points.
+    #[inline]
+    pub fn idle_detail(mut self, delay: std::time::Duration) -> Self {
+        self.idle_detail = Some(delay);
+        self
+    }
+
+    /// Fade items in when they are added to the plot, and out when they are removed, over
+    /// `duration`. Default: `None`, items appear and disappear at once.
+    ///
+    /// Items are matched by [`id`](crate::Line::id) from frame to frame, so dashboards can just
+    /// stop adding an item. A removed item fades out where it was last drawn, even if the view
+    /// moves meanwhile. Items hidden from the legend fade too.
+    #[inline]
+    pub fn item_transitions(mut self, duration: std::time::Duration) -> Self {
+        self.item_transitions = Some(duration);
+        self
+    }
+
+    /// The unit of the line widths, marker radii and text sizes of the items.
+    /// Default: [`ItemScale::Points`].
+    ///
+    /// With [`ItemScale::Factor`] the same dashboard can be made bigger on a large display
+    /// without changing the width of every item. Exports and the legend keep the unscaled sizes.
+    #[inline]
+    pub fn item_scale(mut self, scale: ItemScale) -> Self {
+        self.item_scale = scale;
+        self
+    }
+
+    /// The colors given to the items added without one, e.g. [`Palette::OkabeIto`] for readers
+    /// with color blindness. Default: [`Palette::Hues`].
+    ///
+    /// See also [`PlotUi::auto_color`].
+    #[inline]
+    pub fn palette(mut self, palette: Palette) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Colors and sizes of this plot that override the [`egui::Visuals`] of the [`Ui`] it is
+    /// in, like the background and the colors of the grid, the axes and the tooltips.
+    #[inline]
+    pub fn style(mut self, style: PlotStyle) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Export the plot as a Python script that draws the same items with matplotlib, in
+    /// [`PlotResponse::matplotlib_script`]. Default: `false`.
+    ///
+    /// Meant to be enabled for a single frame, e.g. when the user clicks an export button.
+    /// Items that can't be exported are left as a comment in the script.
+    #[inline]
+    pub fn export_matplotlib(mut self, export: bool) -> Self {
+        self.export_matplotlib = export;
+        self
+    }
+
+    /// Export the plot as a standalone HTML page with an interactive chart of the same items,
+    /// in [`PlotResponse::html`]. Default: `false`.
+    ///
+    /// The page embeds the data and a small script, so it can be opened in any browser without
+    /// network access. It supports zooming with the mouse wheel, panning by dragging, resetting
+    /// with a double-click and hiding series by clicking them in the legend.
+    ///
+    /// Meant to be enabled for a single frame, like [`Self::export_matplotlib`].
+    #[inline]
+    pub fn export_html(mut self, export: bool) -> Self {
+        self.export_html = export;
+        self
+    }
+
+    /// Export only what the plot shows, at the resolution it is shown. Default: `false`.
+    ///
+    /// The lines and markers of [`Self::export_matplotlib`] and [`Self::export_html`] are cut to
+    /// the visible x range, and reduced to the first, lowest, highest and last point of each
+    /// point-wide column on screen. This keeps extracts of huge recordings small while looking
+    /// the same.
+    #[inline]
+    pub fn export_decimated(mut self, decimated: bool) -> Self {
+        self.export_decimated = decimated;
+        self
+    }
+
+    /// Only emit these kinds of events in [`PlotResponse::events`]. Default: [`EventMask::ALL`].
+    ///
+    /// Apps that only follow the bounds can skip the events of every hovered frame:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{EventMask, Plot};
+    /// Plot::new("bounds_only")
+    ///     .emit_events(EventMask::BOUNDS)
+    ///     .show(ui, |_plot_ui| {});
+    /// # });
+    /// ```
+    ///
+    /// Plugins get the same events.
+    #[inline]
+    pub fn emit_events(mut self, mask: EventMask) -> Self {
+        self.event_mask = mask;
+        self
+    }
+
+    /// Merge the [`PlotEvent::PanDelta`]s of a frame into one with the total change, and
+    /// likewise for [`PlotEvent::ZoomDelta`] and [`PlotEvent::AxisZoomDragDelta`].
+    /// Default: `false`.
+    #[inline]
+    pub fn coalesce_events(mut self, coalesce: bool) -> Self {
+        self.coalesce_events = coalesce;
+        self
+    }
+
+    /// Whether to show a menu when right-clicking the plot. Default: `true`.
+    ///
+    /// The menu resets the view, fits it to the data, shows or hides the legend, and copies the
+    /// values at the cursor, the data as CSV, or the plot as an image. The image is also
+    /// reported as [`PlotEvent::ImageCaptured`], so it can be saved as a PNG. Add entries with
+    /// [`Self::context_menu`].
+    #[inline]
+    pub fn show_context_menu(mut self, show: bool) -> Self {
+        self.show_context_menu = show;
+        self
+    }
+
+    /// Add entries to the end of the right-click menu, see [`Self::show_context_menu`].
+    ///
+    /// The closure gets where the menu was opened, like the
+    /// [`PlotEvent::ContextMenuRequested`] of the click that opened it.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::Plot;
+    /// let mut markers: Vec<f64> = Vec::new();
+    /// Plot::new("annotated")
+    ///     .context_menu(|ui, menu| {
+    ///         if ui.button("Add marker here").clicked() {
+    ///             markers.push(menu.pos.x);
+    ///         }
+    ///     })
+    ///     .show(ui, |_plot_ui| {});
+    /// # });
+    /// ```
+    #[inline]
+    pub fn context_menu(mut self, add_entries: impl FnMut(&mut Ui, &PlotMenuContext) + 'a) -> Self {
+        self.context_menu = Some(Box::new(add_entries));
+        self
+    }
+
+    /// How to format the values copied at the cursor, with [`PlotKeymap::copy_values`] or the
+    /// context menu. Default: [`CopyFormat::Tsv`].
+    #[inline]
+    pub fn copy_format(mut self, format: CopyFormat) -> Self {
+        self.copy_format = format;
+        self
+    }
+
+    /// Show a sortable table of the samples of the visible series, instead of or below the plot.
+    /// Default: [`DataTableMode::Off`].
+    ///
+    /// Useful for screen reader users and for inspecting the exact values. Items without point
+    /// data, like bar charts, are not listed.
+    #[inline]
+    pub fn data_table(mut self, mode: DataTableMode) -> Self {
+        self.data_table = mode;
+        self
+    }
+
+    /// Register a tool extending the plot, see [`PlotPlugin`]. Plugins are called in the order
+    /// they were added.
+    #[inline]
+    pub fn plugin(mut self, plugin: &'a mut dyn PlotPlugin) -> Self {
+        self.plugins.push(plugin);
+        self
+    }
+
+    /// Place this plot in a vertical stack of plots sharing their x-axis (usually with
+    /// [`Self::link_axis`]): the top plot shows its main x-axis on its top edge, the bottom plot
+    /// on its bottom edge and the plots in between not at all. Other x-axes stay as configured.
+    ///
+    /// Use [`StackPosition::of`] to derive the position from the index of the plot.
+    ///
+    /// Default: `None`, the x-axes are shown as configured.
+    #[inline]
+    pub fn stack_position(mut self, position: impl Into<Option<StackPosition>>) -> Self {
+        self.stack_position = position.into();
+        self
+    }
+
     /// Set the position of the main X-axis.
     #[inline]
     pub fn x_axis_position(mut self, placement: axis::VPlacement) -> Self {
@@ -739,6 +1500,41 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Spread the x axis non-linearly, e.g. logarithmically, see [`AxisScale`].
+    ///
+    /// Items, ticks and formatters keep working with the values as they are: the plot transform
+    /// maps them to the screen and back. The grid is spaced evenly on the screen, see
+    /// [`GridInput`].
+    #[inline]
+    pub fn x_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[0] = scale;
+        self
+    }
+
+    /// Spread the y axis non-linearly, like [`Self::x_axis_scale`].
+    #[inline]
+    pub fn y_axis_scale(mut self, scale: AxisScale) -> Self {
+        self.axis_scales[1] = scale;
+        self
+    }
+
+    /// Cut gaps out of the x axis, see [`AxisBreaks`].
+    ///
+    /// The segments around each gap touch on the screen, with a break mark on the plot frame
+    /// between them. Items, ticks and formatters keep working with the values as they are.
+    #[inline]
+    pub fn x_axis_breaks(mut self, breaks: AxisBreaks) -> Self {
+        self.axis_breaks[0] = breaks;
+        self
+    }
+
+    /// Cut gaps out of the y axis, like [`Self::x_axis_breaks`].
+    #[inline]
+    pub fn y_axis_breaks(mut self, breaks: AxisBreaks) -> Self {
+        self.axis_breaks[1] = breaks;
+        self
+    }
+
     /// Set the minimum width of the main y-axis, in ui points.
     ///
     /// The width will automatically expand if any tickmark text is wider than this.
@@ -750,6 +1546,17 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// Set the minimum height of the main x-axis, in ui points.
+    ///
+    /// The height will automatically expand if the tick labels are taller than this.
+    #[inline]
+    pub fn x_axis_min_height(mut self, min_height: f32) -> Self {
+        if let Some(main) = self.x_axes.first_mut() {
+            main.min_thickness = min_height;
+        }
+        self
+    }
+
     /// Set the main Y-axis-width by number of digits
     #[inline]
     #[deprecated = "Use `y_axis_min_width` instead"]
@@ -784,6 +1591,65 @@ impl<'a> Plot<'a> {
         self
     }
 
+    /// List the [`Self::validate`] issues over the plot, along with plots of the same link
+    /// group that link different axes. Default: `false`.
+    ///
+    /// Meant for debug builds, e.g. `show_config_issues(cfg!(debug_assertions))`.
+    #[inline]
+    pub fn show_config_issues(mut self, show: bool) -> Self {
+        self.show_config_issues = show;
+        self
+    }
+
+    /// The settings of this plot that contradict each other, so some of them have no effect.
+    ///
+    /// ```
+    /// # use egui_plot::{Axis, Plot, PlotConfigIssue};
+    /// let plot = Plot::new("fixed").default_y_bounds(-1.0, 1.0).auto_bounds(true);
+    /// assert_eq!(
+    ///     plot.validate(),
+    ///     vec![PlotConfigIssue::DefaultBoundsWithAutoBounds(Axis::Y)]
+    /// );
+    /// ```
+    pub fn validate(&self) -> Vec<PlotConfigIssue> {
+        let mut issues = Vec::new();
+        for axis in [Axis::X, Axis::Y] {
+            let i = usize::from(axis);
+            if !self.default_bounds[i] {
+                continue;
+            }
+            if self.default_auto_bounds[i] {
+                issues.push(PlotConfigIssue::DefaultBoundsWithAutoBounds(axis));
+            }
+            let limits = self.bounds_limits[i];
+            if !limits.contains(self.min_auto_bounds.min[i])
+                || !limits.contains(self.min_auto_bounds.max[i])
+            {
+                issues.push(PlotConfigIssue::DefaultBoundsOutsideLimits(axis));
+            }
+        }
+        for (group, axes) in self.linked_axes.iter().copied().chain(self.linked_cursors) {
+            if !axes.any() {
+                issues.push(PlotConfigIssue::LinkWithoutAxes { group });
+            }
+        }
+        let one_axis = |allowed: Vec2b| allowed.x != allowed.y;
+        if self.data_aspect.is_some() && (one_axis(self.allow_zoom) || one_axis(self.allow_drag)) {
+            issues.push(PlotConfigIssue::DataAspectWithLockedAxis);
+        }
+        if self.view_aspect.is_some() && self.width.is_some() && self.height.is_some() {
+            issues.push(PlotConfigIssue::ViewAspectWithFixedSize);
+        }
+        if self.allow_boxed_zoom.any()
+            && self.allow_drag.any()
+            && self.input.box_zoom_button == self.input.pan_button
+            && self.input.box_zoom_modifiers.is_none()
+        {
+            issues.push(PlotConfigIssue::BoxZoomHidesPan);
+        }
+        issues
+    }
+
     /// Interact with and add items to the plot and finally draw it.
     pub fn show<'p, F, R>(self, ui: &mut Ui, build_fn: F) -> PlotResponse<R>
     where
@@ -798,20 +1664,33 @@ impl<'a> Plot<'a> {
     where
         F: FnOnce(&mut PlotUi<'p>) -> R,
     {
+        let mut config_issues = if self.show_config_issues {
+            self.validate()
+        } else {
+            Vec::new()
+        };
         let Self {
             id_source,
             id,
             center_axis,
+            invert_axis,
+            bounds_limits,
+            extent_limits,
+            hidden_items,
+            select_items,
             allow_zoom,
             allow_drag,
             allow_axis_zoom_drag,
+            axis_zoom_zone,
+            axis_zoom_highlight,
             allow_scroll,
-            allow_double_click_reset,
             allow_boxed_zoom,
-            boxed_zoom_pointer_button,
             default_auto_bounds,
             min_auto_bounds,
+            default_bounds: _,
             margin_fraction,
+            fit_item_extents,
+            auto_fit_y_to_visible_x,
             width,
             height,
             mut min_size,
@@ -820,12 +1699,41 @@ impl<'a> Plot<'a> {
             mut show_x,
             mut show_y,
             label_formatter,
+            value_format,
             coordinates_formatter,
-            x_axes,
+            mut x_axes,
             y_axes,
             legend_config,
             cursor_color,
             reset,
+            title,
+            title_text_style,
+            event_lane,
+            minimap,
+            inset,
+            axis_scales,
+            axis_breaks,
+            mut gutters,
+            time_origin,
+            units,
+            input: input_config,
+            data_version,
+            idle_detail,
+            item_transitions,
+            item_scale,
+            palette,
+            style: plot_style,
+            export_matplotlib,
+            export_html,
+            export_decimated,
+            event_mask,
+            coalesce_events,
+            show_context_menu,
+            show_config_issues,
+            mut context_menu,
+            copy_format,
+            data_table,
+            stack_position,
             show_background,
             show_axes,
             show_grid,
@@ -835,12 +1743,26 @@ impl<'a> Plot<'a> {
             clamp_grid,
             grid_spacers,
             sense,
+            mut plugins,
         } = self;
 
-        // Disable interaction if ui is disabled.
-        let allow_zoom = allow_zoom.and(ui.is_enabled());
-        let allow_drag = allow_drag.and(ui.is_enabled());
-        let allow_scroll = allow_scroll.and(ui.is_enabled());
+        // Only the outer edges of a stack of plots get the main x-axis.
+        if let Some(main) = x_axes.first_mut() {
+            match stack_position {
+                Some(StackPosition::Top) => main.placement = Placement::RightTop,
+                Some(StackPosition::Bottom) => main.placement = Placement::LeftBottom,
+                Some(StackPosition::Middle) | None => {}
+            }
+        }
+        let maps = AxisMaps {
+            scales: axis_scales,
+            breaks: axis_breaks,
+        };
+        let shown_x_axes = if stack_position == Some(StackPosition::Middle) {
+            x_axes.get(1..).unwrap_or_default()
+        } else {
+            &x_axes
+        };
 
         // Determine position of widget.
         let pos = ui.available_rect_before_wrap().min;
@@ -879,15 +1801,133 @@ impl<'a> Plot<'a> {
         };
         let plot_id = id.unwrap_or_else(|| ui.make_persistent_id(id_source));
 
+        // Reserve room for the title above everything else.
+        let mut axes_rect = complete_rect;
+        let title_galley = (!title.is_empty()).then(|| {
+            let galley = title.into_galley(
+                ui,
+                Some(egui::TextWrapMode::Truncate),
+                complete_rect.width(),
+                title_text_style,
+            );
+            *axes_rect.top_mut() += galley.size().y + TITLE_GAP;
+            galley
+        });
+
+        // Reserve room for the event lane. It spans the x-range of the plot frame, known later.
+        let lane_strip = event_lane.as_ref().map(|lane| {
+            let height = lane.height.min(axes_rect.height() / 3.0);
+            match lane.placement {
+                VPlacement::Top => {
+                    let strip = axes_rect.y_range().min..=axes_rect.top() + height;
+                    *axes_rect.top_mut() += height + lane::LANE_GAP;
+                    strip
+                }
+                VPlacement::Bottom => {
+                    let strip = axes_rect.bottom() - height..=axes_rect.bottom();
+                    *axes_rect.bottom_mut() -= height + lane::LANE_GAP;
+                    strip
+                }
+            }
+        });
+
+        // Reserve room for the minimap, like for the lane.
+        let minimap_strip = minimap.as_ref().map(|minimap| {
+            let height = minimap.height.min(axes_rect.height() / 3.0);
+            match minimap.placement {
+                VPlacement::Top => {
+                    let strip = axes_rect.y_range().min..=axes_rect.top() + height;
+                    *axes_rect.top_mut() += height + minimap::MINIMAP_GAP;
+                    strip
+                }
+                VPlacement::Bottom => {
+                    let strip = axes_rect.bottom() - height..=axes_rect.bottom();
+                    *axes_rect.bottom_mut() -= height + minimap::MINIMAP_GAP;
+                    strip
+                }
+            }
+        });
+
+        // Reserve room for the data table.
+        let table_rect = match data_table {
+            DataTableMode::Off => None,
+            DataTableMode::Replace => Some(axes_rect),
+            DataTableMode::Below => {
+                let height = axes_rect.height() / 3.0;
+                let rect = Rect::from_min_max(
+                    pos2(axes_rect.left(), axes_rect.bottom() - height),
+                    axes_rect.max,
+                );
+                *axes_rect.bottom_mut() -= height + data_table::TABLE_GAP;
+                Some(rect)
+            }
+        };
+
+        // Reserve room for a legend next to the plot, as big as it was last frame. When its size
+        // changes, e.g. on the first frame, the pass is discarded so the plot never jumps.
+        let last_mem = PlotMemory::load(ui.ctx(), plot_id); // TODO(emilk): avoid loading plot memory twice
+        let last_legend_size = last_mem.as_ref().map_or(Vec2::ZERO, |mem| mem.legend_size);
+        let legend_rect = match legend_config.as_ref().map(|legend| legend.placement) {
+            Some(LegendPlacement::Right) => {
+                let width = last_legend_size.x.min(0.5 * axes_rect.width());
+                *axes_rect.right_mut() -= width;
+                Some(Rect::from_min_max(
+                    pos2(axes_rect.right(), axes_rect.top()),
+                    complete_rect.max,
+                ))
+            }
+            Some(LegendPlacement::Bottom) => {
+                let max_height = complete_rect.height() / 3.0;
+                *axes_rect.bottom_mut() -= last_legend_size.y.min(max_height);
+                Some(Rect::from_min_size(
+                    pos2(complete_rect.left(), axes_rect.bottom()),
+                    vec2(complete_rect.width(), max_height),
+                ))
+            }
+            Some(LegendPlacement::Inside) | None => None,
+        };
+
+        // Reserve room for the gutters next to the axes.
+        let mut gutter_strips: Vec<_> = gutters
+            .iter()
+            .rev()
+            .map(|gutter| gutter.reserve(&mut axes_rect))
+            .collect();
+        gutter_strips.reverse();
+
         let ([x_axis_widgets, y_axis_widgets], plot_rect) = axis_widgets(
-            PlotMemory::load(ui.ctx(), plot_id).as_ref(), // TODO(emilk): avoid loading plot memory twice
+            last_mem.as_ref(),
             show_axes,
-            complete_rect,
-            [&x_axes, &y_axes],
+            axes_rect,
+            [shown_x_axes, &y_axes],
+        );
+
+        if let Some(galley) = &title_galley {
+            let pos = egui::pos2(
+                plot_rect.center().x - galley.size().x / 2.0,
+                complete_rect.top(),
+            )
+            .max(complete_rect.min);
+            ui.painter()
+                .galley(pos, galley.clone(), ui.visuals().strong_text_color());
+        }
+
+        // Until the plot captures the input, drags and the wheel go to an enclosing scroll area.
+        let captures_input = input_config.capture.captures(
+            ui.input(|i| i.modifiers),
+            ui.memory(|memory| memory.has_focus(ui.next_auto_id())),
         );
+        let drag_sense = |sense: Sense| {
+            if captures_input {
+                sense
+            } else {
+                sense - Sense::DRAG
+            }
+        };
 
         // Allocate the plot window.s
-        let mut response = ui.allocate_rect(plot_rect, sense);
+        let mut response = ui.allocate_rect(plot_rect, drag_sense(sense));
+        plot_style.store(ui.ctx(), response.id);
         if response.clicked() || response.secondary_clicked() || response.middle_clicked() {
             response.request_focus();
         }
@@ -895,35 +1935,67 @@ impl<'a> Plot<'a> {
             response.request_focus();
         }
 
-        // Axis hit-areas for axis-zoom-drag
+        // Axis hit-areas for axis-zoom-drag, and for the context menu picking the unit
+        let axis_sense = |axis: usize| {
+            drag_sense(if units[axis].len() > 1 {
+                Sense::click_and_drag()
+            } else {
+                Sense::drag()
+            })
+        };
+        let x_axis_zones = x_axis_widgets
+            .iter()
+            .map(|widget| widget.zoom_zone(Axis::X, axis_zoom_zone))
+            .collect::<Vec<_>>();
+        let y_axis_zones = y_axis_widgets
+            .iter()
+            .map(|widget| widget.zoom_zone(Axis::Y, axis_zoom_zone))
+            .collect::<Vec<_>>();
+        let axis_zoom_affordance =
+            |ui: &Ui, axis_resp: &Response, zone: Rect, cursor: CursorIcon| {
+                let in_zone = axis_resp.hovered()
+                    && axis_resp.hover_pos().is_some_and(|pos| zone.contains(pos));
+                if in_zone || axis_resp.dragged_by(input_config.axis_zoom_button) {
+                    ui.ctx().set_cursor_icon(cursor);
+                    if axis_zoom_highlight {
+                        let fill = ui
+                            .visuals()
+                            .widgets
+                            .hovered
+                            .weak_bg_fill
+                            .gamma_multiply(0.5);
+                        ui.painter().rect_filled(zone, 0.0, fill);
+                    }
+                }
+            };
         let x_axis_responses = x_axis_widgets
             .iter()
-            .map(|widget| {
-                let axis_resp = ui.allocate_rect(widget.rect, Sense::drag());
+            .zip(&x_axis_zones)
+            .map(|(widget, zone)| {
+                let axis_resp = ui.allocate_rect(widget.rect, axis_sense(0));
                 if allow_axis_zoom_drag.x {
-                    axis_resp.on_hover_cursor(CursorIcon::ResizeHorizontal)
-                } else {
-                    axis_resp
+                    axis_zoom_affordance(ui, &axis_resp, *zone, CursorIcon::ResizeHorizontal);
                 }
+                axis_resp
             })
             .collect::<Vec<_>>();
 
         let y_axis_responses = y_axis_widgets
             .iter()
-            .map(|widget| {
-                let axis_resp = ui.allocate_rect(widget.rect, Sense::drag());
+            .zip(&y_axis_zones)
+            .map(|(widget, zone)| {
+                let axis_resp = ui.allocate_rect(widget.rect, axis_sense(1));
                 if allow_axis_zoom_drag.y {
-                    axis_resp.on_hover_cursor(CursorIcon::ResizeVertical)
-                } else {
-                    axis_resp
+                    axis_zoom_affordance(ui, &axis_resp, *zone, CursorIcon::ResizeVertical);
                 }
+                axis_resp
             })
             .collect::<Vec<_>>();
 
         // Load or initialize the memory.
         ui.ctx().check_for_id_clash(plot_id, plot_rect, "Plot");
         let mut mem = if reset {
-            if let Some((name, _)) = linked_axes.as_ref() {
+            for (name, _) in &linked_axes {
                 ui.data_mut(|data| {
                     let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
                     link_groups.0.remove(name);
@@ -937,43 +2009,118 @@ impl<'a> Plot<'a> {
             auto_bounds: default_auto_bounds,
             hovered_legend_item: None,
             hidden_items: Default::default(),
-            transform: PlotTransform::new(plot_rect, min_auto_bounds, center_axis),
+            selected_items: Vec::new(),
+            raised_items: Vec::new(),
+            transform: PlotTransform::new_mapped(plot_rect, min_auto_bounds, center_axis, maps),
             last_click_pos_for_zoom: None,
+            dragged_point: None,
+            dragged_item: None,
+            time_origin: None,
+            series_styles: Default::default(),
+            legend_corner: None,
+            legend_stats: None,
+            data_version: None,
+            last_interaction: None,
+            bounds_animation: None,
+            view_history: Default::default(),
+            data_table_sort: None,
+            units: [0; 2],
+            keyboard_point: None,
+            last_hovered_item: None,
+            dragging_time_origin: false,
+            axis_zoom_drag: None,
+            inset_region: None,
             x_axis_thickness: Default::default(),
             y_axis_thickness: Default::default(),
+            legend_size: Vec2::ZERO,
+            legend_hidden: false,
+            menu_context: None,
         });
 
+        if let Some(hidden_items) = hidden_items {
+            mem.hidden_items = hidden_items;
+        }
+        let hidden_items_before = mem.hidden_items.clone();
+        let selected_items_before = mem.selected_items.clone();
+        if time_origin.is_none() {
+            mem.time_origin = None;
+        } else if mem.time_origin.is_none() {
+            mem.time_origin = time_origin;
+        }
+        let time_origin_before = mem.time_origin;
+
+        let data_changed = data_version.is_some() && mem.data_version != data_version;
+        if data_changed {
+            mem.data_version = data_version;
+            // Don't wait for the bounds to settle to catch up with the new data:
+            mem.legend_stats = None;
+            mem.legend_corner = None;
+            ui.ctx().request_repaint();
+        }
+
         let last_plot_transform = mem.transform;
+        for plugin in &mut plugins {
+            plugin.on_input(&PluginContext {
+                plot_id,
+                response: &response,
+                transform: &last_plot_transform,
+            });
+        }
         // Call the plot build function.
         let mut plot_ui = PlotUi {
             ctx: ui.ctx().clone(),
             actions: ActionQueue::new(),
             next_auto_color_idx: 0,
+            selected_items: mem.selected_items.clone(),
+            palette,
+            dark_mode: ui.visuals().dark_mode,
             last_plot_transform,
             last_auto_bounds: mem.auto_bounds,
             response: response.clone(),
             called_once: false,
+            allow_zoom,
+            allow_drag,
+            allow_scroll,
+            allow_boxed_zoom,
+            allow_axis_zoom_drag,
         };
 
         let inner = build_fn(&mut plot_ui);
+        for plugin in &mut plugins {
+            plugin.contribute_items(&mut plot_ui);
+        }
 
         let PlotUi {
             actions,
             response: _,
             last_plot_transform,
             last_auto_bounds,
+            allow_zoom,
+            allow_drag,
+            allow_scroll,
+            allow_boxed_zoom,
+            allow_axis_zoom_drag,
             ..
         } = plot_ui;
 
+        // Disable interaction if ui is disabled.
+        let allow_zoom = allow_zoom.and(ui.is_enabled());
+        let allow_drag = allow_drag.and(ui.is_enabled());
+        let allow_scroll = allow_scroll.and(ui.is_enabled());
+
         // Background
-        if show_background {
+        if show_background && data_table != DataTableMode::Replace {
             ui.painter()
                 .with_clip_rect(plot_rect)
                 .add(epaint::RectShape::new(
                     plot_rect,
                     2,
-                    ui.visuals().extreme_bg_color,
-                    ui.visuals().widgets.noninteractive.bg_stroke,
+                    plot_style
+                        .background
+                        .unwrap_or(ui.visuals().extreme_bg_color),
+                    plot_style
+                        .frame_stroke
+                        .unwrap_or(ui.visuals().widgets.noninteractive.bg_stroke),
                     egui::StrokeKind::Inside,
                 ));
         }
@@ -988,16 +2135,117 @@ impl<'a> Plot<'a> {
         );
 
         let mut items = applied.items;
+        let reference_change = applied.reference;
+        if mem.time_origin.is_some() {
+            mem.time_origin = applied.time_origin.or(mem.time_origin);
+        }
+        for (id, visible) in applied.item_visibility {
+            if visible {
+                mem.hidden_items.remove(&id);
+            } else {
+                mem.hidden_items.insert(id);
+            }
+        }
+        if let Some(selection) = applied.selection {
+            mem.selected_items = selection;
+        }
+        for id in applied.raised {
+            mem.bring_to_front(id);
+        }
+        let item_colors = applied.item_colors;
         mem.auto_bounds = applied.auto_bounds;
         let mut bounds = applied.bounds;
+        if let Some((target, duration)) = applied.bounds_animation {
+            mem.bounds_animation = Some(BoundsAnimation::new(
+                *last_plot_transform.bounds(),
+                target,
+                ui.input(|i| i.time),
+                duration.as_secs_f64(),
+            ));
+        }
 
         // IMPORTANT: create events ONCE here and keep pushing into it
         let mut events = applied.events;
         let mut last_user_cause: Option<BoundsChangeCause> = None;
 
+        // The screenshot requested with "Copy as image" from the context menu
+        let screenshot = ui.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                egui::Event::Screenshot {
+                    user_data, image, ..
+                } if user_data
+                    .data
+                    .as_ref()
+                    .and_then(|data| data.downcast_ref::<Id>())
+                    == Some(&plot_id) =>
+                {
+                    Some(Arc::clone(image))
+                }
+                _ => None,
+            })
+        });
+        if let Some(screenshot) = screenshot {
+            let image = screenshot.region(&complete_rect, Some(ui.ctx().pixels_per_point()));
+            ui.ctx().copy_image(image.clone());
+            events.push(PlotEvent::ImageCaptured {
+                image: Arc::new(image),
+            });
+        }
+
+        // Move along the animation from `PlotUi::animate_bounds_to`:
+        if let Some(animation) = &mem.bounds_animation {
+            let (animated, done) = animation.at(ui.input(|i| i.time));
+            bounds = animated;
+            mem.auto_bounds = false.into();
+            last_user_cause = Some(BoundsChangeCause::Animation);
+            if done {
+                mem.bounds_animation = None;
+            } else {
+                ui.ctx().request_repaint();
+            }
+        }
+
         // Legend filtering/highlighting
-        let legend = legend_config
-            .and_then(|cfg| LegendWidget::try_new(plot_rect, cfg, &items, &mem.hidden_items));
+        // Recolor the items with `PlotUi::recolor_selected`, like from the legend:
+        for (id, color) in item_colors {
+            if let Some(item) = items.iter().find(|item| item.id() == id) {
+                let mut style = mem
+                    .series_styles
+                    .get(&id)
+                    .cloned()
+                    .unwrap_or_else(|| item.series_style());
+                style.color = Some(color);
+                mem.series_styles.insert(id, style.clone());
+                events.push(PlotEvent::SeriesStyleChanged { item: id, style });
+            }
+        }
+        // Apply the styles edited from the legend:
+        for item in &mut items {
+            if let Some(style) = mem.series_styles.get(&item.id()) {
+                item.set_series_style(style);
+            }
+        }
+
+        let dim_on_legend_hover = legend_config
+            .as_ref()
+            .is_some_and(|legend| legend.hover_highlight);
+        let smart_legend_corner = legend_config.as_ref().is_some_and(|legend| {
+            legend.smart_corner && legend.placement == LegendPlacement::Inside
+        });
+        let legend_corner = legend_config
+            .as_ref()
+            .map_or(Corner::RightTop, |legend| legend.position);
+        let has_legend = legend_config.is_some();
+        let mut legend = legend_config
+            .filter(|_| !mem.legend_hidden)
+            .and_then(|cfg| {
+                LegendWidget::try_new(
+                    legend_rect.unwrap_or(plot_rect),
+                    cfg,
+                    &items,
+                    &mem.hidden_items,
+                )
+            });
 
         if mem.hovered_legend_item.is_some() {
             show_x = false;
@@ -1005,13 +2253,39 @@ impl<'a> Plot<'a> {
         }
         // Remove the deselected items.
         items.retain(|it| !mem.hidden_items.contains(&it.id()));
-        // Highlight the hovered items.
+
+        if let (DataTableMode::Replace, Some(rect)) = (data_table, table_rect) {
+            data_table::table_ui(ui, rect, data_table::rows(&items), &mut mem.data_table_sort);
+            finish_events(&mut events, event_mask, coalesce_events);
+            for plugin in &mut plugins {
+                events.iter().for_each(|event| plugin.handle_event(event));
+            }
+            let transform = mem.transform;
+            mem.store(ui.ctx(), plot_id);
+            ui.advance_cursor_after_rect(complete_rect);
+            return PlotResponse {
+                inner,
+                response,
+                transform,
+                hovered_plot_item: None,
+                events,
+                matplotlib_script: None,
+                html: None,
+                data_changed,
+            };
+        }
+
+        // Highlight the hovered and the selected items.
         if let Some(item_id) = &mem.hovered_legend_item {
             items
                 .iter_mut()
                 .filter(|entry| &entry.id() == item_id)
                 .for_each(|entry| entry.highlight());
         }
+        items
+            .iter_mut()
+            .filter(|entry| mem.selected_items.contains(&entry.id()))
+            .for_each(|entry| entry.highlight());
         // Move highlighted items to front.
         items.sort_by_key(|it| it.highlighted());
 
@@ -1042,10 +2316,14 @@ impl<'a> Plot<'a> {
             Vec::new()
         };
         // Transfer the bounds from a link group.
-        if let Some((id, axes)) = linked_axes.as_ref() {
+        for (id, axes) in &linked_axes {
+            let unlinked = bounds;
             ui.data_mut(|data| {
                 let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
                 if let Some(linked_bounds) = link_groups.0.get(id) {
+                    if linked_bounds.axes != *axes && show_config_issues {
+                        config_issues.push(PlotConfigIssue::LinkGroupAxesMismatch { group: *id });
+                    }
                     if axes.x {
                         bounds.set_x(&linked_bounds.bounds);
                         mem.auto_bounds.x = linked_bounds.auto_bounds.x;
@@ -1056,19 +2334,120 @@ impl<'a> Plot<'a> {
                     }
                 }
             });
+            if bounds != unlinked {
+                last_user_cause.get_or_insert(BoundsChangeCause::LinkSync);
+            }
         }
 
-        // Double-click reset
-        if allow_double_click_reset && response.double_clicked() {
-            mem.auto_bounds = true.into();
-            events.push(PlotEvent::ResetApplied {
-                input: InputInfo {
-                    pointer: ui.input(|i| i.pointer.hover_pos()),
-                    button: Some(PointerButton::Primary),
-                    modifiers: ui.input(|i| i.modifiers),
-                },
-            });
-            last_user_cause = Some(BoundsChangeCause::Reset);
+        // Step through the views from `PlotUi::undo_view` and the keymap:
+        let mut view_steps = applied.view_steps;
+        if response.has_focus() {
+            view_steps += ui.input(|i| input_config.keymap.view_steps(i));
+        }
+        if view_steps != 0 {
+            if let Some(view) = mem.view_history.step(bounds, view_steps) {
+                bounds = view;
+                mem.auto_bounds = Vec2b::FALSE;
+                mem.bounds_animation = None;
+                last_user_cause = Some(BoundsChangeCause::History);
+            }
+        }
+
+        // Fit to the items from `PlotUi::fit_to_items`:
+        if let Some(ids) = applied.fit_items {
+            let fitted = items.iter().filter(|it| ids.contains(&it.id()));
+            let mut fit_bounds = PlotBounds::NOTHING;
+            let mut extent = 0.0_f32;
+            for it in fitted {
+                fit_bounds.merge(&it.bounds());
+                extent = extent.max(it.screen_extent());
+            }
+            if fit_bounds.is_valid() {
+                maps.in_display(&mut fit_bounds, |fit_bounds| {
+                    fit_bounds.add_relative_margin_x(margin_fraction);
+                    fit_bounds.add_relative_margin_y(margin_fraction);
+                    if fit_item_extents {
+                        fit_bounds.add_screen_margin(extent, plot_rect.size(), Vec2b::TRUE);
+                    }
+                });
+                bounds = fit_bounds;
+                mem.bounds_animation = None;
+                last_user_cause = Some(BoundsChangeCause::Programmatic);
+            } else {
+                mem.auto_bounds = last_auto_bounds;
+            }
+        }
+
+        // The extent of the data, for an `XRangeSlider` of the link group.
+        let mut linked_data_bounds = PlotBounds::NOTHING;
+        if !linked_axes.is_empty() {
+            for item in &items {
+                linked_data_bounds.merge(&item.bounds());
+            }
+        }
+
+        // Double-click
+        let double_click = input_config.double_click;
+        let double_click_pos = response
+            .double_clicked()
+            .then(|| response.interact_pointer_pos())
+            .flatten()
+            .filter(|_| double_click != DoubleClickAction::None);
+        if let Some(pointer) = double_click_pos {
+            let input = InputInfo {
+                pointer: Some(pointer),
+                button: Some(PointerButton::Primary),
+                modifiers: ui.input(|i| i.modifiers),
+            };
+            let pos = mem.transform.value_from_position(pointer);
+            let item = items::pick(
+                items.iter().map(|item| &**item),
+                &mem.transform,
+                pointer,
+                ui.style().interaction.interact_radius,
+            )
+            .into_iter()
+            .next()
+            .map(|hit| hit.item);
+            events.push(PlotEvent::DoubleClicked { pos, item, input });
+
+            let fit_item = item
+                .filter(|_| double_click == DoubleClickAction::FitItem)
+                .and_then(|id| items.iter().find(|it| it.id() == id))
+                .filter(|it| it.bounds().is_valid());
+            let mut reset = double_click.auto_bounds();
+            if let Some(fit_item) = fit_item {
+                let mut item_bounds = fit_item.bounds();
+                maps.in_display(&mut item_bounds, |item_bounds| {
+                    item_bounds.add_relative_margin_x(margin_fraction);
+                    item_bounds.add_relative_margin_y(margin_fraction);
+                    if fit_item_extents {
+                        item_bounds.add_screen_margin(
+                            fit_item.screen_extent(),
+                            plot_rect.size(),
+                            Vec2b::TRUE,
+                        );
+                    }
+                });
+                bounds = item_bounds;
+                mem.auto_bounds = Vec2b::FALSE;
+                last_user_cause = Some(BoundsChangeCause::Programmatic);
+            } else if double_click == DoubleClickAction::FitItem {
+                reset = Vec2b::TRUE;
+            } else if double_click == DoubleClickAction::ZoomIn && allow_zoom.any() {
+                let factor = |allowed: bool| if allowed { 2.0 } else { 1.0 };
+                let center = PlotPoint::new(maps.to_display(0, pos.x), maps.to_display(1, pos.y));
+                maps.in_display(&mut bounds, |bounds| {
+                    bounds.zoom(vec2(factor(allow_zoom.x), factor(allow_zoom.y)), center);
+                });
+                mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
+                last_user_cause = Some(BoundsChangeCause::Zoom);
+            }
+            if reset.any() {
+                mem.auto_bounds = mem.auto_bounds.or(reset);
+                events.push(PlotEvent::ResetApplied { input });
+                last_user_cause = Some(BoundsChangeCause::Reset);
+            }
         }
 
         if mem.auto_bounds.x {
@@ -1082,7 +2461,7 @@ impl<'a> Plot<'a> {
         let auto_x = mem.auto_bounds.x && (!min_auto_bounds.is_valid_x() || default_auto_bounds.x);
         let auto_y = mem.auto_bounds.y && (!min_auto_bounds.is_valid_y() || default_auto_bounds.y);
         if auto_x || auto_y {
-            for it in &items {
+            for it in items.iter().filter(|it| it.include_in_auto_fit()) {
                 let b = it.bounds();
                 if auto_x {
                     bounds.merge_x(&b);
@@ -1091,23 +2470,38 @@ impl<'a> Plot<'a> {
                     bounds.merge_y(&b);
                 }
             }
-            if auto_x {
-                bounds.add_relative_margin_x(margin_fraction);
-            }
-            if auto_y {
-                bounds.add_relative_margin_y(margin_fraction);
-            }
+            let extent = items
+                .iter()
+                .filter(|it| fit_item_extents && it.include_in_auto_fit())
+                .map(|it| it.screen_extent())
+                .fold(0.0, f32::max);
+            maps.in_display(&mut bounds, |bounds| {
+                if auto_x {
+                    bounds.add_relative_margin_x(margin_fraction);
+                }
+                if auto_y {
+                    bounds.add_relative_margin_y(margin_fraction);
+                }
+                if fit_item_extents {
+                    bounds.add_screen_margin(extent, plot_rect.size(), Vec2b::new(auto_x, auto_y));
+                }
+            });
+            bounds.clamp(bounds_limits, extent_limits);
             events.push(PlotEvent::AutoFitApplied { new: bounds });
             last_user_cause.get_or_insert(BoundsChangeCause::AutoFit);
         }
 
         // Build transform
-        mem.transform = PlotTransform::new(plot_rect, bounds, center_axis);
+        mem.transform = PlotTransform::new_mapped(plot_rect, bounds, center_axis, maps);
+        mem.transform.set_inverted(invert_axis);
 
         // Aspect
         if let Some(data_aspect) = data_aspect {
-            if let Some((_, linked_axes)) = &linked_axes {
-                let change_x = linked_axes.y && !linked_axes.x;
+            if !linked_axes.is_empty() {
+                let linked = linked_axes
+                    .iter()
+                    .fold(Vec2b::FALSE, |linked, (_, axes)| linked.or(*axes));
+                let change_x = linked.y && !linked.x;
                 mem.transform.set_aspect_by_changing_axis(
                     data_aspect as f64,
                     if change_x { Axis::X } else { Axis::Y },
@@ -1120,25 +2514,114 @@ impl<'a> Plot<'a> {
             }
         }
 
+        // A box zoom takes precedence over all other drags, even when it shares their button
+        let starts_box_zoom = allow_boxed_zoom.any()
+            && response.drag_started_by(input_config.box_zoom_button)
+            && ui.input(|i| i.modifiers.contains(input_config.box_zoom_modifiers));
+        let box_zoom_drag = starts_box_zoom
+            || (allow_boxed_zoom.any()
+                && mem.last_click_pos_for_zoom.is_some()
+                && response.dragged_by(input_config.box_zoom_button));
+
+        // Dragging the t₀ marker takes precedence over panning
+        if let Some(t0) = mem.time_origin {
+            let marker_x = mem.transform.position_from_point_x(t0);
+            let near_marker = |pos: Pos2| (pos.x - marker_x).abs() <= edit::GRAB_RADIUS;
+            if response.drag_started_by(input_config.pan_button) && !box_zoom_drag {
+                mem.dragging_time_origin = ui
+                    .input(|i| i.pointer.press_origin())
+                    .is_some_and(near_marker);
+            }
+            if mem.dragging_time_origin {
+                if let Some(pointer) = response.interact_pointer_pos() {
+                    mem.time_origin = Some(mem.transform.value_from_position(pointer).x);
+                }
+                if !response.dragged_by(input_config.pan_button) {
+                    mem.dragging_time_origin = false;
+                }
+            }
+            if mem.dragging_time_origin || response.hover_pos().is_some_and(near_marker) {
+                response = response.on_hover_cursor(CursorIcon::ResizeHorizontal);
+            }
+        }
+
+        // Point editing takes precedence over dragging lines and spans, which takes precedence
+        // over panning
+        if response.drag_started_by(input_config.pan_button)
+            && !mem.dragging_time_origin
+            && !box_zoom_drag
+        {
+            let origin = ui.input(|i| i.pointer.press_origin());
+            mem.dragged_point =
+                origin.and_then(|origin| edit::find_editable_point(&items, origin, &mem.transform));
+            if mem.dragged_point.is_none() {
+                mem.dragged_item = origin
+                    .and_then(|origin| edit::find_draggable_item(&items, origin, &mem.transform));
+            }
+        }
+        if let Some(drag) = mem.dragged_item {
+            response = response.on_hover_cursor(drag.cursor());
+            if let Some(pointer) = response.interact_pointer_pos() {
+                events.push(PlotEvent::ItemDragged {
+                    id: drag.item,
+                    new_interval: drag.interval(mem.transform.value_from_position(pointer)),
+                });
+            }
+            if !response.dragged_by(input_config.pan_button) {
+                mem.dragged_item = None;
+            }
+        } else if let Some(drag) = response
+            .hover_pos()
+            .and_then(|pointer| edit::find_draggable_item(&items, pointer, &mem.transform))
+        {
+            response = response.on_hover_cursor(drag.cursor());
+        }
+        let editing_point =
+            mem.dragged_point.is_some() || mem.dragged_item.is_some() || mem.dragging_time_origin;
+        if let Some(dragged) = mem.dragged_point {
+            if response.dragged_by(input_config.pan_button)
+                || response.drag_stopped_by(input_config.pan_button)
+            {
+                response = response.on_hover_cursor(CursorIcon::Grabbing);
+                if let Some(new) = response.interact_pointer_pos().and_then(|pointer| {
+                    edit::dragged_value(&items, dragged, pointer, &mem.transform)
+                }) {
+                    events.push(PlotEvent::PointEdited {
+                        item: dragged.0,
+                        index: dragged.1,
+                        new,
+                    });
+                }
+            }
+            if !response.dragged_by(input_config.pan_button) {
+                mem.dragged_point = None;
+            }
+        }
+
         // Pan
-        if allow_drag.any() && response.dragged_by(PointerButton::Primary) {
+        if allow_drag.any()
+            && response.dragged_by(input_config.pan_button)
+            && !editing_point
+            && !box_zoom_drag
+        {
             response = response.on_hover_cursor(CursorIcon::Grabbing);
 
             if response.drag_started() {
                 events.push(PlotEvent::PanStarted {
                     input: InputInfo {
                         pointer: ui.input(|i| i.pointer.press_origin()),
-                        button: Some(PointerButton::Primary),
+                        button: Some(input_config.pan_button),
                         modifiers: ui.input(|i| i.modifiers),
                     },
                 });
             }
 
+            let pan_axes = allow_drag.and(ui.input(|i| input_config.pan_axes(i)));
             let mut delta = -response.drag_delta();
-            if !allow_drag.x {
+            if !pan_axes.x {
                 delta.x = 0.0;
             }
-            if !allow_drag.y {
+            if !pan_axes.y {
                 delta.y = 0.0;
             }
 
@@ -1148,105 +2631,115 @@ impl<'a> Plot<'a> {
                 delta_plot_y: (delta.y as f64) * d[1],
                 input: InputInfo {
                     pointer: ui.input(|i| i.pointer.hover_pos()),
-                    button: Some(PointerButton::Primary),
+                    button: Some(input_config.pan_button),
                     modifiers: ui.input(|i| i.modifiers),
                 },
             });
 
             mem.transform
                 .translate_bounds((delta.x as f64, delta.y as f64));
-            mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+            mem.auto_bounds = mem.auto_bounds.and(!pan_axes);
             last_user_cause = Some(BoundsChangeCause::Pan);
 
             if response.drag_stopped() {
                 events.push(PlotEvent::PanFinished {
                     input: InputInfo {
                         pointer: ui.input(|i| i.pointer.hover_pos()),
-                        button: Some(PointerButton::Primary),
+                        button: Some(input_config.pan_button),
                         modifiers: ui.input(|i| i.modifiers),
                     },
                 });
             }
-        }
+        }
+
+        // Axis zoom drag: starts in the zoom zone of an axis, and always ends with a finished
+        // event, also when the last frames didn't change the zoom.
+        let axis_zoom_button = input_config.axis_zoom_button;
+        for d in 0..2 {
+            let (responses, zones) = if d == 0 {
+                (&x_axis_responses, &x_axis_zones)
+            } else {
+                (&y_axis_responses, &y_axis_zones)
+            };
+            if allow_axis_zoom_drag[d] && mem.axis_zoom_drag.is_none() {
+                let origin = ui.input(|i| i.pointer.press_origin());
+                let started = responses.iter().zip(zones).any(|(r, zone)| {
+                    r.drag_started_by(axis_zoom_button)
+                        && origin.is_some_and(|pos| zone.contains(pos))
+                });
+                if let (true, Some(start)) = (started, origin) {
+                    mem.axis_zoom_drag = Some((d, start));
+                    events.push(PlotEvent::AxisZoomDragStarted {
+                        axis_x: d == 0,
+                        axis_y: d == 1,
+                        input: InputInfo {
+                            pointer: Some(start),
+                            button: Some(axis_zoom_button),
+                            modifiers: ui.input(|i| i.modifiers),
+                        },
+                    });
+                }
+            }
+
+            let Some((drag_axis, start)) = mem.axis_zoom_drag else {
+                continue;
+            };
+            if drag_axis != d {
+                continue;
+            }
+            if let Some(axis_resp) = responses.iter().find(|r| r.dragged_by(axis_zoom_button)) {
+                let delta = axis_resp.drag_delta();
+                let axis_zoom = 1.0 + (0.02 * delta[d]).clamp(-1.0, 1.0);
 
-        // Axis zoom drag
-        for d in 0..2 {
-            if allow_axis_zoom_drag[d] {
-                if let Some(axis_resp) = (if d == 0 {
-                    &x_axis_responses
+                let zoom = if data_aspect.is_some() {
+                    Vec2::splat(axis_zoom)
                 } else {
-                    &y_axis_responses
-                })
-                .iter()
-                .find(|r| r.dragged_by(PointerButton::Primary))
-                {
-                    if let Some(start) = ui.input(|i| i.pointer.press_origin()) {
-                        let delta = axis_resp.drag_delta();
-                        let axis_zoom = 1.0 + (0.02 * delta[d]).clamp(-1.0, 1.0);
+                    let mut z = Vec2::splat(1.0);
+                    z[d] = axis_zoom;
+                    z
+                };
 
-                        let zoom = if data_aspect.is_some() {
-                            Vec2::splat(axis_zoom)
-                        } else {
-                            let mut z = Vec2::splat(1.0);
-                            z[d] = axis_zoom;
-                            z
-                        };
+                if zoom != Vec2::splat(1.0) {
+                    let mut zoom_center = plot_rect.center();
+                    zoom_center[d] = start[d];
+                    mem.transform.zoom(zoom, zoom_center);
+                    mem.auto_bounds = false.into();
 
-                        if zoom != Vec2::splat(1.0) {
-                            if axis_resp.drag_started() {
-                                events.push(PlotEvent::AxisZoomDragStarted {
-                                    axis_x: d == 0,
-                                    axis_y: d == 1,
-                                    input: InputInfo {
-                                        pointer: Some(start),
-                                        button: Some(PointerButton::Primary),
-                                        modifiers: ui.input(|i| i.modifiers),
-                                    },
-                                });
-                            }
-
-                            let mut zoom_center = plot_rect.center();
-                            zoom_center[d] = start[d];
-                            mem.transform.zoom(zoom, zoom_center);
-                            mem.auto_bounds = false.into();
-
-                            events.push(PlotEvent::AxisZoomDragDelta {
-                                factor_x: zoom.x,
-                                factor_y: zoom.y,
-                                input: InputInfo {
-                                    pointer: Some(start),
-                                    button: Some(PointerButton::Primary),
-                                    modifiers: ui.input(|i| i.modifiers),
-                                },
-                            });
-
-                            last_user_cause = Some(if d == 0 {
-                                BoundsChangeCause::AxisZoomX
-                            } else {
-                                BoundsChangeCause::AxisZoomY
-                            });
-
-                            if axis_resp.drag_stopped() {
-                                events.push(PlotEvent::AxisZoomDragFinished {
-                                    input: InputInfo {
-                                        pointer: ui.input(|i| i.pointer.hover_pos()),
-                                        button: Some(PointerButton::Primary),
-                                        modifiers: ui.input(|i| i.modifiers),
-                                    },
-                                });
-                            }
-                        }
-                    }
+                    events.push(PlotEvent::AxisZoomDragDelta {
+                        factor_x: zoom.x,
+                        factor_y: zoom.y,
+                        input: InputInfo {
+                            pointer: Some(start),
+                            button: Some(axis_zoom_button),
+                            modifiers: ui.input(|i| i.modifiers),
+                        },
+                    });
+
+                    last_user_cause = Some(if d == 0 {
+                        BoundsChangeCause::AxisZoomX
+                    } else {
+                        BoundsChangeCause::AxisZoomY
+                    });
                 }
+            } else {
+                // Released, or the axis went away mid-drag
+                mem.axis_zoom_drag = None;
+                events.push(PlotEvent::AxisZoomDragFinished {
+                    input: InputInfo {
+                        pointer: ui.input(|i| i.pointer.hover_pos()),
+                        button: Some(axis_zoom_button),
+                        modifiers: ui.input(|i| i.modifiers),
+                    },
+                });
             }
         }
 
         // Boxed zoom
         let mut boxed_zoom_rect = None;
-        if allow_boxed_zoom {
+        if allow_boxed_zoom.any() {
             // Save last click to allow boxed zooming
 
-            if response.drag_started() && response.dragged_by(boxed_zoom_pointer_button) {
+            if starts_box_zoom {
                 // it would be best for egui that input has a memory of the last click pos because it's a common pattern
 
                 mem.last_click_pos_for_zoom = response.hover_pos();
@@ -1254,7 +2747,7 @@ impl<'a> Plot<'a> {
                     screen_start: mem.last_click_pos_for_zoom.unwrap_or(plot_rect.center()),
                     input: InputInfo {
                         pointer: mem.last_click_pos_for_zoom,
-                        button: Some(boxed_zoom_pointer_button),
+                        button: Some(input_config.box_zoom_button),
                         modifiers: ui.input(|i| i.modifiers),
                     },
                 });
@@ -1263,9 +2756,18 @@ impl<'a> Plot<'a> {
             if let (Some(s), Some(e)) = (start, end) {
                 // while dragging prepare a Shape and draw it later on top of the plot
 
-                if response.dragged_by(boxed_zoom_pointer_button) {
+                if response.dragged_by(input_config.box_zoom_button) {
                     response = response.on_hover_cursor(CursorIcon::ZoomIn);
-                    let rect = epaint::Rect::from_two_pos(s, e);
+                    let mut rect = epaint::Rect::from_two_pos(s, e);
+                    // A box that only zooms one axis spans the whole other axis:
+                    if !allow_boxed_zoom.x {
+                        rect.set_left(plot_rect.left());
+                        rect.set_right(plot_rect.right());
+                    }
+                    if !allow_boxed_zoom.y {
+                        rect.set_top(plot_rect.top());
+                        rect.set_bottom(plot_rect.bottom());
+                    }
                     boxed_zoom_rect = Some((
                         epaint::RectShape::stroke(
                             rect,
@@ -1285,13 +2787,31 @@ impl<'a> Plot<'a> {
                 if response.drag_stopped() {
                     let s_val = mem.transform.value_from_position(s);
                     let e_val = mem.transform.value_from_position(e);
-                    let new_bounds = PlotBounds {
+                    let mut new_bounds = PlotBounds {
                         min: [s_val.x.min(e_val.x), s_val.y.min(e_val.y)],
                         max: [s_val.x.max(e_val.x), s_val.y.max(e_val.y)],
                     };
+                    let old_bounds = mem.transform.bounds();
+                    for d in 0..2 {
+                        if !allow_boxed_zoom[d] {
+                            new_bounds.min[d] = old_bounds.min[d];
+                            new_bounds.max[d] = old_bounds.max[d];
+                        }
+                    }
                     if new_bounds.is_valid() {
                         mem.transform.set_bounds(new_bounds);
-                        mem.auto_bounds = false.into();
+                        if let Some(data_aspect) = data_aspect {
+                            // Keep the whole selected box visible:
+                            mem.transform.set_aspect_by_expanding(data_aspect as f64);
+                        }
+                        mem.auto_bounds = if data_aspect.is_some() {
+                            false.into()
+                        } else {
+                            mem.auto_bounds.and(!allow_boxed_zoom)
+                        };
+                        let mut new_bounds = *mem.transform.bounds();
+                        new_bounds.clamp(bounds_limits, extent_limits);
+                        mem.transform.set_bounds(new_bounds);
                         let new_x = new_bounds.range_x();
                         let new_y = new_bounds.range_y();
                         events.push(PlotEvent::BoxZoomFinished {
@@ -1299,7 +2819,7 @@ impl<'a> Plot<'a> {
                             new_y,
                             input: InputInfo {
                                 pointer: response.hover_pos(),
-                                button: Some(boxed_zoom_pointer_button),
+                                button: Some(input_config.box_zoom_button),
                                 modifiers: ui.input(|i| i.modifiers),
                             },
                         });
@@ -1315,21 +2835,28 @@ impl<'a> Plot<'a> {
         // For instance: The user is painting another interactive widget on top of the plot
         // but they still want to be able to pan/zoom the plot.
         if let (true, Some(hover_pos)) = (
-            response.contains_pointer(),
+            captures_input && response.contains_pointer(),
             ui.input(|i| i.pointer.hover_pos()),
         ) {
             if allow_zoom.any() {
-                let mut zoom_factor = if data_aspect.is_some() {
-                    Vec2::splat(ui.input(|i| i.zoom_delta()))
+                let zoom_axes = if data_aspect.is_some() {
+                    allow_zoom
                 } else {
-                    ui.input(|i| i.zoom_delta_2d())
+                    allow_zoom.and(ui.input(|i| input_config.zoom_axes(i)))
+                };
+                let zoom_factor = if data_aspect.is_some() {
+                    // Zooming a single axis would distort the data:
+                    Vec2::splat(ui.input(|i| input_config.uniform_zoom_delta(i)))
+                } else {
+                    let mut zoom_factor = ui.input(|i| input_config.zoom_delta(i));
+                    if !allow_zoom.x {
+                        zoom_factor.x = 1.0;
+                    }
+                    if !allow_zoom.y {
+                        zoom_factor.y = 1.0;
+                    }
+                    zoom_factor
                 };
-                if !allow_zoom.x {
-                    zoom_factor.x = 1.0;
-                }
-                if !allow_zoom.y {
-                    zoom_factor.y = 1.0;
-                }
                 if zoom_factor != Vec2::splat(1.0) {
                     mem.transform.zoom(zoom_factor, hover_pos);
                     events.push(PlotEvent::ZoomDelta {
@@ -1344,12 +2871,16 @@ impl<'a> Plot<'a> {
                         },
                     });
                     last_user_cause = Some(BoundsChangeCause::Zoom);
-                    mem.auto_bounds = mem.auto_bounds.and(!allow_zoom);
+                    mem.auto_bounds = mem.auto_bounds.and(!zoom_axes);
+                    if input_config.scroll == ScrollBehavior::Zoom {
+                        // Don't also scroll an enclosing scroll area:
+                        ui.input_mut(|i| i.smooth_scroll_delta = Vec2::ZERO);
+                    }
                 }
             }
 
             if allow_scroll.any() {
-                let mut scroll = ui.input(|i| i.smooth_scroll_delta);
+                let mut scroll = ui.input(|i| input_config.scroll_pan(i));
                 if !allow_scroll.x {
                     scroll.x = 0.0;
                 }
@@ -1360,17 +2891,189 @@ impl<'a> Plot<'a> {
                     mem.transform
                         .translate_bounds((-scroll.x as f64, -scroll.y as f64));
                     mem.auto_bounds = false.into();
+                    ui.input_mut(|i| i.smooth_scroll_delta -= scroll);
+                }
+            }
+        }
+        // Keyboard navigation
+        if response.has_focus() {
+            if input_config.keymap.uses_arrows() {
+                let filter = egui::EventFilter {
+                    horizontal_arrows: true,
+                    vertical_arrows: true,
+                    ..Default::default()
+                };
+                ui.memory_mut(|memory| memory.set_focus_lock_filter(response.id, filter));
+            }
+            let (mut pan, zoom) = ui.input(|i| {
+                (
+                    input_config.keymap.pan(i, plot_rect.size()),
+                    input_config.keymap.zoom(i),
+                )
+            });
+            if !allow_drag.x {
+                pan.x = 0.0;
+            }
+            if !allow_drag.y {
+                pan.y = 0.0;
+            }
+            if pan != Vec2::ZERO {
+                mem.transform.translate_bounds((pan.x as f64, pan.y as f64));
+                mem.auto_bounds = mem.auto_bounds.and(!allow_drag);
+                last_user_cause = Some(BoundsChangeCause::Pan);
+            }
+            let zoom_axes = if data_aspect.is_some() {
+                allow_zoom
+            } else {
+                allow_zoom.and(ui.input(|i| input_config.zoom_axes(i)))
+            };
+            if zoom != 1.0 && zoom_axes.any() {
+                let mut zoom_factor = Vec2::splat(zoom);
+                if data_aspect.is_none() {
+                    if !zoom_axes.x {
+                        zoom_factor.x = 1.0;
+                    }
+                    if !zoom_axes.y {
+                        zoom_factor.y = 1.0;
+                    }
+                }
+                mem.transform.zoom(zoom_factor, plot_rect.center());
+                mem.auto_bounds = mem.auto_bounds.and(!zoom_axes);
+                last_user_cause = Some(BoundsChangeCause::Zoom);
+            }
+        }
+
+        // Move the x window with the brush of the minimap
+        let minimap_rect =
+            minimap_strip.map(|strip| Rect::from_x_y_ranges(plot_rect.x_range(), strip));
+        if let Some(rect) = minimap_rect {
+            let overview = minimap::overview(rect, &items, mem.transform.bounds());
+            let view = mem.transform.bounds().range_x();
+            let brush = ui.interact(rect, plot_id.with("minimap"), Sense::click_and_drag());
+            if let Some(range) = minimap::brush_ui(ui, &brush, &overview, view) {
+                let bounds = mem.transform.bounds();
+                mem.transform.set_bounds(PlotBounds::from_min_max(
+                    [*range.start(), bounds.min[1]],
+                    [*range.end(), bounds.max[1]],
+                ));
+                mem.auto_bounds.x = false;
+                last_user_cause = Some(BoundsChangeCause::Pan);
+            }
+        }
+
+        // Move the region of the inset
+        if let Some(inset) = &inset {
+            let region = mem.inset_region.unwrap_or(inset.region);
+            let moved = inset.interact(ui, plot_id.with("inset"), &mem.transform, region);
+            if moved != region {
+                mem.inset_region = Some(moved);
+            }
+        }
+
+        // Panning or zooming stops the animation where it is
+        if !matches!(last_user_cause, Some(BoundsChangeCause::Animation)) {
+            mem.bounds_animation = None;
+        }
+
+        // Fit y to the data in the x range the interaction left
+        if auto_fit_y_to_visible_x {
+            let mut bounds = *mem.transform.bounds();
+            let x_range = bounds.range_x();
+            let mut visible = PlotBounds::NOTHING;
+            let mut extent = 0.0_f32;
+            for it in items.iter().filter(|it| it.include_in_auto_fit()) {
+                let item_bounds = it.bounds_in_x_range(&x_range);
+                if item_bounds.is_finite_y() {
+                    visible.merge_y(&item_bounds);
+                    extent = extent.max(it.screen_extent());
+                }
+            }
+            if visible.is_finite_y() {
+                let height = mem.transform.display_bounds().height();
+                maps.in_display(&mut visible, |visible| {
+                    if visible.height() <= 0.0 {
+                        // Flat data: keep the height of the view, centered on it
+                        visible.set_y_center_height(visible.center().y, height);
+                    }
+                    visible.add_relative_margin_y(margin_fraction);
+                    if fit_item_extents {
+                        visible.add_screen_margin(
+                            extent,
+                            plot_rect.size(),
+                            Vec2b::new(false, true),
+                        );
+                    }
+                });
+                if visible.range_y() != bounds.range_y() {
+                    bounds.set_y(&visible);
+                    mem.transform.set_bounds(bounds);
+                    last_user_cause.get_or_insert(BoundsChangeCause::AutoFit);
                 }
             }
         }
+
+        // Bounds limits apply after all interaction and programmatic changes
+        if bounds_limits != [Interval::all(); 2] || extent_limits != [Interval::all(); 2] {
+            let mut limited = *mem.transform.bounds();
+            limited.clamp(bounds_limits, extent_limits);
+            mem.transform.set_bounds(limited);
+        }
         // --- transform initialized
 
+        if let Some(rect) = minimap_rect {
+            let overview = minimap::overview(rect, &items, mem.transform.bounds());
+            minimap::paint(ui, &overview, &items, mem.transform.bounds().range_x());
+        }
+
+        // Draw the items in draft until the interaction has stopped for a while:
+        if let Some(delay) = idle_detail {
+            let now = ui.input(|i| i.time);
+            let interacting = response.dragged()
+                || !matches!(last_user_cause, None | Some(BoundsChangeCause::AutoFit));
+            if interacting {
+                mem.last_interaction = Some(now);
+            }
+            if let Some(last) = mem.last_interaction {
+                let idle = std::time::Duration::from_secs_f64((now - last).max(0.0));
+                if idle < delay {
+                    items
+                        .iter_mut()
+                        .for_each(|item| item.base_mut().draft = true);
+                    // The one repaint at full fidelity:
+                    ui.ctx().request_repaint_after(delay - idle);
+                } else {
+                    mem.last_interaction = None;
+                }
+            }
+        }
+
+        // Show the values in the units picked from the context menus of the axes:
+        let selected_units = [0, 1].map(|axis| {
+            let index = mem.units[axis].min(units[axis].len().saturating_sub(1));
+            units[axis].get(index).cloned()
+        });
+        let [x_spacer, y_spacer] = grid_spacers;
+        let grid_spacers = [
+            unit::grid_spacer(selected_units[0].clone(), x_spacer),
+            unit::grid_spacer(selected_units[1].clone(), y_spacer),
+        ];
+        let show_hover_label = label_formatter.is_some();
+        let label_formatter =
+            unit::label_formatter(selected_units.clone(), label_formatter, [show_x, show_y]);
+        let coordinates_formatter = coordinates_formatter.map(|(corner, formatter)| {
+            (
+                corner,
+                unit::coordinates_formatter(selected_units.clone(), formatter),
+            )
+        });
+
         // Add legend widgets to plot
         let bounds_now = mem.transform.bounds();
+        let display_now = mem.transform.display_bounds();
         let x_axis_range = bounds_now.range_x();
         let x_steps = Arc::new({
             let input = GridInput {
-                bounds: (bounds_now.min[0], bounds_now.max[0]),
+                bounds: (display_now.min[0], display_now.max[0]),
                 base_step_size: mem.transform.dvalue_dpos()[0].abs() * grid_spacing.min as f64,
             };
             (grid_spacers[0])(input)
@@ -1378,38 +3081,243 @@ impl<'a> Plot<'a> {
         let y_axis_range = bounds_now.range_y();
         let y_steps = Arc::new({
             let input = GridInput {
-                bounds: (bounds_now.min[1], bounds_now.max[1]),
+                bounds: (display_now.min[1], display_now.max[1]),
                 base_step_size: mem.transform.dvalue_dpos()[1].abs() * grid_spacing.min as f64,
             };
             (grid_spacers[1])(input)
         });
 
+        let ui_style = ui.style().clone();
+        plot_style.apply_to_axes(ui.style_mut());
         for (i, mut widget) in x_axis_widgets.into_iter().enumerate() {
+            if let Some(t0) = mem.time_origin {
+                widget.hints.formatter = Arc::new(move |mark, _range| {
+                    time_axis::format_relative_time(mark.value - t0, mark.step_size)
+                });
+            }
+            if let Some(unit) = &selected_units[0] {
+                unit::apply_to_axis(unit, &mut widget.hints, &x_axis_range);
+            }
             widget.range = x_axis_range.clone();
             widget.transform = Some(mem.transform);
             widget.steps = x_steps.clone();
             let (_response, thickness) = widget.ui(ui, Axis::X);
             mem.x_axis_thickness.insert(i, thickness);
+            if let Some(response) = x_axis_responses.get(i) {
+                unit::unit_menu(response, &units[0], &mut mem.units[0]);
+            }
         }
         for (i, mut widget) in y_axis_widgets.into_iter().enumerate() {
+            if let Some(unit) = &selected_units[1] {
+                unit::apply_to_axis(unit, &mut widget.hints, &y_axis_range);
+            }
             widget.range = y_axis_range.clone();
             widget.transform = Some(mem.transform);
             widget.steps = y_steps.clone();
             let (_response, thickness) = widget.ui(ui, Axis::Y);
             mem.y_axis_thickness.insert(i, thickness);
+            if let Some(response) = y_axis_responses.get(i) {
+                unit::unit_menu(response, &units[1], &mut mem.units[1]);
+            }
         }
+        ui.set_style(ui_style);
         // Initialize values from functions.
         for item in &mut items {
             item.initialize(mem.transform.bounds().range_x());
         }
+        if let Some(legend) = &mut legend {
+            if smart_legend_corner {
+                // Only move the legend once the bounds have settled:
+                if mem.legend_corner.is_none()
+                    || mem.transform.bounds() == last_plot_transform.bounds()
+                {
+                    mem.legend_corner = Some(legend::least_covered_corner(
+                        &items,
+                        &mem.transform,
+                        legend_corner,
+                    ));
+                }
+                if let Some(corner) = mem.legend_corner {
+                    legend.set_corner(corner);
+                }
+            }
+            let pointer_x = response
+                .hover_pos()
+                .map(|pos| mem.transform.value_from_position(pos).x);
+            legend.update_values(
+                &items,
+                pointer_x,
+                selected_units[1].as_ref(),
+                &mem.transform,
+                &value_format,
+            );
+            if legend.shows_stats() {
+                // Like the corner, only update the statistics once the bounds have settled:
+                if mem.legend_stats.is_none()
+                    || mem.transform.bounds() == last_plot_transform.bounds()
+                {
+                    let x_range = mem.transform.bounds().range_x();
+                    mem.legend_stats = Some(
+                        items
+                            .iter()
+                            .filter_map(|item| {
+                                legend::SeriesStats::of_visible(&item.geometry(), &x_range)
+                                    .map(|stats| (item.id(), stats))
+                            })
+                            .collect(),
+                    );
+                }
+                if let Some(stats) = &mem.legend_stats {
+                    legend.update_stats(stats, selected_units[1].as_ref());
+                }
+            }
+        }
+
+        // A click on a flag of an `EventFlags` item takes precedence over picking the items:
+        if response.clicked() {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                if let Some((item, flag)) = items.iter().rev().find_map(|item| {
+                    let items::ItemInteraction::Flags(flags) = item.interaction() else {
+                        return None;
+                    };
+                    let flag = flags.flag_at(ui, pointer, &mem.transform)?;
+                    Some((item.id(), flag))
+                }) {
+                    events.push(PlotEvent::FlagClicked { item, flag });
+                } else {
+                    let hit = items::pick(
+                        items.iter().map(|item| &**item),
+                        &mem.transform,
+                        pointer,
+                        ui.style().interaction.interact_radius,
+                    )
+                    .into_iter()
+                    .next();
+                    if select_items {
+                        let toggle = ui.input(|i| i.modifiers.command);
+                        mem.click_select(hit.as_ref().map(|hit| hit.item), toggle);
+                    }
+                    if let Some(hit) = hit {
+                        events.push(PlotEvent::ItemClicked {
+                            item: hit.item,
+                            pos: mem.transform.value_from_position(pointer),
+                            button: PointerButton::Primary,
+                            input: InputInfo {
+                                pointer: Some(pointer),
+                                button: Some(PointerButton::Primary),
+                                modifiers: ui.input(|i| i.modifiers),
+                            },
+                            index: hit.index,
+                            metadata: hit.metadata,
+                        });
+                    }
+                }
+            }
+        }
+
+        // Step through the points of the last hovered series, until the pointer moves again
+        let pointer_moved = response.hovered() && ui.input(|i| i.pointer.delta() != Vec2::ZERO);
+        if pointer_moved || !response.has_focus() {
+            mem.keyboard_point = None;
+        } else {
+            let step = ui.input(|i| input_config.keymap.step(i));
+            if step != 0 {
+                mem.keyboard_point = keymap::step_point(
+                    &items,
+                    mem.keyboard_point,
+                    mem.last_hovered_item,
+                    step,
+                    mem.transform.bounds(),
+                );
+            }
+        }
 
-        // Draw items/grid/tooltip
+        // Draw items/grid/tooltip by stage, then the items brought to the front last, then lowest
+        // z first (the sort is stable, so ties keep their order).
+        items.sort_by_key(|item| {
+            let raised = mem.raised_items.iter().position(|id| *id == item.id());
+            (item.stage(), raised, item.z_order())
+        });
+        let title = title_galley.as_ref().map_or("", |galley| galley.text());
+        let x_label = x_axes.first().map_or("", |axis| axis.label.text());
+        let y_label = y_axes.first().map_or("", |axis| axis.label.text());
+        let labels = [x_label, y_label];
+        let matplotlib_script = export_matplotlib.then(|| {
+            export::matplotlib_script(&items, &mem.transform, title, labels, export_decimated)
+        });
+        let html = export_html
+            .then(|| export::html_page(&items, &mem.transform, title, labels, export_decimated));
+        let table = table_rect.map(|rect| (rect, data_table::rows(&items)));
+        let item_scale = item_scale.points(ui.ctx().pixels_per_point());
+        if item_scale != 1.0 {
+            for item in &mut items {
+                items::scale_item(&mut **item, item_scale);
+            }
+        }
+        let transitions_id = plot_id.with("item_transitions");
+        let mut transitions = item_transitions.map(|duration| {
+            let transitions: ItemTransitions =
+                ui.data_mut(|data| std::mem::take(data.get_temp_mut_or_default(transitions_id)));
+            (transitions, duration.as_secs_f64())
+        });
+        let now = ui.input(|i| i.time);
+        let fade_in = transitions
+            .as_mut()
+            .map(|(transitions, duration)| {
+                transitions.update(items.iter().map(|item| item.id()), now, *duration)
+            })
+            .unwrap_or_default();
+        let faded_out = transitions
+            .as_ref()
+            .map(|(transitions, duration)| transitions.removed_shapes(now, *duration))
+            .unwrap_or_default();
+        let reference_id = plot_id.with("reference");
+        match reference_change {
+            Some(true) => {
+                let reference = Arc::new(Reference::from_items(&items));
+                ui.data_mut(|data| data.insert_temp(reference_id, reference));
+            }
+            Some(false) => ui.data_mut(|data| data.remove::<Arc<Reference>>(reference_id)),
+            None => {}
+        }
+        let ghosts = ui
+            .data(|data| data.get_temp::<Arc<Reference>>(reference_id))
+            .map(|reference| reference.shapes(&mem.transform, ui.visuals().weak_text_color()))
+            .unwrap_or_default();
+        let menu_open = show_context_menu && response.context_menu_opened();
+        let menu_rows = menu_open.then(|| data_table::rows(&items));
+
+        // The values at the cursor, for the hotkey and the context menu
+        let values_at = |pointer: Pos2| {
+            let radius = TooltipOptions::default().radius_px;
+            let items = items.iter().map(|item| &**item);
+            let hits = band_hits(items, &mem.transform, pointer, radius, Color32::TRANSPARENT);
+            let x = mem.transform.value_from_position(pointer).x;
+            copy_format.format(x, &hits)
+        };
+        if let Some(pointer) = response.hover_pos() {
+            if response.has_focus() && ui.input_mut(|i| input_config.keymap.copy_pressed(i)) {
+                ui.ctx().copy_text(values_at(pointer));
+            }
+        }
+        let menu_values = mem
+            .menu_context
+            .filter(|_| menu_open)
+            .map(|context| values_at(mem.transform.position_from_point(&context.pos)));
+        let inset_view = inset.as_ref().map(|inset| {
+            let region = mem.inset_region.unwrap_or(inset.region);
+            let transform = inset.transform(&mem.transform, region);
+            let shapes = inset::item_shapes(ui, &transform, &items);
+            (inset, transform, shapes)
+        });
         let prepared: PreparedPlot<'_, '_> = PreparedPlot {
             plot_area_response: &response,
             items,
             show_x,
             show_y,
             label_formatter,
+            value_format,
+            show_hover_label,
             coordinates_formatter,
             show_grid,
             grid_spacing,
@@ -1420,9 +3328,44 @@ impl<'a> Plot<'a> {
             cursor_color,
             grid_spacers,
             clamp_grid,
+            dim_except: mem.hovered_legend_item.filter(|_| dim_on_legend_hover),
+            keyboard_point: mem.keyboard_point,
+            fade_in,
+            faded_out,
+            keep_item_shapes: transitions.is_some(),
+            ghosts,
+            item_scale,
+            complete_rect,
+            grid_color: plot_style.grid_color,
         };
 
-        let (plot_cursors, mut hovered_plot_item) = prepared.ui(ui, &response);
+        let plugin_painter = ui.painter().with_clip_rect(plot_rect);
+        let plugin_context = PluginContext {
+            plot_id,
+            response: &response,
+            transform: &mem.transform,
+        };
+        for plugin in &mut plugins {
+            plugin.on_before_paint(&plugin_context, &plugin_painter);
+        }
+        let (plot_cursors, mut hovered_plot_item, item_shapes) = prepared.ui(ui, &response);
+        validate::paint_issues(ui, plot_rect, &config_issues);
+        if let Some((mut transitions, duration)) = transitions {
+            transitions.set_last_shapes(item_shapes);
+            if transitions.is_animating(now, duration) {
+                ui.ctx().request_repaint();
+            }
+            ui.data_mut(|data| data.insert_temp(transitions_id, transitions));
+        }
+        if mem.keyboard_point.is_none() {
+            mem.last_hovered_item = hovered_plot_item.or(mem.last_hovered_item);
+        }
+        if let Some((inset, transform, shapes)) = inset_view {
+            inset.paint(ui, &mem.transform, &transform, shapes);
+        }
+        for plugin in &mut plugins {
+            plugin.on_after_paint(&plugin_context, &plugin_painter);
+        }
 
         // Click/Context menu -> events
         if response.clicked() {
@@ -1436,8 +3379,69 @@ impl<'a> Plot<'a> {
                     screen_pos,
                     item: hovered_plot_item,
                 });
+                mem.menu_context = Some(PlotMenuContext {
+                    pos: mem.transform.value_from_position(screen_pos),
+                    item: hovered_plot_item,
+                    bounds: *mem.transform.bounds(),
+                });
             }
         }
+        let menu_command = mem
+            .menu_context
+            .filter(|_| show_context_menu)
+            .and_then(|context| {
+                context_menu::menu_ui(
+                    &response,
+                    &context,
+                    has_legend.then_some(!mem.legend_hidden),
+                    menu_values,
+                    menu_rows.as_deref(),
+                    context_menu.as_mut(),
+                )
+            });
+        match menu_command {
+            Some(MenuCommand::ResetView) => {
+                mem.auto_bounds = default_auto_bounds;
+                if min_auto_bounds.is_valid() {
+                    mem.transform.set_bounds(min_auto_bounds);
+                }
+                events.push(PlotEvent::ResetApplied {
+                    input: InputInfo {
+                        pointer: response.interact_pointer_pos(),
+                        button: Some(PointerButton::Primary),
+                        modifiers: ui.input(|i| i.modifiers),
+                    },
+                });
+                last_user_cause = Some(BoundsChangeCause::Reset);
+            }
+            Some(MenuCommand::FitToData) => mem.auto_bounds = Vec2b::TRUE,
+            Some(MenuCommand::ToggleLegend) => mem.legend_hidden = !mem.legend_hidden,
+            Some(MenuCommand::CopyImage) => {
+                let user_data = egui::UserData::new(plot_id);
+                ui.ctx()
+                    .send_viewport_cmd(egui::ViewportCommand::Screenshot(user_data));
+            }
+            None => {}
+        }
+        if menu_command.is_some() {
+            ui.ctx().request_repaint();
+        }
+
+        if let Some(t0) = mem.time_origin {
+            time_axis::paint_time_origin(ui, plot_rect, &mem.transform, t0);
+        }
+        for (axis, breaks) in axis_breaks.iter().enumerate() {
+            axis_breaks::paint_marks(ui, plot_rect, &mem.transform, axis, breaks);
+        }
+
+        if let (Some(lane), Some(strip)) = (&event_lane, lane_strip) {
+            let lane_rect = Rect::from_x_y_ranges(plot_rect.x_range(), strip);
+            lane.ui(ui, plot_id.with("event_lane"), lane_rect, &mem.transform);
+        }
+
+        for (gutter, strip) in gutters.iter_mut().zip(gutter_strips) {
+            gutter.ui(ui, strip, &mem.transform);
+        }
 
         // Draw boxed zoom preview
         if let Some((outer, inner)) = boxed_zoom_rect {
@@ -1446,16 +3450,61 @@ impl<'a> Plot<'a> {
         }
 
         // Legend UI (updates hidden/hovered)
+        let mut legend_size = Vec2::ZERO;
         if let Some(mut legend) = legend {
-            ui.add(&mut legend);
+            let legend_response = ui.add(&mut legend);
+            if legend.is_outside() {
+                legend_size = legend_response.rect.size();
+            }
             mem.hidden_items = legend.hidden_items();
             mem.hovered_legend_item = legend.hovered_item();
+            for (item, style) in legend.edited_styles() {
+                mem.series_styles.insert(item, style.clone());
+                events.push(PlotEvent::SeriesStyleChanged {
+                    item,
+                    style: style.clone(),
+                });
+            }
 
             if let Some(item_id) = &mem.hovered_legend_item {
                 hovered_plot_item.get_or_insert(*item_id);
             }
         }
 
+        if let Some((rect, rows)) = table {
+            data_table::table_ui(ui, rect, rows, &mut mem.data_table_sort);
+        }
+
+        if (legend_size - mem.legend_size).length() > 0.5 {
+            // Lay out the plot again with the new room for the legend:
+            mem.legend_size = legend_size;
+            ui.ctx().request_discard("Plot legend size changed");
+        }
+
+        if mem.selected_items != selected_items_before {
+            events.push(PlotEvent::SelectionChanged {
+                selected: mem.selected_items.clone(),
+            });
+        }
+
+        // Report visibility changes from the legend and from `PlotUi::set_item_visible`
+        let mut toggled: Vec<(Id, bool)> = hidden_items_before
+            .symmetric_difference(&mem.hidden_items)
+            .map(|id| (*id, !mem.hidden_items.contains(id)))
+            .collect();
+        toggled.sort_by_key(|(id, _)| id.value());
+        events.extend(
+            toggled
+                .into_iter()
+                .map(|(item, now_visible)| PlotEvent::LegendItemToggled { item, now_visible }),
+        );
+
+        if let Some(t0) = mem.time_origin {
+            if time_origin_before != Some(t0) {
+                events.push(PlotEvent::TimeOriginMoved { t0 });
+            }
+        }
+
         // Share linked cursors
         if let Some((id, _)) = linked_cursors.as_ref() {
             // Push the frame we just drew to the list of frames
@@ -1469,7 +3518,7 @@ impl<'a> Plot<'a> {
             });
         }
 
-        if let Some((id, _)) = linked_axes.as_ref() {
+        for (id, axes) in &linked_axes {
             // Save the linked bounds.
 
             ui.data_mut(|data| {
@@ -1479,11 +3528,22 @@ impl<'a> Plot<'a> {
                     LinkedBounds {
                         bounds: *mem.transform.bounds(),
                         auto_bounds: mem.auto_bounds,
+                        axes: *axes,
+                        data_bounds: linked_data_bounds,
                     },
                 );
             });
         }
 
+        // Remember the view before this frame, for `PlotUi::undo_view`:
+        if mem.transform.bounds() != last_plot_transform.bounds() {
+            mem.view_history.record(
+                *last_plot_transform.bounds(),
+                last_user_cause.unwrap_or(BoundsChangeCause::Programmatic),
+                ui.input(|i| i.time),
+            );
+        }
+
         let transform = mem.transform;
         mem.store(ui.ctx(), plot_id);
 
@@ -1494,7 +3554,10 @@ impl<'a> Plot<'a> {
         };
         ui.advance_cursor_after_rect(complete_rect);
 
-        if let Some(screen) = response.hover_pos() {
+        if let Some(screen) = response
+            .hover_pos()
+            .filter(|_| event_mask.contains(EventMask::HOVER))
+        {
             let pos = transform.value_from_position(screen);
             events.push(PlotEvent::Hover { pos });
         }
@@ -1556,12 +3619,20 @@ impl<'a> Plot<'a> {
             });
         }
 
+        finish_events(&mut events, event_mask, coalesce_events);
+        for plugin in &mut plugins {
+            events.iter().for_each(|event| plugin.handle_event(event));
+        }
+
         PlotResponse {
             inner,
             response,
             transform,
             hovered_plot_item,
             events,
+            matplotlib_script,
+            html,
+            data_changed,
         }
     }
 
@@ -1716,6 +3787,10 @@ enum BoundsModification {
 pub struct GridInput {
     /// Min/max of the visible data range (the values at the two edges of the plot,
     /// for the current axis).
+    ///
+    /// With [`Plot::x_axis_scale`] or [`Plot::x_axis_breaks`] these are display coordinates,
+    /// which are spread linearly over the screen, and so are the values of the returned marks.
+    /// The axis formatters get them as values.
     pub bounds: (f64, f64),
 
     /// Recommended (but not required) lower-bound on the step size returned by custom grid spacers.
@@ -1796,6 +3871,10 @@ struct PreparedPlot<'cfg, 'items> {
     show_x: bool,
     show_y: bool,
     label_formatter: LabelFormatter<'cfg>,
+    value_format: ValueFormat,
+
+    /// Whether to show `label_formatter` on hover, or only while stepping with the keyboard.
+    show_hover_label: bool,
     coordinates_formatter: Option<(Corner, CoordinatesFormatter<'cfg>)>,
     // axis_formatters: [AxisFormatter; 2],
     transform: PlotTransform,
@@ -1808,11 +3887,43 @@ struct PreparedPlot<'cfg, 'items> {
     cursor_color: Option<Color32>,
 
     clamp_grid: bool,
+
+    /// Draw all items but this one faded out.
+    dim_except: Option<Id>,
+
+    /// The point selected with the keyboard, shown instead of the hovered one.
+    keyboard_point: Option<(Id, usize)>,
+
+    /// The opacity of the items fading in, see [`Plot::item_transitions`].
+    fade_in: ahash::HashMap<Id, f32>,
+
+    /// The shapes of the removed items fading out, drawn over the data.
+    faded_out: Vec<Shape>,
+
+    /// Whether to return the shapes of each item, so they can fade out once it is removed.
+    keep_item_shapes: bool,
+
+    /// The reference traces, drawn behind the data, see [`PlotUi::set_reference_from_current`].
+    ghosts: Vec<Shape>,
+
+    /// How many points the text sizes of the items are, see [`Plot::item_scale`].
+    item_scale: f32,
+
+    /// The plot with its axes, where the items that aren't clipped to the frame can paint.
+    complete_rect: Rect,
+
+    /// See [`PlotStyle::grid_color`].
+    grid_color: Option<Color32>,
 }
 
 impl PreparedPlot<'_, '_> {
-    fn ui(self, ui: &mut Ui, response: &Response) -> (Vec<Cursor>, Option<Id>) {
+    fn ui(
+        mut self,
+        ui: &mut Ui,
+        response: &Response,
+    ) -> (Vec<Cursor>, Option<Id>, ahash::HashMap<Id, Vec<Shape>>) {
         let mut axes_shapes = Vec::new();
+        let mut item_shapes = ahash::HashMap::default();
 
         if self.show_grid.x {
             self.paint_grid(ui, &mut axes_shapes, Axis::X, self.grid_spacing);
@@ -1824,8 +3935,6 @@ impl PreparedPlot<'_, '_> {
         // Sort the axes by strength so that those with higher strength are drawn in front.
         axes_shapes.sort_by(|(_, strength1), (_, strength2)| strength1.total_cmp(strength2));
 
-        let mut shapes = axes_shapes.into_iter().map(|(shape, _)| shape).collect();
-
         let transform = &self.transform;
 
         let mut plot_ui = ui.new_child(
@@ -1834,12 +3943,36 @@ impl PreparedPlot<'_, '_> {
                 .layout(Layout::default()),
         );
         plot_ui.set_clip_rect(transform.frame().intersect(ui.clip_rect()));
-        for item in &self.items {
-            item.shapes(&plot_ui, transform, &mut shapes);
+        if self.item_scale != 1.0 {
+            for font in plot_ui.style_mut().text_styles.values_mut() {
+                font.size *= self.item_scale;
+            }
         }
 
+        let mut shapes = Vec::new();
+        let mut unclipped = Vec::new();
+        self.paint_items(
+            &plot_ui,
+            PaintStage::Background..=PaintStage::Background,
+            &mut shapes,
+            &mut unclipped,
+            &mut item_shapes,
+        );
+        shapes.extend(axes_shapes.into_iter().map(|(shape, _)| shape));
+        shapes.append(&mut self.ghosts);
+        self.paint_items(
+            &plot_ui,
+            PaintStage::UnderData..=PaintStage::OverData,
+            &mut shapes,
+            &mut unclipped,
+            &mut item_shapes,
+        );
+        shapes.append(&mut self.faded_out);
+
         let hover_pos = response.hover_pos();
-        let (cursors, hovered_item_id) = if let Some(pointer) = hover_pos {
+        let (cursors, hovered_item_id) = if let Some(hover) = self.keyboard_hover(ui, &mut shapes) {
+            hover
+        } else if let Some(pointer) = hover_pos {
             self.hover(ui, pointer, &mut shapes)
         } else {
             (Vec::new(), None)
@@ -1876,8 +4009,23 @@ impl PreparedPlot<'_, '_> {
         draw_cursor(&self.draw_cursors, false);
         draw_cursor(&cursors, true);
 
+        self.paint_items(
+            &plot_ui,
+            PaintStage::Overlay..=PaintStage::Overlay,
+            &mut shapes,
+            &mut unclipped,
+            &mut item_shapes,
+        );
+
         let painter = ui.painter().with_clip_rect(*transform.frame());
-        painter.extend(shapes);
+        let unclipped_painter = ui.painter().with_clip_rect(self.complete_rect);
+        for (index, shape) in shapes.into_iter().enumerate() {
+            if unclipped.iter().any(|range| range.contains(&index)) {
+                unclipped_painter.add(shape);
+            } else {
+                painter.add(shape);
+            }
+        }
 
         // Show coordinates in a corner of the plot:
         if let Some((corner, formatter)) = self.coordinates_formatter.as_ref() {
@@ -1906,7 +4054,46 @@ impl PreparedPlot<'_, '_> {
             }
         }
 
-        (cursors, hovered_item_id)
+        (cursors, hovered_item_id, item_shapes)
+    }
+
+    /// Add the shapes of the items painted in `stages` to `shapes`, in order, and the ranges of
+    /// those not clipped to the frame to `unclipped`.
+    ///
+    /// With [`Plot::item_transitions`], the shapes of each item are also kept in `item_shapes`.
+    fn paint_items(
+        &self,
+        ui: &Ui,
+        stages: RangeInclusive<PaintStage>,
+        shapes: &mut Vec<Shape>,
+        unclipped: &mut Vec<std::ops::Range<usize>>,
+        item_shapes: &mut ahash::HashMap<Id, Vec<Shape>>,
+    ) {
+        for item in self
+            .items
+            .iter()
+            .filter(|item| stages.contains(&item.stage()))
+        {
+            let first_shape = shapes.len();
+            item.shapes(ui, &self.transform, shapes);
+            if !item.clip() {
+                unclipped.push(first_shape..shapes.len());
+            }
+            if self.keep_item_shapes {
+                item_shapes.insert(item.id(), shapes[first_shape..].to_vec());
+            }
+            let mut opacity = self.fade_in.get(&item.id()).copied().unwrap_or(1.0);
+            if self.dim_except.is_some_and(|id| id != item.id()) {
+                opacity *= DIMMED_ALPHA;
+            }
+            if opacity < 1.0 {
+                for shape in &mut shapes[first_shape..] {
+                    epaint::shape_transform::adjust_colors(shape, move |color| {
+                        *color = color.gamma_multiply(opacity);
+                    });
+                }
+            }
+        }
     }
 
     fn paint_grid(&self, ui: &Ui, shapes: &mut Vec<(Shape, f32)>, axis: Axis, fade_range: Rangef) {
@@ -1925,8 +4112,9 @@ impl PreparedPlot<'_, '_> {
         let bounds = transform.bounds();
         let value_cross = 0.0_f64.clamp(bounds.min[1 - iaxis], bounds.max[1 - iaxis]);
 
+        let display = transform.display_bounds();
         let input = GridInput {
-            bounds: (bounds.min[iaxis], bounds.max[iaxis]),
+            bounds: (display.min[iaxis], display.max[iaxis]),
             base_step_size: transform.dvalue_dpos()[iaxis].abs() * fade_range.min as f64,
         };
         let steps = (grid_spacers[iaxis])(input);
@@ -1942,7 +4130,7 @@ impl PreparedPlot<'_, '_> {
         });
 
         for step in steps {
-            let value_main = step.value;
+            let value_main = transform.mark_value(iaxis, step).value;
 
             if let Some(clamp_range) = clamp_range {
                 match axis {
@@ -1973,7 +4161,10 @@ impl PreparedPlot<'_, '_> {
 
             let line_strength = remap_clamp(spacing_in_points, fade_range, 0.0..=1.0);
 
-            let line_color = color_from_strength(ui, line_strength);
+            let line_color = self.grid_color.map_or_else(
+                || color_from_strength(ui, line_strength),
+                |color| color.gamma_multiply(line_strength.sqrt()),
+            );
 
             let mut p0 = pos_in_gui;
             let mut p1 = pos_in_gui;
@@ -2000,6 +4191,45 @@ impl PreparedPlot<'_, '_> {
         }
     }
 
+    /// Highlight the point selected with the keyboard, with its tooltip next to it.
+    fn keyboard_hover(
+        &self,
+        ui: &Ui,
+        shapes: &mut Vec<Shape>,
+    ) -> Option<(Vec<Cursor>, Option<Id>)> {
+        let (id, index) = self.keyboard_point?;
+        let item = self.items.iter().find(|item| item.id() == id)?;
+        let value = *item.geometry().points().get(index)?;
+        let pos = self.transform.position_from_point(&value);
+        shapes.push(Shape::circle_stroke(
+            pos,
+            5.0,
+            Stroke::new(2.0, item.color()),
+        ));
+
+        let plot = items::PlotConfig {
+            ui,
+            transform: &self.transform,
+            show_x: self.show_x,
+            show_y: self.show_y,
+            value_format: &self.value_format,
+        };
+        let mut cursors = Vec::new();
+        if self.show_x {
+            cursors.push(Cursor::Vertical { x: value.x });
+        }
+        if self.show_y {
+            cursors.push(Cursor::Horizontal { y: value.y });
+        }
+        let text = items::tooltip_text(value, item.name(), &plot, &self.label_formatter);
+        items::show_tooltip_text_at(
+            self.plot_area_response,
+            egui::PopupAnchor::Position(pos),
+            text,
+        );
+        Some((cursors, Some(id)))
+    }
+
     fn hover(&self, ui: &Ui, pointer: Pos2, shapes: &mut Vec<Shape>) -> (Vec<Cursor>, Option<Id>) {
         let Self {
             plot_area_response,
@@ -2007,11 +4237,13 @@ impl PreparedPlot<'_, '_> {
             show_x,
             show_y,
             label_formatter,
+            value_format,
+            show_hover_label,
             items,
             ..
         } = self;
 
-        if label_formatter.is_none() {
+        if !show_hover_label {
             let mut cursors = Vec::new();
             let v = transform.value_from_position(pointer);
             if *show_x {
@@ -2047,6 +4279,7 @@ impl PreparedPlot<'_, '_> {
             transform,
             show_x: *show_x,
             show_y: *show_y,
+            value_format,
         };
 
         let mut cursors = Vec::new();
@@ -2077,6 +4310,17 @@ impl PreparedPlot<'_, '_> {
         (cursors, hovered_plot_item_id)
     }
 }
+/// Keep the events of the kinds in `mask`, see [`Plot::emit_events`] and
+/// [`Plot::coalesce_events`].
+fn finish_events(events: &mut Vec<PlotEvent>, mask: EventMask, coalesce: bool) {
+    if mask != EventMask::ALL {
+        events.retain(|event| mask.contains(event.mask()));
+    }
+    if coalesce {
+        action::coalesce_deltas(events);
+    }
+}
+
 /// Returns next bigger power in given base
 /// e.g.
 /// ```ignore
@@ -2212,3 +4456,103 @@ pub fn color_from_strength(ui: &Ui, strength: f32) -> Color32 {
     let base_color = ui.visuals().text_color();
     base_color.gamma_multiply(strength.sqrt())
 }
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_auto_fit_y_to_visible_x() {
+    let xs: Vec<f64> = (0..=100).map(|i| i as f64 / 10.0).collect();
+    let mut harness = PlotHarness::new(egui::vec2(200.0, 100.0));
+    let mut y_range = 0.0..=0.0;
+    harness.run(|ui| {
+        let response = Plot::new("visible_x")
+            .default_x_bounds(2.0, 4.0)
+            .auto_bounds_margin(0.0, 0.0)
+            .auto_fit_y_to_visible_x(true)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::from_series("ramp", ColumnarSeries::new(&xs, &xs)));
+            });
+        y_range = response.transform.bounds().range_y();
+    });
+    assert_eq!(y_range, 2.0..=4.0);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_auto_fit_within_limits() {
+    let mut harness = PlotHarness::new(egui::vec2(200.0, 100.0));
+    let mut fitted = Vec::new();
+    let mut shown = PlotBounds::NOTHING;
+    harness.run(|ui| {
+        let response = Plot::new("auto_fit_limits")
+            .extent_limits(Interval::below(4.0), Interval::all())
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new("ramp", vec![[0.0, 0.0], [10.0, 1.0]]));
+            });
+        fitted.extend(response.events.iter().filter_map(|event| match event {
+            PlotEvent::AutoFitApplied { new } => Some(*new),
+            _ => None,
+        }));
+        shown = *response.transform.bounds();
+    });
+    // The event reports the bounds after the limits, as shown:
+    assert_eq!(fitted.last(), Some(&shown));
+    assert_eq!(shown.range_x(), 3.0..=7.0);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_log_axis_scale() {
+    let mut harness = PlotHarness::new(egui::vec2(200.0, 100.0));
+    let mut transform = None;
+    harness.run(|ui| {
+        let response = Plot::new("log_axis")
+            .y_axis_scale(AxisScale::Log)
+            .auto_bounds_margin(0.0, 0.0)
+            .show(ui, |plot_ui| {
+                plot_ui.line(Line::new(
+                    "powers",
+                    vec![[0.0, 1.0], [1.0, 10.0], [2.0, 100.0]],
+                ));
+            });
+        transform = Some(response.transform);
+    });
+    let transform = transform.unwrap();
+    // The auto-fit bounds are the values, spread by decades on the screen:
+    assert_eq!(transform.bounds().range_y(), 1.0..=100.0);
+    let frame = transform.frame();
+    assert_eq!(transform.position_from_point_y(10.0), frame.center().y);
+    let value = transform.value_from_position(frame.center());
+    assert!((value.y - 10.0).abs() < 1e-9);
+}
+
+#[cfg(feature = "testing")]
+#[test]
+fn test_legend_right_layout() {
+    let mut harness = PlotHarness::new(egui::vec2(300.0, 200.0));
+    let mut show = |name: &str| {
+        let mut widths = Vec::new();
+        let mut room = 0.0;
+        harness.run(|ui| {
+            let font_id = egui::TextStyle::Body.resolve(ui.style());
+            let label = ui.fonts(|f| f.layout_no_wrap(name.into(), font_id, Color32::WHITE));
+            let response = Plot::new("legend_right")
+                .legend(Legend::default().placement(LegendPlacement::Right))
+                .show(ui, |plot_ui| {
+                    plot_ui.line(Line::new(name, vec![[0.0, 0.0], [1.0, 1.0]]));
+                });
+            let frame = response.transform.frame();
+            if !ui.ctx().will_discard() {
+                widths.push(frame.width());
+            }
+            room = ui.max_rect().right() - frame.right() - label.size().x;
+        });
+        assert!(room > 0.0, "the legend overlaps the plot");
+        widths
+    };
+
+    // The pass with the old room for the legend is discarded, so the plot doesn't jump:
+    let short = show("a");
+    let long = show("a much longer name");
+    assert!(long.iter().all(|&width| width == long[0]), "{long:?}");
+    assert!(long[0] < short[0]);
+}
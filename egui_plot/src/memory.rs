@@ -1,8 +1,11 @@
 use std::collections::BTreeMap;
 
-use egui::{Context, Id, Pos2, Vec2b};
+use egui::{Context, Id, Pos2, Vec2, Vec2b};
 
-use crate::{PlotBounds, PlotTransform};
+use crate::{
+    Corner, PlotBounds, PlotMenuContext, PlotTransform, SeriesStyle, data_table::Column,
+    edit::ItemDrag, legend::SeriesStats, transform::BoundsAnimation, view_history::ViewHistory,
+};
 
 /// Information about the plot that has to persist between frames.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
@@ -20,18 +23,110 @@ pub struct PlotMemory {
     /// Which items _not_ to show?
     pub hidden_items: ahash::HashSet<Id>,
 
+    /// The items selected by clicking them, in the order they were selected in, see
+    /// [`crate::Plot::select_items`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub selected_items: Vec<Id>,
+
+    /// The items brought to the front with [`crate::PlotUi::bring_selected_to_front`], the last
+    /// one on top.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) raised_items: Vec<Id>,
+
     /// The transform from last frame.
     pub(crate) transform: PlotTransform,
 
     /// Allows to remember the first click position when performing a boxed zoom
     pub(crate) last_click_pos_for_zoom: Option<Pos2>,
 
+    /// The item and index of the point currently being dragged in edit mode.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dragged_point: Option<(Id, usize)>,
+
+    /// The line or span currently being dragged.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dragged_item: Option<ItemDrag>,
+
+    /// The t₀ of the relative time axis, once set, see [`crate::Plot::time_origin`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) time_origin: Option<f64>,
+
+    /// Styles edited from the legend, applied to the items every frame.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) series_styles: ahash::HashMap<Id, SeriesStyle>,
+
+    /// Corner picked for a legend with [`crate::Legend::smart_corner`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_corner: Option<Corner>,
+
+    /// Whether the legend was hidden from the context menu.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_hidden: bool,
+
+    /// Where the context menu was opened last.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) menu_context: Option<PlotMenuContext>,
+
+    /// Statistics of the visible items shown in the legend, see [`crate::Legend::show_stats`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) legend_stats: Option<ahash::HashMap<Id, SeriesStats>>,
+
+    /// The last [`crate::Plot::data_version`], hashed.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) data_version: Option<u64>,
+
+    /// When the user last panned or zoomed, see [`crate::Plot::idle_detail`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) last_interaction: Option<f64>,
+
+    /// The animation started with [`crate::PlotUi::animate_bounds_to`], if still running.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) bounds_animation: Option<BoundsAnimation>,
+
+    /// The views to go back to with [`crate::PlotUi::undo_view`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) view_history: ViewHistory,
+
+    /// Column and direction (ascending if `true`) the data table is sorted by.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) data_table_sort: Option<(Column, bool)>,
+
+    /// Index of the unit each axis is shown in, see [`crate::Plot::x_units`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) units: [usize; 2],
+
+    /// The item and index of the point selected with the keyboard, see [`crate::PlotKeymap`].
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) keyboard_point: Option<(Id, usize)>,
+
+    /// The item hovered last, which the keyboard steps through.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) last_hovered_item: Option<Id>,
+
+    /// Whether the t₀ marker is being dragged.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) dragging_time_origin: bool,
+
+    /// The axis (0 for x, 1 for y) being zoomed by dragging, and where the drag started.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) axis_zoom_drag: Option<(usize, Pos2)>,
+
+    /// The region of the [`crate::Inset`], once the user moved it.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub(crate) inset_region: Option<PlotBounds>,
+
     /// The thickness of each of the axes the previous frame.
     ///
     /// This is used in the next frame to make the axes thicker
     /// in order to fit the labels, if necessary.
     pub(crate) x_axis_thickness: BTreeMap<usize, f32>,
     pub(crate) y_axis_thickness: BTreeMap<usize, f32>,
+
+    /// Size of a legend placed outside the plot frame the previous frame.
+    ///
+    /// Like the axis thickness, this is used to reserve room for it in the next frame.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) legend_size: Vec2,
 }
 
 impl PlotMemory {
@@ -56,6 +151,31 @@ impl PlotMemory {
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.transform.set_bounds(bounds);
     }
+
+    /// Update the selection for a click on `item`, or next to any item if `None`.
+    ///
+    /// With `toggle` (e.g. with ctrl held) the item is added to or removed from the selection,
+    /// otherwise it replaces it.
+    pub(crate) fn click_select(&mut self, item: Option<Id>, toggle: bool) {
+        match (item, toggle) {
+            (Some(item), true) => {
+                if let Some(index) = self.selected_items.iter().position(|id| *id == item) {
+                    self.selected_items.remove(index);
+                } else {
+                    self.selected_items.push(item);
+                }
+            }
+            (Some(item), false) => self.selected_items = vec![item],
+            (None, true) => {}
+            (None, false) => self.selected_items.clear(),
+        }
+    }
+
+    /// Draw `item` above the others, and the ones brought to the front before.
+    pub(crate) fn bring_to_front(&mut self, item: Id) {
+        self.raised_items.retain(|id| *id != item);
+        self.raised_items.push(item);
+    }
 }
 
 #[cfg(feature = "serde")]
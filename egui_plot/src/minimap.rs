@@ -0,0 +1,217 @@
+//! An overview of all the data next to the plot, with a brush to move the x window of the plot.
+
+use std::ops::RangeInclusive;
+
+use egui::{CursorIcon, Rect, Response, Shape, Stroke, Ui, Vec2b, pos2};
+
+use crate::{
+    PlotBounds, PlotItem, PlotTransform, VPlacement, items::geom_helpers::decimate_columns,
+};
+
+/// How close (in points) the pointer must be to an edge of the brush to resize it.
+const EDGE_GRAB: f32 = 4.0;
+
+/// Space between the minimap and the rest of the plot.
+pub(crate) const MINIMAP_GAP: f32 = 4.0;
+
+/// A miniature of the whole plot above or below it, with a brush showing the x window of the plot.
+///
+/// Dragging the brush pans the plot, dragging its edges zooms it, and clicking elsewhere
+/// centers the plot there. The standard way to navigate long recordings. Add it with
+/// [`crate::Plot::minimap`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Minimap {
+    pub(crate) placement: VPlacement,
+    pub(crate) height: f32,
+}
+
+impl Default for Minimap {
+    fn default() -> Self {
+        Self {
+            placement: VPlacement::Bottom,
+            height: 40.0,
+        }
+    }
+}
+
+impl Minimap {
+    /// A minimap below the plot.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to show the minimap above or below the plot area. Default: `VPlacement::Bottom`.
+    #[inline]
+    pub fn placement(mut self, placement: VPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// Height of the minimap in points. Default: `40.0`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height.max(8.0);
+        self
+    }
+}
+
+/// The part of the brush being dragged.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum BrushDrag {
+    Window,
+    Start,
+    End,
+}
+
+/// The transform of the minimap in `rect`, showing all of `items` and the `view` of the plot.
+pub(crate) fn overview(
+    rect: Rect,
+    items: &[Box<dyn PlotItem + '_>],
+    view: &PlotBounds,
+) -> PlotTransform {
+    let mut bounds = PlotBounds::NOTHING;
+    for item in items {
+        let item_bounds = item.bounds();
+        if item_bounds.is_finite_x() {
+            bounds.merge_x(&item_bounds);
+        }
+        if item_bounds.is_finite_y() {
+            bounds.merge_y(&item_bounds);
+        }
+    }
+    bounds.merge_x(view);
+    bounds.add_relative_margin_y(egui::Vec2::splat(0.1));
+    PlotTransform::new(rect, bounds, Vec2b::FALSE)
+}
+
+/// Let the user drag the brush over the `view` of the plot in the `response` of a minimap or
+/// [`crate::XRangeSlider`], and return the new x range of the plot if it changed.
+pub(crate) fn brush_ui(
+    ui: &Ui,
+    response: &Response,
+    overview: &PlotTransform,
+    view: RangeInclusive<f64>,
+) -> Option<RangeInclusive<f64>> {
+    let rect = *overview.frame();
+    let id = response.id;
+    let (start, end) = (*view.start(), *view.end());
+    let [left, right] = [start, end].map(|x| overview.position_from_point_x(x));
+    let part_at = |x: f32| {
+        if (x - left).abs() <= EDGE_GRAB {
+            Some(BrushDrag::Start)
+        } else if (x - right).abs() <= EDGE_GRAB {
+            Some(BrushDrag::End)
+        } else if (left..=right).contains(&x) {
+            Some(BrushDrag::Window)
+        } else {
+            None
+        }
+    };
+
+    if let Some(pointer) = response.hover_pos() {
+        let icon = match part_at(pointer.x) {
+            Some(BrushDrag::Start | BrushDrag::End) => CursorIcon::ResizeHorizontal,
+            Some(BrushDrag::Window) if response.dragged() => CursorIcon::Grabbing,
+            Some(BrushDrag::Window) => CursorIcon::Grab,
+            None => CursorIcon::PointingHand,
+        };
+        ui.ctx().set_cursor_icon(icon);
+    }
+
+    // Jump to a click outside the brush, keeping the width of the window:
+    let centered_at = |x: f32| {
+        let center = overview.value_from_position(pos2(x, rect.center().y)).x;
+        let half_width = (end - start) / 2.0;
+        center - half_width..=center + half_width
+    };
+
+    if response.drag_started() {
+        let origin = ui.input(|i| i.pointer.press_origin());
+        let part = origin.and_then(|origin| part_at(origin.x));
+        ui.data_mut(|data| data.insert_temp(id, part.unwrap_or(BrushDrag::Window)));
+        if part.is_none() {
+            return origin.map(|origin| centered_at(origin.x));
+        }
+    }
+    if response.dragged() {
+        let part = ui
+            .data(|data| data.get_temp(id))
+            .unwrap_or(BrushDrag::Window);
+        let delta = response.drag_delta().x as f64 * overview.dvalue_dpos()[0];
+        if delta == 0.0 {
+            return None;
+        }
+        // Keep the window at least a few points wide:
+        let min_width = 2.0 * EDGE_GRAB as f64 * overview.dvalue_dpos()[0].abs();
+        return Some(match part {
+            BrushDrag::Window => start + delta..=end + delta,
+            BrushDrag::Start => (start + delta).min(end - min_width)..=end,
+            BrushDrag::End => start..=(end + delta).max(start + min_width),
+        });
+    }
+    if response.clicked() {
+        return response
+            .interact_pointer_pos()
+            .filter(|pointer| part_at(pointer.x).is_none())
+            .map(|pointer| centered_at(pointer.x));
+    }
+    None
+}
+
+/// Paint the minimap: the points of `items` as thin lines, with the brush over the `view` of
+/// the plot.
+pub(crate) fn paint(
+    ui: &Ui,
+    overview: &PlotTransform,
+    items: &[Box<dyn PlotItem + '_>],
+    view: RangeInclusive<f64>,
+) {
+    let rect = *overview.frame();
+    let painter = ui.painter().with_clip_rect(rect);
+    let visuals = ui.visuals();
+    painter.rect(
+        rect,
+        2,
+        visuals.extreme_bg_color,
+        visuals.widgets.noninteractive.bg_stroke,
+        egui::StrokeKind::Inside,
+    );
+
+    for item in items {
+        let points = item.geometry().points();
+        if points.len() < 2 {
+            continue;
+        }
+        let color = if item.color() == egui::Color32::TRANSPARENT {
+            visuals.text_color()
+        } else {
+            item.color()
+        };
+        let line = decimate_columns(
+            points
+                .iter()
+                .map(|point| overview.position_from_point(point)),
+        );
+        painter.add(Shape::line(line, Stroke::new(1.0, color)));
+    }
+
+    // Dim everything outside the brush:
+    let [left, right] = [*view.start(), *view.end()].map(|x| overview.position_from_point_x(x));
+    let dim = visuals.extreme_bg_color.gamma_multiply(0.6);
+    painter.rect_filled(
+        Rect::from_x_y_ranges(rect.left()..=left, rect.y_range()),
+        0,
+        dim,
+    );
+    painter.rect_filled(
+        Rect::from_x_y_ranges(right..=rect.right(), rect.y_range()),
+        0,
+        dim,
+    );
+    painter.rect_stroke(
+        Rect::from_x_y_ranges(left..=right, rect.y_range()),
+        2,
+        visuals.selection.stroke,
+        egui::StrokeKind::Inside,
+    );
+}
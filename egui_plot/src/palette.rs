@@ -0,0 +1,64 @@
+//! The colors given to items without one, see [`crate::Plot::palette`].
+
+use egui::{Color32, epaint::Hsva};
+
+/// The colors given in turn to the items added without a color, see [`crate::Plot::palette`]
+/// and [`crate::PlotUi::auto_color`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub enum Palette {
+    /// Hues spread by the golden ratio, so any number of items get distinct colors. Brighter
+    /// on dark backgrounds than on light ones.
+    #[default]
+    Hues,
+
+    /// The eight colors of Okabe and Ito, without black, told apart by people with any kind of
+    /// color blindness. Repeats after seven items.
+    OkabeIto,
+
+    /// The given colors, repeated once all are used. Falls back to [`Self::Hues`] if empty.
+    Custom(Vec<Color32>),
+}
+
+/// <https://jfly.uni-koeln.de/color/>, without the black.
+const OKABE_ITO: [Color32; 7] = [
+    Color32::from_rgb(0xE6, 0x9F, 0x00), // orange
+    Color32::from_rgb(0x56, 0xB4, 0xE9), // sky blue
+    Color32::from_rgb(0x00, 0x9E, 0x73), // bluish green
+    Color32::from_rgb(0xF0, 0xE4, 0x42), // yellow
+    Color32::from_rgb(0x00, 0x72, 0xB2), // blue
+    Color32::from_rgb(0xD5, 0x5E, 0x00), // vermillion
+    Color32::from_rgb(0xCC, 0x79, 0xA7), // reddish purple
+];
+
+impl Palette {
+    /// The color of the item `index`, on a dark background if `dark_mode`.
+    pub fn color(&self, index: usize, dark_mode: bool) -> Color32 {
+        match self {
+            Self::OkabeIto => OKABE_ITO[index % OKABE_ITO.len()],
+            Self::Custom(colors) if !colors.is_empty() => colors[index % colors.len()],
+            Self::Hues | Self::Custom(_) => {
+                let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
+                let h = index as f32 * golden_ratio;
+                // TODO(emilk): OkLab or some other perspective color space
+                if dark_mode {
+                    Hsva::new(h, 0.75, 0.85, 1.0).into()
+                } else {
+                    Hsva::new(h, 0.85, 0.5, 1.0).into()
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_palette() {
+    let custom = Palette::Custom(vec![Color32::RED, Color32::GREEN]);
+    assert_eq!(custom.color(3, false), Color32::GREEN);
+    assert_eq!(Palette::OkabeIto.color(7, true), OKABE_ITO[0]);
+    assert_eq!(
+        Palette::Custom(Vec::new()).color(2, true),
+        Palette::Hues.color(2, true)
+    );
+    assert_ne!(Palette::Hues.color(1, true), Palette::Hues.color(1, false));
+}
@@ -0,0 +1,191 @@
+//! Fill patterns drawn on top of shaded areas.
+
+use egui::{Color32, Pos2, Rect, Shape, Stroke, Vec2, pos2, vec2};
+
+/// Smallest spacing of a pattern, to keep the number of shapes reasonable.
+const MIN_SPACING: f32 = 2.0;
+
+/// A pattern drawn on top of the fill of a span, band or polygon.
+///
+/// Patterns keep overlapping regions distinguishable in grayscale exports and for color-blind
+/// users. They are aligned to the screen, so neighboring areas with the same pattern line up.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FillPattern {
+    /// Diagonal lines rising to the right, `spacing` points apart.
+    Hatch { spacing: f32 },
+
+    /// Diagonal lines in both directions, `spacing` points apart.
+    CrossHatch { spacing: f32 },
+
+    /// A grid of dots, `spacing` points apart.
+    Dots { spacing: f32 },
+}
+
+impl FillPattern {
+    /// [`Self::Hatch`] with lines 8 points apart.
+    pub fn hatch() -> Self {
+        Self::Hatch { spacing: 8.0 }
+    }
+
+    /// [`Self::CrossHatch`] with lines 8 points apart.
+    pub fn cross_hatch() -> Self {
+        Self::CrossHatch { spacing: 8.0 }
+    }
+
+    /// [`Self::Dots`] 6 points apart.
+    pub fn dots() -> Self {
+        Self::Dots { spacing: 6.0 }
+    }
+
+    /// Draw the pattern inside `polygon` (in screen coordinates, filled with the even-odd rule),
+    /// as far as it is inside `clip`.
+    pub(crate) fn shapes(
+        &self,
+        polygon: &[Pos2],
+        clip: Rect,
+        color: Color32,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let bounds = Rect::from_points(polygon).intersect(clip);
+        if polygon.len() < 3 || !bounds.is_positive() {
+            return;
+        }
+        let stroke = Stroke::new(1.0, color);
+        match *self {
+            Self::Hatch { spacing } => {
+                hatch(polygon, bounds, vec2(1.0, -1.0), spacing, stroke, shapes);
+            }
+            Self::CrossHatch { spacing } => {
+                hatch(polygon, bounds, vec2(1.0, -1.0), spacing, stroke, shapes);
+                hatch(polygon, bounds, vec2(1.0, 1.0), spacing, stroke, shapes);
+            }
+            Self::Dots { spacing } => {
+                let spacing = spacing.max(MIN_SPACING);
+                let mut y = (bounds.top() / spacing).ceil() * spacing;
+                while y <= bounds.bottom() {
+                    let mut x = (bounds.left() / spacing).ceil() * spacing;
+                    while x <= bounds.right() {
+                        if contains(polygon, pos2(x, y)) {
+                            shapes.push(Shape::circle_filled(pos2(x, y), 1.0, color));
+                        }
+                        x += spacing;
+                    }
+                    y += spacing;
+                }
+            }
+        }
+    }
+}
+
+/// Parallel diagonal lines with `direction` through the part of `polygon` in `bounds`.
+fn hatch(
+    polygon: &[Pos2],
+    bounds: Rect,
+    direction: Vec2,
+    spacing: f32,
+    stroke: Stroke,
+    shapes: &mut Vec<Shape>,
+) {
+    // The lines are `x - slope * y = c`, `spacing` apart:
+    let slope = direction.x / direction.y;
+    let step = spacing.max(MIN_SPACING) * std::f32::consts::SQRT_2;
+    let corners = [
+        bounds.left_top(),
+        bounds.right_top(),
+        bounds.left_bottom(),
+        bounds.right_bottom(),
+    ]
+    .map(|p| p.x - slope * p.y);
+    let min = corners.iter().copied().fold(f32::INFINITY, f32::min);
+    let max = corners.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+
+    let mut c = (min / step).ceil() * step;
+    while c <= max {
+        let origin = pos2(c, 0.0);
+        for [start, end] in clip_line(polygon, origin, direction) {
+            shapes.push(Shape::line_segment(
+                [origin + start * direction, origin + end * direction],
+                stroke,
+            ));
+        }
+        c += step;
+    }
+}
+
+/// The parts `[t0, t1]` of the line `origin + t * direction` inside `polygon`.
+fn clip_line(polygon: &[Pos2], origin: Pos2, direction: Vec2) -> Vec<[f32; 2]> {
+    let cross = |a: Vec2, b: Vec2| a.x * b.y - a.y * b.x;
+    let mut crossings: Vec<f32> = Vec::new();
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        let edge = b - a;
+        let denom = cross(direction, edge);
+        if denom.abs() <= f32::EPSILON {
+            continue; // parallel
+        }
+        let w = a - origin;
+        let s = cross(w, direction) / denom;
+        // Half-open, so a line through a vertex crosses only one of its edges:
+        if (0.0..1.0).contains(&s) {
+            crossings.push(cross(w, edge) / denom);
+        }
+    }
+    crossings.sort_by(f32::total_cmp);
+    crossings
+        .chunks_exact(2)
+        .map(|pair| [pair[0], pair[1]])
+        .collect()
+}
+
+/// Whether `point` is inside `polygon`, with the even-odd rule.
+fn contains(polygon: &[Pos2], point: Pos2) -> bool {
+    let mut inside = false;
+    for (i, &a) in polygon.iter().enumerate() {
+        let b = polygon[(i + 1) % polygon.len()];
+        if (a.y > point.y) != (b.y > point.y) {
+            let x = a.x + (point.y - a.y) / (b.y - a.y) * (b.x - a.x);
+            if point.x < x {
+                inside = !inside;
+            }
+        }
+    }
+    inside
+}
+
+/// The corners of `rect`, as a polygon.
+pub(crate) fn rect_polygon(rect: Rect) -> [Pos2; 4] {
+    [
+        rect.left_top(),
+        rect.right_top(),
+        rect.right_bottom(),
+        rect.left_bottom(),
+    ]
+}
+
+#[test]
+fn test_clip_line() {
+    let square = rect_polygon(Rect::from_min_max(pos2(0.0, 0.0), pos2(10.0, 10.0)));
+    assert_eq!(
+        clip_line(&square, pos2(-5.0, 5.0), vec2(1.0, 0.0)),
+        [[5.0, 15.0]]
+    );
+    assert!(clip_line(&square, pos2(-5.0, 20.0), vec2(1.0, 0.0)).is_empty());
+
+    // A U shape is crossed twice:
+    let u = [
+        pos2(0.0, 0.0),
+        pos2(2.0, 0.0),
+        pos2(2.0, 8.0),
+        pos2(8.0, 8.0),
+        pos2(8.0, 0.0),
+        pos2(10.0, 0.0),
+        pos2(10.0, 10.0),
+        pos2(0.0, 10.0),
+    ];
+    assert_eq!(
+        clip_line(&u, pos2(0.0, 5.0), vec2(1.0, 0.0)),
+        [[0.0, 2.0], [8.0, 10.0]]
+    );
+    assert!(contains(&u, pos2(1.0, 5.0)));
+    assert!(!contains(&u, pos2(5.0, 5.0)));
+}
@@ -0,0 +1,296 @@
+//! A matrix of plots with shared axes, the "facet grid" or subplot pattern.
+
+use egui::{Id, NumExt as _, Rect, Sense, Ui, UiBuilder, Vec2, Vec2b, pos2, vec2};
+
+use crate::{Plot, PlotMemory};
+
+/// The thickness of an axis before it has been shown, see [`crate::AxisHints`].
+const DEFAULT_AXIS_THICKNESS: f32 = 14.0;
+
+/// The position of a plot in a [`PlotGrid`], counted from the top left.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GridCell {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// Lays out `rows` × `cols` plots with shared axes.
+///
+/// The plots in a column share their x axis and the plots in a row share their y axis, so
+/// panning or zooming one moves its neighbors along. Only the outer axes are shown: the x axis
+/// below the bottom row and the y axis left of the first column. The frames of all plots have
+/// the same size, as long as the plots have no titles or legends outside the frame.
+///
+/// Each plot is handed to the closure of [`Self::show`] already configured, to add its items
+/// and any further options.
+///
+/// # Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Line, PlotGrid};
+///
+/// PlotGrid::new("facets", 2, 3).show(ui, |ui, cell, plot| {
+///     let scale = (cell.row * 3 + cell.col + 1) as f64;
+///     plot.show(ui, |plot_ui| {
+///         plot_ui.line(Line::new("sine", vec![[0.0, 0.0], [1.0, scale], [2.0, 0.0]]));
+///     });
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct PlotGrid {
+    id: Id,
+    rows: usize,
+    cols: usize,
+    share: Vec2b,
+    link_cursor: bool,
+    spacing: Option<Vec2>,
+    width: Option<f32>,
+    height: Option<f32>,
+}
+
+impl PlotGrid {
+    /// A grid of `rows` × `cols` plots. The plots get ids derived from `id_salt`.
+    pub fn new(id_salt: impl std::hash::Hash, rows: usize, cols: usize) -> Self {
+        Self {
+            id: Id::new(id_salt),
+            rows,
+            cols,
+            share: Vec2b::TRUE,
+            link_cursor: true,
+            spacing: None,
+            width: None,
+            height: None,
+        }
+    }
+
+    /// Whether the plots in a column share the x axis, and the plots in a row the y axis.
+    /// Default: `true` for both.
+    ///
+    /// An axis that isn't shared is shown on every plot.
+    #[inline]
+    pub fn share_axes(mut self, share: impl Into<Vec2b>) -> Self {
+        self.share = share.into();
+        self
+    }
+
+    /// Show the pointer's x position on all plots of its column, if they share the x axis.
+    /// Default: `true`.
+    #[inline]
+    pub fn link_cursor(mut self, link: bool) -> Self {
+        self.link_cursor = link;
+        self
+    }
+
+    /// Space between the plot frames. Default: [`egui::style::Spacing::item_spacing`].
+    #[inline]
+    pub fn spacing(mut self, spacing: impl Into<Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    /// Width of the whole grid. By default the grid fills the ui it is in.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the whole grid. By default the grid fills the ui it is in.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    /// The id of the plot in `cell`, e.g. for [`PlotMemory::load`].
+    pub fn plot_id(&self, cell: GridCell) -> Id {
+        self.id.with((cell.row, cell.col))
+    }
+
+    /// Show the plots, row by row. `add_cell` gets each configured [`Plot`] and shows it in the
+    /// given ui.
+    ///
+    /// Returns what `add_cell` returned, row by row.
+    pub fn show<'a, R>(
+        self,
+        ui: &mut Ui,
+        mut add_cell: impl FnMut(&mut Ui, GridCell, Plot<'a>) -> R,
+    ) -> Vec<R> {
+        let available = ui.available_size_before_wrap();
+        let size = vec2(
+            self.width.unwrap_or(available.x),
+            self.height.unwrap_or(available.y),
+        );
+        let (rect, _) = ui.allocate_exact_size(size, Sense::hover());
+        let spacing = self.spacing.unwrap_or(ui.spacing().item_spacing);
+
+        // Make room for the outer axes, as thick as they were the last frame:
+        let thickness = |cell: GridCell, axis: usize| {
+            PlotMemory::load(ui.ctx(), self.plot_id(cell))
+                .and_then(|mem| {
+                    let thickness = if axis == 0 {
+                        &mem.x_axis_thickness
+                    } else {
+                        &mem.y_axis_thickness
+                    };
+                    thickness.get(&0).copied()
+                })
+                .unwrap_or_default()
+                .max(DEFAULT_AXIS_THICKNESS)
+        };
+        let cells = (0..self.rows * self.cols).map(|i| GridCell {
+            row: i / self.cols,
+            col: i % self.cols,
+        });
+        let x_axis_height = cells
+            .clone()
+            .map(|cell| thickness(cell, 0))
+            .fold(0.0, f32::max);
+        let y_axis_width = cells.map(|cell| thickness(cell, 1)).fold(0.0, f32::max);
+        let axes = vec2(y_axis_width, x_axis_height);
+        let every_axis = Vec2b::new(!self.share.x, !self.share.y);
+        let rects = cell_rects(rect, [self.rows, self.cols], spacing, axes, every_axis);
+        let bottom_row = self.rows.saturating_sub(1);
+
+        let mut results = Vec::with_capacity(rects.len());
+        for (i, cell_rect) in rects.into_iter().enumerate() {
+            let cell = GridCell {
+                row: i / self.cols,
+                col: i % self.cols,
+            };
+            let show_axes = Vec2b::new(
+                !self.share.x || cell.row == bottom_row,
+                !self.share.y || cell.col == 0,
+            );
+            let mut plot = Plot::new(self.plot_id(cell))
+                .id(self.plot_id(cell))
+                .width(cell_rect.width())
+                .height(cell_rect.height())
+                .show_axes(show_axes)
+                .x_axis_min_height(x_axis_height)
+                .y_axis_min_width(y_axis_width);
+            if self.share.x {
+                plot = plot.link_axis(self.id.with(("x", cell.col)), [true, false]);
+                if self.link_cursor {
+                    plot = plot.link_cursor(self.id.with(("cursor", cell.col)), [true, false]);
+                }
+            }
+            if self.share.y {
+                plot = plot.link_axis(self.id.with(("y", cell.row)), [false, true]);
+            }
+
+            let mut child = ui.new_child(
+                UiBuilder::new()
+                    .id_salt((cell.row, cell.col))
+                    .max_rect(cell_rect),
+            );
+            results.push(add_cell(&mut child, cell, plot));
+        }
+        results
+    }
+}
+
+/// The rects of the plots, row by row, so that their frames have the same size.
+///
+/// The first column is wider by the y axis `axes.x` and the bottom row taller by the x axis
+/// `axes.y`, or all of them if `every_axis` is set for that axis.
+fn cell_rects(
+    rect: Rect,
+    [rows, cols]: [usize; 2],
+    spacing: Vec2,
+    axes: Vec2,
+    every_axis: Vec2b,
+) -> Vec<Rect> {
+    // The start and end of each of `n` cells along one direction:
+    let spans = |start: f32, length: f32, n: usize, spacing: f32, axis: f32, every: bool| {
+        let n_axes = if every { n } else { 1 };
+        let frame = ((length - axis * n_axes as f32 - spacing * n.saturating_sub(1) as f32)
+            / n as f32)
+            .at_least(1.0);
+        let mut pos = start;
+        (0..n)
+            .map(|i| {
+                let has_axis = every || i == 0;
+                let span = (pos, pos + frame + if has_axis { axis } else { 0.0 });
+                pos = span.1 + spacing;
+                span
+            })
+            .collect::<Vec<_>>()
+    };
+    if rows == 0 || cols == 0 {
+        return Vec::new();
+    }
+    let xs = spans(
+        rect.left(),
+        rect.width(),
+        cols,
+        spacing.x,
+        axes.x,
+        every_axis.y,
+    );
+    // The x axis is below the plots, so lay out the rows bottom up:
+    let mut ys = spans(
+        -rect.bottom(),
+        rect.height(),
+        rows,
+        spacing.y,
+        axes.y,
+        every_axis.x,
+    );
+    ys.reverse();
+
+    ys.iter()
+        .flat_map(|&(bottom, top)| {
+            xs.iter().map(move |&(left, right)| {
+                Rect::from_min_max(pos2(left, -top), pos2(right, -bottom))
+            })
+        })
+        .collect()
+}
+
+#[test]
+fn test_cell_rects() {
+    let rect = Rect::from_min_size(pos2(0.0, 0.0), vec2(230.0, 130.0));
+    let rects = cell_rects(
+        rect,
+        [2, 2],
+        Vec2::splat(10.0),
+        vec2(20.0, 20.0),
+        Vec2b::FALSE,
+    );
+    assert_eq!(rects.len(), 4);
+
+    // The first column and the bottom row include the outer axes:
+    assert_eq!(
+        rects[0],
+        Rect::from_min_max(pos2(0.0, 0.0), pos2(120.0, 50.0))
+    );
+    assert_eq!(
+        rects[1],
+        Rect::from_min_max(pos2(130.0, 0.0), pos2(230.0, 50.0))
+    );
+    assert_eq!(
+        rects[2],
+        Rect::from_min_max(pos2(0.0, 60.0), pos2(120.0, 130.0))
+    );
+    assert_eq!(
+        rects[3],
+        Rect::from_min_max(pos2(130.0, 60.0), pos2(230.0, 130.0))
+    );
+
+    // Every plot shows its own y axis:
+    let rects = cell_rects(
+        rect,
+        [2, 2],
+        Vec2::splat(10.0),
+        vec2(20.0, 20.0),
+        Vec2b::new(false, true),
+    );
+    assert_eq!(
+        rects[1],
+        Rect::from_min_max(pos2(120.0, 0.0), pos2(230.0, 50.0))
+    );
+
+    assert!(cell_rects(rect, [0, 2], Vec2::ZERO, Vec2::ZERO, Vec2b::FALSE).is_empty());
+}
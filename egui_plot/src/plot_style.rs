@@ -0,0 +1,114 @@
+//! Colors and sizes of a single plot, independent of the [`egui::Visuals`], see
+//! [`crate::Plot::style`].
+
+use egui::{Color32, Context, Id, Stroke, Style, containers::Tooltip};
+
+/// Overrides of the look of a plot, so plots next to each other can have their own theme
+/// without changing the [`egui::Visuals`] of the whole app. See [`crate::Plot::style`].
+///
+/// Each setting left `None` follows the visuals of the [`egui::Ui`] the plot is in.
+///
+/// ```
+/// # use egui_plot::PlotStyle;
+/// let dark = PlotStyle {
+///     background: Some(egui::Color32::from_gray(20)),
+///     grid_color: Some(egui::Color32::from_rgb(60, 90, 60)),
+///     axis_text_color: Some(egui::Color32::LIGHT_GREEN),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PlotStyle {
+    /// Fill of the plot frame. Default: [`egui::Visuals::extreme_bg_color`].
+    pub background: Option<Color32>,
+
+    /// Outline of the plot frame. Default: the stroke of non-interactive widgets.
+    pub frame_stroke: Option<Stroke>,
+
+    /// Color of the strongest grid lines; the finer ones fade out from it. Default: the text
+    /// color.
+    pub grid_color: Option<Color32>,
+
+    /// Color of the tick labels and the labels of the axes. Default: the text color.
+    pub axis_text_color: Option<Color32>,
+
+    /// Font size of the tick labels and the labels of the axes, in points.
+    pub axis_text_size: Option<f32>,
+
+    /// Fill of the tooltips. Default: [`egui::Visuals::window_fill`].
+    pub tooltip_fill: Option<Color32>,
+
+    /// Outline of the tooltips. Default: [`egui::Visuals::window_stroke`].
+    pub tooltip_stroke: Option<Stroke>,
+
+    /// Color of the text in the tooltips. Default: the text color.
+    pub tooltip_text_color: Option<Color32>,
+}
+
+impl PlotStyle {
+    /// Apply the overrides of the axes to `style`, the style the axes are shown with.
+    pub(crate) fn apply_to_axes(&self, style: &mut Style) {
+        if let Some(color) = self.axis_text_color {
+            style.visuals.override_text_color = Some(color);
+        }
+        if let Some(size) = self.axis_text_size {
+            for font in style.text_styles.values_mut() {
+                font.size = size;
+            }
+        }
+    }
+
+    /// Remember the style of the plot with the response `id` this frame, for its tooltips.
+    pub(crate) fn store(self, ctx: &Context, id: Id) {
+        ctx.data_mut(|d| d.insert_temp(id.with("plot_style"), self));
+    }
+
+    /// `tooltip` with the overrides of the tooltips of the plot with the response `id`.
+    pub(crate) fn style_tooltip<'a>(
+        ctx: &Context,
+        id: Id,
+        mut tooltip: Tooltip<'a>,
+    ) -> Tooltip<'a> {
+        let Some(style) = ctx.data(|d| d.get_temp::<Self>(id.with("plot_style"))) else {
+            return tooltip;
+        };
+        let Self {
+            tooltip_fill,
+            tooltip_stroke,
+            tooltip_text_color,
+            ..
+        } = style;
+        if tooltip_fill.is_none() && tooltip_stroke.is_none() && tooltip_text_color.is_none() {
+            return tooltip;
+        }
+        tooltip.popup = tooltip.popup.style(move |style: &mut Style| {
+            if let Some(fill) = tooltip_fill {
+                style.visuals.window_fill = fill;
+            }
+            if let Some(stroke) = tooltip_stroke {
+                style.visuals.window_stroke = stroke;
+            }
+            if let Some(color) = tooltip_text_color {
+                style.visuals.override_text_color = Some(color);
+            }
+        });
+        tooltip
+    }
+}
+
+#[test]
+fn test_apply_to_axes() {
+    let mut style = Style::default();
+    PlotStyle::default().apply_to_axes(&mut style);
+    assert_eq!(style.visuals.override_text_color, None);
+
+    PlotStyle {
+        axis_text_color: Some(Color32::RED),
+        axis_text_size: Some(9.0),
+        ..Default::default()
+    }
+    .apply_to_axes(&mut style);
+    assert_eq!(style.visuals.text_color(), Color32::RED);
+    assert!(style.text_styles.values().all(|font| font.size == 9.0));
+}
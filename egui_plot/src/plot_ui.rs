@@ -1,8 +1,11 @@
 use std::ops::RangeInclusive;
 
-use egui::{Color32, Pos2, Response, Vec2, Vec2b, epaint::Hsva};
+use egui::{Color32, Pos2, Response, Vec2, Vec2b};
 
-use crate::{PlotBounds, PlotItem, PlotPoint, PlotTransform, action::ActionQueue};
+use crate::{
+    Palette, PlotBounds, PlotItem, PlotPoint, PlotTransform, action::ActionQueue,
+    items::BarAnimation,
+};
 
 #[allow(unused_imports)] // for links in docstrings
 use crate::Plot;
@@ -13,10 +16,22 @@ pub struct PlotUi<'a> {
     pub(crate) ctx: egui::Context,
     pub(crate) actions: ActionQueue<Box<dyn PlotItem + 'a>>,
     pub(crate) next_auto_color_idx: usize,
+
+    /// The items selected the last frame, see [`Plot::select_items`].
+    pub(crate) selected_items: Vec<egui::Id>,
+    pub(crate) palette: Palette,
+
+    /// Whether the plot is on a dark background, to pick the colors of the [`Palette`] for.
+    pub(crate) dark_mode: bool,
     pub(crate) last_plot_transform: PlotTransform,
     pub(crate) last_auto_bounds: Vec2b,
     pub(crate) response: Response,
     pub(crate) called_once: bool,
+    pub(crate) allow_zoom: Vec2b,
+    pub(crate) allow_drag: Vec2b,
+    pub(crate) allow_scroll: Vec2b,
+    pub(crate) allow_boxed_zoom: Vec2b,
+    pub(crate) allow_axis_zoom_drag: Vec2b,
 }
 
 impl<'a> PlotUi<'a> {
@@ -30,12 +45,24 @@ impl<'a> PlotUi<'a> {
         self.called_once = true;
         first
     }
-    fn auto_color(&mut self) -> Color32 {
+
+    /// The next color of the [`Plot::palette`], as given to the items added without a color.
+    ///
+    /// Use this to color something else consistently with the items, e.g. a label matching
+    /// the line added next:
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Line, Plot};
+    /// Plot::new("my_plot").show(ui, |plot_ui| {
+    ///     let color = plot_ui.auto_color();
+    ///     plot_ui.line(Line::new("signal", vec![[0.0, 0.0], [1.0, 1.0]]).color(color));
+    /// });
+    /// # });
+    /// ```
+    pub fn auto_color(&mut self) -> Color32 {
         let i = self.next_auto_color_idx;
         self.next_auto_color_idx += 1;
-        let golden_ratio = (5.0_f32.sqrt() - 1.0) / 2.0; // 0.61803398875
-        let h = i as f32 * golden_ratio;
-        Hsva::new(h, 0.85, 0.5, 1.0).into() // TODO(emilk): OkLab or some other perspective color space
+        self.palette.color(i, self.dark_mode)
     }
 
     pub fn ctx(&self) -> &egui::Context {
@@ -65,6 +92,29 @@ impl<'a> PlotUi<'a> {
         self.actions.set_bounds_y(range.into());
     }
 
+    /// Move smoothly from the current bounds to `bounds` over `duration`, easing in and out.
+    ///
+    /// Every frame of the animation is reported as
+    /// [`PlotEvent::BoundsChanged`](crate::PlotEvent::BoundsChanged) with the
+    /// [`Animation`](crate::BoundsChangeCause::Animation) cause. Panning or zooming
+    /// stops it where it is.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Plot, PlotBounds};
+    /// let jump_to_event = ui.button("Jump to event").clicked();
+    /// Plot::new("events").show(ui, |plot_ui| {
+    ///     if jump_to_event {
+    ///         let event = PlotBounds::from_min_max([90.0, -1.0], [110.0, 1.0]);
+    ///         plot_ui.animate_bounds_to(event, std::time::Duration::from_millis(400));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn animate_bounds_to(&mut self, bounds: PlotBounds, duration: std::time::Duration) {
+        self.actions.animate_bounds(bounds, duration);
+    }
+
     /// Move the plot bounds. Can be useful for implementing alternative plot navigation methods.
     pub fn translate_bounds(&mut self, delta_pos: Vec2) {
         self.actions.translate(delta_pos);
@@ -80,6 +130,205 @@ impl<'a> PlotUi<'a> {
     pub fn set_auto_bounds(&mut self, auto_bounds: impl Into<Vec2b>) {
         self.actions.set_auto_bounds(auto_bounds.into());
     }
+    /// Whether to allow zooming in the plot this frame, overriding [`Plot::allow_zoom`].
+    ///
+    /// Like the other `set_allow_*` methods, this takes effect for the pointer and keyboard input
+    /// of the current frame, so it can follow application state:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::Plot;
+    /// let mut following = true;
+    /// ui.checkbox(&mut following, "Follow live data");
+    /// Plot::new("live").show(ui, |plot_ui| {
+    ///     // Allow panning and zooming x while following, but lock y completely:
+    ///     plot_ui.set_allow_drag([!following, false]);
+    ///     plot_ui.set_allow_zoom([!following, false]);
+    ///     plot_ui.set_allow_scroll([!following, false]);
+    ///     plot_ui.set_allow_boxed_zoom([!following, false]);
+    /// });
+    /// # });
+    /// ```
+    pub fn set_allow_zoom(&mut self, on: impl Into<Vec2b>) {
+        self.allow_zoom = on.into();
+    }
+
+    /// Whether to allow dragging in the plot to move the bounds this frame, overriding
+    /// [`Plot::allow_drag`].
+    pub fn set_allow_drag(&mut self, on: impl Into<Vec2b>) {
+        self.allow_drag = on.into();
+    }
+
+    /// Whether to allow scrolling in the plot this frame, overriding [`Plot::allow_scroll`].
+    pub fn set_allow_scroll(&mut self, on: impl Into<Vec2b>) {
+        self.allow_scroll = on.into();
+    }
+
+    /// Whether to allow box zooming this frame, overriding [`Plot::allow_boxed_zoom`].
+    pub fn set_allow_boxed_zoom(&mut self, on: impl Into<Vec2b>) {
+        self.allow_boxed_zoom = on.into();
+    }
+
+    /// Whether to allow dragging in the axis areas to zoom this frame, overriding
+    /// [`Plot::allow_axis_zoom_drag`].
+    ///
+    /// The cursor over the axes follows the builder setting until the next frame.
+    pub fn set_allow_axis_zoom_drag(&mut self, on: impl Into<Vec2b>) {
+        self.allow_axis_zoom_drag = on.into();
+    }
+
+    /// Show or hide the item with the given id, as if it was toggled in the legend.
+    ///
+    /// This also works without a legend. The change is reported as
+    /// [`PlotEvent::LegendItemToggled`](crate::PlotEvent::LegendItemToggled).
+    pub fn set_item_visible(&mut self, id: impl Into<egui::Id>, visible: bool) {
+        self.actions.set_item_visible(id.into(), visible);
+    }
+
+    /// The items selected by clicking them the last frame, in the order they were selected in,
+    /// see [`Plot::select_items`].
+    pub fn selected_items(&self) -> &[egui::Id] {
+        &self.selected_items
+    }
+
+    /// Select exactly these items, as if clicked with ctrl held.
+    ///
+    /// The change is reported as [`PlotEvent::SelectionChanged`](crate::PlotEvent::SelectionChanged).
+    pub fn set_selected_items(&mut self, ids: impl IntoIterator<Item = egui::Id>) {
+        self.actions.set_selection(ids.into_iter().collect());
+    }
+
+    /// Hide the selected items, as if toggled in the legend, and clear the selection.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Line, Plot};
+    /// Plot::new("editor").select_items(true).show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new("a", vec![[0.0, 0.0], [1.0, 1.0]]));
+    ///     plot_ui.line(Line::new("b", vec![[0.0, 1.0], [1.0, 0.0]]));
+    ///     if plot_ui.ctx().input(|i| i.key_pressed(egui::Key::Delete)) {
+    ///         plot_ui.hide_selected();
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn hide_selected(&mut self) {
+        for id in &self.selected_items {
+            self.actions.set_item_visible(*id, false);
+        }
+        self.actions.set_selection(Vec::new());
+    }
+
+    /// Draw the selected items above the other items of their [`PaintStage`](crate::PaintStage),
+    /// whatever their [`PlotItem::z_order`].
+    pub fn bring_selected_to_front(&mut self) {
+        for id in &self.selected_items {
+            self.actions.bring_to_front(*id);
+        }
+    }
+
+    /// Change the color of the selected items, as if edited in the legend.
+    ///
+    /// The change is reported as [`PlotEvent::SeriesStyleChanged`](crate::PlotEvent::SeriesStyleChanged)
+    /// for each item.
+    pub fn recolor_selected(&mut self, color: Color32) {
+        for id in &self.selected_items {
+            self.actions.set_item_color(*id, color);
+        }
+    }
+
+    /// Move the t₀ marker of a plot with a [`time origin`](crate::Plot::time_origin).
+    ///
+    /// The change is reported as [`PlotEvent::TimeOriginMoved`](crate::PlotEvent::TimeOriginMoved).
+    pub fn set_time_origin(&mut self, t0: f64) {
+        self.actions.set_time_origin(t0);
+    }
+
+    /// Keep the items of this frame as a reference, shown ghosted (gray and dashed) behind the
+    /// live data until [`Self::clear_reference`], for before/after comparisons.
+    ///
+    /// The reference is taken once all the items of the frame are added, so it doesn't matter
+    /// when this is called.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Line, Plot};
+    /// # let gain = 1.5;
+    /// let keep_reference = ui.button("Compare from here").clicked();
+    /// Plot::new("tuning").show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new("response", vec![[0.0, 0.0], [1.0, gain]]));
+    ///     if keep_reference {
+    ///         plot_ui.set_reference_from_current();
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn set_reference_from_current(&mut self) {
+        self.actions.set_reference(true);
+    }
+
+    /// Stop showing the reference kept with [`Self::set_reference_from_current`].
+    pub fn clear_reference(&mut self) {
+        self.actions.set_reference(false);
+    }
+
+    /// Go back to the view before the last change of the bounds, like the back button of a
+    /// browser.
+    ///
+    /// Every change of the bounds is remembered, except auto-fitting to the data and following
+    /// linked plots. A continuous pan or zoom is a single step. The change is reported as
+    /// [`PlotEvent::BoundsChanged`](crate::PlotEvent::BoundsChanged) with the
+    /// [`History`](crate::BoundsChangeCause::History) cause. See also
+    /// [`PlotKeymap::undo_view`](crate::PlotKeymap::undo_view).
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Line, Plot};
+    /// let back = ui.button("Back").clicked();
+    /// let forward = ui.button("Forward").clicked();
+    /// Plot::new("recording").show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new("signal", vec![[0.0, 0.0], [1.0, 1.0]]));
+    ///     if back {
+    ///         plot_ui.undo_view();
+    ///     }
+    ///     if forward {
+    ///         plot_ui.redo_view();
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn undo_view(&mut self) {
+        self.actions.undo_view();
+    }
+
+    /// Go forward to the view undone last with [`Self::undo_view`], until the view changes
+    /// again.
+    pub fn redo_view(&mut self) {
+        self.actions.redo_view();
+    }
+
+    /// Fit the bounds to the items with these ids, with the margins of auto-fit, e.g. for a
+    /// "zoom to this series" button. Auto-bounds are turned off, so the view stays there.
+    ///
+    /// Ids of items not in the plot are ignored; if none of them are, nothing changes.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Line, Plot};
+    /// let zoom_to_signal = ui.button("Zoom to signal").clicked();
+    /// Plot::new("zoom_to").show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new("signal", vec![[0.0, 0.0], [1.0, 1.0]]));
+    ///     plot_ui.line(Line::new("baseline", vec![[-100.0, 0.0], [100.0, 0.0]]));
+    ///     if zoom_to_signal {
+    ///         plot_ui.fit_to_items(&[egui::Id::new("signal")]);
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    pub fn fit_to_items(&mut self, ids: &[egui::Id]) {
+        self.actions.fit_to_items(ids.to_vec());
+    }
+
     /// Can be used to check if the plot was hovered or clicked.
     pub fn response(&self) -> &Response {
         &self.response
@@ -138,6 +387,37 @@ impl<'a> PlotUi<'a> {
         self.last_plot_transform.value_from_position(position)
     }
 
+    /// The items added so far with an element within `radius_px` points of `screen_pos`, nearest
+    /// first.
+    ///
+    /// Each hovered item gives its closest point (or bar/box/…), as used for hovering. Use it to
+    /// build your own interactions, like dragging or deleting points:
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// # use egui_plot::{Plot, Points};
+    /// Plot::new("picking").show(ui, |plot_ui| {
+    ///     plot_ui.points(Points::new("samples", vec![[0.0, 1.0], [1.0, 2.0]]));
+    ///     if let Some(pointer) = plot_ui.response().hover_pos() {
+    ///         if let Some(hit) = plot_ui.pick(pointer, 8.0).first() {
+    ///             plot_ui.ctx().debug_text(format!("{} #{}", hit.name, hit.index));
+    ///         }
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// Like [`Self::pointer_coordinate`], this uses the transform of the last frame. Items that
+    /// don't [allow hovering](crate::Line::allow_hover) are skipped.
+    pub fn pick(&self, screen_pos: Pos2, radius_px: f32) -> Vec<crate::PickHit> {
+        crate::items::pick(
+            self.actions.iter_items().map(|item| &**item),
+            &self.last_plot_transform,
+            screen_pos,
+            radius_px,
+        )
+    }
+
     /// Add an arbitrary item.
     pub fn add(&mut self, item: impl PlotItem + 'a) {
         self.actions.add_item(Box::new(item));
@@ -147,6 +427,37 @@ impl<'a> PlotUi<'a> {
     pub fn add_item(&mut self, item: Box<dyn PlotItem + 'a>) {
         self.actions.add_item(item);
     }
+
+    /// Evaluate an expression over the series added so far, e.g. to plot `"a - 2*b"` entered by a
+    /// user. It is evaluated at the x positions of the first series it references, the other
+    /// series are linearly interpolated there.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use egui_plot::{Expression, Line, Plot};
+    ///
+    /// let expression = Expression::parse("a - 2 * b").unwrap();
+    /// Plot::new("plot_with_expression").show(ui, |plot_ui| {
+    ///     plot_ui.line(Line::new("a", vec![[0.0, 1.0], [1.0, 2.0]]));
+    ///     plot_ui.line(Line::new("b", vec![[0.0, 0.0], [2.0, 1.0]]));
+    ///     if let Ok(points) = plot_ui.expression_points(&expression) {
+    ///         plot_ui.line(Line::new("a - 2 * b", points));
+    ///     }
+    /// });
+    /// # });
+    /// ```
+    ///
+    /// # Errors
+    /// If a referenced series wasn't added, or if the expression references no series at all.
+    #[cfg(feature = "expressions")]
+    pub fn expression_points(
+        &self,
+        expression: &crate::Expression,
+    ) -> Result<crate::PlotPoints<'static>, crate::ExpressionError> {
+        let items: Vec<&dyn PlotItem> = self.actions.iter_items().map(|item| &**item).collect();
+        Ok(crate::PlotPoints::Owned(expression.points(&items)?))
+    }
+
     /// Add a data line.
     pub fn line(&mut self, mut line: crate::Line<'a>) {
         if line.stroke.color == Color32::TRANSPARENT {
@@ -166,6 +477,21 @@ impl<'a> PlotUi<'a> {
         self.actions.add_item(Box::new(polygon));
     }
 
+    /// Add a scatter plot. Markers without a color get the next one of the palette.
+    ///
+    /// Colored with [`Scatter::color_by`](crate::Scatter::color_by), it comes with a
+    /// [`Colorbar`](crate::Colorbar) of the scale.
+    pub fn scatter(&mut self, mut scatter: crate::Scatter<'a>) {
+        if scatter.marker.color.is_none() {
+            scatter = scatter.color(self.auto_color());
+        }
+        let colorbar = scatter.auto_colorbar();
+        self.actions.add_item(Box::new(scatter));
+        if let Some(colorbar) = colorbar {
+            self.actions.add_item(Box::new(colorbar));
+        }
+    }
+
     /// Add a text.
     pub fn text(&mut self, text: crate::Text) {
         if text.text.is_empty() {
@@ -239,6 +565,12 @@ impl<'a> PlotUi<'a> {
         if PlotItem::color(&chart) == Color32::TRANSPARENT {
             chart = chart.color(self.auto_color());
         }
+        if let Some(duration) = chart.animation {
+            let id = self.response.id.with(PlotItem::id(&chart));
+            if BarAnimation::tween(&self.ctx, id, &mut chart.bars, duration.as_secs_f64()) {
+                self.ctx.request_repaint();
+            }
+        }
         self.actions.add_item(Box::new(chart));
     }
     /// Add a shaded [`Band`](`crate::Band`) to the plot.
@@ -248,10 +580,63 @@ impl<'a> PlotUi<'a> {
     /// around a central time series.
     ///
     /// If no color is set, one will be chosen automatically.
-    pub fn band(&mut self, mut band: crate::Band) {
+    pub fn band(&mut self, mut band: crate::Band<'a>) {
         if band.color() == Color32::TRANSPARENT {
             band = band.with_color(self.auto_color());
         }
         self.actions.add_item(Box::new(band));
     }
+
+    /// Add a [`FanBand`](`crate::FanBand`) to the plot.
+    ///
+    /// If no color is set, one will be chosen automatically.
+    pub fn fan_band(&mut self, mut fan: crate::FanBand<'a>) {
+        if PlotItem::color(&fan) == Color32::TRANSPARENT {
+            fan = fan.color(self.auto_color());
+        }
+        self.actions.add_item(Box::new(fan));
+    }
+
+    /// Add an [`EnvelopeLine`](`crate::EnvelopeLine`) to the plot.
+    ///
+    /// If no color is set, one will be chosen automatically.
+    pub fn envelope_line(&mut self, mut envelope: crate::EnvelopeLine<'a>) {
+        if PlotItem::color(&envelope) == Color32::TRANSPARENT {
+            envelope = envelope.color(self.auto_color());
+        }
+        self.actions.add_item(Box::new(envelope));
+    }
+
+    /// Add a [`StackedArea`](`crate::StackedArea`) to the plot.
+    ///
+    /// Stack a group of areas with [`StackedArea::stack`](`crate::StackedArea::stack`) or
+    /// [`StackedArea::stack_normalized`](`crate::StackedArea::stack_normalized`) before adding them.
+    ///
+    /// If no color is set, one will be chosen automatically.
+    pub fn stacked_area(&mut self, mut area: crate::StackedArea) {
+        if PlotItem::color(&area) == Color32::TRANSPARENT {
+            area = area.color(self.auto_color());
+        }
+        self.actions.add_item(Box::new(area));
+    }
+
+    /// Add a [`Spline`](`crate::Spline`) to the plot.
+    ///
+    /// If no color is set, one will be chosen automatically.
+    pub fn spline(&mut self, mut spline: crate::Spline) {
+        if PlotItem::color(&spline) == Color32::TRANSPARENT {
+            spline = spline.color(self.auto_color());
+        }
+        self.actions.add_item(Box::new(spline));
+    }
+
+    /// Add [`EventFlags`](`crate::EventFlags`) to the plot.
+    ///
+    /// If no color is set, one will be chosen automatically.
+    pub fn event_flags(&mut self, mut flags: crate::EventFlags) {
+        if PlotItem::color(&flags) == Color32::TRANSPARENT {
+            flags = flags.color(self.auto_color());
+        }
+        self.actions.add_item(Box::new(flags));
+    }
 }
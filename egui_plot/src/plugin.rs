@@ -0,0 +1,83 @@
+//! Hooks for tools living outside this crate, see [`PlotPlugin`].
+
+use egui::{Id, Painter, Response};
+
+use crate::{PlotEvent, PlotTransform, PlotUi};
+
+/// What a [`PlotPlugin`] gets to know about the plot it is called for.
+pub struct PluginContext<'a> {
+    /// The id of the plot, e.g. to keep per-plot state in egui memory.
+    pub plot_id: Id,
+
+    /// The response of the plot area.
+    pub response: &'a Response,
+
+    /// The transform between plot and screen coordinates.
+    ///
+    /// In [`PlotPlugin::on_input`] this is the transform of the previous frame, in the paint
+    /// hooks it is the one of this frame.
+    pub transform: &'a PlotTransform,
+}
+
+/// A tool that extends a [`Plot`](crate::Plot), e.g. for measuring, annotating or exporting,
+/// without changes to this crate.
+///
+/// Register it with [`Plot::plugin`](crate::Plot::plugin). The plot doesn't own the plugin, so
+/// it can keep state between frames. All hooks do nothing by default. They are called in this
+/// order each frame:
+///
+/// 1. [`Self::on_input`], before the items are built.
+/// 2. [`Self::contribute_items`], after the items of the plot were added.
+/// 3. [`Self::on_before_paint`], before the grid and the items are painted.
+/// 4. [`Self::on_after_paint`], on top of the items, below the legend.
+/// 5. [`Self::handle_event`], for every [`PlotEvent`] of the frame.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, PlotPlugin, PlotPoint, PluginContext};
+///
+/// /// Marks the last clicked position.
+/// #[derive(Default)]
+/// struct ClickMarker {
+///     clicked: Option<PlotPoint>,
+/// }
+///
+/// impl PlotPlugin for ClickMarker {
+///     fn on_input(&mut self, ctx: &PluginContext<'_>) {
+///         if ctx.response.clicked() {
+///             if let Some(pos) = ctx.response.interact_pointer_pos() {
+///                 self.clicked = Some(ctx.transform.value_from_position(pos));
+///             }
+///         }
+///     }
+///
+///     fn on_after_paint(&mut self, ctx: &PluginContext<'_>, painter: &egui::Painter) {
+///         if let Some(point) = &self.clicked {
+///             let pos = ctx.transform.position_from_point(point);
+///             painter.circle_stroke(pos, 6.0, (1.0, egui::Color32::RED));
+///         }
+///     }
+/// }
+///
+/// let mut marker = ClickMarker::default(); // Usually kept in your app state.
+/// Plot::new("plot_with_plugin")
+///     .plugin(&mut marker)
+///     .show(ui, |_plot_ui| {});
+/// # });
+/// ```
+pub trait PlotPlugin {
+    /// Look at the input to the plot area, before the items of this frame are built.
+    fn on_input(&mut self, _ctx: &PluginContext<'_>) {}
+
+    /// Add items to the plot, like the build function passed to [`crate::Plot::show`].
+    fn contribute_items(&mut self, _plot_ui: &mut PlotUi<'_>) {}
+
+    /// Paint below the grid and the items. The painter is clipped to the plot area.
+    fn on_before_paint(&mut self, _ctx: &PluginContext<'_>, _painter: &Painter) {}
+
+    /// Paint on top of the items. The painter is clipped to the plot area.
+    fn on_after_paint(&mut self, _ctx: &PluginContext<'_>, _painter: &Painter) {}
+
+    /// React to an event of the plot, see [`crate::PlotResponse::events`].
+    fn handle_event(&mut self, _event: &PlotEvent) {}
+}
@@ -0,0 +1,147 @@
+//! A scrollbar-like slider for the x range of linked plots.
+
+use std::ops::RangeInclusive;
+
+use egui::{Id, Rect, Response, Sense, Stroke, Ui, Vec2b, Widget, vec2};
+
+use crate::{BoundsLinkGroups, PlotBounds, PlotTransform, minimap};
+
+/// A scrollbar-like slider showing and controlling the visible x range of the plots in an axis
+/// link group.
+///
+/// Dragging the thumb pans the plots, dragging its edges zooms them, and clicking the track
+/// centers them there. The plots must link their x-axes with [`crate::Plot::link_axis`]; the
+/// slider shares their bounds through the same group.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui_plot::{Line, Plot, PlotPoints, XRangeSlider};
+/// let group = ui.id().with("recording");
+/// Plot::new("recording")
+///     .link_axis(group, [true, false])
+///     .height(200.0)
+///     .show(ui, |plot_ui| {
+///         let points: PlotPoints<'_> = (0..1000).map(|i| [i as f64, (i as f64).sin()]).collect();
+///         plot_ui.line(Line::new("signal", points));
+///     });
+/// ui.add(XRangeSlider::new(group));
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct XRangeSlider {
+    link_group: Id,
+    full_range: Option<RangeInclusive<f64>>,
+    width: Option<f32>,
+    height: f32,
+}
+
+impl XRangeSlider {
+    /// A slider for the plots linked with `link_group`.
+    pub fn new(link_group: impl Into<Id>) -> Self {
+        Self {
+            link_group: link_group.into(),
+            full_range: None,
+            width: None,
+            height: 16.0,
+        }
+    }
+
+    /// The x range the track of the slider spans.
+    ///
+    /// Default: the x extent of the data of the linked plots.
+    #[inline]
+    pub fn full_range(mut self, range: RangeInclusive<f64>) -> Self {
+        self.full_range = Some(range);
+        self
+    }
+
+    /// Width of the slider in points. Default: the available width.
+    #[inline]
+    pub fn width(mut self, width: f32) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    /// Height of the slider in points. Default: `16.0`.
+    #[inline]
+    pub fn height(mut self, height: f32) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl Widget for XRangeSlider {
+    fn ui(self, ui: &mut Ui) -> Response {
+        let Self {
+            link_group,
+            full_range,
+            width,
+            height,
+        } = self;
+
+        let size = vec2(width.unwrap_or_else(|| ui.available_width()), height);
+        let (rect, mut response) = ui.allocate_exact_size(size, Sense::click_and_drag());
+        let linked = ui.data_mut(|data| {
+            let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+            link_groups.0.get(&link_group).cloned()
+        });
+
+        let visuals = ui.visuals();
+        ui.painter().rect(
+            rect,
+            rect.height() / 2.0,
+            visuals.extreme_bg_color,
+            visuals.widgets.noninteractive.bg_stroke,
+            egui::StrokeKind::Inside,
+        );
+        // Nothing to show until a plot of the group has been shown:
+        let Some(mut linked) = linked else {
+            return response;
+        };
+
+        let mut view = linked.bounds.range_x();
+        let mut track = PlotBounds::from_min_max([*view.start(), 0.0], [*view.end(), 1.0]);
+        match full_range {
+            Some(range) => {
+                track.extend_with_x(*range.start());
+                track.extend_with_x(*range.end());
+            }
+            None if linked.data_bounds.is_finite_x() => track.merge_x(&linked.data_bounds),
+            None => {}
+        }
+        let transform = PlotTransform::new(rect, track, Vec2b::FALSE);
+
+        if let Some(range) = minimap::brush_ui(ui, &response, &transform, view.clone()) {
+            linked.bounds.min[0] = *range.start();
+            linked.bounds.max[0] = *range.end();
+            linked.auto_bounds.x = false;
+            ui.data_mut(|data| {
+                let link_groups: &mut BoundsLinkGroups = data.get_temp_mut_or_default(Id::NULL);
+                link_groups.0.insert(link_group, linked);
+            });
+            ui.ctx().request_repaint();
+            response.mark_changed();
+            view = range;
+        }
+
+        let [left, right] =
+            [*view.start(), *view.end()].map(|x| transform.position_from_point_x(x));
+        let thumb = Rect::from_x_y_ranges(left..=right, rect.y_range()).shrink2(vec2(0.0, 1.0));
+        let style = ui.style().interact(&response);
+        ui.painter().rect(
+            thumb,
+            thumb.height() / 2.0,
+            style.bg_fill,
+            style.bg_stroke,
+            egui::StrokeKind::Inside,
+        );
+        // Grips on the edges, which zoom:
+        let grip = Stroke::new(1.0, style.fg_stroke.color);
+        for x in [thumb.left() + 3.0, thumb.right() - 3.0] {
+            ui.painter()
+                .vline(x, thumb.y_range().shrink(thumb.height() / 4.0), grip);
+        }
+
+        response
+    }
+}
@@ -0,0 +1,46 @@
+//! A snapshot of the items to compare the live data with, see
+//! [`crate::PlotUi::set_reference_from_current`].
+
+use egui::{Color32, Shape, Stroke};
+
+use crate::{PlotItem, PlotTransform, export, items::geom_helpers::decimate_columns};
+
+/// The points of the items when the reference was taken, kept in the temporary data of the
+/// context.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Reference {
+    traces: Vec<(Vec<f64>, Vec<f64>)>,
+}
+
+impl Reference {
+    pub(crate) fn from_items(items: &[Box<dyn PlotItem + '_>]) -> Self {
+        Self {
+            traces: items
+                .iter()
+                .filter_map(|item| export::geometry_columns(&item.geometry()))
+                .collect(),
+        }
+    }
+
+    /// The traces as thin dashed lines in `color`, broken at NaN values.
+    pub(crate) fn shapes(&self, transform: &PlotTransform, color: Color32) -> Vec<Shape> {
+        let stroke = Stroke::new(1.0, color);
+        let mut shapes = Vec::new();
+        for (xs, ys) in &self.traces {
+            let points = xs.iter().zip(ys).map(|(&x, &y)| {
+                let finite = x.is_finite() && y.is_finite();
+                finite.then(|| transform.position_from_point(&crate::PlotPoint::new(x, y)))
+            });
+            let mut run = Vec::new();
+            for point in points.chain(std::iter::once(None)) {
+                if let Some(point) = point {
+                    run.push(point);
+                } else if !run.is_empty() {
+                    let line = decimate_columns(run.drain(..));
+                    shapes.extend(Shape::dashed_line(&line, stroke, 6.0, 4.0));
+                }
+            }
+        }
+        shapes
+    }
+}
@@ -0,0 +1,224 @@
+//! Recording the events of a plot and replaying them, for UI tests and for reproducing
+//! navigation bugs reported by users.
+
+use egui::Vec2b;
+
+use crate::{BoundsChangeCause, PlotEvent, PlotResponse, PlotUi, action::ActionQueue};
+
+/// A [`PlotEvent`] with the frame it happened in, see [`PlotRecorder`].
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct RecordedEvent {
+    /// Seconds since the first recorded frame.
+    pub time: f64,
+
+    /// The number of the frame since the first recorded one.
+    pub frame: u64,
+
+    pub event: PlotEvent,
+}
+
+/// Records the events of a plot with their timestamps, to replay them with a [`PlotReplayer`].
+///
+/// With the `serde` feature the recording can be saved and loaded, e.g. to attach it to a bug
+/// report. Images and [`PointMetadata`](crate::PointMetadata) are not saved.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui_plot::{Line, Plot, PlotRecorder};
+/// let mut recorder = PlotRecorder::new();
+/// let response = Plot::new("recorded").show(ui, |plot_ui| {
+///     plot_ui.line(Line::new("signal", vec![[0.0, 0.0], [1.0, 1.0]]));
+/// });
+/// recorder.record(&response);
+/// # });
+/// ```
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct PlotRecorder {
+    /// When the first frame was recorded, in seconds like [`egui::InputState::time`].
+    start: Option<f64>,
+    frames: u64,
+    events: Vec<RecordedEvent>,
+}
+
+impl PlotRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record the events of a frame of the plot. Call this every frame, with the response of
+    /// [`crate::Plot::show`].
+    pub fn record<R>(&mut self, response: &PlotResponse<R>) {
+        let time = response.response.ctx.input(|i| i.time);
+        let start = *self.start.get_or_insert(time);
+        let frame = self.frames;
+        self.frames += 1;
+        self.events
+            .extend(response.events.iter().map(|event| RecordedEvent {
+                time: time - start,
+                frame,
+                event: event.clone(),
+            }));
+    }
+
+    /// The events recorded so far, in order.
+    pub fn events(&self) -> &[RecordedEvent] {
+        &self.events
+    }
+
+    /// The events recorded, to give to [`PlotReplayer::new`].
+    pub fn into_events(self) -> Vec<RecordedEvent> {
+        self.events
+    }
+
+    /// Forget the events recorded so far, and start over.
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Feeds the events recorded with a [`PlotRecorder`] back to a plot as programmatic actions.
+///
+/// Only the events that change the state of the plot are replayed:
+/// [`PlotEvent::BoundsChanged`] (or going back to automatic bounds, for resets and auto-fits),
+/// [`PlotEvent::AutoBoundsActionApplied`], [`PlotEvent::LegendItemToggled`],
+/// [`PlotEvent::TimeOriginMoved`] and [`PlotEvent::SelectionChanged`]. The plot reports the replayed changes as events of its own.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// # use egui_plot::{Line, Plot, PlotReplayer, RecordedEvent};
+/// # let recording: Vec<RecordedEvent> = Vec::new();
+/// let mut replayer = PlotReplayer::new(recording);
+/// Plot::new("replayed").show(ui, |plot_ui| {
+///     plot_ui.line(Line::new("signal", vec![[0.0, 0.0], [1.0, 1.0]]));
+///     replayer.replay(plot_ui);
+/// });
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct PlotReplayer {
+    events: Vec<RecordedEvent>,
+    next: usize,
+
+    /// When the replay started, in seconds like [`egui::InputState::time`].
+    start: Option<f64>,
+}
+
+impl PlotReplayer {
+    pub fn new(events: impl Into<Vec<RecordedEvent>>) -> Self {
+        Self {
+            events: events.into(),
+            next: 0,
+            start: None,
+        }
+    }
+
+    /// Replay the events that are due, at the pace they were recorded at. Call this every frame
+    /// in the closure of [`crate::Plot::show`].
+    pub fn replay(&mut self, plot_ui: &mut PlotUi<'_>) {
+        let now = plot_ui.ctx().input(|i| i.time);
+        let elapsed = now - *self.start.get_or_insert(now);
+        while let Some(recorded) = self.events.get(self.next) {
+            if recorded.time > elapsed {
+                break;
+            }
+            replay_event(&mut plot_ui.actions, &recorded.event);
+            self.next += 1;
+        }
+        if !self.is_finished() {
+            plot_ui.ctx().request_repaint();
+        }
+    }
+
+    /// Replay the events of the next recorded frame, whenever they happened. For tests that
+    /// step through a recording one frame at a time.
+    pub fn step(&mut self, plot_ui: &mut PlotUi<'_>) {
+        let Some(frame) = self.events.get(self.next).map(|recorded| recorded.frame) else {
+            return;
+        };
+        while let Some(recorded) = self.events.get(self.next) {
+            if recorded.frame != frame {
+                break;
+            }
+            replay_event(&mut plot_ui.actions, &recorded.event);
+            self.next += 1;
+        }
+    }
+
+    /// Whether all the events have been replayed.
+    pub fn is_finished(&self) -> bool {
+        self.next >= self.events.len()
+    }
+
+    /// Start the replay over from the first event.
+    pub fn restart(&mut self) {
+        self.next = 0;
+        self.start = None;
+    }
+}
+
+/// Queue the actions that bring a plot to the state after `event`.
+fn replay_event<I>(actions: &mut ActionQueue<I>, event: &PlotEvent) {
+    match event {
+        PlotEvent::BoundsChanged {
+            cause: BoundsChangeCause::AutoFit | BoundsChangeCause::Reset,
+            ..
+        } => actions.set_auto_bounds(Vec2b::TRUE),
+        PlotEvent::BoundsChanged { new, .. } => {
+            actions.set_bounds_x(new.range_x());
+            actions.set_bounds_y(new.range_y());
+        }
+        PlotEvent::AutoBoundsActionApplied { new, .. } => actions.set_auto_bounds(*new),
+        PlotEvent::LegendItemToggled { item, now_visible } => {
+            actions.set_item_visible(*item, *now_visible);
+        }
+        PlotEvent::TimeOriginMoved { t0 } => actions.set_time_origin(*t0),
+        PlotEvent::SelectionChanged { selected } => actions.set_selection(selected.clone()),
+        _ => {}
+    }
+}
+
+#[test]
+fn test_replay_event() {
+    use crate::{ActionExecutor, PlotBounds};
+
+    let start = PlotBounds::from_min_max([0.0, 0.0], [1.0, 1.0]);
+    let panned = PlotBounds::from_min_max([2.0, -1.0], [3.0, 0.0]);
+    let item = egui::Id::new("a");
+    let events = [
+        PlotEvent::BoundsChanged {
+            old: start,
+            new: panned,
+            cause: BoundsChangeCause::Pan,
+        },
+        PlotEvent::LegendItemToggled {
+            item,
+            now_visible: false,
+        },
+        PlotEvent::CursorMoved {
+            plot_x: 0.5,
+            plot_y: 0.5,
+        },
+    ];
+    let mut actions = ActionQueue::<()>::new();
+    for event in &events {
+        replay_event(&mut actions, event);
+    }
+    let applied = ActionExecutor::apply(actions, start, Vec2b::TRUE, None, None);
+    assert_eq!(applied.bounds, panned);
+    assert_eq!(applied.auto_bounds, Vec2b::FALSE);
+    assert_eq!(applied.item_visibility, vec![(item, false)]);
+
+    let mut actions = ActionQueue::<()>::new();
+    replay_event(
+        &mut actions,
+        &PlotEvent::BoundsChanged {
+            old: panned,
+            new: start,
+            cause: BoundsChangeCause::Reset,
+        },
+    );
+    let applied = ActionExecutor::apply(actions, panned, Vec2b::FALSE, None, None);
+    assert_eq!(applied.auto_bounds, Vec2b::TRUE);
+}
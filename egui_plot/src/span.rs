@@ -1,19 +1,23 @@
 use std::ops::RangeInclusive;
 
-use egui::{Color32, Rect, Shape, Stroke, Ui, pos2};
+use egui::{Align2, Color32, Rect, Shape, Stroke, TextStyle, Ui, epaint::TextShape, pos2, vec2};
 
 use crate::{
-    Interval, PlotBounds, PlotGeometry, PlotItem, PlotItemBase, PlotTransform,
-    interval_to_screen_y, span_utils::interval_to_screen_x,
+    Axis, FillPattern, Interval, IntervalSet, PaintStage, PlotBounds, PlotGeometry, PlotItem,
+    PlotItemBase, PlotTransform, export::ExportShape, interval_to_screen_y, items::ItemInteraction,
+    pattern::rect_polygon, span_utils::interval_to_screen_x,
 };
 
-/// Horizontal shaded band for a Y interval across full plot width.
+/// Horizontal shaded band for a Y interval across the plot width, or a part of it.
 #[derive(Clone, Debug, PartialEq)]
 pub struct HSpan {
     base: PlotItemBase,
 
-    /// Vertical interval in data space.
-    y: Interval,
+    /// Vertical intervals in data space.
+    y: IntervalSet,
+
+    /// The X range the span covers.
+    x_clip: Interval,
 
     /// Fill color of the band
     fill: Color32,
@@ -23,18 +27,45 @@ pub struct HSpan {
 
     /// Toggle visibility via code.
     visible: bool,
+
+    /// Whether the user can drag the edges or the whole span.
+    draggable: bool,
+
+    /// Pattern drawn on top of the fill.
+    pattern: Option<FillPattern>,
+
+    /// Text drawn inside the span.
+    label: Option<String>,
+
+    /// Where the label is placed in the span.
+    label_align: Align2,
+
+    /// Rotation of the label in radians, clockwise.
+    label_angle: f32,
 }
 
 impl HSpan {
     /// Create a horizontal span from an explicit `Interval` in Y.
     pub fn new(name: impl Into<String>, y: Interval) -> Self {
+        Self::from_set(name, y.into())
+    }
+
+    /// Create one horizontal span item shading all intervals of `y`, e.g. the periods
+    /// computed with [`IntervalSet::from_mask`].
+    pub fn from_set(name: impl Into<String>, y: IntervalSet) -> Self {
         let default = Color32::from_rgba_unmultiplied(128, 128, 128, 40);
         Self {
-            base: PlotItemBase::new(name.into()),
+            base: PlotItemBase::new(name.into()).paint_stage(PaintStage::UnderData),
             y,
+            x_clip: Interval::all(),
             fill: default,
             stroke: None,
             visible: true,
+            draggable: false,
+            pattern: None,
+            label: None,
+            label_align: Align2::LEFT_TOP,
+            label_angle: 0.0,
         }
     }
 
@@ -52,33 +83,96 @@ impl HSpan {
         self
     }
 
+    /// Drawing order of the span, see [`PlotItem::z_order`]. Default: `0`.
+    #[inline]
+    pub fn z(mut self, z: i32) -> Self {
+        self.base = self.base.z_order(z);
+        self
+    }
+
     /// Toggle visibility (code-controlled show/hide).
     #[inline]
     pub fn visible(mut self, yes: bool) -> Self {
         self.visible = yes;
         self
     }
-}
 
-impl PlotItem for HSpan {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        if !self.visible {
-            return;
-        }
-        if self.y.is_empty() {
-            return;
-        }
+    /// Let the user drag either edge of the span, or the whole span from its inside.
+    /// Default: `false`.
+    ///
+    /// The plot doesn't move the span itself; apply
+    /// [`PlotEvent::ItemDragged`](crate::PlotEvent::ItemDragged) to your data instead.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
 
-        let (top, bottom) = interval_to_screen_y(&self.y, transform);
+    /// Only cover this X range instead of the whole width, e.g. the part of the time range a
+    /// threshold applies to. Default: [`Interval::all`].
+    #[inline]
+    pub fn x_clip(mut self, x: Interval) -> Self {
+        self.x_clip = x;
+        self
+    }
+
+    /// Draw a pattern on top of the fill, e.g. [`FillPattern::hatch`], in the fill color.
+    #[inline]
+    pub fn pattern(mut self, pattern: FillPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Draw a text inside the span, e.g. `"warn zone"`.
+    #[inline]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Where to place the label in the visible part of the span. Default: [`Align2::LEFT_TOP`].
+    #[inline]
+    pub fn label_align(mut self, align: Align2) -> Self {
+        self.label_align = align;
+        self
+    }
+
+    /// Rotate the label clockwise by `angle` radians, e.g. `-FRAC_PI_2` to read it bottom to
+    /// top along a narrow span. Default: `0.0`.
+    #[inline]
+    pub fn label_angle(mut self, angle: f32) -> Self {
+        self.label_angle = angle;
+        self
+    }
+
+    /// Fill, pattern, outline and label of one interval.
+    fn push_shapes(
+        &self,
+        ui: &Ui,
+        interval: &Interval,
+        transform: &PlotTransform,
+        frame: Rect,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let (top, bottom) = interval_to_screen_y(interval, transform);
 
         if (bottom - top).abs() <= f32::EPSILON {
             return;
         }
 
-        let frame = transform.frame();
-        let rect = Rect::from_min_max(pos2(frame.left(), top), pos2(frame.right(), bottom));
+        let (left, right) = interval_to_screen_x(&self.x_clip, transform);
+        let (left, right) = (left.max(frame.left()), right.min(frame.right()));
+        if right <= left {
+            return;
+        }
+
+        let rect = Rect::from_min_max(pos2(left, top), pos2(right, bottom));
 
         shapes.push(Shape::rect_filled(rect, 0.0, self.fill));
+        if let Some(pattern) = &self.pattern {
+            let polygon = rect_polygon(rect);
+            pattern.shapes(&polygon, frame, self.fill.to_opaque(), shapes);
+        }
 
         if let Some(stroke) = self.stroke {
             shapes.push(Shape::rect_stroke(
@@ -88,6 +182,31 @@ impl PlotItem for HSpan {
                 egui::StrokeKind::Outside,
             ));
         }
+
+        if let Some(label) = &self.label {
+            let color = self.stroke.map_or(self.fill, |stroke| stroke.color);
+            let rect = rect.intersect(frame);
+            push_span_label(
+                ui,
+                label,
+                rect,
+                (self.label_align, self.label_angle),
+                color.to_opaque(),
+                shapes,
+            );
+        }
+    }
+}
+
+impl PlotItem for HSpan {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if !self.visible {
+            return;
+        }
+        let frame = transform.frame();
+        for interval in self.y.intervals() {
+            self.push_shapes(ui, interval, transform, *frame, shapes);
+        }
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -103,16 +222,48 @@ impl PlotItem for HSpan {
     fn bounds(&self) -> PlotBounds {
         let mut b = PlotBounds::NOTHING;
 
-        if self.y.start.is_finite() {
-            b.extend_with_y(self.y.start);
+        for interval in self.y.intervals() {
+            if interval.start.is_finite() {
+                b.extend_with_y(interval.start);
+            }
+            if interval.end.is_finite() {
+                b.extend_with_y(interval.end);
+            }
         }
-        if self.y.end.is_finite() {
-            b.extend_with_y(self.y.end);
+        for value in [self.x_clip.start, self.x_clip.end] {
+            if value.is_finite() {
+                b.extend_with_x(value);
+            }
         }
 
         b
     }
 
+    fn interaction(&self) -> ItemInteraction<'_> {
+        // Only a single interval can be dragged:
+        match self.y.intervals() {
+            [interval] if self.draggable && self.visible => ItemInteraction::Drag {
+                axis: Axis::Y,
+                interval: *interval,
+            },
+            _ => ItemInteraction::None,
+        }
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        if !self.visible {
+            return Some(Vec::new());
+        }
+        let shapes = self.y.intervals().iter().map(|interval| ExportShape::Span {
+            axis: Axis::Y,
+            interval: *interval,
+            clip: self.x_clip,
+            color: self.fill,
+            stroke: self.stroke,
+        });
+        Some(shapes.collect())
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -120,13 +271,16 @@ impl PlotItem for HSpan {
         &mut self.base
     }
 }
-/// Vertical shaded band for an X interval across full plot height.
+/// Vertical shaded band for an X interval across the plot height, or a part of it.
 #[derive(Clone, Debug, PartialEq)]
 pub struct VSpan {
     base: PlotItemBase,
 
-    /// Horizontal interval in data space.
-    x: Interval,
+    /// Horizontal intervals in data space.
+    x: IntervalSet,
+
+    /// The Y range the span covers.
+    y_clip: Interval,
 
     /// Fill color of the band (should usually be translucent).
     fill: Color32,
@@ -136,18 +290,45 @@ pub struct VSpan {
 
     /// Toggle visibility via code.
     visible: bool,
+
+    /// Whether the user can drag the edges or the whole span.
+    draggable: bool,
+
+    /// Pattern drawn on top of the fill.
+    pattern: Option<FillPattern>,
+
+    /// Text drawn inside the span.
+    label: Option<String>,
+
+    /// Where the label is placed in the span.
+    label_align: Align2,
+
+    /// Rotation of the label in radians, clockwise.
+    label_angle: f32,
 }
 
 impl VSpan {
     /// Create a vertical span from an explicit `Interval` in X.
     pub fn new(name: impl Into<String>, x: Interval) -> Self {
+        Self::from_set(name, x.into())
+    }
+
+    /// Create one vertical span item shading all intervals of `x`, e.g. the periods
+    /// computed with [`IntervalSet::from_mask`].
+    pub fn from_set(name: impl Into<String>, x: IntervalSet) -> Self {
         let default = Color32::from_rgba_unmultiplied(128, 128, 128, 40);
         Self {
-            base: PlotItemBase::new(name.into()),
+            base: PlotItemBase::new(name.into()).paint_stage(PaintStage::UnderData),
             x,
+            y_clip: Interval::all(),
             fill: default,
             stroke: None,
             visible: true,
+            draggable: false,
+            pattern: None,
+            label: None,
+            label_align: Align2::LEFT_TOP,
+            label_angle: 0.0,
         }
     }
     /// Set the fill color .
@@ -164,33 +345,95 @@ impl VSpan {
         self
     }
 
+    /// Drawing order of the span, see [`PlotItem::z_order`]. Default: `0`.
+    #[inline]
+    pub fn z(mut self, z: i32) -> Self {
+        self.base = self.base.z_order(z);
+        self
+    }
+
     /// Toggle visibility (code-controlled show/hide).
     #[inline]
     pub fn visible(mut self, yes: bool) -> Self {
         self.visible = yes;
         self
     }
-}
 
-impl PlotItem for VSpan {
-    fn shapes(&self, _ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
-        if !self.visible {
-            return;
-        }
-        if self.x.is_empty() {
-            return;
-        }
+    /// Let the user drag either edge of the span, or the whole span from its inside.
+    /// Default: `false`.
+    ///
+    /// The plot doesn't move the span itself; apply
+    /// [`PlotEvent::ItemDragged`](crate::PlotEvent::ItemDragged) to your data instead.
+    #[inline]
+    pub fn draggable(mut self, draggable: bool) -> Self {
+        self.draggable = draggable;
+        self
+    }
 
-        let (left, right) = interval_to_screen_x(&self.x, transform);
+    /// Only cover this Y range instead of the whole height. Default: [`Interval::all`].
+    #[inline]
+    pub fn y_clip(mut self, y: Interval) -> Self {
+        self.y_clip = y;
+        self
+    }
+
+    /// Draw a pattern on top of the fill, e.g. [`FillPattern::hatch`], in the fill color.
+    #[inline]
+    pub fn pattern(mut self, pattern: FillPattern) -> Self {
+        self.pattern = Some(pattern);
+        self
+    }
+
+    /// Draw a text inside the span, e.g. `"warn zone"`.
+    #[inline]
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Where to place the label in the visible part of the span. Default: [`Align2::LEFT_TOP`].
+    #[inline]
+    pub fn label_align(mut self, align: Align2) -> Self {
+        self.label_align = align;
+        self
+    }
+
+    /// Rotate the label clockwise by `angle` radians, e.g. `-FRAC_PI_2` to read it bottom to
+    /// top along a narrow span. Default: `0.0`.
+    #[inline]
+    pub fn label_angle(mut self, angle: f32) -> Self {
+        self.label_angle = angle;
+        self
+    }
+
+    /// Fill, pattern, outline and label of one interval.
+    fn push_shapes(
+        &self,
+        ui: &Ui,
+        interval: &Interval,
+        transform: &PlotTransform,
+        frame: Rect,
+        shapes: &mut Vec<Shape>,
+    ) {
+        let (left, right) = interval_to_screen_x(interval, transform);
 
         if (right - left).abs() <= f32::EPSILON {
             return;
         }
 
-        let frame = transform.frame();
-        let rect = Rect::from_min_max(pos2(left, frame.top()), pos2(right, frame.bottom()));
+        let (top, bottom) = interval_to_screen_y(&self.y_clip, transform);
+        let (top, bottom) = (top.max(frame.top()), bottom.min(frame.bottom()));
+        if bottom <= top {
+            return;
+        }
+
+        let rect = Rect::from_min_max(pos2(left, top), pos2(right, bottom));
 
         shapes.push(Shape::rect_filled(rect, 0.0, self.fill));
+        if let Some(pattern) = &self.pattern {
+            let polygon = rect_polygon(rect);
+            pattern.shapes(&polygon, frame, self.fill.to_opaque(), shapes);
+        }
 
         if let Some(stroke) = self.stroke {
             shapes.push(Shape::rect_stroke(
@@ -200,6 +443,31 @@ impl PlotItem for VSpan {
                 egui::StrokeKind::Outside,
             ));
         }
+
+        if let Some(label) = &self.label {
+            let color = self.stroke.map_or(self.fill, |stroke| stroke.color);
+            let rect = rect.intersect(frame);
+            push_span_label(
+                ui,
+                label,
+                rect,
+                (self.label_align, self.label_angle),
+                color.to_opaque(),
+                shapes,
+            );
+        }
+    }
+}
+
+impl PlotItem for VSpan {
+    fn shapes(&self, ui: &Ui, transform: &PlotTransform, shapes: &mut Vec<Shape>) {
+        if !self.visible {
+            return;
+        }
+        let frame = transform.frame();
+        for interval in self.x.intervals() {
+            self.push_shapes(ui, interval, transform, *frame, shapes);
+        }
     }
 
     fn initialize(&mut self, _x_range: RangeInclusive<f64>) {}
@@ -215,16 +483,48 @@ impl PlotItem for VSpan {
     fn bounds(&self) -> PlotBounds {
         let mut b = PlotBounds::NOTHING;
 
-        if self.x.start.is_finite() {
-            b.extend_with_x(self.x.start);
+        for interval in self.x.intervals() {
+            if interval.start.is_finite() {
+                b.extend_with_x(interval.start);
+            }
+            if interval.end.is_finite() {
+                b.extend_with_x(interval.end);
+            }
         }
-        if self.x.end.is_finite() {
-            b.extend_with_x(self.x.end);
+        for value in [self.y_clip.start, self.y_clip.end] {
+            if value.is_finite() {
+                b.extend_with_y(value);
+            }
         }
 
         b
     }
 
+    fn interaction(&self) -> ItemInteraction<'_> {
+        // Only a single interval can be dragged:
+        match self.x.intervals() {
+            [interval] if self.draggable && self.visible => ItemInteraction::Drag {
+                axis: Axis::X,
+                interval: *interval,
+            },
+            _ => ItemInteraction::None,
+        }
+    }
+
+    fn export_shapes(&self) -> Option<Vec<ExportShape>> {
+        if !self.visible {
+            return Some(Vec::new());
+        }
+        let shapes = self.x.intervals().iter().map(|interval| ExportShape::Span {
+            axis: Axis::X,
+            interval: *interval,
+            clip: self.y_clip,
+            color: self.fill,
+            stroke: self.stroke,
+        });
+        Some(shapes.collect())
+    }
+
     fn base(&self) -> &PlotItemBase {
         &self.base
     }
@@ -232,3 +532,33 @@ impl PlotItem for VSpan {
         &mut self.base
     }
 }
+
+/// Space between a span label and the edges of the span.
+const LABEL_PADDING: f32 = 4.0;
+
+/// Draw `label` in `rect`, rotated by `angle` and then placed by `align`.
+fn push_span_label(
+    ui: &Ui,
+    label: &str,
+    rect: Rect,
+    (align, angle): (Align2, f32),
+    color: Color32,
+    shapes: &mut Vec<Shape>,
+) {
+    let font_id = TextStyle::Small.resolve(ui.style());
+    let galley = ui.fonts(|f| f.layout_no_wrap(label.to_owned(), font_id, color));
+
+    // The bounding box of the rotated text, relative to where the text starts:
+    let rot = egui::emath::Rot2::from_angle(angle);
+    let size = galley.size();
+    let corners = [vec2(0.0, 0.0), vec2(size.x, 0.0), vec2(0.0, size.y), size].map(|c| rot * c);
+    let min = corners.iter().fold(corners[0], |min, c| min.min(*c));
+    let max = corners.iter().fold(corners[0], |max, c| max.max(*c));
+
+    let placed = align.align_size_within_rect(max - min, rect.shrink(LABEL_PADDING));
+    shapes.push(
+        TextShape::new(placed.min - min, galley, color)
+            .with_angle(angle)
+            .into(),
+    );
+}
@@ -1,21 +1,22 @@
 use crate::{Interval, PlotPoint, PlotTransform};
-use egui::Rect;
 
 /// Convert a Y-interval in data space to screen-space vertical span.
 pub fn interval_to_screen_y(interval: &Interval, tf: &PlotTransform) -> (f32, f32) {
-    let frame: Rect = *tf.frame();
+    let bounds = tf.bounds();
 
+    // Infinite ends extend to the edge of the visible bounds (which may be at either side of the
+    // frame if the axis is inverted):
     let y0 = if interval.start.is_finite() {
         tf.position_from_point(&PlotPoint::new(0.0, interval.start))
             .y
     } else {
-        frame.bottom()
+        tf.position_from_point_y(bounds.min()[1])
     };
 
     let y1 = if interval.end.is_finite() {
         tf.position_from_point(&PlotPoint::new(0.0, interval.end)).y
     } else {
-        frame.top()
+        tf.position_from_point_y(bounds.max()[1])
     };
 
     (y0.min(y1), y0.max(y1))
@@ -23,20 +24,53 @@ pub fn interval_to_screen_y(interval: &Interval, tf: &PlotTransform) -> (f32, f3
 
 /// Convert an X-interval in data space to screen-space horizontal span.
 pub fn interval_to_screen_x(interval: &Interval, tf: &PlotTransform) -> (f32, f32) {
-    let frame: Rect = *tf.frame();
+    let bounds = tf.bounds();
 
     let x0 = if interval.start.is_finite() {
         tf.position_from_point(&PlotPoint::new(interval.start, 0.0))
             .x
     } else {
-        frame.left()
+        tf.position_from_point_x(bounds.min()[0])
     };
 
     let x1 = if interval.end.is_finite() {
         tf.position_from_point(&PlotPoint::new(interval.end, 0.0)).x
     } else {
-        frame.right()
+        tf.position_from_point_x(bounds.max()[0])
     };
 
     (x0.min(x1), x0.max(x1))
 }
+
+#[test]
+fn test_interval_to_screen() {
+    use egui::{Rect, pos2, vec2};
+
+    use crate::PlotBounds;
+
+    let frame = Rect::from_min_size(pos2(0.0, 0.0), vec2(200.0, 100.0));
+    let bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 5.0]);
+    let mut tf = PlotTransform::new(frame, bounds, false);
+
+    // Infinite ends reach the edges of the frame:
+    assert_eq!(
+        interval_to_screen_x(&Interval::above(5.0), &tf),
+        (100.0, 200.0)
+    );
+    assert_eq!(interval_to_screen_x(&Interval::all(), &tf), (0.0, 200.0));
+    assert_eq!(
+        interval_to_screen_y(&Interval::below(1.0), &tf),
+        (80.0, 100.0)
+    );
+
+    // On the other side of the frame when the axis is inverted:
+    tf.set_inverted([true, true]);
+    assert_eq!(
+        interval_to_screen_x(&Interval::above(5.0), &tf),
+        (0.0, 100.0)
+    );
+    assert_eq!(
+        interval_to_screen_y(&Interval::below(1.0), &tf),
+        (0.0, 20.0)
+    );
+}
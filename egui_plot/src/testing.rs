@@ -0,0 +1,242 @@
+//! Running plots without a window, for snapshot tests of their rendering, see [`PlotHarness`].
+
+use egui::{
+    CentralPanel, ClippedPrimitive, Color32, ColorImage, Context, Frame, Pos2, RawInput, Rect,
+    TextureId, Ui, Vec2, ViewportId,
+    epaint::{ClippedShape, ImageData, Mesh, Primitive},
+};
+
+/// The most frames to run until the plot stops asking for repaints.
+const MAX_FRAMES: usize = 10;
+
+/// Seconds between the frames, long enough for the animations of the plot to finish.
+const FRAME_TIME: f64 = 1.0;
+
+/// Runs plots without a window and returns what they paint, for snapshot tests of their
+/// rendering.
+///
+/// Each run shows the plot for a few frames, until it stops asking for repaints, so the axes,
+/// the legend and the animations have settled. The runs are deterministic: the same plot gives
+/// the same shapes and images.
+///
+/// ```
+/// # use egui_plot::{Line, Plot, PlotHarness};
+/// let mut harness = PlotHarness::new(egui::vec2(300.0, 200.0));
+/// let image = harness.render(|ui| {
+///     Plot::new("snapshot")
+///         .default_x_bounds(0.0, 1.0)
+///         .show(ui, |plot_ui| {
+///             plot_ui.line(Line::new("ramp", vec![[0.0, 0.0], [1.0, 1.0]]));
+///         });
+/// });
+/// assert_eq!(image.size, [300, 200]);
+/// ```
+pub struct PlotHarness {
+    ctx: Context,
+    size: Vec2,
+    pixels_per_point: f32,
+    time: f64,
+
+    /// The textures painted with, like the font atlas.
+    textures: ahash::HashMap<TextureId, ColorImage>,
+}
+
+impl PlotHarness {
+    /// A harness for plots filling `size` points.
+    pub fn new(size: Vec2) -> Self {
+        Self {
+            ctx: Context::default(),
+            size,
+            pixels_per_point: 1.0,
+            time: 0.0,
+            textures: Default::default(),
+        }
+    }
+
+    /// Physical pixels per point of the rendered images. Default: `1.0`.
+    #[inline]
+    pub fn pixels_per_point(mut self, pixels_per_point: f32) -> Self {
+        self.pixels_per_point = pixels_per_point;
+        self
+    }
+
+    /// The context the plots are shown in, e.g. to change the style.
+    pub fn ctx(&self) -> &Context {
+        &self.ctx
+    }
+
+    /// Show `add_contents` (usually a [`crate::Plot`]) until it settles, and return the shapes
+    /// painted the last frame.
+    pub fn run(&mut self, mut add_contents: impl FnMut(&mut Ui)) -> Vec<ClippedShape> {
+        let mut shapes = Vec::new();
+        for _ in 0..MAX_FRAMES {
+            let mut input = RawInput {
+                screen_rect: Some(Rect::from_min_size(Pos2::ZERO, self.size)),
+                time: Some(self.time),
+                ..Default::default()
+            };
+            input
+                .viewports
+                .entry(ViewportId::ROOT)
+                .or_default()
+                .native_pixels_per_point = Some(self.pixels_per_point);
+            self.time += FRAME_TIME;
+
+            let output = self.ctx.run(input, |ctx| {
+                CentralPanel::default()
+                    .frame(Frame::central_panel(&ctx.style()).inner_margin(0))
+                    .show(ctx, |ui| add_contents(ui));
+            });
+            for (id, delta) in output.textures_delta.set {
+                let ImageData::Color(patch) = delta.image;
+                match delta.pos {
+                    None => {
+                        self.textures.insert(id, (*patch).clone());
+                    }
+                    Some([x, y]) => {
+                        if let Some(texture) = self.textures.get_mut(&id) {
+                            for row in 0..patch.height() {
+                                let start = (y + row) * texture.width() + x;
+                                texture.pixels[start..start + patch.width()].copy_from_slice(
+                                    &patch.pixels[row * patch.width()..(row + 1) * patch.width()],
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+            shapes = output.shapes;
+            let repaint = output
+                .viewport_output
+                .get(&ViewportId::ROOT)
+                .is_some_and(|viewport| viewport.repaint_delay.is_zero());
+            if !repaint {
+                break;
+            }
+        }
+        shapes
+    }
+
+    /// Show `add_contents` like [`Self::run`], and rasterize what it paints.
+    ///
+    /// The image is drawn in software, without a GPU, so it can differ slightly from what a
+    /// renderer shows, but it is the same on every machine.
+    pub fn render(&mut self, add_contents: impl FnMut(&mut Ui)) -> ColorImage {
+        let shapes = self.run(add_contents);
+        let primitives = self.ctx.tessellate(shapes, self.pixels_per_point);
+        self.rasterize(&primitives)
+    }
+
+    fn rasterize(&self, primitives: &[ClippedPrimitive]) -> ColorImage {
+        let size = (self.size * self.pixels_per_point).round();
+        let mut image = ColorImage::filled([size.x as usize, size.y as usize], Color32::BLACK);
+        for primitive in primitives {
+            let Primitive::Mesh(mesh) = &primitive.primitive else {
+                continue;
+            };
+            let clip = Rect::from_min_max(
+                (primitive.clip_rect.min.to_vec2() * self.pixels_per_point).to_pos2(),
+                (primitive.clip_rect.max.to_vec2() * self.pixels_per_point).to_pos2(),
+            );
+            self.paint_mesh(&mut image, mesh, clip);
+        }
+        image
+    }
+
+    /// Blend the triangles of `mesh` over `image`, sampling pixel centers within `clip`.
+    fn paint_mesh(&self, image: &mut ColorImage, mesh: &Mesh, clip: Rect) {
+        let texture = self.textures.get(&mesh.texture_id);
+        let [width, height] = image.size;
+        let scale = self.pixels_per_point;
+        for triangle in mesh.indices.chunks_exact(3) {
+            let [a, b, c] = [0, 1, 2].map(|i| &mesh.vertices[triangle[i] as usize]);
+            let [pa, pb, pc] = [a, b, c].map(|v| (v.pos.to_vec2() * scale).to_pos2());
+            let area = (pb - pa).x * (pc - pa).y - (pb - pa).y * (pc - pa).x;
+            if area == 0.0 {
+                continue;
+            }
+            let bounds = Rect::from_points(&[pa, pb, pc]).intersect(clip);
+            if !bounds.is_positive() {
+                continue;
+            }
+            let x_range =
+                bounds.min.x.floor().max(0.0) as usize..(bounds.max.x.ceil() as usize).min(width);
+            let y_range =
+                bounds.min.y.floor().max(0.0) as usize..(bounds.max.y.ceil() as usize).min(height);
+            for y in y_range {
+                for x in x_range.clone() {
+                    let p = Pos2::new(x as f32 + 0.5, y as f32 + 0.5);
+                    if !clip.contains(p) {
+                        continue;
+                    }
+                    let edge = |from: Pos2, to: Pos2| {
+                        ((to - from).x * (p - from).y - (to - from).y * (p - from).x) / area
+                    };
+                    let weights = [edge(pb, pc), edge(pc, pa), edge(pa, pb)];
+                    if weights.iter().any(|w| *w < 0.0) {
+                        continue;
+                    }
+                    let mix = |values: [f32; 3]| {
+                        values[0] * weights[0] + values[1] * weights[1] + values[2] * weights[2]
+                    };
+                    let mut color = [0, 1, 2, 3]
+                        .map(|i| mix([a, b, c].map(|v| v.color.to_array()[i] as f32)) / 255.0);
+                    if let Some(texture) = texture {
+                        let u = mix([a.uv.x, b.uv.x, c.uv.x]);
+                        let v = mix([a.uv.y, b.uv.y, c.uv.y]);
+                        let tx = ((u * texture.width() as f32) as usize).min(texture.width() - 1);
+                        let ty = ((v * texture.height() as f32) as usize).min(texture.height() - 1);
+                        let texel = texture.pixels[ty * texture.width() + tx].to_array();
+                        for (channel, texel) in color.iter_mut().zip(texel) {
+                            *channel *= texel as f32 / 255.0;
+                        }
+                    }
+                    // Premultiplied alpha, in gamma space like egui:
+                    let pixel = &mut image.pixels[y * width + x];
+                    let under = pixel.to_array();
+                    let blended = [0, 1, 2, 3].map(|i| {
+                        let over = color[i] * 255.0 + under[i] as f32 * (1.0 - color[3]);
+                        over.round().clamp(0.0, 255.0) as u8
+                    });
+                    *pixel = Color32::from_rgba_premultiplied(
+                        blended[0], blended[1], blended[2], blended[3],
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_plot_harness() {
+    use crate::{Line, Plot};
+
+    let mut harness = PlotHarness::new(egui::vec2(200.0, 100.0));
+    let mut render = || {
+        harness.render(|ui| {
+            Plot::new("test")
+                .show_axes(false)
+                .show_grid(false)
+                .default_x_bounds(0.0, 1.0)
+                .default_y_bounds(0.0, 1.0)
+                .show(ui, |plot_ui| {
+                    plot_ui.line(
+                        Line::new("flat", vec![[0.0, 0.5], [1.0, 0.5]])
+                            .color(Color32::RED)
+                            .width(4.0),
+                    );
+                });
+        })
+    };
+    let image = render();
+    assert_eq!(image.size, [200, 100]);
+    assert_eq!(image.pixels, render().pixels);
+
+    // The line crosses the middle of the plot:
+    let [r, g, b, _] = image.pixels[50 * 200 + 100].to_array();
+    assert!(
+        r > 200 && g < 50 && b < 50,
+        "{:?}",
+        image.pixels[50 * 200 + 100]
+    );
+}
@@ -0,0 +1,605 @@
+//! Calendar-aware time axis: x values are seconds since the Unix epoch.
+//!
+//! A [`TimeHeader`] replaces the tick labels of an X axis by two rows of spanning cells, e.g.
+//! days on top and hours below, like the header of a Gantt chart or a trace viewer.
+//! A [`TradingCalendar`] removes non-trading periods from the x axis.
+
+use egui::{Align2, Rect, Shape, Stroke, TextStyle, Ui, pos2};
+
+use crate::{CoordinatesFormatter, PlotPoint, PlotTransform, VPlacement};
+
+/// Padding above and below the text of each row.
+const ROW_PADDING: f32 = 2.0;
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A calendar unit, used for the cells of a [`TimeHeader`] row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TimeUnit {
+    Second,
+    Minute,
+    Hour,
+    Day,
+    Month,
+    Year,
+}
+
+impl TimeUnit {
+    /// All units, from the shortest to the longest.
+    pub const ALL: [Self; 6] = [
+        Self::Second,
+        Self::Minute,
+        Self::Hour,
+        Self::Day,
+        Self::Month,
+        Self::Year,
+    ];
+
+    /// Typical length of the unit in seconds. Months and years vary.
+    pub fn seconds(self) -> f64 {
+        match self {
+            Self::Second => 1.0,
+            Self::Minute => 60.0,
+            Self::Hour => 3_600.0,
+            Self::Day => SECONDS_PER_DAY as f64,
+            Self::Month => 30.44 * SECONDS_PER_DAY as f64,
+            Self::Year => 365.25 * SECONDS_PER_DAY as f64,
+        }
+    }
+
+    /// The next longer unit, if any.
+    pub fn next(self) -> Option<Self> {
+        let i = Self::ALL.iter().position(|unit| *unit == self)?;
+        Self::ALL.get(i + 1).copied()
+    }
+
+    /// Start of the cell containing the (local) time `t`, in whole seconds.
+    fn floor(self, t: i64) -> i64 {
+        let days = t.div_euclid(SECONDS_PER_DAY);
+        match self {
+            Self::Second => t,
+            Self::Minute => t.div_euclid(60) * 60,
+            Self::Hour => t.div_euclid(3_600) * 3_600,
+            Self::Day => days * SECONDS_PER_DAY,
+            Self::Month => {
+                let (y, m, _) = civil_from_days(days);
+                days_from_civil(y, m, 1) * SECONDS_PER_DAY
+            }
+            Self::Year => {
+                let (y, _, _) = civil_from_days(days);
+                days_from_civil(y, 1, 1) * SECONDS_PER_DAY
+            }
+        }
+    }
+
+    /// Start of the cell after the one starting at `start`.
+    fn step(self, start: i64) -> i64 {
+        match self {
+            Self::Second => start + 1,
+            Self::Minute => start + 60,
+            Self::Hour => start + 3_600,
+            Self::Day => start + SECONDS_PER_DAY,
+            Self::Month => {
+                let (y, m, _) = civil_from_days(start.div_euclid(SECONDS_PER_DAY));
+                let (y, m) = if m == 12 { (y + 1, 1) } else { (y, m + 1) };
+                days_from_civil(y, m, 1) * SECONDS_PER_DAY
+            }
+            Self::Year => {
+                let (y, _, _) = civil_from_days(start.div_euclid(SECONDS_PER_DAY));
+                days_from_civil(y + 1, 1, 1) * SECONDS_PER_DAY
+            }
+        }
+    }
+
+    /// Label of the cell starting at the (local) time `start`.
+    fn label(self, start: i64) -> String {
+        let (y, m, d) = civil_from_days(start.div_euclid(SECONDS_PER_DAY));
+        let secs = start.rem_euclid(SECONDS_PER_DAY);
+        let (hh, mm, ss) = (secs / 3_600, secs / 60 % 60, secs % 60);
+        match self {
+            Self::Second => format!("{hh:02}:{mm:02}:{ss:02}"),
+            Self::Minute => format!("{hh:02}:{mm:02}"),
+            Self::Hour => format!("{hh:02}:00"),
+            Self::Day => format!("{y}-{m:02}-{d:02}"),
+            Self::Month => format!("{y}-{m:02}"),
+            Self::Year => format!("{y}"),
+        }
+    }
+}
+
+/// Trading sessions of a market, used to skip nights, weekends and holidays on the x axis.
+///
+/// The plot works in *display* coordinates: the number of trading seconds since the Unix
+/// epoch. Convert times with [`Self::to_display`] before plotting them, and plot positions back
+/// with [`Self::to_time`], e.g. for tooltips. Candles of consecutive sessions then touch,
+/// whatever lies between them.
+///
+/// The plot doesn't know about the calendar: only a [`TimeHeader`] with
+/// [`TimeHeader::calendar`] labels the axis in time. The hover labels and the pointer
+/// coordinates show display seconds unless given [`Self::label_formatter`] and
+/// [`Self::coordinates_formatter`].
+///
+/// ```
+/// # use egui_plot::TradingCalendar;
+/// // 09:30 to 16:00, Monday to Friday, New York time in winter:
+/// let calendar = TradingCalendar::new(9 * 3600 + 1800, 16 * 3600)
+///     .utc_offset(-5 * 3600)
+///     .holiday(2024, 12, 25);
+/// let friday_close = 1_734_728_400.0; // 2024-12-20 16:00 EST
+/// let monday_open = friday_close + 2.0 * 86_400.0 + 17.5 * 3_600.0;
+/// assert_eq!(calendar.to_display(friday_close), calendar.to_display(monday_open));
+/// assert_eq!(calendar.to_time(calendar.to_display(monday_open)), monday_open);
+/// assert_eq!(calendar.format(calendar.to_display(monday_open)), "2024-12-23 09:30:00");
+/// ```
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{AxisHints, Corner, Plot, TimeHeader, TradingCalendar};
+///
+/// let calendar = TradingCalendar::new(9 * 3600 + 1800, 16 * 3600);
+/// let header = TimeHeader::new().calendar(calendar.clone());
+/// Plot::new("trading_hours")
+///     .custom_x_axes(vec![AxisHints::new_x().time_header(header)])
+///     .label_formatter(calendar.label_formatter())
+///     .coordinates_formatter(Corner::LeftBottom, calendar.coordinates_formatter())
+///     .show(ui, |_plot_ui| {});
+/// # });
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TradingCalendar {
+    open: i64,
+    close: i64,
+    weekdays: [bool; 7],
+    /// Sorted local day numbers of the holidays that fall on trading weekdays.
+    holidays: Vec<i64>,
+    utc_offset: i64,
+}
+
+impl TradingCalendar {
+    /// A session from `open` to `close`, in seconds since local midnight, Monday to Friday.
+    pub fn new(open: u32, close: u32) -> Self {
+        Self {
+            open: i64::from(open.min(close)),
+            close: i64::from(close.min(SECONDS_PER_DAY as u32)),
+            weekdays: [true, true, true, true, true, false, false],
+            holidays: Vec::new(),
+            utc_offset: 0,
+        }
+    }
+
+    /// Which days of the week are traded, starting on Monday. Default: Monday to Friday.
+    #[inline]
+    pub fn trading_weekdays(mut self, weekdays: [bool; 7]) -> Self {
+        self.weekdays = weekdays;
+        self.holidays.retain(|day| weekdays[weekday(*day)]);
+        self
+    }
+
+    /// Skip the given local date.
+    #[inline]
+    pub fn holiday(mut self, year: i64, month: u32, day: u32) -> Self {
+        let day = days_from_civil(year, month, day);
+        if self.weekdays[weekday(day)] {
+            if let Err(i) = self.holidays.binary_search(&day) {
+                self.holidays.insert(i, day);
+            }
+        }
+        self
+    }
+
+    /// Offset of the local time of the market from UTC, in seconds. Default: `0`.
+    #[inline]
+    pub fn utc_offset(mut self, seconds: i64) -> Self {
+        self.utc_offset = seconds;
+        self
+    }
+
+    fn session_len(&self) -> i64 {
+        self.close - self.open
+    }
+
+    fn is_degenerate(&self) -> bool {
+        self.session_len() <= 0 || !self.weekdays.contains(&true)
+    }
+
+    /// Average share of the time that is traded.
+    fn trading_fraction(&self) -> f64 {
+        if self.is_degenerate() {
+            return 1.0;
+        }
+        let days = self.weekdays.iter().filter(|traded| **traded).count();
+        (self.session_len() * days as i64) as f64 / (7 * SECONDS_PER_DAY) as f64
+    }
+
+    /// Number of trading days in `[0, day)`, negative for days before the epoch.
+    fn trading_days_before(&self, day: i64) -> i64 {
+        let per_week = self.weekdays.iter().filter(|traded| **traded).count() as i64;
+        let partial = (0..day.rem_euclid(7))
+            .filter(|i| self.weekdays[weekday(*i)])
+            .count() as i64;
+        let holidays = self.holidays.partition_point(|h| *h < day) as i64
+            - self.holidays.partition_point(|h| *h < 0) as i64;
+        day.div_euclid(7) * per_week + partial - holidays
+    }
+
+    fn is_trading_day(&self, day: i64) -> bool {
+        self.weekdays[weekday(day)] && self.holidays.binary_search(&day).is_err()
+    }
+
+    /// Display position of the time `t` (seconds since the Unix epoch).
+    ///
+    /// Times outside of the sessions map to the close of the previous session.
+    pub fn to_display(&self, t: f64) -> f64 {
+        if self.is_degenerate() || !t.is_finite() {
+            return t;
+        }
+        let local = t + self.utc_offset as f64;
+        let day = (local / SECONDS_PER_DAY as f64).floor() as i64;
+        let in_day = local - (day * SECONDS_PER_DAY) as f64;
+        let in_session = if self.is_trading_day(day) {
+            (in_day - self.open as f64).clamp(0.0, self.session_len() as f64)
+        } else {
+            0.0
+        };
+        (self.trading_days_before(day) * self.session_len()) as f64 + in_session
+    }
+
+    /// The time (seconds since the Unix epoch) at the display position `x`.
+    ///
+    /// Session boundaries map to the open of the next session.
+    pub fn to_time(&self, x: f64) -> f64 {
+        if self.is_degenerate() || !x.is_finite() {
+            return x;
+        }
+        let len = self.session_len() as f64;
+        let n = (x / len).floor() as i64;
+        let in_session = x - n as f64 * len;
+
+        // First day with `n` trading days before it, which is a trading day:
+        let (mut lo, mut hi) = (-(1_i64 << 40), 1_i64 << 40);
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            if self.trading_days_before(mid + 1) > n {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        let local = (lo * SECONDS_PER_DAY + self.open) as f64 + in_session;
+        local - self.utc_offset as f64
+    }
+
+    /// The local date and time at the display position `x`, e.g. `2024-12-20 15:59:30`.
+    pub fn format(&self, x: f64) -> String {
+        let t = self.to_time(x);
+        if !t.is_finite() {
+            return format!("{t}");
+        }
+        let local = t.floor() as i64 + self.utc_offset;
+        let (y, m, d) = civil_from_days(local.div_euclid(SECONDS_PER_DAY));
+        let secs = local.rem_euclid(SECONDS_PER_DAY);
+        let (hh, mm, ss) = (secs / 3_600, secs / 60 % 60, secs % 60);
+        format!("{y}-{m:02}-{d:02} {hh:02}:{mm:02}:{ss:02}")
+    }
+
+    /// A [`crate::Plot::label_formatter`] showing the hovered time with [`Self::format`]
+    /// instead of display seconds.
+    pub fn label_formatter(&self) -> impl Fn(&str, &PlotPoint) -> String + use<> {
+        let calendar = self.clone();
+        move |name, value| {
+            let time = calendar.format(value.x);
+            if name.is_empty() {
+                format!("{time}\ny = {:.2}", value.y)
+            } else {
+                format!("{name}\n{time}\ny = {:.2}", value.y)
+            }
+        }
+    }
+
+    /// A [`crate::CoordinatesFormatter`] showing the pointer time with [`Self::format`] instead
+    /// of display seconds.
+    pub fn coordinates_formatter(&self) -> CoordinatesFormatter<'static> {
+        let calendar = self.clone();
+        CoordinatesFormatter::new(move |value, _bounds| {
+            format!("{} y: {:.2}", calendar.format(value.x), value.y)
+        })
+    }
+}
+
+/// Day of the week of a day number, with Monday as `0`.
+fn weekday(day: i64) -> usize {
+    // 1970-01-01 was a Thursday.
+    (day + 3).rem_euclid(7) as usize
+}
+
+/// Two rows of time cells replacing the tick labels of an X axis, see
+/// [`crate::AxisHints::time_header`].
+///
+/// X values are interpreted as seconds since the Unix epoch. By default the units of the rows
+/// follow the zoom level: the lower row uses the shortest unit whose cells are at least
+/// [`Self::min_cell_width`] wide, the upper row the next longer unit.
+#[derive(Clone, Debug, PartialEq)]
+pub struct TimeHeader {
+    pub(crate) units: Option<[TimeUnit; 2]>,
+    pub(crate) utc_offset: i64,
+    pub(crate) min_cell_width: f32,
+    pub(crate) calendar: Option<TradingCalendar>,
+}
+
+impl Default for TimeHeader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimeHeader {
+    /// A header choosing its units automatically, in UTC.
+    pub fn new() -> Self {
+        Self {
+            units: None,
+            utc_offset: 0,
+            min_cell_width: 48.0,
+            calendar: None,
+        }
+    }
+
+    /// Use fixed units for the upper (`major`) and lower (`minor`) rows, e.g. days and hours.
+    ///
+    /// Cells narrower than a couple of points are not drawn.
+    #[inline]
+    pub fn units(mut self, major: TimeUnit, minor: TimeUnit) -> Self {
+        self.units = Some([major, minor]);
+        self
+    }
+
+    /// Offset of the displayed local time from UTC, in seconds. Default: `0`.
+    #[inline]
+    pub fn utc_offset(mut self, seconds: i64) -> Self {
+        self.utc_offset = seconds;
+        self
+    }
+
+    /// Minimum width of the cells of the lower row when choosing units automatically.
+    /// Default: `48.0`.
+    #[inline]
+    pub fn min_cell_width(mut self, width: f32) -> Self {
+        self.min_cell_width = width;
+        self
+    }
+
+    /// Interpret the axis values as display positions of the given calendar, see
+    /// [`TradingCalendar::to_display`]. Non-trading periods get no cells.
+    #[inline]
+    pub fn calendar(mut self, calendar: TradingCalendar) -> Self {
+        self.calendar = Some(calendar);
+        self
+    }
+
+    /// Average length of the unit on the axis, in display seconds.
+    fn display_seconds(&self, unit: TimeUnit) -> f64 {
+        match &self.calendar {
+            Some(calendar) if unit >= TimeUnit::Day => unit.seconds() * calendar.trading_fraction(),
+            _ => unit.seconds(),
+        }
+    }
+
+    /// Units of the upper and lower row at the given scale.
+    fn units_for(&self, points_per_second: f64) -> [TimeUnit; 2] {
+        if let Some(units) = self.units {
+            return units;
+        }
+        let minor = TimeUnit::ALL
+            .into_iter()
+            .find(|unit| {
+                self.display_seconds(*unit) * points_per_second >= self.min_cell_width as f64
+            })
+            .unwrap_or(TimeUnit::Year);
+        [minor.next().unwrap_or(minor), minor]
+    }
+
+    /// Draw both rows into `rect`. Returns the thickness used.
+    pub(crate) fn ui(
+        &self,
+        ui: &Ui,
+        rect: Rect,
+        transform: &PlotTransform,
+        placement: VPlacement,
+    ) -> f32 {
+        let font_id = TextStyle::Body.resolve(ui.style());
+        let row_height = ui.fonts(|f| f.row_height(&font_id)) + 2.0 * ROW_PADDING;
+        let thickness = 2.0 * row_height;
+
+        let range = transform.bounds().range_x();
+        let points_per_second = transform.dpos_dvalue_x().abs();
+        if !(range.start().is_finite() && range.end().is_finite() && points_per_second > 0.0) {
+            return thickness;
+        }
+        let units = self.units_for(points_per_second);
+
+        let top = match placement {
+            VPlacement::Bottom => rect.top(),
+            VPlacement::Top => rect.bottom() - thickness,
+        };
+        let painter = ui.painter_at(rect.with_min_y(top).with_max_y(top + thickness));
+        let to_display = |t: f64| match &self.calendar {
+            Some(calendar) => calendar.to_display(t),
+            None => t,
+        };
+        let to_time = |x: f64| match &self.calendar {
+            Some(calendar) => calendar.to_time(x),
+            None => x,
+        };
+        // Positions of local times:
+        let x_of =
+            |t: i64| transform.position_from_point_x(to_display((t - self.utc_offset) as f64));
+        let stroke = ui.visuals().widgets.noninteractive.bg_stroke;
+        let text_color = ui.visuals().text_color();
+
+        for (row, unit) in units.into_iter().enumerate() {
+            // Too many cells to be readable, or to draw at all:
+            if self.display_seconds(unit) * points_per_second < 2.0 {
+                continue;
+            }
+            let y_range = egui::Rangef::new(
+                top + row as f32 * row_height,
+                top + (row + 1) as f32 * row_height,
+            );
+            let t_min = to_time(range.start().min(*range.end())).floor() as i64 + self.utc_offset;
+            let t_max = to_time(range.start().max(*range.end())).ceil() as i64 + self.utc_offset;
+
+            let mut start = unit.floor(t_min);
+            while start < t_max {
+                let end = unit.step(start);
+                let (x_start, x_end) = (x_of(start), x_of(end));
+                if x_start == x_end {
+                    // Not traded: continue with the cell of the next session.
+                    let next_open = to_time(to_display((end - self.utc_offset) as f64));
+                    start = end.max(unit.floor(next_open as i64 + self.utc_offset));
+                    continue;
+                }
+
+                painter.add(Shape::line_segment(
+                    [pos2(x_start, y_range.min), pos2(x_start, y_range.max)],
+                    stroke,
+                ));
+
+                // Keep the label inside the visible part of the cell:
+                let visible = rect
+                    .x_range()
+                    .intersection(egui::Rangef::new(x_start.min(x_end), x_start.max(x_end)));
+                let galley = painter.layout_no_wrap(unit.label(start), font_id.clone(), text_color);
+                if galley.size().x + 2.0 * ROW_PADDING <= visible.span() {
+                    let pos = pos2(visible.center(), y_range.center());
+                    let rect = Align2::CENTER_CENTER.anchor_size(pos, galley.size());
+                    painter.galley(rect.min, galley, text_color);
+                }
+
+                start = end;
+            }
+        }
+        painter.add(Shape::hline(rect.x_range(), top + row_height, stroke));
+
+        thickness
+    }
+}
+
+/// Label of a tick `dt` seconds after t₀, e.g. `T−10 s` or `T+1:05`, with as many decimals as
+/// the tick `step` needs.
+pub(crate) fn format_relative_time(dt: f64, step: f64) -> String {
+    let decimals = if step < 1.0 {
+        ((-step.log10()).ceil() as usize).min(6)
+    } else {
+        0
+    };
+    let scale = 10_f64.powi(decimals as i32);
+    let dt = (dt * scale).round() / scale;
+    let sign = if dt < 0.0 { '−' } else { '+' };
+    let abs = dt.abs();
+    if abs < 60.0 {
+        return format!("T{sign}{abs:.decimals$} s");
+    }
+    let hours = (abs / 3_600.0).floor();
+    let minutes = ((abs - hours * 3_600.0) / 60.0).floor();
+    let seconds = abs - hours * 3_600.0 - minutes * 60.0;
+    let width = if decimals > 0 { decimals + 3 } else { 2 };
+    if hours > 0.0 {
+        format!("T{sign}{hours}:{minutes:02}:{seconds:0width$.decimals$}")
+    } else {
+        format!("T{sign}{minutes}:{seconds:0width$.decimals$}")
+    }
+}
+
+/// Paint the t₀ marker of a relative time axis across `frame`.
+pub(crate) fn paint_time_origin(ui: &Ui, frame: Rect, transform: &PlotTransform, t0: f64) {
+    let x = transform.position_from_point_x(t0);
+    if !frame.x_range().contains(x) {
+        return;
+    }
+    let color = ui.visuals().warn_fg_color;
+    let painter = ui.painter_at(frame);
+    painter.add(Shape::vline(x, frame.y_range(), Stroke::new(1.5, color)));
+    painter.text(
+        pos2(x + 3.0, frame.top() + 2.0),
+        Align2::LEFT_TOP,
+        "T₀",
+        TextStyle::Small.resolve(ui.style()),
+        color,
+    );
+}
+
+/// Days since 1970-01-01 of the given civil date (proleptic Gregorian calendar).
+fn days_from_civil(y: i64, m: u32, d: u32) -> i64 {
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (i64::from(m) + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + i64::from(d) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Civil date `(year, month, day)` of the given number of days since 1970-01-01.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let m = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y, m, d)
+}
+
+#[test]
+fn test_time_units() {
+    assert_eq!(civil_from_days(0), (1970, 1, 1));
+    assert_eq!(days_from_civil(2024, 2, 29), 19_782);
+    assert_eq!(civil_from_days(19_782), (2024, 2, 29));
+    assert_eq!(civil_from_days(-1), (1969, 12, 31));
+
+    // 2024-02-29 13:45:10 UTC
+    let t = 19_782 * SECONDS_PER_DAY + 13 * 3_600 + 45 * 60 + 10;
+    assert_eq!(TimeUnit::Hour.label(TimeUnit::Hour.floor(t)), "13:00");
+    assert_eq!(TimeUnit::Day.label(TimeUnit::Day.floor(t)), "2024-02-29");
+    let month = TimeUnit::Month.floor(t);
+    assert_eq!(
+        TimeUnit::Month.label(TimeUnit::Month.step(month)),
+        "2024-03"
+    );
+    assert_eq!(TimeUnit::Year.label(TimeUnit::Year.step(t)), "2025");
+}
+
+#[test]
+fn test_trading_calendar() {
+    // Monday 2024-03-04, 10:00 to 12:00 UTC sessions.
+    let monday = days_from_civil(2024, 3, 4);
+    assert_eq!(weekday(monday), 0);
+    let calendar = TradingCalendar::new(10 * 3_600, 12 * 3_600).holiday(2024, 3, 5);
+    let t = |day: i64, hour: i64| (day * SECONDS_PER_DAY + hour * 3_600) as f64;
+
+    let open = calendar.to_display(t(monday, 10));
+    assert_eq!(calendar.to_display(t(monday, 11)) - open, 3_600.0);
+    // Tuesday is a holiday, Wednesday opens where Monday closed:
+    assert_eq!(calendar.to_display(t(monday + 1, 11)) - open, 7_200.0);
+    assert_eq!(calendar.to_display(t(monday + 2, 11)) - open, 10_800.0);
+    for time in [
+        t(monday, 10),
+        t(monday, 11),
+        t(monday + 2, 11),
+        t(monday - 7, 11),
+    ] {
+        assert_eq!(calendar.to_time(calendar.to_display(time)), time);
+    }
+}
+
+#[test]
+fn test_format_relative_time() {
+    assert_eq!(format_relative_time(-10.0, 5.0), "T−10 s");
+    assert_eq!(format_relative_time(3.0, 1.0), "T+3 s");
+    assert_eq!(format_relative_time(0.25, 0.05), "T+0.25 s");
+    assert_eq!(format_relative_time(-0.001, 0.5), "T+0.0 s");
+    assert_eq!(format_relative_time(65.0, 5.0), "T+1:05");
+    assert_eq!(format_relative_time(-3_725.0, 60.0), "T−1:02:05");
+}
@@ -2,7 +2,7 @@ use std::ops::RangeInclusive;
 
 use egui::{Pos2, Rect, Vec2, Vec2b, pos2, remap};
 
-use crate::Axis;
+use crate::{Axis, AxisBreaks, AxisScale, GridMark, Interval};
 
 use super::PlotPoint;
 
@@ -239,6 +239,25 @@ impl PlotBounds {
         self.expand_y(margin_fraction.y as f64 * height);
     }
 
+    /// Widen the `axes` so that `margin` points are left on each side when shown in a frame
+    /// of `size`.
+    pub(crate) fn add_screen_margin(&mut self, margin: f32, size: Vec2, axes: Vec2b) {
+        let pad = |extent: f64, pixels: f32| {
+            let inner = pixels - 2.0 * margin;
+            if inner > 0.0 && extent > 0.0 {
+                extent * margin as f64 / inner as f64
+            } else {
+                0.0
+            }
+        };
+        if axes.x {
+            self.expand_x(pad(self.width(), size.x));
+        }
+        if axes.y {
+            self.expand_y(pad(self.height(), size.y));
+        }
+    }
+
     #[inline]
     pub fn range_x(&self) -> RangeInclusive<f64> {
         self.min[0]..=self.max[0]
@@ -262,9 +281,203 @@ impl PlotBounds {
         self.min[1] = -y_abs;
         self.max[1] = y_abs;
     }
+
+    /// Keep the range of one axis (`0` = x, `1` = y) inside `limits`, with its extent inside
+    /// `extent`.
+    ///
+    /// The extent is clamped around the current center, then the range is shifted (not
+    /// resized) back inside `limits` where possible.
+    pub(crate) fn clamp_axis(&mut self, axis: usize, limits: Interval, extent: Interval) {
+        let (min, max) = (self.min[axis], self.max[axis]);
+        if !(min.is_finite() && max.is_finite() && min <= max) {
+            return;
+        }
+
+        let limits_extent = limits.end - limits.start;
+        let size = (max - min)
+            .clamp(extent.start.max(0.0), extent.end)
+            .min(limits_extent);
+        let center = 0.5 * (min + max);
+        let (mut min, mut max) = (center - 0.5 * size, center + 0.5 * size);
+
+        if min < limits.start {
+            (min, max) = (limits.start, limits.start + size);
+        } else if max > limits.end {
+            (min, max) = (limits.end - size, limits.end);
+        }
+        self.min[axis] = min;
+        self.max[axis] = max;
+    }
+
+    /// [`Self::clamp_axis`] for both axes.
+    pub(crate) fn clamp(&mut self, limits: [Interval; 2], extent: [Interval; 2]) {
+        for axis in 0..2 {
+            self.clamp_axis(axis, limits[axis], extent[axis]);
+        }
+    }
+}
+
+/// `limits` for [`PlotBounds::clamp_axis`]: a NaN end is unbounded, and an empty range is no
+/// limit at all.
+pub(crate) fn sanitize_bounds_limits(limits: Interval) -> Interval {
+    let start = if limits.start.is_nan() {
+        f64::NEG_INFINITY
+    } else {
+        limits.start
+    };
+    let end = if limits.end.is_nan() {
+        f64::INFINITY
+    } else {
+        limits.end
+    };
+    if start < end {
+        Interval { start, end }
+    } else {
+        Interval::all()
+    }
+}
+
+/// `extent` for [`PlotBounds::clamp_axis`]: sizes are at least `0`, a NaN end is unbounded, and
+/// an empty range is no limit at all.
+pub(crate) fn sanitize_extent_limits(extent: Interval) -> Interval {
+    let start = if extent.start.is_nan() {
+        0.0
+    } else {
+        extent.start.max(0.0)
+    };
+    let end = if extent.end.is_nan() {
+        f64::INFINITY
+    } else {
+        extent.end
+    };
+    if start <= end && end > 0.0 {
+        Interval { start, end }
+    } else {
+        Interval::all()
+    }
+}
+
+/// A smooth move of the bounds over several frames, see [`crate::PlotUi::animate_bounds_to`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct BoundsAnimation {
+    from: PlotBounds,
+    to: PlotBounds,
+
+    /// In seconds, like [`egui::InputState::time`].
+    start: f64,
+    duration: f64,
+}
+
+impl BoundsAnimation {
+    pub(crate) fn new(from: PlotBounds, to: PlotBounds, start: f64, duration: f64) -> Self {
+        Self {
+            from,
+            to,
+            start,
+            duration,
+        }
+    }
+
+    /// The bounds at `time`, and whether the animation is over.
+    pub(crate) fn at(&self, time: f64) -> (PlotBounds, bool) {
+        let t = if self.duration > 0.0 {
+            ((time - self.start) / self.duration).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        let eased = ease_in_out(t);
+        let lerp = |a: f64, b: f64| a + (b - a) * eased;
+        let bounds = PlotBounds {
+            min: [0, 1].map(|axis| lerp(self.from.min[axis], self.to.min[axis])),
+            max: [0, 1].map(|axis| lerp(self.from.max[axis], self.to.max[axis])),
+        };
+        (bounds, t >= 1.0)
+    }
+}
+
+/// Cubic easing of `t` in `0..=1`, so an animation neither jumps into motion nor stops abruptly.
+pub(crate) fn ease_in_out(t: f64) -> f64 {
+    if t < 0.5 {
+        4.0 * t * t * t
+    } else {
+        1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+    }
+}
+
+/// How the values of each axis map to the *display* coordinates, which the plot spreads linearly
+/// over the screen.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct AxisMaps {
+    pub(crate) scales: [AxisScale; 2],
+    pub(crate) breaks: [AxisBreaks; 2],
+}
+
+impl AxisMaps {
+    /// Whether the values of `axis` are its display coordinates.
+    #[inline]
+    fn is_linear(self, axis: usize) -> bool {
+        matches!(self.scales[axis], AxisScale::Linear) && self.breaks[axis].is_empty()
+    }
+
+    /// Display coordinate of `value` on `axis`.
+    #[inline]
+    pub(crate) fn to_display(self, axis: usize, value: f64) -> f64 {
+        if self.is_linear(axis) {
+            value
+        } else {
+            self.scales[axis].to_display(self.breaks[axis].to_display(value))
+        }
+    }
+
+    /// The value at the display coordinate `display` on `axis`.
+    #[inline]
+    pub(crate) fn to_value(self, axis: usize, display: f64) -> f64 {
+        if self.is_linear(axis) {
+            display
+        } else {
+            self.breaks[axis].to_value(self.scales[axis].to_value(display))
+        }
+    }
+
+    pub(crate) fn to_display_bounds(self, bounds: &PlotBounds) -> PlotBounds {
+        let mut display = PlotBounds {
+            min: [0, 1].map(|axis| self.to_display(axis, bounds.min[axis])),
+            max: [0, 1].map(|axis| self.to_display(axis, bounds.max[axis])),
+        };
+        // Values the scale can't show, like zero on a log axis, leave one display unit:
+        for axis in 0..2 {
+            let (min, max) = (display.min[axis], display.max[axis]);
+            if bounds.min[axis].is_finite() && bounds.max[axis].is_finite() {
+                if !min.is_finite() && max.is_finite() {
+                    display.min[axis] = max - 1.0;
+                } else if min.is_finite() && !max.is_finite() {
+                    display.max[axis] = min + 1.0;
+                }
+            }
+        }
+        display
+    }
+
+    pub(crate) fn to_value_bounds(self, display: &PlotBounds) -> PlotBounds {
+        PlotBounds {
+            min: [0, 1].map(|axis| self.to_value(axis, display.min[axis])),
+            max: [0, 1].map(|axis| self.to_value(axis, display.max[axis])),
+        }
+    }
+
+    /// Change `bounds` in display coordinates, e.g. to add margins that look the same on every
+    /// part of the axis.
+    pub(crate) fn in_display(&self, bounds: &mut PlotBounds, change: impl FnOnce(&mut PlotBounds)) {
+        let mut display = self.to_display_bounds(bounds);
+        change(&mut display);
+        *bounds = self.to_value_bounds(&display);
+    }
 }
 
 /// Contains the screen rectangle and the plot bounds and provides methods to transform between them.
+///
+/// Axes with an [`AxisScale`] or [`AxisBreaks`] are spread linearly in display coordinates rather
+/// than in values, and the `dpos`/`dvalue` ratios are per display unit.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Clone, Copy, Debug)]
 pub struct PlotTransform {
@@ -276,15 +489,39 @@ pub struct PlotTransform {
 
     /// Whether to always center the x-range or y-range of the bounds.
     centered: Vec2b,
+
+    /// Whether the x-axis grows to the left and/or the y-axis grows downwards.
+    #[cfg_attr(feature = "serde", serde(default))]
+    inverted: Vec2b,
+
+    /// How the values map to the display coordinates spread over the frame.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    maps: AxisMaps,
 }
 
 impl PlotTransform {
+    /// The transform showing `bounds` in the screen rectangle `frame`.
+    ///
+    /// Empty or infinite ranges of `bounds` are replaced by valid ones, and the axes in
+    /// `center_axis` are made symmetrical around zero. See [`Self::check_invariants`] to test
+    /// code built on top of it.
     pub fn new(frame: Rect, bounds: PlotBounds, center_axis: impl Into<Vec2b>) -> Self {
+        Self::new_mapped(frame, bounds, center_axis, AxisMaps::default())
+    }
+
+    /// Like [`Self::new`], with the bounds sanitized in the display coordinates of `maps`.
+    pub(crate) fn new_mapped(
+        frame: Rect,
+        bounds: PlotBounds,
+        center_axis: impl Into<Vec2b>,
+        maps: AxisMaps,
+    ) -> Self {
         debug_assert!(
             0.0 <= frame.width() && 0.0 <= frame.height(),
             "Bad plot frame: {frame:?}"
         );
         let center_axis = center_axis.into();
+        let bounds = maps.to_display_bounds(&bounds);
 
         // Since the current Y bounds an affect the final X bounds and vice versa, we need to keep
         // the original version of the `bounds` before we start modifying it.
@@ -336,8 +573,44 @@ impl PlotTransform {
 
         Self {
             frame,
-            bounds: new_bounds,
+            bounds: maps.to_value_bounds(&new_bounds),
             centered: center_axis,
+            inverted: Vec2b::FALSE,
+            maps,
+        }
+    }
+
+    /// Flip the direction of the x- and/or y-axis on screen.
+    ///
+    /// Normally x grows to the right and y grows upwards.
+    #[inline]
+    pub fn set_inverted(&mut self, inverted: impl Into<Vec2b>) {
+        self.inverted = inverted.into();
+    }
+
+    /// Whether the x- and/or y-axis direction is flipped on screen.
+    #[inline]
+    pub fn inverted(&self) -> Vec2b {
+        self.inverted
+    }
+
+    /// Screen range the x-bounds map to, taking inversion into account.
+    fn screen_range_x(&self) -> RangeInclusive<f64> {
+        let (left, right) = (self.frame.left() as f64, self.frame.right() as f64);
+        if self.inverted.x {
+            right..=left
+        } else {
+            left..=right
+        }
+    }
+
+    /// Screen range the y-bounds map to, taking inversion into account.
+    fn screen_range_y(&self) -> RangeInclusive<f64> {
+        let (top, bottom) = (self.frame.top() as f64, self.frame.bottom() as f64);
+        if self.inverted.y {
+            top..=bottom
+        } else {
+            bottom..=top // negated y axis!
         }
     }
 
@@ -353,6 +626,46 @@ impl PlotTransform {
         &self.bounds
     }
 
+    /// The bounds in display coordinates, which are spread linearly over the frame.
+    pub(crate) fn display_bounds(&self) -> PlotBounds {
+        self.maps.to_display_bounds(&self.bounds)
+    }
+
+    /// Display range of `axis`.
+    fn display_range(&self, axis: usize) -> RangeInclusive<f64> {
+        let min = self.maps.to_display(axis, self.bounds.min[axis]);
+        let max = self.maps.to_display(axis, self.bounds.max[axis]);
+        min..=max
+    }
+
+    /// Screen range the display range of `axis` maps to.
+    fn screen_range(&self, axis: usize) -> RangeInclusive<f64> {
+        if axis == 0 {
+            self.screen_range_x()
+        } else {
+            self.screen_range_y()
+        }
+    }
+
+    /// Screen position of the display coordinate `display` on `axis`.
+    pub(crate) fn position_from_display(&self, axis: usize, display: f64) -> f32 {
+        remap(display, self.display_range(axis), self.screen_range(axis)) as f32
+    }
+
+    /// The grid `mark` in display coordinates of `axis` as a value and the (smaller) value step
+    /// to its neighbours, as the formatters expect it.
+    pub(crate) fn mark_value(&self, axis: usize, mark: GridMark) -> GridMark {
+        if self.maps.is_linear(axis) {
+            return mark;
+        }
+        let value = self.maps.to_value(axis, mark.value);
+        let step = |display: f64| (self.maps.to_value(axis, display) - value).abs();
+        GridMark {
+            value,
+            step_size: step(mark.value - mark.step_size).min(step(mark.value + mark.step_size)),
+        }
+    }
+
     #[inline]
     pub fn set_bounds(&mut self, bounds: PlotBounds) {
         self.bounds = bounds;
@@ -367,35 +680,40 @@ impl PlotTransform {
         }
         delta_pos.0 *= self.dvalue_dpos()[0];
         delta_pos.1 *= self.dvalue_dpos()[1];
-        self.bounds.translate((delta_pos.0, delta_pos.1));
+        self.maps.in_display(&mut self.bounds, |display| {
+            display.translate((delta_pos.0, delta_pos.1));
+        });
     }
 
     /// Zoom by a relative factor with the given screen position as center.
     pub fn zoom(&mut self, zoom_factor: Vec2, center: Pos2) {
-        let center = self.value_from_position(center);
+        let center = PlotPoint::new(
+            remap(
+                center.x as f64,
+                self.screen_range_x(),
+                self.display_range(0),
+            ),
+            remap(
+                center.y as f64,
+                self.screen_range_y(),
+                self.display_range(1),
+            ),
+        );
 
-        let mut new_bounds = self.bounds;
+        let mut new_bounds = self.display_bounds();
         new_bounds.zoom(zoom_factor, center);
 
         if new_bounds.is_valid() {
-            self.bounds = new_bounds;
+            self.bounds = self.maps.to_value_bounds(&new_bounds);
         }
     }
 
     pub fn position_from_point_x(&self, value: f64) -> f32 {
-        remap(
-            value,
-            self.bounds.min[0]..=self.bounds.max[0],
-            (self.frame.left() as f64)..=(self.frame.right() as f64),
-        ) as f32
+        self.position_from_display(0, self.maps.to_display(0, value))
     }
 
     pub fn position_from_point_y(&self, value: f64) -> f32 {
-        remap(
-            value,
-            self.bounds.min[1]..=self.bounds.max[1],
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-        ) as f32
+        self.position_from_display(1, self.maps.to_display(1, value))
     }
 
     /// Screen/ui position from point on plot.
@@ -408,17 +726,9 @@ impl PlotTransform {
 
     /// Plot point from screen/ui position.
     pub fn value_from_position(&self, pos: Pos2) -> PlotPoint {
-        let x = remap(
-            pos.x as f64,
-            (self.frame.left() as f64)..=(self.frame.right() as f64),
-            self.bounds.range_x(),
-        );
-        let y = remap(
-            pos.y as f64,
-            (self.frame.bottom() as f64)..=(self.frame.top() as f64), // negated y axis!
-            self.bounds.range_y(),
-        );
-        PlotPoint::new(x, y)
+        let x = remap(pos.x as f64, self.screen_range_x(), self.display_range(0));
+        let y = remap(pos.y as f64, self.screen_range_y(), self.display_range(1));
+        PlotPoint::new(self.maps.to_value(0, x), self.maps.to_value(1, y))
     }
 
     /// Transform a rectangle of plot values to a screen-coordinate rectangle.
@@ -437,12 +747,24 @@ impl PlotTransform {
 
     /// delta position / delta value = how many ui points per step in the X axis in "plot space"
     pub fn dpos_dvalue_x(&self) -> f64 {
-        self.frame.width() as f64 / self.bounds.width()
+        let range = self.display_range(0);
+        let dpos_dvalue = self.frame.width() as f64 / (range.end() - range.start());
+        if self.inverted.x {
+            -dpos_dvalue
+        } else {
+            dpos_dvalue
+        }
     }
 
     /// delta position / delta value = how many ui points per step in the Y axis in "plot space"
     pub fn dpos_dvalue_y(&self) -> f64 {
-        -self.frame.height() as f64 / self.bounds.height() // negated y axis!
+        let range = self.display_range(1);
+        let dpos_dvalue = -self.frame.height() as f64 / (range.end() - range.start()); // negated y axis!
+        if self.inverted.y {
+            -dpos_dvalue
+        } else {
+            dpos_dvalue
+        }
     }
 
     /// delta position / delta value = how many ui points per step in "plot space"
@@ -461,7 +783,8 @@ impl PlotTransform {
     fn aspect(&self) -> f64 {
         let rw = self.frame.width() as f64;
         let rh = self.frame.height() as f64;
-        (self.bounds.width() / rw) / (self.bounds.height() / rh)
+        let display = self.display_bounds();
+        (display.width() / rw) / (display.height() / rh)
     }
 
     /// Sets the aspect ratio by expanding the x- or y-axis.
@@ -476,13 +799,13 @@ impl PlotTransform {
             return;
         }
 
-        if current_aspect < aspect {
-            self.bounds
-                .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
-        } else {
-            self.bounds
-                .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
-        }
+        self.maps.in_display(&mut self.bounds, |display| {
+            if current_aspect < aspect {
+                display.expand_x((aspect / current_aspect - 1.0) * display.width() * 0.5);
+            } else {
+                display.expand_y((current_aspect / aspect - 1.0) * display.height() * 0.5);
+            }
+        });
     }
 
     /// Sets the aspect ratio by changing either the X or Y axis (callers choice).
@@ -495,15 +818,331 @@ impl PlotTransform {
             return;
         }
 
-        match axis {
-            Axis::X => {
-                self.bounds
-                    .expand_x((aspect / current_aspect - 1.0) * self.bounds.width() * 0.5);
+        self.maps
+            .in_display(&mut self.bounds, |display| match axis {
+                Axis::X => {
+                    display.expand_x((aspect / current_aspect - 1.0) * display.width() * 0.5);
+                }
+                Axis::Y => {
+                    display.expand_y((current_aspect / aspect - 1.0) * display.height() * 0.5);
+                }
+            });
+    }
+
+    /// How far off `value` is, per axis in plot units, after going to the screen and back.
+    ///
+    /// Screen positions are `f32`, so this grows with the distance from the origin of the
+    /// screen and with the plot units per point.
+    pub fn round_trip_error(&self, value: &PlotPoint) -> [f64; 2] {
+        let back = self.value_from_position(self.position_from_point(value));
+        [(back.x - value.x).abs(), (back.y - value.y).abs()]
+    }
+
+    /// Check that the transform behaves, at a grid of `samples` × `samples` values across the
+    /// bounds:
+    ///
+    /// - values come back from the screen within a thousandth of a point (or the precision of
+    ///   `f64` at these values), see [`Self::round_trip_error`];
+    /// - larger values are always on the same side on the screen;
+    /// - zooming around a screen position keeps the value under it, shrinks the bounds when
+    ///   zooming in, and keeps the order of the values.
+    ///
+    /// Meant for property tests of code that builds or changes transforms:
+    ///
+    /// ```
+    /// # use egui_plot::{PlotBounds, PlotTransform};
+    /// let frame = egui::Rect::from_min_size(egui::pos2(10.0, 20.0), egui::vec2(400.0, 300.0));
+    /// for offset in [-1e9, 0.0, 1e6] {
+    ///     let bounds = PlotBounds::from_min_max([offset, -1.0], [offset + 1e-3, 1.0]);
+    ///     let transform = PlotTransform::new(frame, bounds, false);
+    ///     assert_eq!(transform.check_invariants(8), Ok(()));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    /// The first [`TransformViolation`] found.
+    pub fn check_invariants(&self, samples: usize) -> Result<(), TransformViolation> {
+        let samples = samples.max(2);
+        // Spread evenly over the screen, so that none fall into the gaps of axis breaks:
+        let along = |axis: usize| {
+            let (min, max) = self.display_range(axis).into_inner();
+            (0..samples).map(move |i| {
+                let display = min + (max - min) * i as f64 / (samples - 1) as f64;
+                self.maps.to_value(axis, display)
+            })
+        };
+        // A thousandth of a point, or a few steps of `f64` for values far from zero:
+        let tolerance = [0, 1].map(|axis| {
+            let magnitude = self.bounds.min[axis].abs().max(self.bounds.max[axis].abs());
+            self.dvalue_dpos()[axis].abs() * 1e-3 + magnitude * 4.0 * f64::EPSILON
+        });
+
+        for x in along(0) {
+            for y in along(1) {
+                let point = PlotPoint::new(x, y);
+                let error = self.round_trip_error(&point);
+                if error[0] > tolerance[0] || error[1] > tolerance[1] {
+                    return Err(TransformViolation::RoundTrip { point, error });
+                }
+            }
+        }
+        self.check_monotonic(along(0), along(1))?;
+
+        let frame = self.frame;
+        for center in [frame.center(), frame.left_top(), frame.right_bottom()] {
+            for factor in [0.5, 2.0] {
+                let mut zoomed = *self;
+                zoomed.zoom(Vec2::splat(factor), center);
+                let violation = TransformViolation::Zoom { factor, center };
+                let before = self.value_from_position(center);
+                let after = zoomed.value_from_position(center);
+                if (after.x - before.x).abs() > tolerance[0]
+                    || (after.y - before.y).abs() > tolerance[1]
+                {
+                    return Err(violation);
+                }
+                let (from, to) = (self.display_bounds(), zoomed.display_bounds());
+                let shrunk = to.width() < from.width() && to.height() < from.height();
+                if shrunk != (factor > 1.0) {
+                    return Err(violation);
+                }
+                zoomed.check_monotonic(along(0), along(1))?;
             }
-            Axis::Y => {
-                self.bounds
-                    .expand_y((current_aspect / aspect - 1.0) * self.bounds.height() * 0.5);
+        }
+        Ok(())
+    }
+
+    /// Check that the screen positions of increasing `xs` and `ys` move in one direction.
+    fn check_monotonic(
+        &self,
+        xs: impl Iterator<Item = f64>,
+        ys: impl Iterator<Item = f64>,
+    ) -> Result<(), TransformViolation> {
+        let sign = self.dpos_dvalue().map(f64::signum);
+        let positions = [
+            xs.map(|x| (x, self.position_from_point_x(x)))
+                .collect::<Vec<_>>(),
+            ys.map(|y| (y, self.position_from_point_y(y)))
+                .collect::<Vec<_>>(),
+        ];
+        for (axis, positions) in [Axis::X, Axis::Y].into_iter().zip(positions) {
+            for pair in positions.windows(2) {
+                let [(a, pos_a), (b, pos_b)] = [pair[0], pair[1]];
+                if (pos_b - pos_a) as f64 * sign[usize::from(axis)] < 0.0 {
+                    return Err(TransformViolation::NotMonotonic { axis, a, b });
+                }
             }
         }
+        Ok(())
+    }
+}
+
+/// An invariant a [`PlotTransform`] breaks, found by [`PlotTransform::check_invariants`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum TransformViolation {
+    /// `point` came back from the screen off by `error` plot units per axis.
+    RoundTrip { point: PlotPoint, error: [f64; 2] },
+
+    /// The larger value `b` is on the other side of `a` on the screen than the plot direction.
+    NotMonotonic { axis: Axis, a: f64, b: f64 },
+
+    /// Zooming by `factor` around the screen position `center` moved the value under it,
+    /// didn't change the bounds the right way, or broke the order of the values.
+    Zoom { factor: f32, center: Pos2 },
+}
+
+#[test]
+fn test_clamp_axis() {
+    let mut bounds = PlotBounds::from_min_max([-5.0, 0.0], [5.0, 1000.0]);
+    bounds.clamp_axis(0, Interval::above(0.0), Interval::all());
+    assert_eq!(bounds.range_x(), 0.0..=10.0);
+    bounds.clamp_axis(1, Interval::all(), Interval::new(0.0, 100.0));
+    assert_eq!(bounds.range_y(), 450.0..=550.0);
+}
+
+#[test]
+fn test_sanitize_limits() {
+    let reversed = Interval {
+        start: 5.0,
+        end: 1.0,
+    };
+    assert_eq!(sanitize_bounds_limits(reversed), Interval::all());
+    let nan_start = Interval {
+        start: f64::NAN,
+        end: 1.0,
+    };
+    assert_eq!(sanitize_bounds_limits(nan_start), Interval::below(1.0));
+    assert_eq!(
+        sanitize_bounds_limits(Interval::new(2.0, 2.0)),
+        Interval::all()
+    );
+
+    assert_eq!(
+        sanitize_extent_limits(Interval::below(-1.0)),
+        Interval::all()
+    );
+    let nan_end = Interval {
+        start: 1.0,
+        end: f64::NAN,
+    };
+    assert_eq!(sanitize_extent_limits(nan_end), Interval::above(1.0));
+    assert_eq!(sanitize_extent_limits(reversed), Interval::all());
+
+    // Used to panic in `f64::clamp`:
+    let mut bounds = PlotBounds::from_min_max([-5.0, 0.0], [5.0, 1.0]);
+    for extent in [Interval::below(-1.0), nan_end, reversed] {
+        bounds.clamp_axis(0, Interval::all(), sanitize_extent_limits(extent));
+    }
+    assert_eq!(bounds.range_x(), -5.0..=5.0);
+}
+
+#[test]
+fn test_inverted_transform() {
+    let frame = Rect::from_min_size(pos2(0.0, 0.0), Vec2::new(200.0, 100.0));
+    let bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 5.0]);
+    let mut transform = PlotTransform::new(frame, bounds, false);
+    assert_eq!(transform.dpos_dvalue(), [20.0, -20.0]);
+
+    transform.set_inverted([true, true]);
+    assert_eq!(transform.dpos_dvalue(), [-20.0, 20.0]);
+    // The minimum of the bounds is at the top right, the maximum at the bottom left:
+    assert_eq!(
+        transform.position_from_point(&PlotPoint::new(0.0, 0.0)),
+        pos2(200.0, 0.0)
+    );
+    assert_eq!(
+        transform.position_from_point(&PlotPoint::new(10.0, 5.0)),
+        pos2(0.0, 100.0)
+    );
+    let value = PlotPoint::new(2.5, 1.0);
+    let pos = transform.position_from_point(&value);
+    assert_eq!(pos, pos2(150.0, 20.0));
+    assert_eq!(transform.value_from_position(pos), value);
+
+    for inverted in [Vec2b::new(true, false), Vec2b::new(false, true)] {
+        transform.set_bounds(bounds);
+        transform.set_inverted(inverted);
+        let pos = pos2(60.0, 30.0);
+        let value = transform.value_from_position(pos);
+        assert_eq!(transform.position_from_point(&value), pos);
+
+        // Panning moves the value under the pointer along with it:
+        let drag = Vec2::new(10.0, 20.0);
+        transform.translate_bounds((-drag.x as f64, -drag.y as f64));
+        assert_eq!(transform.position_from_point(&value), pos + drag);
+
+        // Zooming keeps the value under the center in place:
+        let center = pos + drag;
+        transform.zoom(Vec2::splat(2.0), center);
+        assert_eq!(transform.position_from_point(&value), center);
     }
 }
+
+#[test]
+fn test_bounds_animation() {
+    let from = PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]);
+    let to = PlotBounds::from_min_max([100.0, -10.0], [120.0, 0.0]);
+    let animation = BoundsAnimation::new(from, to, 1.0, 2.0);
+    assert_eq!(animation.at(0.5), (from, false));
+    let (halfway, done) = animation.at(2.0);
+    assert_eq!(halfway, PlotBounds::from_min_max([50.0, -5.0], [65.0, 5.0]));
+    assert!(!done);
+    assert_eq!(animation.at(3.0), (to, true));
+    assert_eq!(animation.at(5.0), (to, true));
+}
+
+#[test]
+fn test_check_invariants() {
+    let frame = Rect::from_min_size(pos2(0.0, 0.0), Vec2::new(200.0, 100.0));
+    let bounds = PlotBounds::from_min_max([-3.0, 5.0], [7.0, 6.0]);
+    let mut transform = PlotTransform::new(frame, bounds, [true, false]);
+    assert_eq!(transform.check_invariants(5), Ok(()));
+    transform.set_inverted([true, true]);
+    assert_eq!(transform.check_invariants(5), Ok(()));
+
+    // Far from the origin of the screen, `f32` positions are too coarse:
+    let far = frame.translate(Vec2::new(1e8, 0.0));
+    let transform = PlotTransform::new(far, bounds, false);
+    assert!(matches!(
+        transform.check_invariants(5),
+        Err(TransformViolation::RoundTrip { .. })
+    ));
+}
+
+#[test]
+fn test_add_screen_margin() {
+    let mut bounds = PlotBounds::from_min_max([0.0, 0.0], [10.0, 10.0]);
+    bounds.add_screen_margin(10.0, Vec2::new(120.0, 30.0), Vec2b::new(true, false));
+    // 12 units over 120 points leaves 10 points on each side:
+    assert_eq!(bounds, PlotBounds::from_min_max([-1.0, 0.0], [11.0, 10.0]));
+
+    // No room for the margin:
+    bounds.add_screen_margin(20.0, Vec2::new(120.0, 30.0), Vec2b::new(false, true));
+    assert_eq!(bounds.range_y(), 0.0..=10.0);
+}
+
+#[test]
+fn test_axis_breaks_transform() {
+    let frame = Rect::from_min_size(pos2(0.0, 0.0), Vec2::new(200.0, 100.0));
+    let bounds = PlotBounds::from_min_max([0.0, 0.0], [10_100.0, 1.0]);
+    let maps = AxisMaps {
+        breaks: [AxisBreaks::new().gap(100.0, 10_000.0), AxisBreaks::new()],
+        ..Default::default()
+    };
+    let mut transform = PlotTransform::new_mapped(frame, bounds, false, maps);
+    assert_eq!(transform.dpos_dvalue_x(), 1.0);
+
+    // The segments on both sides of the gap touch:
+    assert_eq!(transform.position_from_point_x(50.0), 50.0);
+    assert_eq!(transform.position_from_point_x(5_000.0), 100.0);
+    assert_eq!(transform.position_from_point_x(10_050.0), 150.0);
+    assert_eq!(
+        transform.value_from_position(pos2(150.0, 50.0)),
+        PlotPoint::new(10_050.0, 0.5)
+    );
+    assert_eq!(transform.check_invariants(9), Ok(()));
+
+    // Panning and zooming move the display coordinates:
+    transform.translate_bounds((-50.0, 0.0));
+    assert_eq!(transform.bounds().range_x(), -50.0..=10_050.0);
+    transform.zoom(Vec2::new(2.0, 1.0), pos2(100.0, 50.0));
+    assert_eq!(transform.bounds().range_x(), 0.0..=10_000.0);
+    assert_eq!(transform.check_invariants(9), Ok(()));
+
+    let mark = GridMark {
+        value: 150.0,
+        step_size: 50.0,
+    };
+    assert_eq!(
+        transform.mark_value(0, mark),
+        GridMark {
+            value: 10_050.0,
+            step_size: 50.0,
+        }
+    );
+}
+
+#[test]
+fn test_axis_scale_transform() {
+    let frame = Rect::from_min_size(pos2(0.0, 0.0), Vec2::new(300.0, 100.0));
+    let bounds = PlotBounds::from_min_max([1.0, -1.0], [1000.0, 1.0]);
+    let maps = AxisMaps {
+        scales: [AxisScale::Log, AxisScale::Linear],
+        ..Default::default()
+    };
+    let mut transform = PlotTransform::new_mapped(frame, bounds, false, maps);
+    assert_eq!(transform.position_from_point_x(100.0), 200.0);
+    assert_eq!(transform.dpos_dvalue_x(), 100.0);
+    assert_eq!(transform.check_invariants(9), Ok(()));
+
+    // Zooming keeps the value under the pointer, and shrinks the decades evenly:
+    transform.zoom(Vec2::new(3.0, 1.0), pos2(100.0, 50.0));
+    assert!((transform.value_from_position(pos2(100.0, 50.0)).x - 10.0).abs() < 1e-9);
+    let range = transform.bounds().range_x();
+    assert!((range.end() / range.start() - 10.0).abs() < 1e-9);
+
+    // Zero can't be shown on a log axis, so it leaves one decade below the other end:
+    let bounds = PlotBounds::from_min_max([0.0, -1.0], [1000.0, 1.0]);
+    let transform = PlotTransform::new_mapped(frame, bounds, false, maps);
+    assert!((transform.bounds().min[0] - 100.0).abs() < 1e-9);
+}
@@ -0,0 +1,118 @@
+//! Fading items in and out as they are added to and removed from the plot, see
+//! [`crate::Plot::item_transitions`].
+
+use egui::{Id, Shape, epaint};
+
+use crate::transform::ease_in_out;
+
+/// The items of a plot over the last frames, kept in the temporary data of the context.
+#[derive(Clone, Default)]
+pub(crate) struct ItemTransitions {
+    /// Whether the plot was shown before, so its first items don't fade in.
+    started: bool,
+
+    /// When each item of the last frame was added, in seconds like [`egui::InputState::time`].
+    added: ahash::HashMap<Id, f64>,
+
+    /// The shapes each item painted the last frame, to fade out if it is removed.
+    last_shapes: ahash::HashMap<Id, Vec<Shape>>,
+
+    /// The last shapes of the removed items, and when they were removed.
+    removed: Vec<(Vec<Shape>, f64)>,
+}
+
+impl ItemTransitions {
+    /// Note the items shown this frame, and return the opacity of those still fading in.
+    ///
+    /// The items of the first frame are shown right away.
+    pub(crate) fn update(
+        &mut self,
+        ids: impl Iterator<Item = Id>,
+        time: f64,
+        duration: f64,
+    ) -> ahash::HashMap<Id, f32> {
+        let first_frame = !self.started;
+        self.started = true;
+        let mut added = ahash::HashMap::default();
+        for id in ids {
+            let since = self.added.get(&id).copied().unwrap_or(if first_frame {
+                time - duration
+            } else {
+                time
+            });
+            added.insert(id, since);
+        }
+        let mut removed: Vec<(Id, Vec<Shape>)> = self
+            .last_shapes
+            .drain()
+            .filter(|(id, _)| !added.contains_key(id))
+            .collect();
+        removed.sort_by_key(|(id, _)| id.value());
+        self.removed
+            .extend(removed.into_iter().map(|(_, shapes)| (shapes, time)));
+        self.added = added;
+        self.removed.retain(|(_, since)| time < since + duration);
+
+        self.added
+            .iter()
+            .filter(|(_, since)| time < *since + duration)
+            .map(|(id, since)| (*id, opacity(time - since, duration)))
+            .collect()
+    }
+
+    /// The last shapes of the items removed recently, fading out.
+    pub(crate) fn removed_shapes(&self, time: f64, duration: f64) -> Vec<Shape> {
+        let mut faded = Vec::new();
+        for (shapes, since) in &self.removed {
+            let opacity = 1.0 - opacity(time - since, duration);
+            faded.extend(shapes.iter().cloned().map(|mut shape| {
+                epaint::shape_transform::adjust_colors(&mut shape, move |color| {
+                    *color = color.gamma_multiply(opacity);
+                });
+                shape
+            }));
+        }
+        faded
+    }
+
+    /// Keep the shapes the items painted this frame, in case they are removed.
+    pub(crate) fn set_last_shapes(&mut self, shapes: ahash::HashMap<Id, Vec<Shape>>) {
+        self.last_shapes = shapes;
+    }
+
+    /// Whether any item is still fading in or out.
+    pub(crate) fn is_animating(&self, time: f64, duration: f64) -> bool {
+        !self.removed.is_empty() || self.added.values().any(|since| time < since + duration)
+    }
+}
+
+/// How far along a fade of `duration` is after `elapsed`, from `0.0` to `1.0`.
+fn opacity(elapsed: f64, duration: f64) -> f32 {
+    if duration > 0.0 {
+        ease_in_out((elapsed / duration).clamp(0.0, 1.0)) as f32
+    } else {
+        1.0
+    }
+}
+
+#[test]
+fn test_item_transitions() {
+    let [a, b] = [Id::new("a"), Id::new("b")];
+    let mut transitions = ItemTransitions::default();
+    assert!(transitions.update(std::iter::once(a), 0.0, 1.0).is_empty());
+    transitions.set_last_shapes(std::iter::once((a, vec![Shape::Noop])).collect());
+
+    // `b` fades in while `a` fades out:
+    let fading_in = transitions.update(std::iter::once(b), 1.0, 1.0);
+    assert_eq!(fading_in.get(&b), Some(&0.0));
+    assert_eq!(transitions.removed_shapes(1.0, 1.0).len(), 1);
+    assert_eq!(
+        transitions.update(std::iter::once(b), 1.5, 1.0).get(&b),
+        Some(&0.5)
+    );
+    assert!(transitions.is_animating(1.5, 1.0));
+
+    assert!(transitions.update(std::iter::once(b), 2.0, 1.0).is_empty());
+    assert!(transitions.removed_shapes(2.0, 1.0).is_empty());
+    assert!(!transitions.is_animating(2.0, 1.0));
+}
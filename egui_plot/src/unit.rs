@@ -0,0 +1,302 @@
+//! Units the values of an axis can be shown in, picked at runtime, see [`crate::Plot::y_units`].
+
+use std::{ops::RangeInclusive, sync::Arc};
+
+use egui::{Response, WidgetText};
+
+use crate::axis::AxisFormatterFn;
+use crate::{
+    AxisHints, CoordinatesFormatter, GridInput, GridMark, GridSpacer, LabelFormatter, PlotPoint,
+    format_number,
+};
+
+type ConversionFn = dyn Fn(f64) -> f64 + Send + Sync;
+
+/// The SI prefixes of the powers of 1000, from 10⁻²⁴ to 10²⁴.
+const SI_PREFIXES: [&str; 17] = [
+    "y", "z", "a", "f", "p", "n", "µ", "m", "", "k", "M", "G", "T", "P", "E", "Z", "Y",
+];
+
+/// A unit to show the values of an axis in, with the conversion from and to the unit of the data.
+///
+/// Only the displayed values change, so switching units doesn't need the data to be rebuilt.
+#[derive(Clone)]
+pub struct Unit {
+    name: String,
+    from_data: Arc<ConversionFn>,
+    to_data: Arc<ConversionFn>,
+    si_prefixes: bool,
+}
+
+impl Unit {
+    /// The unit the data is in, shown unconverted.
+    pub fn data(name: impl Into<String>) -> Self {
+        Self::new(name, |value| value, |value| value)
+    }
+
+    /// The unit the data is in, like `"V"` or `"Hz"`, shown with SI prefixes, see
+    /// [`Self::si_prefixes`].
+    pub fn si(name: impl Into<String>) -> Self {
+        Self::data(name).si_prefixes(true)
+    }
+
+    /// `display = scale * data + offset`, like °C to °F with `scale = 1.8` and `offset = 32`.
+    pub fn linear(name: impl Into<String>, scale: f64, offset: f64) -> Self {
+        Self::new(
+            name,
+            move |value| scale * value + offset,
+            move |value| (value - offset) / scale,
+        )
+    }
+
+    /// Any monotonic conversion, like dBm to mW, with its inverse.
+    pub fn new(
+        name: impl Into<String>,
+        from_data: impl Fn(f64) -> f64 + Send + Sync + 'static,
+        to_data: impl Fn(f64) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            from_data: Arc::new(from_data),
+            to_data: Arc::new(to_data),
+            si_prefixes: false,
+        }
+    }
+
+    /// Scale the values by a power of 1000 and show the SI prefix with the name, like `ms`,
+    /// `µV` or `kHz`. Default: `false`.
+    ///
+    /// The axis picks the prefix of the largest value in view, and the hover label the prefix of
+    /// each value.
+    #[inline]
+    pub fn si_prefixes(mut self, si_prefixes: bool) -> Self {
+        self.si_prefixes = si_prefixes;
+        self
+    }
+
+    /// The factor to divide values of about `magnitude` in this unit by, and the name with the
+    /// SI prefix of the factor.
+    fn prefixed(&self, magnitude: f64) -> (f64, String) {
+        if !self.si_prefixes {
+            return (1.0, self.name.clone());
+        }
+        let (factor, prefix) = si_prefix(magnitude);
+        (factor, format!("{prefix}{}", self.name))
+    }
+
+    #[inline]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Convert a value of the data to this unit.
+    #[inline]
+    pub fn convert(&self, value: f64) -> f64 {
+        (self.from_data)(value)
+    }
+
+    /// Convert a value in this unit back to the unit of the data.
+    #[inline]
+    pub fn convert_back(&self, value: f64) -> f64 {
+        (self.to_data)(value)
+    }
+
+    /// Displayed units per data unit, on average over `range`.
+    fn scale(&self, range: &RangeInclusive<f64>) -> f64 {
+        let scale = ((self.convert(*range.end()) - self.convert(*range.start()))
+            / (range.end() - range.start()))
+        .abs();
+        if scale.is_finite() && scale > 0.0 {
+            scale
+        } else {
+            1.0
+        }
+    }
+}
+
+impl std::fmt::Debug for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("Unit").field(&self.name).finish()
+    }
+}
+
+/// Let `spacer` place the grid marks at round values of `unit`.
+pub(crate) fn grid_spacer(unit: Option<Unit>, spacer: GridSpacer<'_>) -> GridSpacer<'_> {
+    let Some(unit) = unit else {
+        return spacer;
+    };
+    Box::new(move |input: GridInput| {
+        let (min, max) = input.bounds;
+        let scale = unit.scale(&(min..=max));
+        let (a, b) = (unit.convert(min), unit.convert(max));
+        spacer(GridInput {
+            bounds: (a.min(b), a.max(b)),
+            base_step_size: input.base_step_size * scale,
+        })
+        .into_iter()
+        .map(|mark| GridMark {
+            value: unit.convert_back(mark.value),
+            step_size: mark.step_size / scale,
+        })
+        .collect()
+    })
+}
+
+/// The factor of the SI prefix for values of about `magnitude`, and the prefix.
+fn si_prefix(magnitude: f64) -> (f64, &'static str) {
+    let magnitude = magnitude.abs();
+    if magnitude == 0.0 || !magnitude.is_finite() {
+        return (1.0, "");
+    }
+    let power = (magnitude.log10() / 3.0).floor().clamp(-8.0, 8.0) as i32;
+    (1000_f64.powi(power), SI_PREFIXES[(power + 8) as usize])
+}
+
+/// Format the ticks of an axis in `unit` over `range`, and add its name to the axis label.
+pub(crate) fn apply_to_axis(unit: &Unit, hints: &mut AxisHints<'_>, range: &RangeInclusive<f64>) {
+    let magnitude = unit
+        .convert(*range.start())
+        .abs()
+        .max(unit.convert(*range.end()).abs());
+    let (factor, name) = unit.prefixed(magnitude);
+    hints.formatter = axis_formatter(unit.clone(), factor, hints.formatter.clone());
+    hints.label = if hints.label.is_empty() {
+        WidgetText::from(name)
+    } else {
+        WidgetText::from(format!("{} [{name}]", hints.label.text()))
+    };
+}
+
+/// Let `formatter` format the tick values in `unit`, divided by the `factor` of its prefix.
+// The wrapped formatter isn't `Send` either.
+#[allow(clippy::arc_with_non_send_sync)]
+fn axis_formatter(
+    unit: Unit,
+    factor: f64,
+    formatter: Arc<AxisFormatterFn<'_>>,
+) -> Arc<AxisFormatterFn<'_>> {
+    Arc::new(move |mark, range| {
+        let scale = unit.scale(range) / factor;
+        let (a, b) = (
+            unit.convert(*range.start()) / factor,
+            unit.convert(*range.end()) / factor,
+        );
+        let mark = GridMark {
+            value: unit.convert(mark.value) / factor,
+            step_size: mark.step_size * scale,
+        };
+        formatter(mark, &(a.min(b)..=a.max(b)))
+    })
+}
+
+/// Show the hovered values in the selected units, with the names of the units unless
+/// `formatter` is custom.
+///
+/// Without a custom `formatter`, the label is only shown while stepping through the points with
+/// the keyboard.
+pub(crate) fn label_formatter(
+    units: [Option<Unit>; 2],
+    formatter: LabelFormatter<'_>,
+    [show_x, show_y]: [bool; 2],
+) -> LabelFormatter<'_> {
+    if units.iter().all(Option::is_none) {
+        return formatter;
+    }
+    Some(match formatter {
+        Some(formatter) => Box::new(move |name, point| formatter(name, &convert(&units, point))),
+        None => Box::new(move |name, point| {
+            let mut lines = Vec::new();
+            if !name.is_empty() {
+                lines.push(name.to_owned());
+            }
+            if show_x {
+                lines.push(format!("x = {}", format_value(units[0].as_ref(), point.x)));
+            }
+            if show_y {
+                lines.push(format!("y = {}", format_value(units[1].as_ref(), point.y)));
+            }
+            lines.join("\n")
+        }),
+    })
+}
+
+/// Convert the pointer coordinates to the selected units before formatting them.
+pub(crate) fn coordinates_formatter(
+    units: [Option<Unit>; 2],
+    formatter: CoordinatesFormatter<'_>,
+) -> CoordinatesFormatter<'_> {
+    if units.iter().all(Option::is_none) {
+        return formatter;
+    }
+    CoordinatesFormatter::new(move |point, bounds| {
+        formatter.format(&convert(&units, point), bounds)
+    })
+}
+
+fn convert(units: &[Option<Unit>; 2], point: &PlotPoint) -> PlotPoint {
+    let convert =
+        |unit: &Option<Unit>, value| unit.as_ref().map_or(value, |unit| unit.convert(value));
+    PlotPoint::new(convert(&units[0], point.x), convert(&units[1], point.y))
+}
+
+/// Let the user pick one of `units` from the context menu of an axis.
+pub(crate) fn unit_menu(response: &Response, units: &[Unit], selected: &mut usize) {
+    if units.len() < 2 {
+        return;
+    }
+    response.context_menu(|ui| {
+        for (index, unit) in units.iter().enumerate() {
+            if ui
+                .selectable_label(*selected == index, unit.name())
+                .clicked()
+            {
+                *selected = index;
+                ui.close();
+            }
+        }
+    });
+}
+
+fn format_value(unit: Option<&Unit>, value: f64) -> String {
+    match unit {
+        Some(unit) => {
+            let value = unit.convert(value);
+            let (factor, name) = unit.prefixed(value);
+            format!("{} {name}", format_number(value / factor, 3))
+        }
+        None => format_number(value, 3),
+    }
+}
+
+#[test]
+fn test_grid_spacer() {
+    // 0..11 °C is 32..51.8 °F, so the marks should be at 40 °F and 50 °F:
+    let fahrenheit = Unit::linear("°F", 1.8, 32.0);
+    let spacer = grid_spacer(
+        Some(fahrenheit.clone()),
+        crate::uniform_grid_spacer(|_| [10.0, 100.0, 1000.0]),
+    );
+    let marks = spacer(GridInput {
+        bounds: (0.0, 11.0),
+        base_step_size: 1.0,
+    });
+    let values: Vec<f64> = marks
+        .iter()
+        .map(|mark| (fahrenheit.convert(mark.value) * 1e6).round() / 1e6)
+        .collect();
+    assert_eq!(values, [40.0, 50.0]);
+    assert!((marks[0].step_size - 10.0 / 1.8).abs() < 1e-9);
+}
+
+#[test]
+fn test_si_prefix() {
+    assert_eq!(si_prefix(0.0), (1.0, ""));
+    assert_eq!(si_prefix(999.0), (1.0, ""));
+    assert_eq!(si_prefix(-2_500.0), (1000.0, "k"));
+    assert_eq!(si_prefix(0.02), (1e-3, "m"));
+    assert_eq!(si_prefix(1e40).1, "Y");
+
+    let volts = Unit::si("V");
+    assert_eq!(format_value(Some(&volts), 0.0123), "12.300 mV");
+    assert_eq!(format_value(Some(&Unit::data("V")), 0.5), "0.500 V");
+}
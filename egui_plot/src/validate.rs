@@ -0,0 +1,103 @@
+//! Settings of a plot that contradict each other, see [`crate::Plot::validate`].
+
+use std::fmt;
+
+use egui::{Align2, Id, Rect, TextStyle, Ui};
+
+use crate::Axis;
+
+/// A contradiction in the settings of a plot, found by [`crate::Plot::validate`].
+///
+/// These don't stop the plot from working, but some of the settings have no effect.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PlotConfigIssue {
+    /// Default bounds are set for the axis, but so are automatic bounds, which fit the data
+    /// instead, only widened to include the default bounds.
+    DefaultBoundsWithAutoBounds(Axis),
+
+    /// The default bounds of the axis are not within its [`crate::Plot::bounds_limits`].
+    DefaultBoundsOutsideLimits(Axis),
+
+    /// The bounds or the cursors are linked to a group without linking any axis.
+    LinkWithoutAxes { group: Id },
+
+    /// The plots of a link group link different axes, so they follow each other on some axes
+    /// only one way. Only found while showing the plot.
+    LinkGroupAxesMismatch { group: Id },
+
+    /// Zooming or panning is allowed on one axis only, but a [`crate::Plot::data_aspect`]
+    /// changes both together.
+    DataAspectWithLockedAxis,
+
+    /// Both the width and the height are set, so the [`crate::Plot::view_aspect`] has no
+    /// effect.
+    ViewAspectWithFixedSize,
+
+    /// Box zooming starts with the button used to pan, without modifiers, so it takes over and
+    /// the plot can't be panned by dragging.
+    BoxZoomHidesPan,
+}
+
+impl fmt::Display for PlotConfigIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let axis = |axis: &Axis| match axis {
+            Axis::X => "x",
+            Axis::Y => "y",
+        };
+        match self {
+            Self::DefaultBoundsWithAutoBounds(a) => write!(
+                f,
+                "default {} bounds are set, but auto-bounds are on for that axis",
+                axis(a)
+            ),
+            Self::DefaultBoundsOutsideLimits(a) => write!(
+                f,
+                "default {} bounds are outside the bounds limits",
+                axis(a)
+            ),
+            Self::LinkWithoutAxes { group } => {
+                write!(f, "link group {group:?} doesn't link any axis")
+            }
+            Self::LinkGroupAxesMismatch { group } => {
+                write!(f, "plots of link group {group:?} link different axes")
+            }
+            Self::DataAspectWithLockedAxis => write!(
+                f,
+                "data aspect changes both axes, but zoom or drag is locked on one"
+            ),
+            Self::ViewAspectWithFixedSize => {
+                write!(
+                    f,
+                    "view aspect is ignored, as width and height are both set"
+                )
+            }
+            Self::BoxZoomHidesPan => write!(
+                f,
+                "box zoom uses the pan button without modifiers, so dragging never pans"
+            ),
+        }
+    }
+}
+
+/// List the `issues` in the top left corner of the plot `frame`.
+pub(crate) fn paint_issues(ui: &Ui, frame: Rect, issues: &[PlotConfigIssue]) {
+    if issues.is_empty() {
+        return;
+    }
+    let painter = ui.painter().with_clip_rect(frame);
+    let text = issues
+        .iter()
+        .map(|issue| format!("⚠ {issue}"))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let font_id = TextStyle::Small.resolve(ui.style());
+    let color = ui.visuals().warn_fg_color;
+    let galley = painter.layout(text, font_id, color, frame.width() - 16.0);
+    let rect = Align2::LEFT_TOP.anchor_size(frame.shrink(8.0).left_top(), galley.size());
+    painter.rect_filled(
+        rect.expand(4.0),
+        ui.style().visuals.window_corner_radius,
+        ui.visuals().extreme_bg_color.gamma_multiply(0.9),
+    );
+    painter.galley(rect.min, galley, color);
+}
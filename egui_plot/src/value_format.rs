@@ -0,0 +1,145 @@
+//! Formatting of the values in cursor readouts and the default tooltips.
+
+/// How the plot formats values in its readouts, see [`crate::Plot::value_format`].
+///
+/// By default a value gets as many decimals as tell apart two points a pixel apart.
+///
+/// ```
+/// # use egui_plot::ValueFormat;
+/// let format = ValueFormat::new().thousands_separator(',');
+/// assert_eq!(format.format(1234567.891, 2), "1,234,567.89");
+///
+/// let format = ValueFormat::new().significant_digits(3).scientific(1e-3, 1e6);
+/// assert_eq!(format.format(0.000123456, 2), "1.23e-4");
+/// assert_eq!(format.format(12.3456, 2), "12.3");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct ValueFormat {
+    significant_digits: Option<usize>,
+    scientific: Option<(f64, f64)>,
+    thousands_separator: Option<char>,
+}
+
+impl ValueFormat {
+    /// Decimals as fine as a pixel, no scientific notation and no thousands separators.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Show `digits` significant digits instead of the decimals of a pixel, e.g. `12.3` or
+    /// `0.000123` for `3`.
+    #[inline]
+    pub fn significant_digits(mut self, digits: usize) -> Self {
+        self.significant_digits = Some(digits.clamp(1, 17));
+        self
+    }
+
+    /// Show values smaller than `small` or at least as large as `large` in scientific
+    /// notation, e.g. `1.5e-7`. Zero is always shown as is.
+    #[inline]
+    pub fn scientific(mut self, small: f64, large: f64) -> Self {
+        self.scientific = Some((small.abs(), large.abs()));
+        self
+    }
+
+    /// Group the digits before the decimal point in threes, e.g. `1 234 567` for `' '`.
+    #[inline]
+    pub fn thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Format `value`, with `decimals` unless [`Self::significant_digits`] is set.
+    pub fn format(&self, value: f64, decimals: usize) -> String {
+        if !value.is_finite() {
+            return format!("{value}");
+        }
+        let exponent = if value == 0.0 {
+            0
+        } else {
+            value.abs().log10().floor() as i32
+        };
+
+        let scientific = self
+            .scientific
+            .is_some_and(|(small, large)| value != 0.0 && !(small..large).contains(&value.abs()));
+        if scientific {
+            let precision = match self.significant_digits {
+                Some(digits) => digits - 1,
+                None => (decimals as i32 + exponent).clamp(0, 3) as usize,
+            };
+            return format!("{value:.precision$e}");
+        }
+
+        let (value, decimals) = match self.significant_digits {
+            Some(digits) => {
+                let places = digits as i32 - 1 - exponent;
+                // Round away the digits before the decimal point that aren't significant:
+                if places < 0 {
+                    let unit = 10_f64.powi(-places);
+                    ((value / unit).round() * unit, 0)
+                } else {
+                    (value, places as usize)
+                }
+            }
+            None => (value, decimals),
+        };
+        let formatted = format!("{value:.decimals$}");
+        match self.thousands_separator {
+            Some(separator) => group_thousands(&formatted, separator),
+            None => formatted,
+        }
+    }
+}
+
+/// The decimals telling apart two values `scale` apart, as for the default readouts.
+pub(crate) fn decimals_for(scale: f64) -> usize {
+    ((-scale.abs().log10()).ceil().max(0.0) as usize).clamp(1, 6)
+}
+
+/// Insert `separator` between each group of three digits before the decimal point.
+fn group_thousands(formatted: &str, separator: char) -> String {
+    let (sign, unsigned) = match formatted.strip_prefix('-') {
+        Some(unsigned) => ("-", unsigned),
+        None => ("", formatted),
+    };
+    let (integer, fraction) = match unsigned.find('.') {
+        Some(dot) => unsigned.split_at(dot),
+        None => (unsigned, ""),
+    };
+    let mut grouped = String::from(sign);
+    for (i, digit) in integer.chars().enumerate() {
+        if i > 0 && (integer.len() - i) % 3 == 0 {
+            grouped.push(separator);
+        }
+        grouped.push(digit);
+    }
+    grouped.push_str(fraction);
+    grouped
+}
+
+#[test]
+fn test_value_format() {
+    let default = ValueFormat::new();
+    assert_eq!(default.format(1.23456, 2), "1.23");
+    assert_eq!(default.format(f64::NAN, 2), "NaN");
+
+    let grouped = ValueFormat::new().thousands_separator(' ');
+    assert_eq!(grouped.format(-1234567.0, 0), "-1 234 567");
+    assert_eq!(grouped.format(123.0, 1), "123.0");
+    assert_eq!(grouped.format(1000.5, 1), "1 000.5");
+
+    let significant = ValueFormat::new().significant_digits(3);
+    assert_eq!(significant.format(0.000123456, 1), "0.000123");
+    assert_eq!(significant.format(98765.0, 1), "98800");
+    assert_eq!(significant.format(0.0, 4), "0.00");
+
+    let scientific = ValueFormat::new().scientific(1e-4, 1e6);
+    assert_eq!(scientific.format(2.5e7, 0), "2.500e7");
+    assert_eq!(scientific.format(-2.5e7, 0), "-2.500e7");
+    assert_eq!(scientific.format(0.0, 2), "0.00");
+    assert_eq!(scientific.format(1.5e-5, 6), "1.5e-5");
+
+    assert_eq!(decimals_for(0.01), 2);
+    assert_eq!(decimals_for(100.0), 1);
+}
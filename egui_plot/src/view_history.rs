@@ -0,0 +1,90 @@
+//! The views the user went through, to step back to with [`crate::PlotUi::undo_view`].
+
+use crate::{BoundsChangeCause, PlotBounds};
+
+/// How many views to remember.
+const MAX_VIEWS: usize = 100;
+
+/// Changes with the same cause closer together than this (in seconds) are one step, so a
+/// continuous pan or zoom is undone at once.
+const COALESCE_SECONDS: f64 = 0.5;
+
+/// The bounds before each change of the view, and those undone since.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ViewHistory {
+    back: Vec<PlotBounds>,
+    forward: Vec<PlotBounds>,
+
+    /// The cause and time of the last recorded change.
+    last_change: Option<(BoundsChangeCause, f64)>,
+}
+
+impl ViewHistory {
+    /// Remember the bounds `old` the view changed from at `time`.
+    ///
+    /// Auto-fitting to the data, following a linked plot and stepping through the history
+    /// itself are not new views.
+    pub(crate) fn record(&mut self, old: PlotBounds, cause: BoundsChangeCause, time: f64) {
+        if matches!(
+            cause,
+            BoundsChangeCause::AutoFit | BoundsChangeCause::LinkSync | BoundsChangeCause::History
+        ) {
+            self.last_change = None;
+            return;
+        }
+        let continued = self
+            .last_change
+            .is_some_and(|(last, at)| last == cause && time - at < COALESCE_SECONDS);
+        self.last_change = Some((cause, time));
+        if continued {
+            return;
+        }
+        self.back.push(old);
+        if self.back.len() > MAX_VIEWS {
+            self.back.remove(0);
+        }
+        self.forward.clear();
+    }
+
+    /// The bounds `steps` views back from `current`, or forward if negative, if there are any.
+    pub(crate) fn step(&mut self, current: PlotBounds, steps: isize) -> Option<PlotBounds> {
+        let (from, to) = if steps > 0 {
+            (&mut self.back, &mut self.forward)
+        } else {
+            (&mut self.forward, &mut self.back)
+        };
+        let mut bounds = None;
+        for _ in 0..steps.unsigned_abs() {
+            let Some(previous) = from.pop() else {
+                break;
+            };
+            to.push(bounds.unwrap_or(current));
+            bounds = Some(previous);
+        }
+        self.last_change = None;
+        bounds
+    }
+}
+
+#[test]
+fn test_view_history() {
+    let view = |x: f64| PlotBounds::from_min_max([x, 0.0], [x + 1.0, 1.0]);
+    let mut history = ViewHistory::default();
+
+    // A continuous pan is a single step:
+    history.record(view(0.0), BoundsChangeCause::Pan, 0.0);
+    history.record(view(1.0), BoundsChangeCause::Pan, 0.1);
+    history.record(view(2.0), BoundsChangeCause::Zoom, 0.2);
+    history.record(view(3.0), BoundsChangeCause::AutoFit, 0.3);
+    assert_eq!(history.back, vec![view(0.0), view(2.0)]);
+
+    assert_eq!(history.step(view(4.0), 1), Some(view(2.0)));
+    assert_eq!(history.step(view(2.0), 5), Some(view(0.0)));
+    assert_eq!(history.step(view(0.0), 1), None);
+    assert_eq!(history.step(view(0.0), -1), Some(view(2.0)));
+
+    // A new view drops the ones undone:
+    history.record(view(2.0), BoundsChangeCause::BoxZoom, 1.0);
+    assert_eq!(history.step(view(5.0), -1), None);
+    assert_eq!(history.back, vec![view(0.0), view(2.0)]);
+}
@@ -0,0 +1,312 @@
+//! Weibull probability plot helper.
+
+use std::ops::RangeInclusive;
+
+use egui::Color32;
+
+use crate::{Band, GridMark, Line, MarkerShape, PlotPoints, PlotUi, Points};
+
+/// Samples, linearized coordinates and the fitted line of a Weibull probability plot.
+///
+/// Failure times are ranked with Bernard's median-rank approximation and plotted in the
+/// linearized Weibull space: `x = ln(t)` and `y = ln(-ln(1 - F))`.
+/// A least-squares line through these points gives the shape (`β`, the slope) and
+/// scale (`η`, where the line crosses `F = 63.2%`) parameters.
+///
+/// The plot axes are in the transformed space; use [`WeibullPlot::x_axis_formatter`] and
+/// [`WeibullPlot::y_axis_formatter`] to label the ticks in time and percent instead.
+///
+/// # Example
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use egui_plot::{Plot, WeibullPlot};
+///
+/// let weibull = WeibullPlot::new("bearings", &[16.0, 34.0, 53.0, 75.0, 93.0, 120.0]);
+/// Plot::new("weibull")
+///     .x_axis_formatter(WeibullPlot::x_axis_formatter)
+///     .y_axis_formatter(WeibullPlot::y_axis_formatter)
+///     .show(ui, |plot_ui| plot_ui.weibull(&weibull));
+/// # });
+/// ```
+#[derive(Clone, Debug)]
+pub struct WeibullPlot {
+    name: String,
+
+    /// `ln(t)` of the sorted failure times.
+    xs: Vec<f64>,
+
+    /// `ln(-ln(1 - F))` of the median ranks.
+    ys: Vec<f64>,
+
+    /// Slope of the fitted line, i.e. the Weibull shape parameter `β`.
+    shape: f64,
+
+    /// Intercept of the fitted line.
+    intercept: f64,
+
+    /// Residual standard error of the fit, in the linearized y-space.
+    residual_std: f64,
+
+    r_squared: f64,
+
+    confidence: f64,
+    color: Color32,
+}
+
+impl WeibullPlot {
+    /// Rank and fit the given failure times.
+    ///
+    /// Non-finite and non-positive samples are ignored. At least two distinct samples are
+    /// needed for a fit; otherwise the parameters are `NaN`.
+    pub fn new(name: impl Into<String>, failure_times: &[f64]) -> Self {
+        let mut times: Vec<f64> = failure_times
+            .iter()
+            .copied()
+            .filter(|t| t.is_finite() && *t > 0.0)
+            .collect();
+        times.sort_by(f64::total_cmp);
+
+        let n = times.len() as f64;
+        let xs: Vec<f64> = times.iter().map(|t| t.ln()).collect();
+        let ys: Vec<f64> = (0..times.len())
+            .map(|i| {
+                // Bernard's approximation of the median rank:
+                let f = (i as f64 + 1.0 - 0.3) / (n + 0.4);
+                (-(1.0 - f).ln()).ln()
+            })
+            .collect();
+
+        let mut plot = Self {
+            name: name.into(),
+            xs,
+            ys,
+            shape: f64::NAN,
+            intercept: f64::NAN,
+            residual_std: f64::NAN,
+            r_squared: f64::NAN,
+            confidence: 0.95,
+            color: Color32::TRANSPARENT,
+        };
+        plot.fit();
+        plot
+    }
+
+    /// Two-sided confidence level of the bounds around the fitted line. Default: `0.95`.
+    #[inline]
+    pub fn confidence(mut self, level: f64) -> Self {
+        self.confidence = level.clamp(0.0, 0.9999);
+        self
+    }
+
+    /// Color of the samples, fitted line and bounds.
+    /// Default is `Color32::TRANSPARENT` which means a color will be auto-assigned.
+    #[inline]
+    pub fn color(mut self, color: impl Into<Color32>) -> Self {
+        self.color = color.into();
+        self
+    }
+
+    /// Weibull shape parameter `β` (slope of the fitted line).
+    #[inline]
+    pub fn shape(&self) -> f64 {
+        self.shape
+    }
+
+    /// Weibull scale parameter `η` (characteristic life, where 63.2% have failed).
+    #[inline]
+    pub fn scale(&self) -> f64 {
+        (-self.intercept / self.shape).exp()
+    }
+
+    /// Coefficient of determination of the fit in the linearized space.
+    #[inline]
+    pub fn r_squared(&self) -> f64 {
+        self.r_squared
+    }
+
+    /// Human readable summary of the fitted parameters, e.g. for a legend or label.
+    pub fn parameter_readout(&self) -> String {
+        format!(
+            "β = {:.3}, η = {:.3}, R² = {:.4}",
+            self.shape(),
+            self.scale(),
+            self.r_squared()
+        )
+    }
+
+    /// Formats a tick on the `ln(t)` axis as the corresponding time `t`.
+    pub fn x_axis_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+        let t = mark.value.exp();
+        if t >= 1e4 || t < 1e-2 {
+            format!("{t:.1e}")
+        } else {
+            format!("{t:.3}")
+                .trim_end_matches('0')
+                .trim_end_matches('.')
+                .to_owned()
+        }
+    }
+
+    /// Formats a tick on the `ln(-ln(1 - F))` axis as the unreliability `F` in percent.
+    pub fn y_axis_formatter(mark: GridMark, _range: &RangeInclusive<f64>) -> String {
+        let f = 1.0 - (-mark.value.exp()).exp();
+        format!("{:.3}%", 100.0 * f)
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_owned()
+    }
+
+    /// The ranked samples in the linearized space.
+    pub fn points(&self) -> Points<'static> {
+        let points: PlotPoints<'static> = self
+            .xs
+            .iter()
+            .zip(&self.ys)
+            .map(|(&x, &y)| [x, y])
+            .collect();
+        Points::new(self.name.clone(), points)
+            .shape(MarkerShape::Circle)
+            .radius(3.0)
+            .color(self.color)
+    }
+
+    /// The fitted line, spanning the range of the samples.
+    pub fn fit_line(&self) -> Line<'static> {
+        let (x0, x1) = self.x_range();
+        let y = |x: f64| self.intercept + self.shape * x;
+        let points = PlotPoints::new(vec![[x0, y(x0)], [x1, y(x1)]]);
+        Line::new(format!("{} fit", self.name), points).color(self.color)
+    }
+
+    /// Confidence bounds around the fitted line.
+    pub fn confidence_band(&self) -> Band<'static> {
+        const SAMPLES: usize = 64;
+        let (x0, x1) = self.x_range();
+        let n = self.xs.len() as f64;
+        let mean_x = self.xs.iter().sum::<f64>() / n;
+        let sxx: f64 = self.xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+        let z = normal_quantile(0.5 + 0.5 * self.confidence);
+
+        let xs: Vec<f64> = (0..SAMPLES)
+            .map(|i| x0 + (x1 - x0) * i as f64 / (SAMPLES - 1) as f64)
+            .collect();
+        let half_width =
+            |x: f64| z * self.residual_std * (1.0 / n + (x - mean_x).powi(2) / sxx).sqrt();
+        let lower: Vec<f64> = xs
+            .iter()
+            .map(|&x| self.intercept + self.shape * x - half_width(x))
+            .collect();
+        let upper: Vec<f64> = xs
+            .iter()
+            .map(|&x| self.intercept + self.shape * x + half_width(x))
+            .collect();
+
+        let band = Band::with_name(format!(
+            "{} {:.0}% bounds",
+            self.name,
+            100.0 * self.confidence
+        ))
+        .with_series(&xs, &lower, &upper);
+        if self.color == Color32::TRANSPARENT {
+            band
+        } else {
+            band.with_color(self.color.gamma_multiply(0.25))
+        }
+    }
+
+    fn x_range(&self) -> (f64, f64) {
+        let min = self.xs.first().copied().unwrap_or(f64::NAN);
+        let max = self.xs.last().copied().unwrap_or(f64::NAN);
+        (min, max)
+    }
+
+    /// Least-squares regression of `y` on `x`.
+    fn fit(&mut self) {
+        let n = self.xs.len();
+        if n < 2 {
+            return;
+        }
+        let nf = n as f64;
+        let mean_x = self.xs.iter().sum::<f64>() / nf;
+        let mean_y = self.ys.iter().sum::<f64>() / nf;
+        let mut sxx = 0.0;
+        let mut sxy = 0.0;
+        let mut syy = 0.0;
+        for (x, y) in self.xs.iter().zip(&self.ys) {
+            sxx += (x - mean_x) * (x - mean_x);
+            sxy += (x - mean_x) * (y - mean_y);
+            syy += (y - mean_y) * (y - mean_y);
+        }
+        if sxx <= 0.0 {
+            return;
+        }
+
+        self.shape = sxy / sxx;
+        self.intercept = mean_y - self.shape * mean_x;
+
+        let ss_res: f64 = self
+            .xs
+            .iter()
+            .zip(&self.ys)
+            .map(|(x, y)| (y - (self.intercept + self.shape * x)).powi(2))
+            .sum();
+        self.r_squared = if syy > 0.0 { 1.0 - ss_res / syy } else { 1.0 };
+        self.residual_std = if n > 2 {
+            (ss_res / (nf - 2.0)).sqrt()
+        } else {
+            0.0
+        };
+    }
+}
+
+impl PlotUi<'_> {
+    /// Add the samples, fitted line and confidence bounds of a [`WeibullPlot`].
+    ///
+    /// The fitted parameters are shown in the legend entry of the fitted line.
+    pub fn weibull(&mut self, weibull: &WeibullPlot) {
+        let mut weibull = weibull.clone();
+        if weibull.color == Color32::TRANSPARENT {
+            weibull.color = self.auto_color();
+        }
+        if !weibull.shape.is_finite() {
+            self.points(weibull.points());
+            return;
+        }
+        self.band(weibull.confidence_band());
+        let readout = weibull.parameter_readout();
+        self.line(
+            weibull
+                .fit_line()
+                .name(format!("{} fit ({readout})", weibull.name)),
+        );
+        self.points(weibull.points());
+    }
+}
+
+/// Inverse of the standard normal CDF (Abramowitz & Stegun 26.2.23, error < 4.5e-4).
+fn normal_quantile(p: f64) -> f64 {
+    let p = p.clamp(1e-12, 1.0 - 1e-12);
+    let (q, sign) = if p < 0.5 { (p, -1.0) } else { (1.0 - p, 1.0) };
+    let t = (-2.0 * q.ln()).sqrt();
+    let z = t
+        - (2.515_517 + 0.802_853 * t + 0.010_328 * t * t)
+            / (1.0 + 1.432_788 * t + 0.189_269 * t * t + 0.001_308 * t * t * t);
+    sign * z
+}
+
+#[test]
+fn test_weibull_fit() {
+    // Samples drawn exactly on the median ranks of a Weibull(β = 2, η = 100) distribution:
+    let n = 10;
+    let times: Vec<f64> = (1..=n)
+        .map(|i| {
+            let f = (i as f64 - 0.3) / (n as f64 + 0.4);
+            100.0 * (-(1.0 - f).ln()).powf(0.5)
+        })
+        .collect();
+    let weibull = WeibullPlot::new("test", &times);
+    assert!((weibull.shape() - 2.0).abs() < 1e-9);
+    assert!((weibull.scale() - 100.0).abs() < 1e-6);
+    assert!((weibull.r_squared() - 1.0).abs() < 1e-9);
+    assert!((normal_quantile(0.975) - 1.96).abs() < 1e-3);
+}
@@ -54,9 +54,8 @@ impl App for VarianceDemoApp {
             ui.label("Shaded area shows uncertainty (y_min..y_max); white line is the mean.");
 
             Plot::new("variance_band_plot").show(ui, |plot_ui| {
-                let band = Band::new()
-                    .with_color(Color32::from_rgb(64, 160, 255))
-                    .with_series(&self.xs, &self.y_min, &self.y_max);
+                let band = Band::from_columns(&self.xs, &self.y_min, &self.y_max)
+                    .with_color(Color32::from_rgb(64, 160, 255));
                 plot_ui.band(band);
 
                 let center_series: Vec<[f64; 2]> = self
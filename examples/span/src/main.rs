@@ -1,7 +1,7 @@
 #![allow(rustdoc::missing_crate_level_docs)]
 use eframe::egui;
-use egui::{Color32, Stroke};
-use egui_plot::{HSpan, Interval, Line, Plot, VSpan};
+use egui::{Align2, Color32, Stroke};
+use egui_plot::{FillPattern, HSpan, Interval, Line, Plot, VSpan};
 
 fn main() -> eframe::Result<()> {
     eframe::run_native(
@@ -68,13 +68,17 @@ impl eframe::App for MyApp {
 
                         plot_ui.add(
                             HSpan::new("below -0.6", Interval::new(f64::NEG_INFINITY, -0.6))
-                                .color(Color32::from_rgba_unmultiplied(220, 80, 80, self.alpha)),
+                                .color(Color32::from_rgba_unmultiplied(220, 80, 80, self.alpha))
+                                .label("warn zone")
+                                .label_align(Align2::RIGHT_TOP)
+                                .x_clip(Interval::new(f64::NEG_INFINITY, 6.0)),
                         );
 
                         plot_ui.add(
                             HSpan::new("above 0.8", Interval::new(0.8, f64::INFINITY))
                                 .color(Color32::from_rgba_unmultiplied(80, 80, 220, self.alpha))
-                                .outline(Stroke::new(1.0, Color32::from_rgb(80, 80, 220))),
+                                .outline(Stroke::new(1.0, Color32::from_rgb(80, 80, 220)))
+                                .pattern(FillPattern::hatch()),
                         );
                     }
 
@@ -82,7 +86,10 @@ impl eframe::App for MyApp {
                         plot_ui.add(
                             VSpan::new("x ∈ [2, 4]", Interval::new(2.0, 4.0))
                                 .color(Color32::from_rgba_unmultiplied(120, 200, 140, self.alpha))
-                                .outline(Stroke::new(1.0, Color32::from_rgb(120, 200, 140))),
+                                .outline(Stroke::new(1.0, Color32::from_rgb(120, 200, 140)))
+                                .label("measurement window")
+                                .label_align(Align2::LEFT_BOTTOM)
+                                .label_angle(-std::f32::consts::FRAC_PI_2),
                         );
 
                         plot_ui.add(
@@ -93,7 +100,8 @@ impl eframe::App for MyApp {
                         plot_ui.add(
                             VSpan::new("x ≥ 8", Interval::new(8.0, f64::INFINITY))
                                 .color(Color32::from_rgba_unmultiplied(150, 120, 220, self.alpha))
-                                .outline(Stroke::new(1.0, Color32::from_rgb(150, 120, 220))),
+                                .outline(Stroke::new(1.0, Color32::from_rgb(150, 120, 220)))
+                                .pattern(FillPattern::cross_hatch()),
                         );
                     }
                 });